@@ -0,0 +1,62 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+    Throughput,
+};
+use wavefront_obj::obj;
+
+
+/// The number of vertices along each side of the generated grid mesh. This
+/// yields an input of roughly 120 MB.
+const GRID_SIZE: usize = 1000;
+
+
+/// Generate a large grid mesh with texture coordinates and normals, so that
+/// the benchmark exercises every kind of token the parser sees in practice.
+fn generate_grid(size: usize) -> String {
+    let mut string = String::new();
+    for i in 0..size {
+        for j in 0..size {
+            string += &format!("v {}.125 {}.500 -{}.250\n", i, j, i + j);
+        }
+    }
+    for i in 0..size {
+        for j in 0..size {
+            string += &format!("vt 0.{:06} 0.{:06}\n", i, j);
+        }
+    }
+    for i in 0..size {
+        string += &format!("vn 0.{:06} 0.500000 0.250000\n", i);
+    }
+    for i in 0..(size - 1) {
+        for j in 0..(size - 1) {
+            let a = i * size + j + 1;
+            let b = a + 1;
+            let c = a + size + 1;
+            let d = a + size;
+            let n = i + 1;
+            string += &format!(
+                "f {}/{}/{} {}/{}/{} {}/{}/{} {}/{}/{}\n",
+                a, a, n, b, b, n, c, c, n, d, d, n
+            );
+        }
+    }
+
+    string
+}
+
+fn benchmark(c: &mut Criterion) {
+    let buffer = generate_grid(GRID_SIZE);
+    let mut group = c.benchmark_group("large");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(buffer.len() as u64));
+    group.bench_function("parse generated grid", |b| {
+        b.iter(|| obj::parse(black_box(&buffer)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -5,7 +5,7 @@ use std::io;
 use std::io::Read;
 use wavefront_obj::obj;
 
-const SAMPLE_DATA: &str = "sample_data/teapot.obj";
+const SAMPLE_DATA: &str = "assets/teapot.obj";
 
 
 fn main() -> io::Result<()> {
@@ -0,0 +1,345 @@
+//! Extract interleaved vertex and index buffers from objects for uploading
+//! to a graphics API.
+//!
+//! Each buffer comes with a [`VertexLayout`] describing the offset and format
+//! of every attribute inside a vertex, so the data can be bound without any
+//! manual offset arithmetic. When the crate is built with the `wgpu` feature,
+//! a layout converts directly into a `wgpu::VertexBufferLayout`.
+use crate::obj::{
+    Element,
+    Object,
+    VTNIndex,
+};
+use std::collections::HashMap;
+use std::mem;
+
+
+/// The data type of a vertex attribute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VertexFormat {
+    /// Two 32-bit floating point values.
+    Float32x2,
+    /// Three 32-bit floating point values.
+    Float32x3,
+}
+
+impl VertexFormat {
+    /// The number of components in the format.
+    pub fn components(self) -> usize {
+        match self {
+            VertexFormat::Float32x2 => 2,
+            VertexFormat::Float32x3 => 3,
+        }
+    }
+
+    /// The size of the format in bytes.
+    pub fn size(self) -> u64 {
+        (self.components() * mem::size_of::<f32>()) as u64
+    }
+}
+
+/// The meaning of a vertex attribute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VertexSemantic {
+    /// The position of the vertex, from a `v` statement.
+    Position,
+    /// The texture coordinates of the vertex, from a `vt` statement.
+    TextureCoordinate,
+    /// The normal vector of the vertex, from a `vn` statement.
+    Normal,
+}
+
+/// The description of one attribute inside an interleaved vertex.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VertexAttribute {
+    /// What the attribute holds.
+    pub semantic: VertexSemantic,
+    /// The data type of the attribute.
+    pub format: VertexFormat,
+    /// The offset of the attribute from the start of the vertex, in bytes.
+    pub offset: u64,
+    /// The location the attribute is bound to in a shader.
+    pub shader_location: u32,
+}
+
+/// The layout of an interleaved vertex buffer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VertexLayout {
+    /// The distance between the starts of consecutive vertices, in bytes.
+    pub array_stride: u64,
+    /// The attributes of each vertex, in the order they appear in memory.
+    pub attributes: Vec<VertexAttribute>,
+}
+
+impl VertexLayout {
+    /// Construct a layout that packs the attributes with the given semantics
+    /// and formats tightly, in order, at consecutive shader locations.
+    pub fn packed(attributes: &[(VertexSemantic, VertexFormat)]) -> VertexLayout {
+        let mut offset = 0;
+        let mut packed = vec![];
+        for (shader_location, &(semantic, format)) in attributes.iter().enumerate() {
+            packed.push(VertexAttribute {
+                semantic: semantic,
+                format: format,
+                offset: offset,
+                shader_location: shader_location as u32,
+            });
+            offset += format.size();
+        }
+
+        VertexLayout {
+            array_stride: offset,
+            attributes: packed,
+        }
+    }
+
+    /// Find the attribute with the given semantic.
+    pub fn attribute(&self, semantic: VertexSemantic) -> Option<&VertexAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.semantic == semantic)
+    }
+
+    /// Convert the attributes of the layout into `wgpu` vertex attributes.
+    ///
+    /// The result is the storage that [`VertexLayout::to_wgpu`] borrows.
+    #[cfg(feature = "wgpu")]
+    pub fn wgpu_attributes(&self) -> Vec<wgpu::VertexAttribute> {
+        self.attributes
+            .iter()
+            .map(|attribute| wgpu::VertexAttribute {
+                format: match attribute.format {
+                    VertexFormat::Float32x2 => wgpu::VertexFormat::Float32x2,
+                    VertexFormat::Float32x3 => wgpu::VertexFormat::Float32x3,
+                },
+                offset: attribute.offset,
+                shader_location: attribute.shader_location,
+            })
+            .collect()
+    }
+
+    /// Describe the layout as a per-vertex `wgpu::VertexBufferLayout`.
+    ///
+    /// The attributes are borrowed from the result of
+    /// [`VertexLayout::wgpu_attributes`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::buffer::VertexBuffer;
+    /// # use wavefront_obj::obj;
+    /// #
+    /// let object_set = obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1\n").unwrap();
+    /// let buffer = VertexBuffer::from_object(&object_set.objects[0]);
+    /// let attributes = buffer.layout.wgpu_attributes();
+    /// let layout = buffer.layout.to_wgpu(&attributes);
+    ///
+    /// assert_eq!(layout.array_stride, 24);
+    /// assert_eq!(layout.attributes[1].offset, 12);
+    /// ```
+    #[cfg(feature = "wgpu")]
+    pub fn to_wgpu<'a>(&self, attributes: &'a [wgpu::VertexAttribute]) -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: self.array_stride,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: attributes,
+        }
+    }
+}
+
+/// An interleaved vertex buffer and a triangle list index buffer holding the
+/// faces of an object.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VertexBuffer {
+    /// The interleaved vertex data, laid out as described by `layout`.
+    pub vertices: Vec<f32>,
+    /// The indices of the vertices of each triangle.
+    pub indices: Vec<u32>,
+    /// The layout of each vertex in `vertices`.
+    pub layout: VertexLayout,
+}
+
+impl VertexBuffer {
+    /// Extract the faces of an object into an interleaved vertex buffer.
+    ///
+    /// Every vertex has a position. Texture coordinates and normals are
+    /// included when every face of the object has them. Each distinct
+    /// combination of vertex, texture vertex, and normal vertex is stored
+    /// once. Points and lines are not included.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::buffer::{
+    /// #     VertexBuffer,
+    /// #     VertexSemantic,
+    /// # };
+    /// # use wavefront_obj::obj;
+    /// #
+    /// let object_set = obj::parse(
+    ///     "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nf 1/1 2/1 3/1\nf 1/1 3/1 4/1\n"
+    /// ).unwrap();
+    /// let buffer = VertexBuffer::from_object(&object_set.objects[0]);
+    ///
+    /// assert_eq!(buffer.vertex_count(), 4);
+    /// assert_eq!(buffer.indices, vec![0, 1, 2, 0, 2, 3]);
+    /// assert_eq!(buffer.layout.array_stride, 20);
+    /// assert_eq!(buffer.layout.attribute(VertexSemantic::TextureCoordinate).unwrap().offset, 12);
+    /// assert!(buffer.layout.attribute(VertexSemantic::Normal).is_none());
+    /// ```
+    pub fn from_object(object: &Object) -> VertexBuffer {
+        let faces: Vec<[VTNIndex; 3]> = object
+            .element_set
+            .iter()
+            .filter_map(|element| match *element {
+                Element::Face(vtn1, vtn2, vtn3) => Some([vtn1, vtn2, vtn3]),
+                _ => None,
+            })
+            .collect();
+        let has_texture_vertices = !faces.is_empty()
+            && faces
+                .iter()
+                .flatten()
+                .all(|vtn| vtn.texture_vertex_index().is_some());
+        let has_normal_vertices = !faces.is_empty()
+            && faces
+                .iter()
+                .flatten()
+                .all(|vtn| vtn.normal_vertex_index().is_some());
+
+        let mut semantics = vec![(VertexSemantic::Position, VertexFormat::Float32x3)];
+        if has_texture_vertices {
+            semantics.push((VertexSemantic::TextureCoordinate, VertexFormat::Float32x2));
+        }
+        if has_normal_vertices {
+            semantics.push((VertexSemantic::Normal, VertexFormat::Float32x3));
+        }
+        let layout = VertexLayout::packed(&semantics);
+        let components = layout.array_stride as usize / mem::size_of::<f32>();
+
+        let mut vertices = vec![];
+        let mut indices = vec![];
+        let mut emitted = HashMap::new();
+        for face in faces.iter() {
+            let data: Option<Vec<_>> = face.iter().map(|&vtn| object.get_vtn_data(vtn)).collect();
+            let data = match data {
+                Some(data) => data,
+                None => continue,
+            };
+            for (vtn, data) in face.iter().zip(data.iter()) {
+                let key = (
+                    vtn.vertex_index().0,
+                    vtn.texture_vertex_index().map(|index| index.0),
+                    vtn.normal_vertex_index().map(|index| index.0),
+                );
+                let index = *emitted.entry(key).or_insert_with(|| {
+                    let index = (vertices.len() / components) as u32;
+                    vertices.extend([data.vertex.x as f32, data.vertex.y as f32, data.vertex.z as f32]);
+                    if let (true, Some(texture_vertex)) = (has_texture_vertices, data.texture_vertex) {
+                        vertices.extend([texture_vertex.u as f32, texture_vertex.v as f32]);
+                    }
+                    if let (true, Some(normal_vertex)) = (has_normal_vertices, data.normal_vertex) {
+                        vertices.extend([
+                            normal_vertex.x as f32,
+                            normal_vertex.y as f32,
+                            normal_vertex.z as f32,
+                        ]);
+                    }
+
+                    index
+                });
+                indices.push(index);
+            }
+        }
+
+        VertexBuffer {
+            vertices: vertices,
+            indices: indices,
+            layout: layout,
+        }
+    }
+
+    /// The number of vertices in the vertex buffer.
+    pub fn vertex_count(&self) -> usize {
+        let stride = self.layout.array_stride as usize / mem::size_of::<f32>();
+
+        self.vertices.len().checked_div(stride).unwrap_or(0)
+    }
+}
+
+
+#[cfg(test)]
+mod buffer_tests {
+    use super::{
+        VertexBuffer,
+        VertexFormat,
+        VertexLayout,
+        VertexSemantic,
+    };
+    use crate::obj;
+
+
+    #[test]
+    fn test_packed_layout() {
+        let layout = VertexLayout::packed(&[
+            (VertexSemantic::Position, VertexFormat::Float32x3),
+            (VertexSemantic::TextureCoordinate, VertexFormat::Float32x2),
+            (VertexSemantic::Normal, VertexFormat::Float32x3),
+        ]);
+        let offsets: Vec<u64> = layout
+            .attributes
+            .iter()
+            .map(|attribute| attribute.offset)
+            .collect();
+        let locations: Vec<u32> = layout
+            .attributes
+            .iter()
+            .map(|attribute| attribute.shader_location)
+            .collect();
+
+        assert_eq!(layout.array_stride, 32);
+        assert_eq!(offsets, vec![0, 12, 20]);
+        assert_eq!(locations, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_from_object_interleaves_attributes() {
+        let object_set =
+            obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0.5 0.25\nvn 0 0 1\nf 1/1/1 2/1/1 3/1/1\n").unwrap();
+        let buffer = VertexBuffer::from_object(&object_set.objects[0]);
+
+        assert_eq!(buffer.layout.array_stride, 32);
+        assert_eq!(buffer.vertex_count(), 3);
+        assert_eq!(buffer.indices, vec![0, 1, 2]);
+        assert_eq!(
+            &buffer.vertices[8..16],
+            &[1.0, 0.0, 0.0, 0.5, 0.25, 0.0, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_from_object_splits_vertices_with_different_normals() {
+        let object_set = obj::parse(
+            "v 0 0 0\nv 1 0 0\nv 0 1 0\nv 0 0 1\nvn 0 0 1\nvn 1 0 0\nf 1//1 2//1 3//1\nf 1//2 3//2 4//2\n",
+        )
+        .unwrap();
+        let buffer = VertexBuffer::from_object(&object_set.objects[0]);
+
+        assert_eq!(buffer.vertex_count(), 6);
+        assert_eq!(buffer.indices, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_from_object_without_faces() {
+        let object_set = obj::parse("v 0 0 0\nv 1 0 0\nl 1 2\n").unwrap();
+        let buffer = VertexBuffer::from_object(&object_set.objects[0]);
+
+        assert_eq!(buffer.vertex_count(), 0);
+        assert!(buffer.indices.is_empty());
+        assert_eq!(buffer.layout.attributes.len(), 1);
+        assert_eq!(
+            buffer.layout.attribute(VertexSemantic::Position).unwrap().format,
+            VertexFormat::Float32x3
+        );
+    }
+}
@@ -0,0 +1,752 @@
+//! Conversions between the data model of this crate and the data model of
+//! the [`tobj`](https://docs.rs/tobj) crate.
+//!
+//! `tobj` stores each object as one or more models, one per material, each
+//! holding flat arrays of positions, texture coordinates, and normals with
+//! index arrays into them. Converting an object set into models splits each
+//! object by geometry, keeping only the vertices each geometry refers to.
+//! Converting models into an object set joins consecutive models with the
+//! same name into one object with one geometry per model. Groups and
+//! smoothing groups have no counterpart in `tobj`, so they are lost.
+//!
+//! This module covers `tobj` only. Conversions for the data model of the
+//! [`obj`](https://docs.rs/obj) crate are not part of it.
+use crate::mtl::{
+    Color,
+    IlluminationModel,
+    Material,
+    MaterialSet,
+};
+use crate::obj::{
+    Element,
+    ElementIndex,
+    Geometry,
+    Group,
+    GroupIndex,
+    NormalVertex,
+    Object,
+    ObjectSet,
+    ShapeEntry,
+    ShapeEntryIndex,
+    SmoothingGroup,
+    SmoothingGroupIndex,
+    TextureVertex,
+    VTNIndex,
+    Vertex,
+};
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::sync::Arc;
+
+
+/// Convert an object set into `tobj` models.
+///
+/// Each geometry of each object becomes one model named after the object.
+/// The material id of a model is the position of its material in
+/// `material_set`, or `None` if the geometry has no material or the material
+/// set does not contain it.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::compat;
+/// # use wavefront_obj::mtl;
+/// # use wavefront_obj::obj;
+/// #
+/// let object_set = obj::parse("o quad\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nusemtl red\nf 1 2 3 4\n").unwrap();
+/// let material_set = mtl::parse("newmtl blue\nnewmtl red\n").unwrap();
+/// let models = compat::to_tobj(&object_set, &material_set);
+///
+/// assert_eq!(models.len(), 1);
+/// assert_eq!(models[0].name, "quad");
+/// assert_eq!(models[0].mesh.positions.len(), 12);
+/// assert_eq!(models[0].mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+/// assert_eq!(models[0].mesh.material_id, Some(1));
+/// ```
+pub fn to_tobj(object_set: &ObjectSet, material_set: &MaterialSet) -> Vec<tobj::Model> {
+    let mut models = vec![];
+    for object in object_set.objects.iter() {
+        for geometry in object.geometry_set.iter() {
+            let material_id = geometry
+                .material_name
+                .as_ref()
+                .and_then(|material_name| material_set.position(material_name));
+            let mesh = mesh_from_geometry(object, geometry, material_id);
+            models.push(tobj::Model::new(mesh, object.name.clone()));
+        }
+    }
+
+    models
+}
+
+/// Build the mesh of a single geometry of an object.
+fn mesh_from_geometry(object: &Object, geometry: &Geometry, material_id: Option<usize>) -> tobj::Mesh {
+    let elements: Vec<Element> = geometry
+        .shapes
+        .iter()
+        .map(|&shape| object.element_set[object.shape_set[shape].element])
+        .collect();
+    let vtn_indices: Vec<VTNIndex> = elements.iter().flat_map(element_vtn_indices).collect();
+    let has_texture_vertices =
+        !vtn_indices.is_empty() && vtn_indices.iter().all(|vtn| vtn.texture_vertex_index().is_some());
+    let has_normal_vertices =
+        !vtn_indices.is_empty() && vtn_indices.iter().all(|vtn| vtn.normal_vertex_index().is_some());
+
+    let mut mesh = tobj::Mesh {
+        material_id: material_id,
+        ..tobj::Mesh::default()
+    };
+    let mut vertex_map = HashMap::new();
+    let mut texture_vertex_map = HashMap::new();
+    let mut normal_vertex_map = HashMap::new();
+    for vtn in vtn_indices.iter() {
+        let vertex_index = vtn.vertex_index();
+        let index = *vertex_map.entry(vertex_index.0).or_insert_with(|| {
+            let vertex = &object.vertex_set[vertex_index];
+            mesh.positions
+                .extend([vertex.x as f32, vertex.y as f32, vertex.z as f32]);
+            (mesh.positions.len() / 3 - 1) as u32
+        });
+        mesh.indices.push(index);
+
+        if let (true, Some(texture_vertex_index)) = (has_texture_vertices, vtn.texture_vertex_index()) {
+            let index = *texture_vertex_map
+                .entry(texture_vertex_index.0)
+                .or_insert_with(|| {
+                    let texture_vertex = &object.texture_vertex_set[texture_vertex_index];
+                    mesh.texcoords
+                        .extend([texture_vertex.u as f32, texture_vertex.v as f32]);
+                    (mesh.texcoords.len() / 2 - 1) as u32
+                });
+            mesh.texcoord_indices.push(index);
+        }
+
+        if let (true, Some(normal_vertex_index)) = (has_normal_vertices, vtn.normal_vertex_index()) {
+            let index = *normal_vertex_map.entry(normal_vertex_index.0).or_insert_with(|| {
+                let normal_vertex = &object.normal_vertex_set[normal_vertex_index];
+                mesh.normals.extend([
+                    normal_vertex.x as f32,
+                    normal_vertex.y as f32,
+                    normal_vertex.z as f32,
+                ]);
+                (mesh.normals.len() / 3 - 1) as u32
+            });
+            mesh.normal_indices.push(index);
+        }
+    }
+
+    // `tobj` treats a mesh without face arities as a list of triangles.
+    if elements
+        .iter()
+        .any(|element| !matches!(element, Element::Face(..)))
+    {
+        mesh.face_arities = elements
+            .iter()
+            .map(|element| element_vtn_indices(element).len() as u32)
+            .collect();
+    }
+
+    mesh
+}
+
+/// The VTN indices of an element in order.
+fn element_vtn_indices(element: &Element) -> Vec<VTNIndex> {
+    match *element {
+        Element::Point(vtn) => vec![vtn],
+        Element::Line(vtn1, vtn2) => vec![vtn1, vtn2],
+        Element::Face(vtn1, vtn2, vtn3) => vec![vtn1, vtn2, vtn3],
+    }
+}
+
+/// An error returned by [`from_tobj`] when the arrays of the mesh of a model
+/// do not fit together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MeshError {
+    /// The face arities add up to a different number of corners than there
+    /// are position indices. Without face arities, the number of position
+    /// indices is not a multiple of three.
+    FaceArities {
+        /// The name of the model.
+        model: String,
+        /// The number of corners of the faces.
+        corners: usize,
+        /// The number of position indices.
+        indices: usize,
+    },
+    /// There is not one texture coordinate or normal index for each position
+    /// index.
+    IndexCount {
+        /// The name of the model.
+        model: String,
+        /// The attribute the indices refer to.
+        attribute: &'static str,
+        /// The number of indices of the attribute.
+        count: usize,
+        /// The number of position indices.
+        indices: usize,
+    },
+    /// An index refers past the end of the positions, texture coordinates,
+    /// or normals.
+    IndexOutOfRange {
+        /// The name of the model.
+        model: String,
+        /// The attribute the index refers to.
+        attribute: &'static str,
+        /// The index.
+        index: u32,
+        /// The number of values of the attribute.
+        count: usize,
+    },
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MeshError::FaceArities {
+                ref model,
+                corners,
+                indices,
+            } => write!(
+                formatter,
+                "The faces of the mesh of `{}` have {} corners, but it has {} position indices.",
+                model, corners, indices
+            ),
+            MeshError::IndexCount {
+                ref model,
+                attribute,
+                count,
+                indices,
+            } => write!(
+                formatter,
+                "The mesh of `{}` has {} {} indices, but {} position indices.",
+                model, count, attribute, indices
+            ),
+            MeshError::IndexOutOfRange {
+                ref model,
+                attribute,
+                index,
+                count,
+            } => write!(
+                formatter,
+                "The mesh of `{}` refers to {} {}, but it has {}.",
+                model, attribute, index, count
+            ),
+        }
+    }
+}
+
+impl error::Error for MeshError {}
+
+/// Convert `tobj` models into an object set.
+///
+/// Consecutive models with the same name become one object, with one
+/// geometry per model. The material name of a geometry is the name of the
+/// material that the material id of its model refers to in `materials`.
+/// Faces with more than three vertices are triangulated as fans.
+///
+/// The index arrays of each mesh are checked before it is converted, and a
+/// [`MeshError`] is returned when they do not fit together.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::compat;
+/// #
+/// let mesh = tobj::Mesh {
+///     positions: vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+///     indices: vec![0, 1, 2],
+///     material_id: Some(0),
+///     ..tobj::Mesh::default()
+/// };
+/// let material = tobj::Material {
+///     name: String::from("red"),
+///     ..tobj::Material::default()
+/// };
+/// let models = vec![tobj::Model::new(mesh, String::from("triangle"))];
+/// let object_set = compat::from_tobj(&models, &[material]).unwrap();
+///
+/// assert_eq!(object_set.objects[0].name, "triangle");
+/// assert_eq!(object_set.objects[0].element_set.len(), 1);
+/// assert_eq!(object_set.objects[0].geometry_set.as_slice()[0].material_name.as_deref(), Some("red"));
+/// ```
+pub fn from_tobj(models: &[tobj::Model], materials: &[tobj::Material]) -> Result<ObjectSet, MeshError> {
+    let mut objects: Vec<Object> = vec![];
+    for model in models.iter() {
+        check_mesh(model)?;
+        let material_name = model
+            .mesh
+            .material_id
+            .and_then(|material_id| materials.get(material_id))
+            .map(|material| material.name.clone());
+        match objects.last_mut() {
+            Some(object) if object.name == model.name => {
+                append_mesh(object, &model.mesh, material_name);
+            }
+            _ => {
+                let mut object = Object {
+                    name: model.name.clone(),
+                    group_set: vec![Group::default()].into(),
+                    smoothing_group_set: vec![SmoothingGroup(0)].into(),
+                    ..Object::default()
+                };
+                append_mesh(&mut object, &model.mesh, material_name);
+                objects.push(object);
+            }
+        }
+    }
+
+    Ok(ObjectSet {
+        material_libraries: vec![],
+        external_references: vec![],
+        unknown_statements: vec![],
+        warnings: vec![],
+        objects: objects,
+    })
+}
+
+/// The indices of a mesh for an attribute with `count` values. Without
+/// separate index arrays, `tobj` indexes every attribute with the position
+/// indices.
+fn attribute_indices<'a>(mesh: &'a tobj::Mesh, count: usize, indices: &'a [u32]) -> Option<&'a [u32]> {
+    match (count, indices.is_empty()) {
+        (0, _) => None,
+        (_, true) => Some(&mesh.indices),
+        (_, false) => Some(indices),
+    }
+}
+
+/// Check that the index arrays of the mesh of a model fit together, so that
+/// it can be appended to an object.
+fn check_mesh(model: &tobj::Model) -> Result<(), MeshError> {
+    let mesh = &model.mesh;
+    let corners = if mesh.face_arities.is_empty() {
+        mesh.indices.len() - mesh.indices.len() % 3
+    } else {
+        mesh.face_arities.iter().map(|&arity| arity as usize).sum()
+    };
+    if corners != mesh.indices.len() {
+        return Err(MeshError::FaceArities {
+            model: model.name.clone(),
+            corners: corners,
+            indices: mesh.indices.len(),
+        });
+    }
+
+    let attributes = [
+        ("position", mesh.positions.len() / 3, Some(&mesh.indices[..])),
+        (
+            "texture coordinate",
+            mesh.texcoords.len() / 2,
+            attribute_indices(mesh, mesh.texcoords.len(), &mesh.texcoord_indices),
+        ),
+        (
+            "normal",
+            mesh.normals.len() / 3,
+            attribute_indices(mesh, mesh.normals.len(), &mesh.normal_indices),
+        ),
+    ];
+    for (attribute, count, indices) in attributes {
+        let indices = match indices {
+            Some(indices) => indices,
+            None => continue,
+        };
+        if indices.len() != mesh.indices.len() {
+            return Err(MeshError::IndexCount {
+                model: model.name.clone(),
+                attribute: attribute,
+                count: indices.len(),
+                indices: mesh.indices.len(),
+            });
+        }
+        if let Some(&index) = indices.iter().find(|&&index| index as usize >= count) {
+            return Err(MeshError::IndexOutOfRange {
+                model: model.name.clone(),
+                attribute: attribute,
+                index: index,
+                count: count,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Append the contents of a mesh to an object as a new geometry. The mesh
+/// must have passed [`check_mesh`].
+fn append_mesh(object: &mut Object, mesh: &tobj::Mesh, material_name: Option<String>) {
+    let vertex_offset = object.vertex_set.len();
+    let texture_vertex_offset = object.texture_vertex_set.len();
+    let normal_vertex_offset = object.normal_vertex_set.len();
+    object
+        .vertex_set
+        .extend(mesh.positions.chunks_exact(3).map(|position| Vertex {
+            x: position[0] as f64,
+            y: position[1] as f64,
+            z: position[2] as f64,
+            w: 1_f64,
+        }));
+    object
+        .texture_vertex_set
+        .extend(mesh.texcoords.chunks_exact(2).map(|texcoord| TextureVertex {
+            u: texcoord[0] as f64,
+            v: texcoord[1] as f64,
+            w: 0_f64,
+        }));
+    object
+        .normal_vertex_set
+        .extend(mesh.normals.chunks_exact(3).map(|normal| NormalVertex {
+            x: normal[0] as f64,
+            y: normal[1] as f64,
+            z: normal[2] as f64,
+        }));
+
+    let texture_indices = attribute_indices(mesh, mesh.texcoords.len(), &mesh.texcoord_indices);
+    let normal_indices = attribute_indices(mesh, mesh.normals.len(), &mesh.normal_indices);
+    let vtn_index = |i: usize| {
+        let vertex = vertex_offset + mesh.indices[i] as usize;
+        let texture_vertex = texture_indices.map(|indices| texture_vertex_offset + indices[i] as usize);
+        let normal_vertex = normal_indices.map(|indices| normal_vertex_offset + indices[i] as usize);
+        match (texture_vertex, normal_vertex) {
+            (None, None) => VTNIndex::V(vertex),
+            (Some(texture_vertex), None) => VTNIndex::VT(vertex, texture_vertex),
+            (None, Some(normal_vertex)) => VTNIndex::VN(vertex, normal_vertex),
+            (Some(texture_vertex), Some(normal_vertex)) => {
+                VTNIndex::VTN(vertex, texture_vertex, normal_vertex)
+            }
+        }
+    };
+
+    let arities: Vec<usize> = if mesh.face_arities.is_empty() {
+        vec![3; mesh.indices.len() / 3]
+    } else {
+        mesh.face_arities.iter().map(|&arity| arity as usize).collect()
+    };
+    let groups: Arc<[GroupIndex]> = vec![GroupIndex(0)].into();
+    let mut shapes = vec![];
+    let mut start = 0;
+    for arity in arities {
+        let mut elements = vec![];
+        match arity {
+            0 => {}
+            1 => elements.push(Element::Point(vtn_index(start))),
+            2 => elements.push(Element::Line(vtn_index(start), vtn_index(start + 1))),
+            _ => {
+                for corner in 1..arity - 1 {
+                    elements.push(Element::Face(
+                        vtn_index(start),
+                        vtn_index(start + corner),
+                        vtn_index(start + corner + 1),
+                    ));
+                }
+            }
+        }
+        for element in elements {
+            shapes.push(ShapeEntryIndex(object.shape_set.len()));
+            object.shape_set.push(ShapeEntry {
+                element: ElementIndex(object.element_set.len()),
+                groups: groups.clone(),
+                smoothing_group: SmoothingGroupIndex(0),
+            });
+            object.element_set.push(element);
+        }
+        start += arity;
+    }
+
+    object.geometry_set.push(Geometry {
+        material_name: material_name,
+        shapes: shapes,
+    });
+}
+
+impl From<&ObjectSet> for Vec<tobj::Model> {
+    fn from(object_set: &ObjectSet) -> Vec<tobj::Model> {
+        to_tobj(object_set, &MaterialSet::new(vec![]))
+    }
+}
+
+impl TryFrom<&[tobj::Model]> for ObjectSet {
+    type Error = MeshError;
+
+    fn try_from(models: &[tobj::Model]) -> Result<ObjectSet, MeshError> {
+        from_tobj(models, &[])
+    }
+}
+
+impl From<&Material> for tobj::Material {
+    fn from(material: &Material) -> tobj::Material {
+        let color = |color: &Color| [color.r as f32, color.g as f32, color.b as f32];
+
+        tobj::Material {
+            name: material.name.clone(),
+            ambient: Some(color(&material.color_ambient)),
+            diffuse: Some(color(&material.color_diffuse)),
+            specular: Some(color(&material.color_specular)),
+            shininess: Some(material.specular_exponent as f32),
+            dissolve: Some(material.dissolve as f32),
+            optical_density: material
+                .optical_density
+                .map(|optical_density| optical_density as f32),
+            ambient_texture: material.map_ambient.clone(),
+            diffuse_texture: material.map_diffuse.clone(),
+            specular_texture: material.map_specular.clone(),
+            normal_texture: material.map_normal.clone(),
+            shininess_texture: material.map_specular_exponent.clone(),
+            dissolve_texture: material.map_dissolve.clone(),
+            illumination_model: Some(material.illumination_model.number()),
+            unknown_param: HashMap::new(),
+        }
+    }
+}
+
+impl From<&tobj::Material> for Material {
+    fn from(material: &tobj::Material) -> Material {
+        let color = |color: Option<[f32; 3]>| match color {
+            Some([r, g, b]) => Color {
+                r: r as f64,
+                g: g as f64,
+                b: b as f64,
+            },
+            None => Color::zero(),
+        };
+        let illumination_model = match material.illumination_model {
+            Some(0) => IlluminationModel::Ambient,
+            Some(1) => IlluminationModel::AmbientDiffuse,
+            Some(2) | None => IlluminationModel::AmbientDiffuseSpecular,
+            Some(number) => IlluminationModel::Other(number),
+        };
+
+        Material {
+            name: material.name.clone(),
+            color_ambient: color(material.ambient),
+            color_diffuse: color(material.diffuse),
+            color_specular: color(material.specular),
+            specular_exponent: material.shininess.map_or(0_f64, |shininess| shininess as f64),
+            dissolve: material.dissolve.map_or(1_f64, |dissolve| dissolve as f64),
+            optical_density: material
+                .optical_density
+                .map(|optical_density| optical_density as f64),
+            illumination_model: illumination_model,
+            map_ambient: material.ambient_texture.clone(),
+            map_diffuse: material.diffuse_texture.clone(),
+            map_specular: material.specular_texture.clone(),
+            map_specular_exponent: material.shininess_texture.clone(),
+            map_normal: material.normal_texture.clone(),
+            map_dissolve: material.dissolve_texture.clone(),
+            ..Material::new()
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod compat_tests {
+    use super::{
+        from_tobj,
+        to_tobj,
+        MeshError,
+    };
+    use crate::mtl;
+    use crate::mtl::{
+        Material,
+        MaterialSet,
+    };
+    use crate::obj;
+    use crate::obj::{
+        Element,
+        ObjectSet,
+        VTNIndex,
+    };
+
+
+    #[test]
+    fn test_to_tobj_splits_objects_by_geometry() {
+        let object_set = obj::parse(
+            "o a\nv 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\nvn 0 0 1\nusemtl red\nf 1//1 2//1 3//1\nusemtl blue\nf 2//1 4//1 3//1\n",
+        )
+        .unwrap();
+        let models = to_tobj(&object_set, &MaterialSet::new(vec![]));
+
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[1].name, "a");
+        assert_eq!(
+            models[1].mesh.positions,
+            vec![1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]
+        );
+        assert_eq!(models[1].mesh.indices, vec![0, 1, 2]);
+        assert_eq!(models[1].mesh.normals, vec![0.0, 0.0, 1.0]);
+        assert_eq!(models[1].mesh.normal_indices, vec![0, 0, 0]);
+        assert!(models[1].mesh.face_arities.is_empty());
+        assert_eq!(models[1].mesh.material_id, None);
+    }
+
+    #[test]
+    fn test_to_tobj_records_arities_of_points_and_lines() {
+        let object_set = obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\np 1\nl 1 2\nf 1 2 3\n").unwrap();
+        let models: Vec<tobj::Model> = (&object_set).into();
+
+        assert_eq!(models[0].mesh.face_arities, vec![1, 2, 3]);
+        assert_eq!(models[0].mesh.indices, vec![0, 0, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_tobj_roundtrip() {
+        let object_set = obj::parse(
+            "o a\nv 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 1\nusemtl red\nf 1/1 2/2 3/1\no b\nv 0 0 1\nv 1 0 1\nl 4 5\n",
+        )
+        .unwrap();
+        let material_set = mtl::parse("newmtl red\nKd 1 0 0\n").unwrap();
+        let models = to_tobj(&object_set, &material_set);
+        let materials: Vec<tobj::Material> =
+            material_set.materials.iter().map(tobj::Material::from).collect();
+        let result = from_tobj(&models, &materials).unwrap();
+
+        assert_eq!(result.objects.len(), 2);
+        assert_eq!(result.objects[0].name, "a");
+        assert_eq!(
+            result.objects[0].element_set.as_slice(),
+            &[Element::Face(
+                VTNIndex::VT(0, 0),
+                VTNIndex::VT(1, 1),
+                VTNIndex::VT(2, 0)
+            )]
+        );
+        assert_eq!(
+            result.objects[0].geometry_set.as_slice()[0]
+                .material_name
+                .as_deref(),
+            Some("red")
+        );
+        assert_eq!(
+            result.objects[1].element_set.as_slice(),
+            &[Element::Line(VTNIndex::V(0), VTNIndex::V(1))]
+        );
+        assert_eq!(result.objects[1].vertex_set.as_slice()[1].x, 1.0);
+    }
+
+    #[test]
+    fn test_from_tobj_joins_models_with_the_same_name() {
+        let object_set =
+            obj::parse("o a\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl x\nf 1 2 3\nusemtl y\nf 3 2 1\n").unwrap();
+        let models = to_tobj(&object_set, &MaterialSet::new(vec![]));
+        let result = ObjectSet::try_from(&models[..]).unwrap();
+
+        assert_eq!(result.objects.len(), 1);
+        assert_eq!(result.objects[0].vertex_set.len(), 6);
+        assert_eq!(result.objects[0].geometry_set.len(), 2);
+        assert_eq!(
+            result.objects[0].element_set.as_slice()[1],
+            Element::Face(VTNIndex::V(3), VTNIndex::V(4), VTNIndex::V(5))
+        );
+    }
+
+    #[test]
+    fn test_from_tobj_triangulates_polygons() {
+        let mesh = tobj::Mesh {
+            positions: vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            indices: vec![0, 1, 2, 3],
+            face_arities: vec![4],
+            ..tobj::Mesh::default()
+        };
+        let result = from_tobj(&[tobj::Model::new(mesh, String::from("quad"))], &[]).unwrap();
+
+        assert_eq!(
+            result.objects[0].element_set.as_slice(),
+            &[
+                Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+                Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
+            ]
+        );
+        assert_eq!(result.objects[0].shape_set.len(), 2);
+    }
+
+    #[test]
+    fn test_from_tobj_rejects_mismatched_arrays() {
+        let triangle = || tobj::Mesh {
+            positions: vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            indices: vec![0, 1, 2],
+            ..tobj::Mesh::default()
+        };
+        let convert = |mesh: tobj::Mesh| from_tobj(&[tobj::Model::new(mesh, String::from("m"))], &[]);
+
+        assert!(convert(triangle()).is_ok());
+        assert_eq!(
+            convert(tobj::Mesh {
+                face_arities: vec![4],
+                ..triangle()
+            }),
+            Err(MeshError::FaceArities {
+                model: String::from("m"),
+                corners: 4,
+                indices: 3,
+            })
+        );
+        assert_eq!(
+            convert(tobj::Mesh {
+                indices: vec![0, 1, 2, 0],
+                ..triangle()
+            }),
+            Err(MeshError::FaceArities {
+                model: String::from("m"),
+                corners: 3,
+                indices: 4,
+            })
+        );
+        assert_eq!(
+            convert(tobj::Mesh {
+                texcoords: vec![0.0, 0.0],
+                texcoord_indices: vec![0, 0],
+                ..triangle()
+            }),
+            Err(MeshError::IndexCount {
+                model: String::from("m"),
+                attribute: "texture coordinate",
+                count: 2,
+                indices: 3,
+            })
+        );
+        assert_eq!(
+            convert(tobj::Mesh {
+                normals: vec![0.0, 0.0, 1.0],
+                ..triangle()
+            }),
+            Err(MeshError::IndexOutOfRange {
+                model: String::from("m"),
+                attribute: "normal",
+                index: 1,
+                count: 1,
+            })
+        );
+        assert_eq!(
+            convert(tobj::Mesh {
+                indices: vec![0, 1, 3],
+                ..triangle()
+            }),
+            Err(MeshError::IndexOutOfRange {
+                model: String::from("m"),
+                attribute: "position",
+                index: 3,
+                count: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_material_conversion() {
+        let material_set =
+            mtl::parse("newmtl brick\nKd 0.5 0.25 0.0\nNs 10\nd 0.5\nillum 1\nmap_Kd brick.png\n").unwrap();
+        let material = &material_set.materials[0];
+        let converted = tobj::Material::from(material);
+
+        assert_eq!(converted.diffuse, Some([0.5, 0.25, 0.0]));
+        assert_eq!(converted.illumination_model, Some(1));
+        assert_eq!(converted.diffuse_texture.as_deref(), Some("brick.png"));
+
+        let result = Material::from(&converted);
+        assert_eq!(result.color_diffuse, material.color_diffuse);
+        assert_eq!(result.specular_exponent, 10.0);
+        assert_eq!(result.dissolve, 0.5);
+        assert_eq!(result.illumination_model, material.illumination_model);
+        assert_eq!(result.map_diffuse, material.map_diffuse);
+    }
+}
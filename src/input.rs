@@ -0,0 +1,133 @@
+use std::borrow::Cow;
+use std::io;
+use std::io::Read;
+use std::str;
+
+
+/// The magic number at the start of every gzip stream.
+const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+
+/// The location of the first byte showing that an input is binary data rather
+/// than text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct NotText {
+    /// The byte offset of the offending byte.
+    pub offset: usize,
+    /// The line number of the offending byte.
+    pub line_number: usize,
+    /// Whether the offending byte is a NUL byte, rather than the start of an
+    /// invalid UTF-8 sequence.
+    pub nul: bool,
+}
+
+impl NotText {
+    /// Find the first NUL byte of a text, if it has one.
+    pub(crate) fn find_nul(text: &str) -> Option<NotText> {
+        text.find('\0').map(|offset| NotText {
+            offset: offset,
+            line_number: line_number(text.as_bytes(), offset),
+            nul: true,
+        })
+    }
+
+    /// Locate the invalid UTF-8 sequence of a byte buffer.
+    fn invalid_utf8(bytes: &[u8], error: str::Utf8Error) -> NotText {
+        let offset = error.valid_up_to();
+
+        NotText {
+            offset: offset,
+            line_number: line_number(bytes, offset),
+            nul: false,
+        }
+    }
+
+    /// A message describing why the input is not a text file.
+    pub(crate) fn message(&self) -> String {
+        let reason = if self.nul { "a NUL byte" } else { "invalid UTF-8" };
+
+        format!(
+            "The input is not a text file: it contains {} at byte offset {}.",
+            reason, self.offset
+        )
+    }
+}
+
+/// An error decoding the contents of a file into a string.
+#[derive(Debug)]
+pub(crate) enum DecodeError {
+    /// The contents could not be read or decompressed.
+    Io(io::Error),
+    /// The contents are binary data rather than text.
+    NotText(NotText),
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(error: io::Error) -> DecodeError {
+        DecodeError::Io(error)
+    }
+}
+
+/// The line number of the byte at `offset`, counting `"\n"`, `"\r\n"`, and
+/// a lone `"\r"` as line terminators the same way the lexer does.
+fn line_number(bytes: &[u8], offset: usize) -> usize {
+    let terminators = bytes[..offset]
+        .iter()
+        .enumerate()
+        .filter(|&(i, &ch)| ch == b'\n' || (ch == b'\r' && bytes.get(i + 1) != Some(&b'\n')))
+        .count();
+
+    terminators + 1
+}
+
+/// Determine whether a byte buffer holds a gzip compressed stream.
+#[inline]
+pub(crate) fn is_gzip(bytes: &[u8]) -> bool {
+    bytes.starts_with(&GZIP_MAGIC_NUMBER)
+}
+
+/// Convert the contents of a file into a string, decompressing it first if
+/// it is a gzip stream and the `flate2` feature is enabled.
+pub(crate) fn decode(bytes: Vec<u8>) -> Result<String, DecodeError> {
+    if is_gzip(&bytes) {
+        return decompress(&bytes[..]);
+    }
+
+    String::from_utf8(bytes)
+        .map_err(|error| DecodeError::NotText(NotText::invalid_utf8(error.as_bytes(), error.utf8_error())))
+}
+
+/// Convert a byte slice into a string without copying it, unless it is a
+/// gzip stream that has to be decompressed first.
+pub(crate) fn decode_slice(bytes: &[u8]) -> Result<Cow<'_, str>, DecodeError> {
+    if is_gzip(bytes) {
+        return decompress(bytes).map(Cow::Owned);
+    }
+
+    str::from_utf8(bytes)
+        .map(Cow::Borrowed)
+        .map_err(|error| DecodeError::NotText(NotText::invalid_utf8(bytes, error)))
+}
+
+/// Decompress a gzip stream into a string.
+#[cfg(feature = "flate2")]
+pub(crate) fn decompress<R: Read>(reader: R) -> Result<String, DecodeError> {
+    let mut decoder = flate2::read::MultiGzDecoder::new(reader);
+    let mut buffer = vec![];
+    decoder.read_to_end(&mut buffer)?;
+
+    String::from_utf8(buffer)
+        .map_err(|error| DecodeError::NotText(NotText::invalid_utf8(error.as_bytes(), error.utf8_error())))
+}
+
+/// Decompress a gzip stream into a string.
+///
+/// Without the `flate2` feature the crate cannot decompress anything, so this
+/// function always returns an error.
+#[cfg(not(feature = "flate2"))]
+pub(crate) fn decompress<R: Read>(_reader: R) -> Result<String, DecodeError> {
+    Err(DecodeError::Io(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "The input is gzip compressed, but gzip support requires the `flate2` feature.",
+    )))
+}
@@ -1,20 +1,32 @@
+use std::ops;
 use std::str;
 
 
 /// A lexer tokenizes an input character stream.
-#[derive(Clone)]
+///
+/// The lexer makes a single pass over the input. Each token is a subslice of
+/// the input stream, so tokenizing never copies or rescans the input.
 pub struct Lexer<'a> {
-    /// The current line position in the token stream.
-    current_line_number: usize,
     /// The cursor position in the character stream.
     stream_position: usize,
     /// The input stream.
     stream: &'a [u8],
 }
 
+/// Determine whether a byte separates the tokens of a line.
+///
+/// Every ASCII whitespace byte other than a line terminator separates tokens,
+/// i.e. spaces, tabs, vertical tabs, and form feeds. A backslash continues a
+/// statement onto the next line, so it separates tokens as well.
 #[inline]
 fn is_whitespace(ch: u8) -> bool {
-    ch == b' ' || ch == b'\\' || ch == b'\t'
+    matches!(ch, b' ' | b'\t' | b'\x0B' | b'\x0C' | b'\\')
+}
+
+/// Determine whether a character separates the tokens of a line.
+#[inline]
+pub fn is_separator(ch: char) -> bool {
+    ch.is_ascii() && is_whitespace(ch as u8)
 }
 
 #[inline]
@@ -22,16 +34,123 @@ fn is_newline(ch: u8) -> bool {
     ch == b'\n' || ch == b'\r'
 }
 
+/// Normalize a line terminator token to `"\n"`.
+///
+/// The lexer returns each line terminator as a single token, whether the line
+/// ends with `"\n"`, `"\r\n"`, or a lone `"\r"`. Every other token is
+/// returned unchanged.
+#[inline]
+pub fn normalize_line_ending(token: &str) -> &str {
+    match token {
+        "\r\n" | "\r" => "\n",
+        _ => token,
+    }
+}
+
+/// The bytes that terminate a token.
+const TOKEN_DELIMITERS: [u8; 8] = [b' ', b'\\', b'\t', b'\x0B', b'\x0C', b'\n', b'\r', b'#'];
+
+/// The bytes that terminate a comment.
+const NEWLINES: [u8; 2] = [b'\n', b'\r'];
+
+/// A word with every byte set to one.
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+
+/// A word with the high bit of every byte set.
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Compute a mask whose lowest set bit is the high bit of the first byte in
+/// `word` equal to `needle`, or zero if no byte of `word` equals `needle`.
+///
+/// Bits above the first match may be set spuriously, so only the lowest set
+/// bit of the mask is meaningful.
+#[inline(always)]
+fn match_mask(word: u64, needle: u8) -> u64 {
+    let bytes = word ^ (LOW_BITS * needle as u64);
+
+    bytes.wrapping_sub(LOW_BITS) & !bytes & HIGH_BITS
+}
+
+/// Find the position of the first byte in `stream` that is one of `needles`,
+/// or the length of the stream if there is none.
+///
+/// The stream is searched eight bytes at a time, so that long tokens and
+/// comments are skipped in bulk instead of byte by byte.
 #[inline]
-fn is_whitespace_or_newline(ch: u8) -> bool {
-    is_whitespace(ch) || is_newline(ch)
+fn find_any<const N: usize>(stream: &[u8], needles: [u8; N]) -> usize {
+    let mut position = 0;
+    while position + 8 <= stream.len() {
+        let mut word_bytes = [0_u8; 8];
+        word_bytes.copy_from_slice(&stream[position..(position + 8)]);
+        let word = u64::from_le_bytes(word_bytes);
+        let mask = needles
+            .iter()
+            .fold(0, |mask, &needle| mask | match_mask(word, needle));
+        if mask != 0 {
+            return position + (mask.trailing_zeros() / 8) as usize;
+        }
+        position += 8;
+    }
+
+    stream[position..]
+        .iter()
+        .position(|ch| needles.contains(ch))
+        .map_or(stream.len(), |offset| position + offset)
+}
+
+/// Find the position of the first line terminator byte, `'\n'` or `'\r'`,
+/// in a stream, or the length of the stream if there is none.
+///
+/// A `"\r\n"` pair ends a single line, so the `'\n'` after a `'\r'`
+/// terminates a blank line when a stream is split with this function.
+pub fn find_line_end(stream: &[u8]) -> usize {
+    find_any(stream, NEWLINES)
+}
+
+/// Find the first token of a line without its terminator, or `None` if the
+/// line is blank or a comment.
+pub fn first_token(line: &[u8]) -> Option<&[u8]> {
+    let start = line.iter().position(|&ch| !is_whitespace(ch))?;
+    let length = find_any(&line[start..], TOKEN_DELIMITERS);
+    if length == 0 {
+        None
+    } else {
+        Some(&line[start..(start + length)])
+    }
+}
+
+/// Find the first line of a stream that is longer than `limit` bytes, not
+/// counting its line terminator.
+///
+/// This function returns the line number and the byte range of the line. The
+/// stream is searched in a single pass, so the search takes linear time even
+/// when the stream is one gigantic line.
+pub fn find_long_line(stream: &str, limit: usize) -> Option<(usize, ops::Range<usize>)> {
+    let bytes = stream.as_bytes();
+    let mut start = 0;
+    let mut line_number = 1;
+    loop {
+        let length = find_any(&bytes[start..], NEWLINES);
+        if length > limit {
+            return Some((line_number, start..(start + length)));
+        }
+
+        let mut end = start + length;
+        if end == bytes.len() {
+            return None;
+        }
+        if bytes[end] == b'\r' && bytes.get(end + 1) == Some(&b'\n') {
+            end += 1;
+        }
+        start = end + 1;
+        line_number += 1;
+    }
 }
 
 impl<'a> Lexer<'a> {
     /// Construct a new tokenizer.
     pub fn new(stream: &'a str) -> Lexer<'a> {
         Lexer {
-            current_line_number: 1,
             stream_position: 0,
             stream: stream.as_bytes(),
         }
@@ -44,46 +163,32 @@ impl<'a> Lexer<'a> {
         self.stream.get(self.stream_position)
     }
 
-    /// Advance the lexer by one character in the byte stream.
-    fn advance(&mut self) {
-        match self.peek() {
-            Some(&ch) if is_newline(ch) => {
-                self.current_line_number += 1;
-            }
-            _ => {}
-        }
-        self.stream_position += 1;
-    }
-
     /// Given a boolean predicate that operates on bytes, advance through the
     /// stream while the predicate is still satisfied.
     ///
     /// This function returns the number of characters skipped.
+    #[inline]
     fn skip_while<P: Fn(u8) -> bool>(&mut self, predicate: P) -> usize {
-        let mut skipped = 0;
-        loop {
-            match self.peek() {
-                Some(&ch) if predicate(ch) => {
-                    self.advance();
-                    skipped += 1;
-                }
-                Some(_) | None => {
-                    break;
-                }
-            }
-        }
+        let remaining = &self.stream[self.stream_position..];
+        let skipped = remaining
+            .iter()
+            .position(|&ch| !predicate(ch))
+            .unwrap_or(remaining.len());
+        self.stream_position += skipped;
 
         skipped
     }
 
-    /// Given a predicate that operates on bytes, advance through stream while
-    /// the predicates is not satisfied.
+    /// Advance through the stream until reaching one of the bytes in
+    /// `needles`, or the end of the stream.
     ///
-    /// That is, advance one character at a time unless the predicate is
-    /// satisfied, and then stop. This function returns the number of characters
-    /// skipped.
-    fn skip_unless<P: Fn(u8) -> bool>(&mut self, not_predicate: P) -> usize {
-        self.skip_while(|ch| !not_predicate(ch))
+    /// This function returns the number of characters skipped.
+    #[inline]
+    fn skip_until<const N: usize>(&mut self, needles: [u8; N]) -> usize {
+        let skipped = find_any(&self.stream[self.stream_position..], needles);
+        self.stream_position += skipped;
+
+        skipped
     }
 
     /// Consume a comment line without returning it.
@@ -92,7 +197,7 @@ impl<'a> Lexer<'a> {
     /// of the comment line.
     fn skip_comment(&mut self) -> usize {
         match self.peek() {
-            Some(b'#') => self.skip_unless(is_newline),
+            Some(b'#') => self.skip_until(NEWLINES),
             _ => 0,
         }
     }
@@ -114,12 +219,19 @@ impl<'a> Lexer<'a> {
         let start_position = self.stream_position;
 
         match self.peek() {
+            Some(&b'\r') => {
+                self.stream_position += 1;
+                if let Some(&b'\n') = self.peek() {
+                    self.stream_position += 1;
+                }
+                self.stream.get(start_position..self.stream_position)
+            }
             Some(&ch) if is_newline(ch) => {
-                self.advance();
+                self.stream_position += 1;
                 self.stream.get(start_position..self.stream_position)
             }
             Some(_) => {
-                let skipped = self.skip_unless(|ch| is_whitespace_or_newline(ch) || ch == b'#');
+                let skipped = self.skip_until(TOKEN_DELIMITERS);
                 if skipped > 0 {
                     self.stream.get(start_position..self.stream_position)
                 } else {
@@ -141,7 +253,9 @@ impl<'a> Iterator for Lexer<'a> {
 
 /// A lexical analyzer that caches tokens from the tokenizer to supports
 /// peeking into the steam without advancing the stream, and lookahead.
-#[derive(Clone)]
+///
+/// At most one token is cached, so a token is scanned exactly once no matter
+/// how many times it is peeked.
 pub struct PeekableLexer<'a> {
     /// The tokenizer for the input byte stream.
     inner: Lexer<'a>,
@@ -202,8 +316,13 @@ impl<'a> Iterator for PeekableLexer<'a> {
 #[cfg(test)]
 mod tests {
     use super::{
+        find_any,
+        find_long_line,
+        normalize_line_ending,
         Lexer,
         PeekableLexer,
+        NEWLINES,
+        TOKEN_DELIMITERS,
     };
     use std::slice;
 
@@ -218,7 +337,7 @@ mod tests {
     }
 
     impl Test {
-        fn iter(&self) -> TestIter {
+        fn iter(&self) -> TestIter<'_> {
             TestIter {
                 inner: self.test_cases.iter(),
             }
@@ -452,4 +571,78 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_find_any_matches_bytewise_search() {
+        let data = "vertex_normal_name_without_delimiters 0.000000E+00\t# comment\\\r\nusemtl x";
+        let bytes = data.as_bytes();
+        let bytewise_search = |stream: &[u8], needles: &[u8]| -> usize {
+            stream
+                .iter()
+                .position(|ch| needles.contains(ch))
+                .unwrap_or(stream.len())
+        };
+        for start in 0..bytes.len() {
+            let stream = &bytes[start..];
+
+            assert_eq!(
+                find_any(stream, TOKEN_DELIMITERS),
+                bytewise_search(stream, &TOKEN_DELIMITERS)
+            );
+            assert_eq!(find_any(stream, NEWLINES), bytewise_search(stream, &NEWLINES));
+        }
+    }
+
+    #[test]
+    fn test_find_any_ignores_bytes_above_ascii() {
+        let data = "é€ñøß漢字 x";
+        let expected = data.find(' ').unwrap();
+
+        assert_eq!(find_any(data.as_bytes(), TOKEN_DELIMITERS), expected);
+    }
+
+    #[test]
+    fn test_lexer_line_endings() {
+        let expected = vec!["v", "1.0", "2.0", "3.0", "\n", "p", "1", "\n"];
+        for line_ending in ["\n", "\r\n", "\r"] {
+            let data = format!("v 1.0 2.0 3.0{}p 1 # comment{}", line_ending, line_ending);
+            let lexer = PeekableLexer::new(Lexer::new(&data));
+            let result = lexer.map(normalize_line_ending).collect::<Vec<&str>>();
+
+            assert_eq!(result, expected, "line_ending = {:?}", line_ending);
+        }
+    }
+
+    #[test]
+    fn test_lexer_mixed_line_endings() {
+        let data = "a\r\n\r\rb\n\r\nc\r";
+        let lexer = PeekableLexer::new(Lexer::new(data));
+        let result = lexer.collect::<Vec<&str>>();
+
+        assert_eq!(
+            result,
+            vec!["a", "\r\n", "\r", "\r", "b", "\n", "\r\n", "c", "\r"]
+        );
+    }
+
+    #[test]
+    fn test_lexer_ascii_whitespace_separates_tokens() {
+        let data = "v\t1.0\t\t2.0 \x0C3.0\x0B\t# comment\nvn\x0C0.0\x0B0.0\t1.0\t\n";
+        let lexer = PeekableLexer::new(Lexer::new(data));
+        let result = lexer.collect::<Vec<&str>>();
+        let expected = vec!["v", "1.0", "2.0", "3.0", "\n", "vn", "0.0", "0.0", "1.0", "\n"];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_find_long_line() {
+        let data = "v 0 0 0\r\nvn 0 0 1\rf 1//1 1//1 1//1\n\np 1";
+
+        assert_eq!(find_long_line(data, 16), None);
+        assert_eq!(find_long_line(data, 15), Some((3, 18..34)));
+        assert_eq!(find_long_line(data, 7), Some((2, 9..17)));
+        assert_eq!(find_long_line("", 0), None);
+        assert_eq!(find_long_line("\n\nabc", 2), Some((3, 2..5)));
+    }
 }
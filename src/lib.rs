@@ -1,6 +1,15 @@
 #![doc = include_str!("../README.md")]
 #![allow(clippy::redundant_field_names)]
+mod input;
 mod lexer;
 
+pub mod buffer;
+#[cfg(feature = "tobj")]
+pub mod compat;
 pub mod mtl;
+pub mod number;
 pub mod obj;
+pub mod scene;
+pub mod testing;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
@@ -1,9 +1,28 @@
+use crate::input;
 use crate::lexer::{
+    is_separator,
+    normalize_line_ending,
     Lexer,
     PeekableLexer,
 };
+use crate::number;
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    HashSet,
+};
 use std::error;
 use std::fmt;
+use std::io;
+use std::io::Write;
+use std::ops;
+use std::sync::{
+    Mutex,
+    PoisonError,
+};
+
+pub use crate::number::NonFinitePolicy;
+pub use crate::obj::Compositor;
 
 
 /// Parse a material library file from a string.
@@ -39,28 +58,33 @@ use std::fmt;
 ///     bump height.png
 /// ");
 /// // let expected = ...;
-/// # let expected = MaterialSet {
-/// #     materials: vec![Material {
-/// #         name: String::from("my_material"),
-/// #         color_ambient: Color { r: 0.0435, g: 0.0435, b: 0.0435 },
-/// #         color_diffuse: Color { r: 0.1086, g: 0.1086, b: 0.1086 },
-/// #         color_specular: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
-/// #         color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-/// #         specular_exponent: 10.0000,
-/// #         dissolve: 0.6600,
-/// #         optical_density: Some(1.19713),
-/// #         illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-/// #         map_ambient: Some(String::from("ambient.jpg")),
-/// #         map_diffuse: Some(String::from("diffuse.jpg")),
-/// #         map_specular: Some(String::from("specular.jpg")),
-/// #         map_emissive: Some(String::from("emissive.jpg")),
-/// #         map_specular_exponent: Some(String::from("specular_exponent.jpg")),
-/// #         map_bump: Some(String::from("height.png")),
-/// #         map_displacement: Some(String::from("displacement.png")),
-/// #         map_dissolve: Some(String::from("dissolve.png")),
-/// #         map_decal: Some(String::from("decal.jpg")),
-/// #     }]
-/// # };
+/// # let expected = MaterialSet::new(vec![Material {
+/// #     name: String::from("my_material"),
+/// #     color_ambient: Color { r: 0.0435, g: 0.0435, b: 0.0435 },
+/// #     color_diffuse: Color { r: 0.1086, g: 0.1086, b: 0.1086 },
+/// #     color_specular: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
+/// #     color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+/// #     emissive_strength: None,
+/// #     specular_exponent: 10.0000,
+/// #     dissolve: 0.6600,
+/// #     optical_density: Some(1.19713),
+/// #     illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+/// #     map_ambient: Some(String::from("ambient.jpg")),
+/// #     map_diffuse: Some(String::from("diffuse.jpg")),
+/// #     map_specular: Some(String::from("specular.jpg")),
+/// #     map_emissive: Some(String::from("emissive.jpg")),
+/// #     map_specular_exponent: Some(String::from("specular_exponent.jpg")),
+/// #     map_bump: Some(String::from("height.png")),
+/// #     map_normal: None,
+/// #     map_displacement: Some(String::from("displacement.png")),
+/// #     map_dissolve: Some(String::from("dissolve.png")),
+/// #     map_decal: Some(String::from("decal.jpg")),
+/// #     anti_alias_maps: false,
+/// #     transparency: None,
+/// #     extras: Default::default(),
+/// #     line_number: 2,
+/// #     end_line_number: 18,
+/// # }]);
 /// let result = mtl::parse(&mtl_file);
 /// assert!(result.is_ok());
 ///
@@ -71,6 +95,31 @@ pub fn parse<T: AsRef<str>>(input: T) -> Result<MaterialSet, ParseError> {
     Parser::new(input.as_ref()).parse_mtlset()
 }
 
+/// Parse a material library file from a string with non-default parser options.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl;
+/// # use wavefront_obj::mtl::{
+/// #     DissolveConflict,
+/// #     ParserOptions,
+/// # };
+/// #
+/// let mtl_file = "newmtl glass\nd 0.6\nTr 0.75\n";
+/// let options = ParserOptions {
+///     dissolve_conflict: DissolveConflict::PreferTransparency,
+///     ..ParserOptions::default()
+/// };
+/// let result = mtl::parse_with(mtl_file, options).unwrap();
+///
+/// assert_eq!(result.materials[0].dissolve, 0.25);
+/// assert_eq!(result.materials[0].transparency, Some(0.75));
+/// ```
+pub fn parse_with<T: AsRef<str>>(input: T, options: ParserOptions) -> Result<MaterialSet, ParseError> {
+    Parser::with_options(input.as_ref(), options).parse_mtlset()
+}
+
 /// A representation of a material's color attributes, such as
 /// the ambient color, diffuse color, specular color, and the emissive color.
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
@@ -83,15 +132,143 @@ pub struct Color {
     pub b: f64,
 }
 
+/// Convert one sRGB encoded color channel to linear light.
+fn srgb_to_linear(channel: f64) -> f64 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert one linear light color channel to sRGB encoding.
+fn linear_to_srgb(channel: f64) -> f64 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 impl Color {
     #[inline]
-    const fn zero() -> Color {
+    pub(crate) const fn zero() -> Color {
         Color {
             r: 0_f64,
             g: 0_f64,
             b: 0_f64,
         }
     }
+
+    /// Construct a color from an array of its red, green, and blue components.
+    pub const fn from_array(array: [f64; 3]) -> Color {
+        Color {
+            r: array[0],
+            g: array[1],
+            b: array[2],
+        }
+    }
+
+    /// Convert a color into an array of its red, green, and blue components.
+    pub const fn to_array(&self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// Parse a color from a hexadecimal string of the form `#rrggbb`.
+    ///
+    /// The leading `#` is optional. Each component is scaled to the range
+    /// `[0, 1]`. The function returns `None` if the string is not a six digit
+    /// hexadecimal color.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl::Color;
+    /// #
+    /// let color = Color::from_hex("#ff8000").unwrap();
+    ///
+    /// assert_eq!(color.r, 1.0);
+    /// assert_eq!(color.g, 128.0 / 255.0);
+    /// assert_eq!(color.b, 0.0);
+    /// assert!(Color::from_hex("#ff80").is_none());
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |start: usize| -> Option<f64> {
+            u8::from_str_radix(&digits[start..(start + 2)], 16)
+                .ok()
+                .map(|value| value as f64 / 255_f64)
+        };
+
+        Some(Color {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        })
+    }
+
+    /// Format a color as a hexadecimal string of the form `#rrggbb`.
+    ///
+    /// The components are clamped to the range `[0, 1]` first.
+    pub fn to_hex(&self) -> String {
+        let color = self.clamp();
+        let channel = |value: f64| (value * 255_f64).round() as u8;
+
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            channel(color.r),
+            channel(color.g),
+            channel(color.b)
+        )
+    }
+
+    /// Clamp each component of a color to the range `[0, 1]`.
+    pub fn clamp(&self) -> Color {
+        Color {
+            r: self.r.clamp(0_f64, 1_f64),
+            g: self.g.clamp(0_f64, 1_f64),
+            b: self.b.clamp(0_f64, 1_f64),
+        }
+    }
+
+    /// Convert a color from sRGB encoding to linear light.
+    ///
+    /// Colors in MTL files are conventionally sRGB encoded, while lighting
+    /// calculations expect linear colors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl::Color;
+    /// #
+    /// let color = Color { r: 0.5, g: 0.0, b: 1.0 };
+    /// let linear = color.to_linear();
+    ///
+    /// assert!((linear.r - 0.214041).abs() < 1e-6);
+    /// assert_eq!(linear.g, 0.0);
+    /// assert_eq!(linear.b, 1.0);
+    /// ```
+    pub fn to_linear(&self) -> Color {
+        Color {
+            r: srgb_to_linear(self.r),
+            g: srgb_to_linear(self.g),
+            b: srgb_to_linear(self.b),
+        }
+    }
+
+    /// Convert a color from linear light to sRGB encoding.
+    ///
+    /// This is the inverse of [`Color::to_linear`].
+    pub fn to_srgb(&self) -> Color {
+        Color {
+            r: linear_to_srgb(self.r),
+            g: linear_to_srgb(self.g),
+            b: linear_to_srgb(self.b),
+        }
+    }
 }
 
 /// The illumination model describes how to illuminate an object with a given
@@ -108,6 +285,30 @@ pub enum IlluminationModel {
     AmbientDiffuse,
     /// Apply a Phone shading model to a material.
     AmbientDiffuseSpecular,
+    /// Any other illumination model. The crate does not interpret these models,
+    /// but keeps the model number so it survives round trips.
+    Other(u8),
+}
+
+impl IlluminationModel {
+    /// The number of the illumination model in an `illum` statement.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl::IlluminationModel;
+    /// #
+    /// assert_eq!(IlluminationModel::AmbientDiffuseSpecular.number(), 2);
+    /// assert_eq!(IlluminationModel::Other(7).number(), 7);
+    /// ```
+    pub fn number(&self) -> u8 {
+        match *self {
+            IlluminationModel::Ambient => 0,
+            IlluminationModel::AmbientDiffuse => 1,
+            IlluminationModel::AmbientDiffuseSpecular => 2,
+            IlluminationModel::Other(number) => number,
+        }
+    }
 }
 
 /// A material description associated with an object in a scene describes
@@ -136,6 +337,11 @@ pub struct Material {
     /// a light source, the emissive color descibes the color of light the
     /// object emits.
     pub color_emissive: Color,
+    /// The strength of the light the material emits, derived by the parser from
+    /// the largest component of the emissive color. This is `None` when the
+    /// material has no `Ke` statement. Emissive colors written with high dynamic
+    /// range have a strength greater than one.
+    pub emissive_strength: Option<f64>,
     /// The specular exponent of the material used in either Phong shading or
     /// Blinn-Phong shading.
     pub specular_exponent: f64,
@@ -167,6 +373,10 @@ pub struct Material {
     /// gets perturbed across a surface for providing extra surface detail at low
     /// computational cost.
     pub map_bump: Option<String>,
+    /// A texture map that stores tangent space normal vectors, as set by the
+    /// `norm` statement. Unlike a bump map, which stores heights that a renderer
+    /// differentiates, a normal map replaces the normal vector directly.
+    pub map_normal: Option<String>,
     /// A texture map that describes the local deformation of the surface of an
     /// object, creating surface roughness. Displacement mapping differs from bump
     /// mapping in that a displacement map describes how to actually modify the
@@ -179,16 +389,317 @@ pub struct Material {
     /// A texture map that replaces the main surface color with a color looked up
     /// from the decal map.
     pub map_decal: Option<String>,
+    /// Whether texture anti-aliasing is turned on for the material's texture
+    /// maps, as set by the `map_aat` statement. Anti-aliasing is off by default.
+    pub anti_alias_maps: bool,
+    /// The transparency of the material as given by a `Tr` statement. Transparency
+    /// is the complement of dissolve, so a material with no `d` statement gets a
+    /// dissolve of `1 - Tr`. When a material has both statements, the
+    /// [`DissolveConflict`] rule of the parser decides which one sets the dissolve.
+    pub transparency: Option<f64>,
+    /// Statements the parser does not recognize, keyed by keyword, as captured
+    /// by a [`StatementHandler`] such as [`StoreExtras`]. Each value holds the
+    /// arguments of the statement.
+    pub extras: BTreeMap<String, Vec<String>>,
+    /// The line number of the `newmtl` statement that starts the material in
+    /// the library file.
+    pub line_number: usize,
+    /// The line number of the last statement of the material in the library
+    /// file. Blank lines and comments after the last statement do not count.
+    pub end_line_number: usize,
 }
 
 impl Material {
-    fn new() -> Material {
+    /// The color of the light the material emits, with each component in the
+    /// range `[0, 1]`.
+    ///
+    /// High dynamic range emissive colors are divided by the emissive strength.
+    /// A material with an emissive map but no `Ke` statement gets a white
+    /// emissive color, so that the map determines the emitted color.
+    pub fn emissive_color(&self) -> Color {
+        match self.emissive_strength {
+            Some(strength) if strength > 1_f64 => Color {
+                r: self.color_emissive.r / strength,
+                g: self.color_emissive.g / strength,
+                b: self.color_emissive.b / strength,
+            },
+            Some(_) => self.color_emissive,
+            None if self.map_emissive.is_some() => Color {
+                r: 1_f64,
+                g: 1_f64,
+                b: 1_f64,
+            },
+            None => Color::zero(),
+        }
+    }
+
+    /// The factor to multiply the emissive color by to get the emitted light.
+    ///
+    /// This is the emissive strength for high dynamic range emissive colors, one
+    /// for other emissive colors and for emissive maps, and zero for materials
+    /// that do not emit light.
+    pub fn emissive_intensity(&self) -> f64 {
+        match self.emissive_strength {
+            Some(strength) if strength > 1_f64 => strength,
+            Some(_) => 1_f64,
+            None if self.map_emissive.is_some() => 1_f64,
+            None => 0_f64,
+        }
+    }
+
+    /// The light the material emits, combining the emissive color and the
+    /// emissive intensity. A renderer multiplies this value by the emissive map,
+    /// if the material has one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// # use wavefront_obj::mtl::Color;
+    /// #
+    /// let mtl_file = "newmtl lamp\nKe 4.0 2.0 0.0\nnewmtl screen\nmap_Ke screen.png\n";
+    /// let result = mtl::parse(mtl_file).unwrap();
+    /// let lamp = &result.materials[0];
+    /// let screen = &result.materials[1];
+    ///
+    /// assert_eq!(lamp.emissive_strength, Some(4.0));
+    /// assert_eq!(lamp.emissive_color(), Color { r: 1.0, g: 0.5, b: 0.0 });
+    /// assert_eq!(lamp.emissive(), Color { r: 4.0, g: 2.0, b: 0.0 });
+    /// assert_eq!(screen.emissive_strength, None);
+    /// assert_eq!(screen.emissive(), Color { r: 1.0, g: 1.0, b: 1.0 });
+    /// ```
+    pub fn emissive(&self) -> Color {
+        let color = self.emissive_color();
+        let intensity = self.emissive_intensity();
+
+        Color {
+            r: color.r * intensity,
+            g: color.g * intensity,
+            b: color.b * intensity,
+        }
+    }
+
+    /// Approximate the material with a metallic-roughness physically based
+    /// material, as used by glTF.
+    ///
+    /// The conversion follows the usual heuristics of importers:
+    ///
+    /// * The roughness is `sqrt(2 / (Ns + 2))`, the roughness of the
+    ///   microfacet distribution whose highlight matches a Phong highlight
+    ///   with specular exponent `Ns`.
+    /// * A specular color up to `0.5` is treated as the highlight of a
+    ///   dielectric, and brighter specular colors as increasingly metallic,
+    ///   reaching a metallic factor of one at `1.0`. The base color of a metal
+    ///   is tinted by its specular color.
+    /// * The base color otherwise is the diffuse color, the base color map is
+    ///   the diffuse map, and the alpha is the dissolve.
+    ///
+    /// `Pr` and `Pm` statements stored in the extras of the material, e.g. by
+    /// [`StoreExtras`], take precedence over the heuristic roughness and
+    /// metallic factors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// #
+    /// let mtl_file = "newmtl plastic\nKd 0.8 0.1 0.1\nKs 0.5 0.5 0.5\nNs 98\nmap_Kd red.png\n";
+    /// let result = mtl::parse(mtl_file).unwrap();
+    /// let pbr = result.materials[0].to_pbr();
+    ///
+    /// assert_eq!(pbr.base_color, result.materials[0].color_diffuse);
+    /// assert_eq!(pbr.base_color_map.as_deref(), Some("red.png"));
+    /// assert_eq!(pbr.metallic, 0.0);
+    /// assert!((pbr.roughness - 0.1414).abs() < 1e-4);
+    /// ```
+    pub fn to_pbr(&self) -> PbrMaterial {
+        let extra = |key: &str| {
+            self.extras
+                .get(key)
+                .and_then(|arguments| arguments.first())
+                .and_then(|argument| number::parse_f64(argument, false))
+        };
+        let specular = self
+            .color_specular
+            .r
+            .max(self.color_specular.g)
+            .max(self.color_specular.b);
+        let metallic = extra("Pm").unwrap_or((specular - 0.5) / 0.5).clamp(0_f64, 1_f64);
+        let roughness = extra("Pr")
+            .unwrap_or_else(|| (2_f64 / (self.specular_exponent.max(0_f64) + 2_f64)).sqrt())
+            .clamp(0_f64, 1_f64);
+        let mix = |diffuse: f64, specular: f64| diffuse + (specular - diffuse) * metallic;
+
+        PbrMaterial {
+            name: self.name.clone(),
+            base_color: Color {
+                r: mix(self.color_diffuse.r, self.color_specular.r),
+                g: mix(self.color_diffuse.g, self.color_specular.g),
+                b: mix(self.color_diffuse.b, self.color_specular.b),
+            },
+            base_color_map: self.map_diffuse.clone(),
+            alpha: self.dissolve,
+            metallic: metallic,
+            roughness: roughness,
+            emissive: self.emissive(),
+            emissive_map: self.map_emissive.clone(),
+            normal_map: self.map_normal.clone().or_else(|| self.map_bump.clone()),
+        }
+    }
+
+    /// Pack the material into a block of plain data laid out for upload as a
+    /// uniform buffer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// # use wavefront_obj::mtl::MaterialUniformBlock;
+    /// #
+    /// let mtl_file = "newmtl glass\nKd 0.5 0.5 1.0\nd 0.25\nNs 50\nmap_Kd glass.png\n";
+    /// let result = mtl::parse(mtl_file).unwrap();
+    /// let block = result.materials[0].uniform_block();
+    ///
+    /// assert_eq!(block.diffuse, [0.5, 0.5, 1.0, 0.25]);
+    /// assert_eq!(block.parameters[0], 50.0);
+    /// assert_eq!(block.map_flags, MaterialUniformBlock::MAP_DIFFUSE);
+    /// assert_eq!(block.to_bytes().len(), MaterialUniformBlock::SIZE);
+    /// ```
+    pub fn uniform_block(&self) -> MaterialUniformBlock {
+        let color =
+            |color: &Color, alpha: f64| [color.r as f32, color.g as f32, color.b as f32, alpha as f32];
+        let maps = [
+            (&self.map_ambient, MaterialUniformBlock::MAP_AMBIENT),
+            (&self.map_diffuse, MaterialUniformBlock::MAP_DIFFUSE),
+            (&self.map_specular, MaterialUniformBlock::MAP_SPECULAR),
+            (&self.map_emissive, MaterialUniformBlock::MAP_EMISSIVE),
+            (
+                &self.map_specular_exponent,
+                MaterialUniformBlock::MAP_SPECULAR_EXPONENT,
+            ),
+            (&self.map_bump, MaterialUniformBlock::MAP_BUMP),
+            (&self.map_normal, MaterialUniformBlock::MAP_NORMAL),
+            (&self.map_displacement, MaterialUniformBlock::MAP_DISPLACEMENT),
+            (&self.map_dissolve, MaterialUniformBlock::MAP_DISSOLVE),
+            (&self.map_decal, MaterialUniformBlock::MAP_DECAL),
+        ];
+        let map_flags = maps
+            .iter()
+            .filter(|(map, _)| map.is_some())
+            .fold(0, |flags, &(_, flag)| flags | flag);
+
+        MaterialUniformBlock {
+            ambient: color(&self.color_ambient, 1_f64),
+            diffuse: color(&self.color_diffuse, self.dissolve),
+            specular: color(&self.color_specular, 1_f64),
+            emissive: color(&self.emissive_color(), self.emissive_intensity()),
+            parameters: [
+                self.specular_exponent as f32,
+                self.dissolve as f32,
+                self.optical_density.unwrap_or(1_f64) as f32,
+                self.transparency.unwrap_or(1_f64 - self.dissolve) as f32,
+            ],
+            map_flags: map_flags,
+            illumination_model: u32::from(self.illumination_model.number()),
+            anti_alias_maps: u32::from(self.anti_alias_maps),
+            padding: 0,
+        }
+    }
+
+    /// Check the values of the material for suspicious values.
+    ///
+    /// The ambient, diffuse, and specular colors, the dissolve, and the
+    /// transparency should lie in the range `[0, 1]`, the specular exponent
+    /// should not be negative, and the optical density should be at least one.
+    /// The emissive color is not checked, because high dynamic range emissive
+    /// colors are legitimate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// # use wavefront_obj::mtl::WarningKind;
+    /// #
+    /// let mtl_file = "newmtl suspicious\nKd 1.5 0.5 0.5\nNs -10.0\n";
+    /// let result = mtl::parse(mtl_file).unwrap();
+    /// let warnings = result.materials[0].validate();
+    /// let kinds: Vec<WarningKind> = warnings.iter().map(|warning| warning.kind).collect();
+    ///
+    /// assert_eq!(
+    ///     kinds,
+    ///     vec![WarningKind::ColorOutOfRange, WarningKind::NegativeSpecularExponent]
+    /// );
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = vec![];
+        let mut warn = |kind: WarningKind, message: String| {
+            warnings.push(ValidationWarning {
+                material: self.name.clone(),
+                kind: kind,
+                message: message,
+            });
+        };
+        let in_unit_range = |value: f64| (0_f64..=1_f64).contains(&value);
+
+        let colors = [
+            ("ambient", "Ka", &self.color_ambient),
+            ("diffuse", "Kd", &self.color_diffuse),
+            ("specular", "Ks", &self.color_specular),
+        ];
+        for &(name, tag, color) in colors.iter() {
+            if !(in_unit_range(color.r) && in_unit_range(color.g) && in_unit_range(color.b)) {
+                warn(
+                    WarningKind::ColorOutOfRange,
+                    format!(
+                        "The {} color `{} {} {} {}` has a component outside the range [0, 1].",
+                        name, tag, color.r, color.g, color.b
+                    ),
+                );
+            }
+        }
+        if !in_unit_range(self.dissolve) {
+            warn(
+                WarningKind::DissolveOutOfRange,
+                format!("The dissolve `{}` lies outside the range [0, 1].", self.dissolve),
+            );
+        }
+        if let Some(transparency) = self.transparency {
+            if !in_unit_range(transparency) {
+                warn(
+                    WarningKind::DissolveOutOfRange,
+                    format!(
+                        "The transparency `{}` lies outside the range [0, 1].",
+                        transparency
+                    ),
+                );
+            }
+        }
+        if self.specular_exponent < 0_f64 {
+            warn(
+                WarningKind::NegativeSpecularExponent,
+                format!("The specular exponent `{}` is negative.", self.specular_exponent),
+            );
+        }
+        if let Some(optical_density) = self.optical_density {
+            if optical_density < 1_f64 {
+                warn(
+                    WarningKind::OpticalDensityBelowOne,
+                    format!("The optical density `{}` is less than one.", optical_density),
+                );
+            }
+        }
+
+        warnings
+    }
+
+    pub(crate) fn new() -> Material {
         Material {
             name: String::new(),
             color_ambient: Color::zero(),
             color_diffuse: Color::zero(),
             color_specular: Color::zero(),
             color_emissive: Color::zero(),
+            emissive_strength: None,
             specular_exponent: 0_f64,
             dissolve: 1_f64,
             optical_density: None,
@@ -199,363 +710,1006 @@ impl Material {
             map_emissive: None,
             map_specular_exponent: None,
             map_bump: None,
+            map_normal: None,
             map_displacement: None,
             map_dissolve: None,
             map_decal: None,
+            anti_alias_maps: false,
+            transparency: None,
+            extras: BTreeMap::new(),
+            line_number: 0,
+            end_line_number: 0,
         }
     }
 }
 
-/// A collection of materials that may be used by multiple parts of a single
-/// object, or referenced when rendering a collection of objects.
+/// A metallic-roughness physically based approximation of a [`Material`], as
+/// returned by [`Material::to_pbr`].
 #[derive(Clone, Debug, PartialEq)]
-pub struct MaterialSet {
-    pub materials: Vec<Material>,
-}
-
-/// A marker indicating the type of error generated during parsing of a
-/// Wavefront MTL file.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub enum ErrorKind {
-    /// The parser prematurely reached the end of the input.
-    EndOfFile,
-    /// The parser expected a tag statement that was not present.
-    ExpectedTagStatement,
-    /// The parser expected a floating point number but got something else
-    /// instead.
-    ExpectedFloat,
-    /// The parser expected an integer but got something else instead.
-    ExpectedInteger,
-    /// The parser expected there to be no more input.
-    ExpectedEndOfInput,
-    /// The MTL file specified an unsupported or unknown illumination model.
-    UnknownIlluminationModel,
-    /// A general parsing error occurred.
-    ErrorParsingMaterial,
+pub struct PbrMaterial {
+    /// The name of the material.
+    pub name: String,
+    /// The base color of the material.
+    pub base_color: Color,
+    /// The texture map that the base color is multiplied by.
+    pub base_color_map: Option<String>,
+    /// The opacity of the material, in the range `[0, 1]`.
+    pub alpha: f64,
+    /// The metallic factor, in the range `[0, 1]`.
+    pub metallic: f64,
+    /// The roughness factor, in the range `[0, 1]`.
+    pub roughness: f64,
+    /// The light the material emits.
+    pub emissive: Color,
+    /// The texture map that the emitted light is multiplied by.
+    pub emissive_map: Option<String>,
+    /// The normal map of the material, or its bump map if it has no normal
+    /// map.
+    pub normal_map: Option<String>,
 }
 
-/// An error that is returned from parsing an invalid `*.mtl` file, or
-/// another kind of error.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ParseError {
-    /// The line number where the error occurred.
-    line_number: usize,
-    /// The kind of error that occurred.
-    kind: ErrorKind,
-    /// A message describing why the parse error was generated.
-    message: String,
+/// The values of a [`Material`] as plain data, ready to upload as a uniform
+/// buffer, as returned by [`Material::uniform_block`].
+///
+/// Every field is 16 bytes long, so the layout is the same under the `std140`
+/// and `std430` rules of GLSL and the uniform buffer rules of WGSL. The
+/// matching GLSL declaration is
+///
+/// ```text
+/// layout(std140) uniform Material {
+///     vec4 ambient;
+///     vec4 diffuse;
+///     vec4 specular;
+///     vec4 emissive;
+///     vec4 parameters;
+///     uint map_flags;
+///     uint illumination_model;
+///     uint anti_alias_maps;
+///     uint padding;
+/// };
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MaterialUniformBlock {
+    /// The ambient color, with an alpha of one.
+    pub ambient: [f32; 4],
+    /// The diffuse color, with the dissolve as its alpha.
+    pub diffuse: [f32; 4],
+    /// The specular color, with an alpha of one.
+    pub specular: [f32; 4],
+    /// The emissive color, see [`Material::emissive_color`], with the emissive
+    /// intensity, see [`Material::emissive_intensity`], as its alpha.
+    pub emissive: [f32; 4],
+    /// The specular exponent, the dissolve, the optical density, and the
+    /// transparency, in that order. A material without an optical density
+    /// gets one, and a material without a transparency gets one minus its
+    /// dissolve.
+    pub parameters: [f32; 4],
+    /// A bit for each texture map the material has, e.g.
+    /// [`MaterialUniformBlock::MAP_DIFFUSE`].
+    pub map_flags: u32,
+    /// The number of the illumination model.
+    pub illumination_model: u32,
+    /// One if the texture maps are anti-aliased, zero otherwise.
+    pub anti_alias_maps: u32,
+    /// Padding to a multiple of 16 bytes, always zero.
+    pub padding: u32,
 }
 
-impl ParseError {
-    /// Construct a new parse error.
-    fn new(line_number: usize, kind: ErrorKind, message: String) -> ParseError {
-        ParseError {
-            line_number: line_number,
-            kind: kind,
-            message: message,
+impl MaterialUniformBlock {
+    /// The size of the block in bytes.
+    pub const SIZE: usize = 96;
+    /// The bit of [`MaterialUniformBlock::map_flags`] set for an ambient map.
+    pub const MAP_AMBIENT: u32 = 1 << 0;
+    /// The bit set for a diffuse map.
+    pub const MAP_DIFFUSE: u32 = 1 << 1;
+    /// The bit set for a specular map.
+    pub const MAP_SPECULAR: u32 = 1 << 2;
+    /// The bit set for an emissive map.
+    pub const MAP_EMISSIVE: u32 = 1 << 3;
+    /// The bit set for a specular exponent map.
+    pub const MAP_SPECULAR_EXPONENT: u32 = 1 << 4;
+    /// The bit set for a bump map.
+    pub const MAP_BUMP: u32 = 1 << 5;
+    /// The bit set for a normal map.
+    pub const MAP_NORMAL: u32 = 1 << 6;
+    /// The bit set for a displacement map.
+    pub const MAP_DISPLACEMENT: u32 = 1 << 7;
+    /// The bit set for a dissolve map.
+    pub const MAP_DISSOLVE: u32 = 1 << 8;
+    /// The bit set for a decal map.
+    pub const MAP_DECAL: u32 = 1 << 9;
+
+    /// The bytes of the block in native byte order, as a graphics API expects
+    /// them for a buffer upload.
+    pub fn to_bytes(&self) -> [u8; MaterialUniformBlock::SIZE] {
+        let mut bytes = [0_u8; MaterialUniformBlock::SIZE];
+        let vectors = [
+            self.ambient,
+            self.diffuse,
+            self.specular,
+            self.emissive,
+            self.parameters,
+        ];
+        let scalars = [
+            self.map_flags,
+            self.illumination_model,
+            self.anti_alias_maps,
+            self.padding,
+        ];
+        let words = vectors
+            .iter()
+            .flatten()
+            .map(|value| value.to_ne_bytes())
+            .chain(scalars.iter().map(|value| value.to_ne_bytes()));
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word);
         }
-    }
-}
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            formatter,
-            "Parse error at line {}: {}",
-            self.line_number, self.message
-        )
+        bytes
     }
 }
 
-impl error::Error for ParseError {}
-
-
-/// A Wavefront MTL file parser.
-pub struct Parser<'a> {
-    /// the current line number in the input stream.
-    line_number: usize,
-    /// The underlying lexer that tokenizes the input stream.
-    lexer: PeekableLexer<'a>,
+/// A collection of materials that may be used by multiple parts of a single
+/// object, or referenced when rendering a collection of objects.
+///
+/// Lookups by name go through an index that is built on the first lookup.
+/// The methods that can change the materials clear the index. Modifying
+/// `materials` directly bypasses them, so use [`MaterialSet::materials_mut`]
+/// to rename or reorder the materials of a material set that was already
+/// searched by name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MaterialSet {
+    pub materials: Vec<Material>,
+    /// The suspicious values the parser accepted under
+    /// [`NonFinitePolicy::Warn`], in the order they appear.
+    pub warnings: Vec<ValidationWarning>,
+    /// The position of each material by name. The index never affects
+    /// equality.
+    name_index: MaterialNameIndex,
 }
 
-impl<'a> Parser<'a> {
-    /// Construct a new parser for an mtl file input as a string.
-    pub fn new(input: &'a str) -> Parser<'a> {
-        Parser {
-            line_number: 1,
-            lexer: PeekableLexer::new(Lexer::new(input)),
+impl MaterialSet {
+    /// Construct a material set from its materials, without warnings.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// # use wavefront_obj::mtl::MaterialSet;
+    /// #
+    /// let material_set = mtl::parse("newmtl red\n").unwrap();
+    ///
+    /// assert_eq!(MaterialSet::new(material_set.materials.clone()), material_set);
+    /// ```
+    pub fn new(materials: Vec<Material>) -> MaterialSet {
+        MaterialSet {
+            materials: materials,
+            warnings: vec![],
+            name_index: Default::default(),
         }
     }
 
-    /// Construct a new parse error.
-    fn error<T>(&self, kind: ErrorKind, message: String) -> Result<T, ParseError> {
-        Err(ParseError::new(self.line_number, kind, message))
-    }
+    /// Get the materials of the material set for modification, clearing the
+    /// name index so that later lookups by name see the modified materials.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// #
+    /// let mut material_set = mtl::parse("newmtl red\nnewmtl blue\n").unwrap();
+    /// assert_eq!(material_set.position("blue"), Some(1));
+    ///
+    /// material_set.materials_mut()[0].name = String::from("blue");
+    ///
+    /// assert_eq!(material_set.position("blue"), Some(0));
+    /// ```
+    pub fn materials_mut(&mut self) -> &mut Vec<Material> {
+        self.name_index.clear();
 
-    /// Peek at the currently held token without advancing the token stream.
-    fn peek(&mut self) -> Option<&'a str> {
-        self.lexer.peek()
+        &mut self.materials
     }
 
-    /// Advance the token stream one step returning the currently held string.
-    fn next(&mut self) -> Option<&'a str> {
-        let token = self.lexer.next();
-        if let Some(val) = token {
-            if val == "\n" {
-                self.line_number += 1;
-            }
-        }
-
-        token
+    /// Check the values of every material in the material set for suspicious
+    /// values.
+    ///
+    /// See [`Material::validate`] for the checks performed.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        self.materials
+            .iter()
+            .flat_map(|material| material.validate())
+            .collect()
     }
 
-    /// Advance the token stream one step without returning the current token.
-    fn advance(&mut self) {
-        self.next();
+    /// Find the position of the first material with a given name.
+    ///
+    /// Lookups use the name index, so resolving every `usemtl` statement of
+    /// a large scene takes time linear in the number of statements rather
+    /// than in their product with the number of materials.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// #
+    /// let material_set = mtl::parse("newmtl blue\nnewmtl red\n").unwrap();
+    ///
+    /// assert_eq!(material_set.position("red"), Some(1));
+    /// assert_eq!(material_set.position("green"), None);
+    /// ```
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.name_index.position(&self.materials, name)
     }
 
-    /// Advance the token stream one step, returning the next token in the
-    /// stream.
+    /// Find the first material with a given name.
     ///
-    /// This function generates an error is it runs out of input.
-    fn next_string(&mut self) -> Result<&'a str, ParseError> {
-        match self.next() {
-            Some(st) => Ok(st),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected token but reached end of input.".to_owned(),
-            ),
-        }
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// #
+    /// let material_set = mtl::parse("newmtl blue\nKd 0 0 1\nnewmtl red\nKd 1 0 0\n").unwrap();
+    /// let material = material_set.get("red").unwrap();
+    ///
+    /// assert_eq!(material.color_diffuse.r, 1.0);
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&Material> {
+        self.position(name).map(|index| &self.materials[index])
     }
 
-    /// Advance the token stream if the next token in the stream matches the
-    /// input tag.
+    /// Find the first material with a given name for modification.
     ///
-    /// This functions returns an error if the expected tag is not present.
-    fn expect_tag(&mut self, tag: &str) -> Result<(), ParseError> {
-        match self.next() {
-            None => self.error(ErrorKind::EndOfFile, String::new()),
-            Some(st) if st != tag => self.error(
-                ErrorKind::ExpectedTagStatement,
-                format!("Expected statement {} but got statement {}", tag, st),
-            ),
-            _ => Ok(()),
-        }
+    /// The material may be renamed, so the name index is cleared.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Material> {
+        let index = self.position(name)?;
+
+        Some(&mut self.materials_mut()[index])
     }
 
-    /// Skip zero or more newlines in the input stream.
-    fn skip_zero_or_more_newlines(&mut self) {
-        while let Some("\n") = self.peek() {
-            self.advance();
-        }
+    /// Determine whether the material set has a material with a given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
     }
 
-    /// Parse a floating point number from the current token in the stream.
-    fn parse_f64(&mut self) -> Result<f64, ParseError> {
-        let st = self.next_string()?;
-        match st.parse::<f64>() {
-            Ok(val) => Ok(val),
-            Err(_) => self.error(
-                ErrorKind::ExpectedFloat,
-                format!("Expected floating point number but got {}", st),
-            ),
+    /// Merge the materials of another material set into this one, e.g. to
+    /// combine the material libraries a scene refers to.
+    ///
+    /// A material whose name is not in use yet is appended. A material whose
+    /// name is already in use is a conflict, which the policy resolves. With
+    /// [`MaterialConflict::Error`], the material set is left unchanged when
+    /// there is any conflict.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// # use wavefront_obj::mtl::MaterialConflict;
+    /// #
+    /// let mut material_set = mtl::parse("newmtl red\nKd 1 0 0\n").unwrap();
+    /// let other = mtl::parse("newmtl red\nKd 0.5 0 0\nnewmtl blue\nKd 0 0 1\n").unwrap();
+    /// let report = material_set.merge(other, MaterialConflict::PreferLast).unwrap();
+    ///
+    /// assert_eq!(report.conflicts, vec![String::from("red")]);
+    /// assert_eq!(material_set.materials.len(), 2);
+    /// assert_eq!(material_set.get("red").unwrap().color_diffuse.r, 0.5);
+    /// ```
+    pub fn merge(&mut self, other: MaterialSet, policy: MaterialConflict) -> Result<MergeReport, MergeError> {
+        if policy == MaterialConflict::Error {
+            let mut names = HashSet::new();
+            let conflicts: Vec<String> = other
+                .materials
+                .iter()
+                .filter(|material| self.contains(&material.name) || !names.insert(material.name.as_str()))
+                .map(|material| material.name.clone())
+                .collect();
+            if !conflicts.is_empty() {
+                return Err(MergeError { conflicts: conflicts });
+            }
         }
-    }
 
-    /// Parse an integer from the current token in the stream.
-    fn parse_usize(&mut self) -> Result<usize, ParseError> {
-        let st = self.next_string()?;
-        match st.parse::<usize>() {
-            Ok(val) => Ok(val),
-            Err(_) => self.error(
-                ErrorKind::ExpectedInteger,
-                format!("Expected integer but got {}", st),
-            ),
+        let mut conflicts = vec![];
+        for material in other.materials.into_iter() {
+            match self.position(&material.name) {
+                None => {
+                    self.name_index.push(&material.name, self.materials.len());
+                    self.materials.push(material);
+                }
+                Some(index) => {
+                    conflicts.push(material.name.clone());
+                    if policy == MaterialConflict::PreferLast {
+                        self.materials[index] = material;
+                    }
+                }
+            }
         }
+        self.warnings.extend(other.warnings);
+
+        Ok(MergeReport { conflicts: conflicts })
     }
+}
 
-    /// Parse a RGB color from the input stream.
-    fn parse_color(&mut self) -> Result<Color, ParseError> {
-        let r = self.parse_f64()?;
-        let g = self.parse_f64()?;
-        let b = self.parse_f64()?;
+/// The rule for resolving a conflict when merging two material sets that
+/// both have a material with the same name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MaterialConflict {
+    /// Keep the material that is already in the material set.
+    #[default]
+    PreferFirst,
+    /// Replace the material that is already in the material set, keeping its
+    /// position.
+    PreferLast,
+    /// Fail with a [`MergeError`].
+    Error,
+}
 
-        Ok(Color { r: r, g: g, b: b })
-    }
+/// The outcome of merging two material sets.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeReport {
+    /// The name of each merged material whose name was already in use, in
+    /// the order of the merged material set.
+    pub conflicts: Vec<String>,
+}
 
-    /// Parse a material's ambient component from the input stream.
-    fn parse_ambient_component(&mut self) -> Result<Color, ParseError> {
-        self.expect_tag("Ka")?;
-        self.parse_color()
+/// An error returned by [`MaterialSet::merge`] when two material sets both
+/// have a material with the same name under [`MaterialConflict::Error`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeError {
+    /// The name of each material that is in both material sets.
+    pub conflicts: Vec<String>,
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "The material sets both define the materials `{}`.",
+            self.conflicts.join("`, `")
+        )
     }
+}
 
-    /// Parse a material's diffuse component from the input stream.
-    fn parse_diffuse_component(&mut self) -> Result<Color, ParseError> {
-        self.expect_tag("Kd")?;
-        self.parse_color()
+impl error::Error for MergeError {}
+
+/// A lazily built map from the name of each material in a [`MaterialSet`]
+/// to the position of the first material with that name.
+///
+/// The index is built from all of the materials on the first lookup, and
+/// cleared by every method of the material set that can change them. A
+/// position found in the index is still checked against the materials, so
+/// that a stale index never yields a material with another name; the index
+/// is rebuilt when the check fails.
+#[derive(Default)]
+struct MaterialNameIndex {
+    /// The number of materials the index was built from, and the position
+    /// of each name.
+    positions: Mutex<Option<(usize, HashMap<String, usize>)>>,
+}
+
+impl MaterialNameIndex {
+    fn position(&self, materials: &[Material], name: &str) -> Option<usize> {
+        let mut positions = self.positions.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((material_count, positions)) = positions.as_ref() {
+            match positions.get(name) {
+                Some(&index) if materials.get(index).is_some_and(|material| material.name == name) => {
+                    return Some(index);
+                }
+                None if *material_count == materials.len() => return None,
+                _ => {}
+            }
+        }
+
+        let mut rebuilt = HashMap::with_capacity(materials.len());
+        for (index, material) in materials.iter().enumerate() {
+            rebuilt.entry(material.name.clone()).or_insert(index);
+        }
+        let index = rebuilt.get(name).copied();
+        *positions = Some((materials.len(), rebuilt));
+
+        index
     }
 
-    /// Parse a material's specular component from the input stream.
-    fn parse_specular_component(&mut self) -> Result<Color, ParseError> {
-        self.expect_tag("Ks")?;
-        self.parse_color()
+    /// Record a material appended at `index` without rebuilding the index.
+    fn push(&self, name: &str, index: usize) {
+        let mut positions = self.positions.lock().unwrap_or_else(PoisonError::into_inner);
+        match positions.as_mut() {
+            Some((material_count, positions)) if *material_count == index => {
+                positions.entry(String::from(name)).or_insert(index);
+                *material_count += 1;
+            }
+            _ => *positions = None,
+        }
     }
 
-    /// parse a material's emissive component from the input stream.
-    fn parse_emissive_component(&mut self) -> Result<Color, ParseError> {
-        self.expect_tag("Ke")?;
-        self.parse_color()
+    fn clear(&mut self) {
+        *self.positions.get_mut().unwrap_or_else(PoisonError::into_inner) = None;
     }
+}
 
-    /// Parse a material's dissolve (alpha) component from the input stream.
-    fn parse_dissolve_component(&mut self) -> Result<f64, ParseError> {
-        self.expect_tag("d")?;
-        self.parse_f64()
+impl Clone for MaterialNameIndex {
+    fn clone(&self) -> MaterialNameIndex {
+        let positions = self.positions.lock().unwrap_or_else(PoisonError::into_inner);
+
+        MaterialNameIndex {
+            positions: Mutex::new(positions.clone()),
+        }
     }
+}
 
-    /// Parse a material's specular exponent from the input stream.
-    fn parse_specular_exponent(&mut self) -> Result<f64, ParseError> {
-        self.expect_tag("Ns")?;
-        self.parse_f64()
+impl PartialEq for MaterialNameIndex {
+    fn eq(&self, _other: &MaterialNameIndex) -> bool {
+        true
     }
+}
 
-    /// Parse a material's optical density componeont from the input stream.
-    fn parse_optical_density(&mut self) -> Result<f64, ParseError> {
-        self.expect_tag("Ni")?;
-        self.parse_f64()
+impl fmt::Debug for MaterialNameIndex {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("MaterialNameIndex")
+            .finish_non_exhaustive()
     }
+}
 
-    /// Parse the name of a material's ambient texture map from the input stream.
-    fn parse_map_ambient(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("map_Ka") => {}
-            _ => return Ok(None),
-        }
+/// A marker indicating the type of suspicious value found by validating a
+/// material.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningKind {
+    /// A component of the ambient, diffuse, or specular color lies outside the
+    /// range `[0, 1]`.
+    ColorOutOfRange,
+    /// The dissolve or the transparency lies outside the range `[0, 1]`.
+    DissolveOutOfRange,
+    /// The specular exponent is negative.
+    NegativeSpecularExponent,
+    /// The optical density is less than one, which no physical material has.
+    OpticalDensityBelowOne,
+    /// A number is NaN or an infinity.
+    NonFiniteValue,
+}
 
-        self.expect_tag("map_Ka")?;
-        match self.next() {
-            Some(st) => Ok(Some(st)),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
-            ),
-        }
+/// A suspicious value found by validating a material.
+///
+/// Warnings do not prevent a material from being used; they flag values that
+/// are likely to be mistakes so that asset quality assurance tools can report
+/// them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationWarning {
+    /// The name of the material with the suspicious value.
+    pub material: String,
+    /// The kind of suspicious value.
+    pub kind: WarningKind,
+    /// A message describing the suspicious value.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Material `{}`: {}", self.material, self.message)
     }
+}
 
-    /// Parse the name of a material's diffuse texture map from the input stream.
-    fn parse_map_diffuse(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("map_Kd") => {}
-            _ => return Ok(None),
-        }
+/// A marker indicating the type of error generated during parsing of a
+/// Wavefront MTL file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The parser prematurely reached the end of the input.
+    EndOfFile,
+    /// The parser expected a tag statement that was not present.
+    ExpectedTagStatement,
+    /// The parser expected a floating point number but got something else
+    /// instead.
+    ExpectedFloat,
+    /// The parser expected an integer but got something else instead.
+    ExpectedInteger,
+    /// The parser expected there to be no more input.
+    ExpectedEndOfInput,
+    /// The MTL file specified an illumination model number that does not fit in a `u8`.
+    UnknownIlluminationModel,
+    /// A general parsing error occurred.
+    ErrorParsingMaterial,
+    /// The input is binary data rather than text, e.g. it contains a NUL byte
+    /// or invalid UTF-8.
+    NotATextFile,
+    /// A number is NaN or an infinity, and the parser rejects those under
+    /// [`NonFinitePolicy::Reject`].
+    NonFiniteValue,
+}
 
-        self.expect_tag("map_Kd")?;
-        match self.next() {
-            Some(st) => Ok(Some(st)),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
-            ),
+impl ErrorKind {
+    /// The stable code of the error kind, e.g. `MTL0006`.
+    ///
+    /// Codes never change meaning between versions of the crate, and new
+    /// error kinds get new codes, so applications can match on codes or use
+    /// them as keys for localized messages.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl::ErrorKind;
+    /// #
+    /// assert_eq!(ErrorKind::UnknownIlluminationModel.code(), "MTL0006");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ErrorKind::EndOfFile => "MTL0001",
+            ErrorKind::ExpectedTagStatement => "MTL0002",
+            ErrorKind::ExpectedFloat => "MTL0003",
+            ErrorKind::ExpectedInteger => "MTL0004",
+            ErrorKind::ExpectedEndOfInput => "MTL0005",
+            ErrorKind::UnknownIlluminationModel => "MTL0006",
+            ErrorKind::ErrorParsingMaterial => "MTL0007",
+            ErrorKind::NotATextFile => "MTL0008",
+            ErrorKind::NonFiniteValue => "MTL0009",
         }
     }
+}
 
-    /// Parse the name of a material's specular texture map from the input stream.
-    fn parse_map_specular(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("map_Ks") => {}
-            _ => return Ok(None),
-        }
+/// An error that is returned from parsing an invalid `*.mtl` file, or
+/// another kind of error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line number where the error occurred.
+    pub line_number: usize,
+    /// The kind of error that occurred.
+    pub kind: ErrorKind,
+    /// A message describing why the parse error was generated.
+    pub message: String,
+    /// The byte range in the input of the token the parser was looking at
+    /// when it detected the error, if known.
+    pub span: Option<ops::Range<usize>>,
+}
 
-        self.expect_tag("map_Ks")?;
-        match self.next() {
-            Some(st) => Ok(Some(st)),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
-            ),
+impl ParseError {
+    /// Construct a new parse error.
+    fn new(line_number: usize, kind: ErrorKind, message: String) -> ParseError {
+        ParseError {
+            line_number: line_number,
+            kind: kind,
+            message: message,
+            span: None,
         }
     }
 
-    /// Parse the name of a material's emissive texture map from the input stream.
-    fn parse_map_emissive(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("map_Ke") => {}
-            _ => return Ok(None),
-        }
+    /// Construct a parse error for an input that is binary data rather than
+    /// text.
+    pub(crate) fn not_a_text_file(not_text: input::NotText) -> ParseError {
+        let mut error = ParseError::new(not_text.line_number, ErrorKind::NotATextFile, not_text.message());
+        error.span = Some(not_text.offset..(not_text.offset + 1));
 
-        self.expect_tag("map_Ke")?;
-        match self.next() {
-            Some(st) => Ok(Some(st)),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
-            ),
-        }
+        error
     }
 
-    /// Parse the name of a material's bump texture map from the input stream.
-    fn parse_map_bump(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("map_Bump") => {
-                self.expect_tag("map_Bump")?;
-            }
-            Some("bump") => {
-                self.expect_tag("bump")?;
-            }
-            _ => return Ok(None),
-        }
+    /// The line number where the error occurred.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
 
-        match self.next() {
-            Some(st) => Ok(Some(st)),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
-            ),
-        }
+    /// The kind of error that occurred.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
     }
 
-    /// Parse the name of a material's displacement texture map from the input stream.
-    fn parse_map_displacement(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("disp") => {}
-            _ => return Ok(None),
+    /// A message describing why the parse error was generated.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The stable code of the kind of error that occurred.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// The byte range in the input of the token the parser was looking at
+    /// when it detected the error, if known.
+    pub fn span(&self) -> Option<ops::Range<usize>> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Parse error {} at line {}: {}",
+            self.code(),
+            self.line_number,
+            self.message
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A parse error is a diagnostic whose code is the stable code of the error
+/// and whose label underlines the token where the parser detected the error.
+/// Attach the parsed text to render the error as an annotated snippet.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl;
+/// #
+/// let text = "newmtl red\nKd 1 x 0\n";
+/// let error = mtl::parse(text).unwrap_err();
+/// let report = miette::Report::new(error).with_source_code(text);
+///
+/// let label = report.labels().unwrap().next().unwrap();
+/// assert_eq!(label.offset()..(label.offset() + label.len()), 16..17);
+/// ```
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.code()))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let span = self.span.clone()?;
+        let label = miette::LabeledSpan::new_with_span(Some(self.message.clone()), span);
+
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+
+/// The rule for choosing a material's dissolve when it has both a `d` and a
+/// `Tr` statement.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DissolveConflict {
+    /// Use the `d` statement and ignore the `Tr` statement.
+    #[default]
+    PreferDissolve,
+    /// Use the `Tr` statement and ignore the `d` statement.
+    PreferTransparency,
+    /// Use whichever statement appears last in the material.
+    LastWins,
+}
+
+/// A handler for statements that the MTL parser does not recognize.
+///
+/// Applications implement this trait to support vendor extensions, e.g.
+/// proprietary physically based rendering keys, without forking the parser.
+/// Closures with the same signature as [`StatementHandler::handle`] implement
+/// the trait.
+pub trait StatementHandler {
+    /// Handle an unrecognized statement inside a material.
+    ///
+    /// The handler receives the material being parsed, the keyword of the
+    /// statement, and the remaining tokens on the line. Returning an error
+    /// message rejects the statement, and the parser fails with that message.
+    fn handle(&mut self, material: &mut Material, keyword: &str, arguments: &[&str]) -> Result<(), String>;
+}
+
+impl<F> StatementHandler for F
+where
+    F: FnMut(&mut Material, &str, &[&str]) -> Result<(), String>,
+{
+    fn handle(&mut self, material: &mut Material, keyword: &str, arguments: &[&str]) -> Result<(), String> {
+        self(material, keyword, arguments)
+    }
+}
+
+/// A statement handler that stores every unrecognized statement in
+/// [`Material::extras`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StoreExtras;
+
+impl StatementHandler for StoreExtras {
+    fn handle(&mut self, material: &mut Material, keyword: &str, arguments: &[&str]) -> Result<(), String> {
+        let arguments = arguments.iter().map(|argument| String::from(*argument)).collect();
+        material.extras.insert(String::from(keyword), arguments);
+
+        Ok(())
+    }
+}
+
+/// Options that control the behavior of a Wavefront MTL file parser.
+#[derive(Default)]
+pub struct ParserOptions {
+    /// The rule for choosing a material's dissolve when it has both a `d` and
+    /// a `Tr` statement.
+    pub dissolve_conflict: DissolveConflict,
+    /// The handler for unrecognized statements. Without a handler, an
+    /// unrecognized statement is an error.
+    pub statement_handler: Option<Box<dyn StatementHandler>>,
+    /// Accept a comma as the decimal separator of a number, e.g. `1,5`, as
+    /// written by tools that format numbers for a European locale.
+    ///
+    /// Numbers otherwise follow the same grammar as in Wavefront OBJ files,
+    /// described in the [`number`] module.
+    pub decimal_comma: bool,
+    /// The treatment of numbers that are NaN or an infinity, such as color
+    /// components. The default is to accept them.
+    pub non_finite: NonFinitePolicy,
+}
+
+impl ParserOptions {
+    /// Set the handler for unrecognized statements.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::mtl;
+    /// # use wavefront_obj::mtl::{
+    /// #     ParserOptions,
+    /// #     StoreExtras,
+    /// # };
+    /// #
+    /// let mtl_file = "newmtl metal\nKd 0.5 0.5 0.5\nPm 1.0\nKe_strength 2.0\n";
+    /// let options = ParserOptions::default().statement_handler(StoreExtras);
+    /// let result = mtl::parse_with(mtl_file, options).unwrap();
+    ///
+    /// assert_eq!(result.materials[0].extras["Pm"], vec![String::from("1.0")]);
+    /// assert_eq!(result.materials[0].extras["Ke_strength"], vec![String::from("2.0")]);
+    /// ```
+    pub fn statement_handler<H: StatementHandler + 'static>(mut self, handler: H) -> ParserOptions {
+        self.statement_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl fmt::Debug for ParserOptions {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("ParserOptions")
+            .field("dissolve_conflict", &self.dissolve_conflict)
+            .field(
+                "statement_handler",
+                &self.statement_handler.as_ref().map(|_| "StatementHandler"),
+            )
+            .field("decimal_comma", &self.decimal_comma)
+            .field("non_finite", &self.non_finite)
+            .finish()
+    }
+}
+
+/// A Wavefront MTL file parser.
+pub struct Parser<'a> {
+    /// The input text.
+    input: &'a str,
+    /// The options controlling the parser.
+    options: ParserOptions,
+    /// the current line number in the input stream.
+    line_number: usize,
+    /// The underlying lexer that tokenizes the input stream.
+    lexer: PeekableLexer<'a>,
+    /// The token most recently taken from the token stream, which parse
+    /// errors point at.
+    last_token: Option<&'a str>,
+    /// The name of the material being parsed.
+    material_name: &'a str,
+    /// The warnings recorded so far.
+    warnings: Vec<ValidationWarning>,
+}
+
+impl<'a> Parser<'a> {
+    /// Construct a new parser for an mtl file input as a string.
+    pub fn new(input: &'a str) -> Parser<'a> {
+        Parser::with_options(input, ParserOptions::default())
+    }
+
+    /// Construct a new parser for an mtl file input as a string with non-default
+    /// options.
+    pub fn with_options(input: &'a str, options: ParserOptions) -> Parser<'a> {
+        Parser {
+            input: input,
+            options: options,
+            line_number: 1,
+            lexer: PeekableLexer::new(Lexer::new(input)),
+            last_token: None,
+            material_name: "",
+            warnings: Vec::new(),
         }
+    }
 
-        self.expect_tag("disp")?;
+    /// Construct a new parse error.
+    #[cold]
+    fn error<T>(&self, kind: ErrorKind, message: String) -> Result<T, ParseError> {
+        let mut error = ParseError::new(self.line_number, kind, message);
+        error.span = self.last_token.map(|token| {
+            let start = token.as_ptr() as usize - self.input.as_ptr() as usize;
+            start..(start + token.len())
+        });
+
+        Err(error)
+    }
+
+    /// Peek at the currently held token without advancing the token stream.
+    fn peek(&mut self) -> Option<&'a str> {
+        self.lexer.peek().map(normalize_line_ending)
+    }
+
+    /// Advance the token stream one step returning the currently held string.
+    ///
+    /// Line terminators are returned as `"\n"` regardless of the line ending
+    /// convention of the input.
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.lexer.next();
+        if let Some(val) = token {
+            self.last_token = token;
+            let val = normalize_line_ending(val);
+            if val == "\n" {
+                self.line_number += 1;
+            }
+
+            return Some(val);
+        }
+
+        None
+    }
+
+    /// Advance the token stream one step without returning the current token.
+    fn advance(&mut self) {
+        self.next();
+    }
+
+    /// Advance the token stream one step, returning the next token in the
+    /// stream.
+    ///
+    /// This function generates an error is it runs out of input.
+    fn next_string(&mut self) -> Result<&'a str, ParseError> {
         match self.next() {
-            Some(st) => Ok(Some(st)),
+            Some(st) => Ok(st),
             None => self.error(
                 ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
+                "Expected token but reached end of input.".to_owned(),
             ),
         }
     }
 
-    /// Parse the name of a material's dissolve (alpha) texture map from the input stream.
-    fn parse_map_dissolve(&mut self) -> Result<Option<&'a str>, ParseError> {
-        match self.peek() {
-            Some("map_d") => {}
-            _ => return Ok(None),
+    /// Advance the token stream if the next token in the stream matches the
+    /// input tag.
+    ///
+    /// This functions returns an error if the expected tag is not present.
+    fn expect_tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        match self.next() {
+            None => self.error(ErrorKind::EndOfFile, String::new()),
+            Some(st) if st != tag => self.error(
+                ErrorKind::ExpectedTagStatement,
+                format!("Expected statement {} but got statement {}", tag, st),
+            ),
+            _ => Ok(()),
         }
+    }
 
-        self.expect_tag("map_d")?;
-        match self.next() {
-            Some(st) => Ok(Some(st)),
+    /// Skip zero or more newlines in the input stream.
+    fn skip_zero_or_more_newlines(&mut self) {
+        while let Some("\n") = self.peek() {
+            self.advance();
+        }
+    }
+
+    /// Parse a floating point number from the current token in the stream.
+    fn parse_f64(&mut self) -> Result<f64, ParseError> {
+        let st = self.next_string()?;
+        match number::parse_f64(st, self.options.decimal_comma) {
+            Some(val) => self.check_finite(val),
             None => self.error(
-                ErrorKind::EndOfFile,
-                "Expected texture map name but got end of input.".to_owned(),
+                ErrorKind::ExpectedFloat,
+                format!("Expected floating point number but got {}", st),
             ),
         }
     }
 
-    /// Parse the name of a material's decal texture map from the input stream.
-    fn parse_map_decal(&mut self) -> Result<Option<&'a str>, ParseError> {
+    /// Apply the non-finite value policy to the number most recently taken
+    /// from the token stream.
+    fn check_finite(&mut self, value: f64) -> Result<f64, ParseError> {
+        if value.is_finite() {
+            return Ok(value);
+        }
+
+        match self.options.non_finite {
+            NonFinitePolicy::Accept => Ok(value),
+            NonFinitePolicy::Reject => self.error(
+                ErrorKind::NonFiniteValue,
+                format!("Expected a finite number but got `{}` instead.", value),
+            ),
+            NonFinitePolicy::Warn => {
+                self.warnings.push(ValidationWarning {
+                    material: String::from(self.material_name),
+                    kind: WarningKind::NonFiniteValue,
+                    message: format!(
+                        "The number `{}` on line {} is not finite.",
+                        value, self.line_number
+                    ),
+                });
+                Ok(value)
+            }
+            NonFinitePolicy::Clamp => Ok(number::clamp_non_finite(value)),
+        }
+    }
+
+    /// Parse an integer from the current token in the stream.
+    fn parse_usize(&mut self) -> Result<usize, ParseError> {
+        let st = self.next_string()?;
+        match st.parse::<usize>() {
+            Ok(val) => Ok(val),
+            Err(_) => self.error(
+                ErrorKind::ExpectedInteger,
+                format!("Expected integer but got {}", st),
+            ),
+        }
+    }
+
+    /// Collect the remaining tokens on the current line, leaving the newline
+    /// in the token stream.
+    fn parse_rest_of_line(&mut self) -> Vec<&'a str> {
+        let mut tokens = vec![];
+        while let Some(st) = self.peek() {
+            if st == "\n" {
+                break;
+            }
+            tokens.push(st);
+            self.advance();
+        }
+
+        tokens
+    }
+
+    /// Parse a RGB color from the input stream.
+    fn parse_color(&mut self) -> Result<Color, ParseError> {
+        let r = self.parse_f64()?;
+        let g = self.parse_f64()?;
+        let b = self.parse_f64()?;
+
+        Ok(Color { r: r, g: g, b: b })
+    }
+
+    /// Parse a material's ambient component from the input stream.
+    fn parse_ambient_component(&mut self) -> Result<Color, ParseError> {
+        self.expect_tag("Ka")?;
+        self.parse_color()
+    }
+
+    /// Parse a material's diffuse component from the input stream.
+    fn parse_diffuse_component(&mut self) -> Result<Color, ParseError> {
+        self.expect_tag("Kd")?;
+        self.parse_color()
+    }
+
+    /// Parse a material's specular component from the input stream.
+    fn parse_specular_component(&mut self) -> Result<Color, ParseError> {
+        self.expect_tag("Ks")?;
+        self.parse_color()
+    }
+
+    /// parse a material's emissive component from the input stream.
+    fn parse_emissive_component(&mut self) -> Result<Color, ParseError> {
+        self.expect_tag("Ke")?;
+        self.parse_color()
+    }
+
+    /// Parse a material's dissolve (alpha) component from the input stream.
+    fn parse_dissolve_component(&mut self) -> Result<f64, ParseError> {
+        self.expect_tag("d")?;
+        self.parse_f64()
+    }
+
+    /// Parse a material's transparency component from the input stream.
+    fn parse_transparency_component(&mut self) -> Result<f64, ParseError> {
+        self.expect_tag("Tr")?;
+        self.parse_f64()
+    }
+
+    /// Parse a material's specular exponent from the input stream.
+    fn parse_specular_exponent(&mut self) -> Result<f64, ParseError> {
+        self.expect_tag("Ns")?;
+        self.parse_f64()
+    }
+
+    /// Parse a material's optical density componeont from the input stream.
+    fn parse_optical_density(&mut self) -> Result<f64, ParseError> {
+        self.expect_tag("Ni")?;
+        self.parse_f64()
+    }
+
+    /// Parse the name of a material's ambient texture map from the input stream.
+    fn parse_map_ambient(&mut self) -> Result<Option<&'a str>, ParseError> {
         match self.peek() {
-            Some("decal") => {}
+            Some("map_Ka") => {}
             _ => return Ok(None),
         }
 
-        self.expect_tag("decal")?;
+        self.expect_tag("map_Ka")?;
         match self.next() {
             Some(st) => Ok(Some(st)),
             None => self.error(
@@ -565,14 +1719,14 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parse the name of a material's specular exponent texture map from the input stream.
-    fn parse_map_specular_exponent(&mut self) -> Result<Option<&'a str>, ParseError> {
+    /// Parse the name of a material's diffuse texture map from the input stream.
+    fn parse_map_diffuse(&mut self) -> Result<Option<&'a str>, ParseError> {
         match self.peek() {
-            Some("map_Ns") => {}
+            Some("map_Kd") => {}
             _ => return Ok(None),
         }
 
-        self.expect_tag("map_Ns")?;
+        self.expect_tag("map_Kd")?;
         match self.next() {
             Some(st) => Ok(Some(st)),
             None => self.error(
@@ -582,7 +1736,164 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parse a material's illumination model.
+    /// Parse the name of a material's specular texture map from the input stream.
+    fn parse_map_specular(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("map_Ks") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("map_Ks")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's emissive texture map from the input stream.
+    fn parse_map_emissive(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("map_Ke") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("map_Ke")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's bump texture map from the input stream.
+    fn parse_map_bump(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("map_Bump") => {
+                self.expect_tag("map_Bump")?;
+            }
+            Some("bump") => {
+                self.expect_tag("bump")?;
+            }
+            _ => return Ok(None),
+        }
+
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's normal texture map from the input stream.
+    fn parse_map_normal(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("norm") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("norm")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's displacement texture map from the input stream.
+    fn parse_map_displacement(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("disp") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("disp")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's dissolve (alpha) texture map from the input stream.
+    fn parse_map_dissolve(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("map_d") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("map_d")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's decal texture map from the input stream.
+    fn parse_map_decal(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("decal") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("decal")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse the name of a material's specular exponent texture map from the input stream.
+    fn parse_map_specular_exponent(&mut self) -> Result<Option<&'a str>, ParseError> {
+        match self.peek() {
+            Some("map_Ns") => {}
+            _ => return Ok(None),
+        }
+
+        self.expect_tag("map_Ns")?;
+        match self.next() {
+            Some(st) => Ok(Some(st)),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected texture map name but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse a material's texture anti-aliasing setting from the input stream.
+    fn parse_map_aat(&mut self) -> Result<bool, ParseError> {
+        self.expect_tag("map_aat")?;
+        match self.next() {
+            Some("on") => Ok(true),
+            Some("off") => Ok(false),
+            Some(st) => self.error(
+                ErrorKind::ErrorParsingMaterial,
+                format!("Expected `on` or `off` after `map_aat` but got `{}`.", st),
+            ),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Expected `on` or `off` but got end of input.".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse a material's illumination model.
     fn parse_illumination_model(&mut self) -> Result<IlluminationModel, ParseError> {
         self.expect_tag("illum")?;
         let model_number = self.parse_usize()?;
@@ -590,6 +1901,7 @@ impl<'a> Parser<'a> {
             0 => Ok(IlluminationModel::Ambient),
             1 => Ok(IlluminationModel::AmbientDiffuse),
             2 => Ok(IlluminationModel::AmbientDiffuseSpecular),
+            n if n <= u8::MAX as usize => Ok(IlluminationModel::Other(n as u8)),
             n => self.error(
                 ErrorKind::UnknownIlluminationModel,
                 format!("Unknown illumination model: {}.", n),
@@ -627,11 +1939,19 @@ impl<'a> Parser<'a> {
     /// Parse one material from a MTL file.
     fn parse_material(&mut self) -> Result<Material, ParseError> {
         let mut material = Material::new();
+        material.line_number = self.line_number;
+        material.end_line_number = self.line_number;
         let name = self.parse_newmtl()?;
+        self.material_name = name;
         material.name = String::from(name);
+        let mut dissolve = None;
+        let mut transparency_is_last = false;
 
         self.skip_zero_or_more_newlines();
         loop {
+            if !matches!(self.peek(), Some("newmtl") | None) {
+                material.end_line_number = self.line_number;
+            }
             match self.peek() {
                 Some("Ka") => {
                     material.color_ambient = self.parse_ambient_component()?;
@@ -643,10 +1963,18 @@ impl<'a> Parser<'a> {
                     material.color_specular = self.parse_specular_component()?;
                 }
                 Some("Ke") => {
-                    material.color_emissive = self.parse_emissive_component()?;
+                    let color_emissive = self.parse_emissive_component()?;
+                    material.color_emissive = color_emissive;
+                    material.emissive_strength =
+                        Some(color_emissive.r.max(color_emissive.g).max(color_emissive.b));
                 }
                 Some("d") => {
-                    material.dissolve = self.parse_dissolve_component()?;
+                    dissolve = Some(self.parse_dissolve_component()?);
+                    transparency_is_last = false;
+                }
+                Some("Tr") => {
+                    material.transparency = Some(self.parse_transparency_component()?);
+                    transparency_is_last = true;
                 }
                 Some("illum") => {
                     material.illumination_model = self.parse_illumination_model()?;
@@ -682,6 +2010,10 @@ impl<'a> Parser<'a> {
                     let map_bump = self.parse_map_bump()?;
                     material.map_bump = map_bump.map(String::from);
                 }
+                Some("norm") => {
+                    let map_normal = self.parse_map_normal()?;
+                    material.map_normal = map_normal.map(String::from);
+                }
                 Some("disp") => {
                     let map_displacement = self.parse_map_displacement()?;
                     material.map_displacement = map_displacement.map(String::from);
@@ -694,9 +2026,21 @@ impl<'a> Parser<'a> {
                     let map_decal = self.parse_map_decal()?;
                     material.map_decal = map_decal.map(String::from);
                 }
+                Some("map_aat") => {
+                    material.anti_alias_maps = self.parse_map_aat()?;
+                }
                 Some("newmtl") | None => {
                     break;
                 }
+                Some(other_st) if self.options.statement_handler.is_some() => {
+                    self.advance();
+                    let arguments = self.parse_rest_of_line();
+                    if let Some(ref mut handler) = self.options.statement_handler {
+                        if let Err(message) = handler.handle(&mut material, other_st, &arguments) {
+                            return self.error(ErrorKind::ErrorParsingMaterial, message);
+                        }
+                    }
+                }
                 Some(other_st) => {
                     return self.error(
                         ErrorKind::ErrorParsingMaterial,
@@ -707,6 +2051,18 @@ impl<'a> Parser<'a> {
             self.skip_zero_or_more_newlines();
         }
 
+        material.dissolve = match (dissolve, material.transparency) {
+            (Some(dissolve), None) => dissolve,
+            (None, Some(transparency)) => 1_f64 - transparency,
+            (Some(dissolve), Some(transparency)) => match self.options.dissolve_conflict {
+                DissolveConflict::PreferDissolve => dissolve,
+                DissolveConflict::PreferTransparency => 1_f64 - transparency,
+                DissolveConflict::LastWins if transparency_is_last => 1_f64 - transparency,
+                DissolveConflict::LastWins => dissolve,
+            },
+            (None, None) => material.dissolve,
+        };
+
         Ok(material)
     }
 
@@ -743,313 +2099,1556 @@ impl<'a> Parser<'a> {
     ///     bump height.png
     /// ");
     /// // let expected = ...;
-    /// # let expected = MaterialSet {
-    /// #     materials: vec![Material {
-    /// #         name: String::from("my_material"),
-    /// #         color_ambient: Color { r: 0.0435, g: 0.0435, b: 0.0435 },
-    /// #         color_diffuse: Color { r: 0.1086, g: 0.1086, b: 0.1086 },
-    /// #         color_specular: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
-    /// #         color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-    /// #         specular_exponent: 10.0000,
-    /// #         dissolve: 0.6600,
-    /// #         optical_density: Some(1.19713),
-    /// #         illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-    /// #         map_ambient: Some(String::from("ambient.jpg")),
-    /// #         map_diffuse: Some(String::from("diffuse.jpg")),
-    /// #         map_specular: Some(String::from("specular.jpg")),
-    /// #         map_emissive: Some(String::from("emissive.jpg")),
-    /// #         map_specular_exponent: Some(String::from("specular_exponent.jpg")),
-    /// #         map_bump: Some(String::from("height.png")),
-    /// #         map_displacement: Some(String::from("displacement.png")),
-    /// #         map_dissolve: Some(String::from("dissolve.png")),
-    /// #         map_decal: Some(String::from("decal.jpg")),
-    /// #     }]
-    /// # };
+    /// # let expected = MaterialSet::new(vec![Material {
+    /// #     name: String::from("my_material"),
+    /// #     color_ambient: Color { r: 0.0435, g: 0.0435, b: 0.0435 },
+    /// #     color_diffuse: Color { r: 0.1086, g: 0.1086, b: 0.1086 },
+    /// #     color_specular: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
+    /// #     color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+    /// #     emissive_strength: None,
+    /// #     specular_exponent: 10.0000,
+    /// #     dissolve: 0.6600,
+    /// #     optical_density: Some(1.19713),
+    /// #     illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+    /// #     map_ambient: Some(String::from("ambient.jpg")),
+    /// #     map_diffuse: Some(String::from("diffuse.jpg")),
+    /// #     map_specular: Some(String::from("specular.jpg")),
+    /// #     map_emissive: Some(String::from("emissive.jpg")),
+    /// #     map_specular_exponent: Some(String::from("specular_exponent.jpg")),
+    /// #     map_bump: Some(String::from("height.png")),
+    /// #     map_normal: None,
+    /// #     map_displacement: Some(String::from("displacement.png")),
+    /// #     map_dissolve: Some(String::from("dissolve.png")),
+    /// #     map_decal: Some(String::from("decal.jpg")),
+    /// #     anti_alias_maps: false,
+    /// #     transparency: None,
+    /// #     extras: Default::default(),
+    /// #     line_number: 2,
+    /// #     end_line_number: 18,
+    /// # }]);
     /// let result = Parser::new(&mtl_file).parse_mtlset();
     /// assert!(result.is_ok());
     ///
     /// let result = result.unwrap();
     /// assert_eq!(result, expected);
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn parse_mtlset(&mut self) -> Result<MaterialSet, ParseError> {
+        if let Some(not_text) = input::NotText::find_nul(self.input) {
+            return Err(ParseError::not_a_text_file(not_text));
+        }
+
         self.skip_zero_or_more_newlines();
 
-        let mut materials = Vec::new();
+        let mut materials = Vec::new();
+
+        while let Some("newmtl") = self.peek() {
+            let material = self.parse_material()?;
+            materials.push(material);
+        }
+
+        if let Some(st) = self.peek() {
+            return self.error(
+                ErrorKind::ExpectedEndOfInput,
+                format!("Expected end of input but got `{}`.", st),
+            );
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            lines = self.line_number,
+            materials = materials.len(),
+            "Parsed a material library."
+        );
+
+        Ok(MaterialSet {
+            materials: materials,
+            warnings: std::mem::take(&mut self.warnings),
+            name_index: Default::default(),
+        })
+    }
+}
+
+
+/// A range of bytes in the source text of a [`Document`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The byte offset of the start of the range.
+    pub start: usize,
+    /// The byte offset one past the end of the range.
+    pub end: usize,
+}
+
+/// Determine whether a character separates the tokens of a statement.
+#[inline]
+fn is_statement_separator(ch: char) -> bool {
+    ch == '\r' || is_separator(ch)
+}
+
+/// One line of a material library in a [`Document`].
+///
+/// A statement consists of an optional keyword, e.g. `Kd`, the arguments of
+/// the keyword, and an optional trailing comment. Blank lines and comment
+/// lines are statements without a keyword. A statement parsed from a source
+/// text remembers its original text, and writes it back verbatim until it is
+/// modified.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    /// The keyword of the statement.
+    keyword: Option<String>,
+    /// The arguments of the statement.
+    arguments: Vec<String>,
+    /// The text of the comment following the `#`, if any.
+    comment: Option<String>,
+    /// The line number of the statement in the source text.
+    line_number: Option<usize>,
+    /// The location of the statement in the source text.
+    span: Option<Span>,
+    /// The text of the statement as it appears in the source text, or `None`
+    /// if the statement was created or modified after parsing.
+    original: Option<String>,
+    /// The line terminator following the statement.
+    line_ending: String,
+}
+
+impl Statement {
+    /// Construct a new statement from a keyword and its arguments.
+    pub fn new<S: Into<String>>(keyword: S, arguments: Vec<String>) -> Statement {
+        Statement {
+            keyword: Some(keyword.into()),
+            arguments: arguments,
+            comment: None,
+            line_number: None,
+            span: None,
+            original: None,
+            line_ending: String::from("\n"),
+        }
+    }
+
+    /// Construct a new statement consisting of a comment only. The text of the
+    /// comment follows the `#`.
+    pub fn new_comment<S: Into<String>>(comment: S) -> Statement {
+        Statement {
+            keyword: None,
+            arguments: vec![],
+            comment: Some(comment.into()),
+            line_number: None,
+            span: None,
+            original: None,
+            line_ending: String::from("\n"),
+        }
+    }
+
+    /// Construct a new blank line.
+    pub fn new_blank() -> Statement {
+        Statement {
+            keyword: None,
+            arguments: vec![],
+            comment: None,
+            line_number: None,
+            span: None,
+            original: None,
+            line_ending: String::from("\n"),
+        }
+    }
+
+    /// Parse one line of source text.
+    fn parse(line: &str, line_ending: &str, line_number: usize, start: usize) -> Statement {
+        let mut tokens = vec![];
+        let mut comment = None;
+        let mut token_start = None;
+        for (i, ch) in line.char_indices().chain(Some((line.len(), ' '))) {
+            if is_statement_separator(ch) || ch == '#' {
+                if let Some(start) = token_start.take() {
+                    tokens.push(&line[start..i]);
+                }
+                if ch == '#' {
+                    comment = Some(String::from(line[(i + 1)..].trim_end()));
+                    break;
+                }
+            } else if token_start.is_none() {
+                token_start = Some(i);
+            }
+        }
+
+        let mut tokens = tokens.into_iter().map(String::from);
+        let keyword = tokens.next();
+        let arguments = tokens.collect();
+
+        Statement {
+            keyword: keyword,
+            arguments: arguments,
+            comment: comment,
+            line_number: Some(line_number),
+            span: Some(Span {
+                start: start,
+                end: start + line.len(),
+            }),
+            original: Some(String::from(line)),
+            line_ending: String::from(line_ending),
+        }
+    }
+
+    /// The keyword of the statement, or `None` for blank lines and comment lines.
+    pub fn keyword(&self) -> Option<&str> {
+        self.keyword.as_deref()
+    }
+
+    /// The arguments of the statement.
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    /// The text of the comment following the `#`, if the statement has one.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// The line number of the statement in the source text, or `None` if the
+    /// statement was not parsed from a source text.
+    pub fn line_number(&self) -> Option<usize> {
+        self.line_number
+    }
+
+    /// The location of the statement in the source text, excluding the line
+    /// terminator, or `None` if the statement was not parsed from a source text.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Determine whether the statement was created or modified after parsing.
+    pub fn is_modified(&self) -> bool {
+        self.original.is_none()
+    }
+
+    /// Replace the keyword of the statement.
+    pub fn set_keyword<S: Into<String>>(&mut self, keyword: S) {
+        self.keyword = Some(keyword.into());
+        self.original = None;
+    }
+
+    /// Replace the arguments of the statement.
+    pub fn set_arguments(&mut self, arguments: Vec<String>) {
+        self.arguments = arguments;
+        self.original = None;
+    }
+
+    /// Replace the comment of the statement.
+    pub fn set_comment(&mut self, comment: Option<String>) {
+        self.comment = comment;
+        self.original = None;
+    }
+
+    /// Write the statement in canonical form, without a line terminator.
+    ///
+    /// The canonical form separates the keyword and each argument by a single
+    /// space, and separates a trailing comment from the statement by a space.
+    pub fn format(&self) -> String {
+        let mut string = String::new();
+        if let Some(ref keyword) = self.keyword {
+            string += keyword;
+            for argument in self.arguments.iter() {
+                string += " ";
+                string += argument;
+            }
+        }
+        if let Some(ref comment) = self.comment {
+            if !string.is_empty() {
+                string += " ";
+            }
+            string += "#";
+            string += comment;
+        }
+
+        string
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.original {
+            Some(ref original) => write!(formatter, "{}", original),
+            None => write!(formatter, "{}", self.format()),
+        }
+    }
+}
+
+/// A lossless representation of a material library.
+///
+/// Unlike a [`MaterialSet`], a document keeps every line of the source text,
+/// including comments, blank lines, and statements the crate does not
+/// understand, in order. Displaying a document reproduces its source text
+/// exactly, except for the statements that were modified, which are written
+/// in canonical form. This makes documents suitable for editing hand-written
+/// material libraries without disturbing their layout.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl::Document;
+/// #
+/// let mtl_file = "# A shiny material.\nnewmtl shiny\n\tKd  1.0 0.0 0.0   # red\n\tNs 10\n";
+/// let mut document = Document::parse(mtl_file);
+/// assert_eq!(document.to_string(), mtl_file);
+///
+/// let range = document.material_range("shiny").unwrap();
+/// let statement = document.statements[range]
+///     .iter_mut()
+///     .find(|statement| statement.keyword() == Some("Ns"))
+///     .unwrap();
+/// statement.set_arguments(vec![String::from("100")]);
+///
+/// assert_eq!(
+///     document.to_string(),
+///     "# A shiny material.\nnewmtl shiny\n\tKd  1.0 0.0 0.0   # red\nNs 100\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Document {
+    /// The statements of the document, one per line.
+    pub statements: Vec<Statement>,
+}
+
+impl Document {
+    /// Parse a material library into a lossless document.
+    ///
+    /// Parsing a document never fails: every line becomes a statement, whether
+    /// or not it is a valid MTL statement. Use [`Document::to_material_set`] to
+    /// check the contents of the document.
+    pub fn parse(input: &str) -> Document {
+        let mut statements = vec![];
+        let mut start = 0;
+        let mut line_number = 1;
+        while start < input.len() {
+            let (line, line_ending) = match input[start..].find('\n') {
+                Some(length) => {
+                    let line = &input[start..(start + length)];
+                    match line.strip_suffix('\r') {
+                        Some(line) => (line, "\r\n"),
+                        None => (line, "\n"),
+                    }
+                }
+                None => (&input[start..], ""),
+            };
+            statements.push(Statement::parse(line, line_ending, line_number, start));
+            start += line.len() + line_ending.len();
+            line_number += 1;
+        }
+
+        Document {
+            statements: statements,
+        }
+    }
+
+    /// The range of indices of the statements belonging to the material with
+    /// the given name, starting with its `newmtl` statement.
+    pub fn material_range(&self, name: &str) -> Option<ops::Range<usize>> {
+        let is_newmtl = |statement: &Statement| statement.keyword() == Some("newmtl");
+        let start = self.statements.iter().position(|statement| {
+            is_newmtl(statement) && statement.arguments().first().map(String::as_str) == Some(name)
+        })?;
+        let end = self.statements[(start + 1)..]
+            .iter()
+            .position(is_newmtl)
+            .map(|length| start + 1 + length)
+            .unwrap_or(self.statements.len());
+
+        Some(start..end)
+    }
+
+    /// Write the document in canonical form.
+    ///
+    /// Each statement is written in canonical form on its own line terminated
+    /// by a newline, and runs of blank lines are collapsed into one.
+    pub fn format(&self) -> String {
+        let mut string = String::new();
+        let mut previous_is_blank = false;
+        for statement in self.statements.iter() {
+            let line = statement.format();
+            if line.is_empty() && previous_is_blank {
+                continue;
+            }
+            previous_is_blank = line.is_empty();
+            string += &line;
+            string += "\n";
+        }
+
+        string
+    }
+
+    /// Parse the contents of the document into a material set.
+    pub fn to_material_set(&self) -> Result<MaterialSet, ParseError> {
+        parse(self.to_string())
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let last = self.statements.len().saturating_sub(1);
+        for (i, statement) in self.statements.iter().enumerate() {
+            // Only the last line of a source text may lack a line terminator.
+            let line_ending = if statement.line_ending.is_empty() && (i != last || statement.is_modified()) {
+                "\n"
+            } else {
+                &statement.line_ending
+            };
+            write!(formatter, "{}{}", statement, line_ending)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Writes a material set as Wavefront MTL text, as written by the
+/// [`TextMaterialSetCompositor`].
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl;
+/// #
+/// let material_set = mtl::parse("newmtl red\nKd 1 0 0\n").unwrap();
+/// let text = material_set.to_string();
+///
+/// assert!(text.starts_with("newmtl red\n"));
+/// let reparsed = mtl::parse(&text).unwrap();
+/// assert_eq!(reparsed.materials[0].color_diffuse, material_set.materials[0].color_diffuse);
+/// ```
+impl fmt::Display for MaterialSet {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        formatter.write_str(&TextMaterialSetCompositor::new().compose(self))
+    }
+}
+
+/// Write a material set as a gzip compressed stream using a compositor, and
+/// return the writer once the stream is finished.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl;
+/// # use wavefront_obj::mtl::TextMaterialSetCompositor;
+/// # use flate2::read::GzDecoder;
+/// # use std::io::Read;
+/// #
+/// let material_set = mtl::parse("newmtl red\nKd 1 0 0\n").unwrap();
+/// let compressed = mtl::write_gzip(vec![], &material_set, &TextMaterialSetCompositor::new()).unwrap();
+///
+/// let mut text = String::new();
+/// GzDecoder::new(&compressed[..]).read_to_string(&mut text).unwrap();
+/// assert_eq!(text, material_set.to_string());
+/// ```
+#[cfg(feature = "flate2")]
+pub fn write_gzip<W: io::Write, C: Compositor<MaterialSet> + ?Sized>(
+    writer: W,
+    material_set: &MaterialSet,
+    compositor: &C,
+) -> io::Result<W> {
+    let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+    compositor.compose_to(material_set, &mut encoder)?;
+
+    encoder.finish()
+}
+
+/// A compositor that writes a material set as Wavefront MTL text.
+///
+/// Every material is written in full, so parsing the output yields the same
+/// materials as the original ones, apart from their line numbers. A `Tr`
+/// statement is written before the `d` statement, so that the dissolve
+/// survives every [`DissolveConflict`] rule except
+/// [`DissolveConflict::PreferTransparency`]. The extras of a material are
+/// written after its other statements, and only parse again with a
+/// [`StatementHandler`] such as [`StoreExtras`].
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl;
+/// # use wavefront_obj::mtl::{
+/// #     Compositor,
+/// #     TextMaterialSetCompositor,
+/// # };
+/// #
+/// let material_set = mtl::parse("newmtl shiny\nKd 1 0 0\nNs 250\nmap_Kd shiny.png\n").unwrap();
+/// let text = TextMaterialSetCompositor::new().compose(&material_set);
+///
+/// assert!(text.contains("Ns 250\n"));
+/// assert!(text.contains("map_Kd shiny.png\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TextMaterialSetCompositor {}
+
+impl TextMaterialSetCompositor {
+    /// Construct a new Wavefront MTL text compositor.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Write a single material.
+    fn write_material<W: io::Write>(&self, writer: &mut W, material: &Material) -> io::Result<()> {
+        writeln!(writer, "newmtl {}", material.name)?;
+        let colors = [
+            ("Ka", material.color_ambient),
+            ("Kd", material.color_diffuse),
+            ("Ks", material.color_specular),
+        ];
+        for (keyword, color) in colors.iter() {
+            writeln!(writer, "{} {} {} {}", keyword, color.r, color.g, color.b)?;
+        }
+        if material.emissive_strength.is_some() || material.color_emissive != Color::zero() {
+            let color = material.color_emissive;
+            writeln!(writer, "Ke {} {} {}", color.r, color.g, color.b)?;
+        }
+        writeln!(writer, "Ns {}", material.specular_exponent)?;
+        if let Some(optical_density) = material.optical_density {
+            writeln!(writer, "Ni {}", optical_density)?;
+        }
+        if let Some(transparency) = material.transparency {
+            writeln!(writer, "Tr {}", transparency)?;
+        }
+        writeln!(writer, "d {}", material.dissolve)?;
+        writeln!(writer, "illum {}", material.illumination_model.number())?;
+
+        let maps = [
+            ("map_Ka", &material.map_ambient),
+            ("map_Kd", &material.map_diffuse),
+            ("map_Ks", &material.map_specular),
+            ("map_Ke", &material.map_emissive),
+            ("map_Ns", &material.map_specular_exponent),
+            ("bump", &material.map_bump),
+            ("norm", &material.map_normal),
+            ("disp", &material.map_displacement),
+            ("map_d", &material.map_dissolve),
+            ("decal", &material.map_decal),
+        ];
+        for (keyword, map) in maps.iter() {
+            if let Some(ref map_name) = **map {
+                writeln!(writer, "{} {}", keyword, map_name)?;
+            }
+        }
+        if material.anti_alias_maps {
+            writer.write_all(b"map_aat on\n")?;
+        }
+
+        for (keyword, arguments) in material.extras.iter() {
+            writer.write_all(keyword.as_bytes())?;
+            for argument in arguments.iter() {
+                write!(writer, " {}", argument)?;
+            }
+            writer.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Compositor<MaterialSet> for TextMaterialSetCompositor {
+    fn compose_to(&self, material_set: &MaterialSet, writer: &mut dyn io::Write) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(writer);
+        for (index, material) in material_set.materials.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b"\n")?;
+            }
+            self.write_material(&mut writer, material)?;
+        }
+
+        writer.flush()
+    }
+}
+
+
+#[cfg(test)]
+mod mtl_primitive_tests {
+    use super::{
+        Color,
+        Parser,
+    };
+
+
+    #[test]
+    fn test_parse_f64() {
+        let mut parser = Parser::new("-1.929448");
+        assert_eq!(parser.parse_f64(), Ok(-1.929448));
+    }
+
+    #[test]
+    fn test_parse_usize() {
+        let mut parser = Parser::new("    763   ");
+        assert_eq!(parser.parse_usize(), Ok(763));
+    }
+
+    #[test]
+    fn test_parse_color() {
+        let mut parser = Parser::new("    0.1345345 0.63453 0.982430   ");
+        assert_eq!(
+            parser.parse_color(),
+            Ok(Color {
+                r: 0.1345345,
+                g: 0.63453,
+                b: 0.982430
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod mtl_color_tests {
+    use super::Color;
+
+
+    #[test]
+    fn test_color_from_hex() {
+        let expected = Some(Color {
+            r: 1.0,
+            g: 0.0,
+            b: 51.0 / 255.0,
+        });
+
+        assert_eq!(Color::from_hex("#FF0033"), expected);
+        assert_eq!(Color::from_hex("ff0033"), expected);
+    }
+
+    #[test]
+    fn test_color_from_hex_invalid() {
+        assert_eq!(Color::from_hex("#ff003"), None);
+        assert_eq!(Color::from_hex("#ff00gg"), None);
+        assert_eq!(Color::from_hex("#+f0033"), None);
+        assert_eq!(Color::from_hex("#ff00€"), None);
+    }
+
+    #[test]
+    fn test_color_to_hex_clamps() {
+        let color = Color {
+            r: 1.5,
+            g: -0.5,
+            b: 0.2,
+        };
+
+        assert_eq!(color.to_hex(), "#ff0033");
+    }
+
+    #[test]
+    fn test_color_linear_srgb_roundtrip() {
+        for &value in [0.0, 0.002, 0.04, 0.2, 0.5, 0.75, 1.0].iter() {
+            let color = Color {
+                r: value,
+                g: value,
+                b: value,
+            };
+            let result = color.to_linear().to_srgb();
+
+            assert!((result.r - value).abs() < 1e-12, "value = {}", value);
+        }
+    }
+
+    #[test]
+    fn test_color_array_conversion() {
+        let color = Color::from_array([0.1, 0.2, 0.3]);
+
+        assert_eq!(color.to_array(), [0.1, 0.2, 0.3]);
+    }
+}
+
+#[cfg(test)]
+mod mtl_illumination_statement_tests {
+    use super::{
+        Color,
+        ErrorKind,
+        IlluminationModel,
+        Parser,
+    };
+
+
+    #[test]
+    fn test_parse_ambient_component() {
+        let mut parser = Parser::new("Ka 0.1345345 0.63453 0.982430");
+        let expected = Ok(Color {
+            r: 0.1345345,
+            g: 0.63453,
+            b: 0.982430,
+        });
+        let result = parser.parse_ambient_component();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_diffuse_component() {
+        let mut parser = Parser::new("Kd 0.1345345 0.63453 0.982430");
+        let expected = Ok(Color {
+            r: 0.1345345,
+            g: 0.63453,
+            b: 0.982430,
+        });
+        let result = parser.parse_diffuse_component();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_specular_component() {
+        let mut parser = Parser::new("Ks 0.1345345 0.63453 0.982430");
+        let expected = Ok(Color {
+            r: 0.1345345,
+            g: 0.63453,
+            b: 0.982430,
+        });
+        let result = parser.parse_specular_component();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_emissive_component() {
+        let mut parser = Parser::new("Ke 0.1345345 0.63453 0.982430");
+        let expected = Ok(Color {
+            r: 0.1345345,
+            g: 0.63453,
+            b: 0.982430,
+        });
+        let result = parser.parse_emissive_component();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_dissolve_component() {
+        let mut parser = Parser::new("d 0.24325634");
+        let expected = Ok(0.24325634);
+        let result = parser.parse_dissolve_component();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_specular_exponent() {
+        let mut parser = Parser::new("Ns 3.24325634");
+        let expected = Ok(3.24325634);
+        let result = parser.parse_specular_exponent();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_optical_density() {
+        let mut parser = Parser::new("Ni 1.24325634");
+        let expected = Ok(1.24325634);
+        let result = parser.parse_optical_density();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_ambient() {
+        let mut parser = Parser::new("map_Ka ambient.png");
+        let expected = Ok(Some("ambient.png"));
+        let result = parser.parse_map_ambient();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_diffuse() {
+        let mut parser = Parser::new("map_Kd diffuse.png");
+        let expected = Ok(Some("diffuse.png"));
+        let result = parser.parse_map_diffuse();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_specular() {
+        let mut parser = Parser::new("map_Ks specular.png");
+        let expected = Ok(Some("specular.png"));
+        let result = parser.parse_map_specular();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_emissive() {
+        let mut parser = Parser::new("map_Ke emissive.png");
+        let expected = Ok(Some("emissive.png"));
+        let result = parser.parse_map_emissive();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_bump1() {
+        let mut parser = Parser::new("map_Bump normal.png");
+        let expected = Ok(Some("normal.png"));
+        let result = parser.parse_map_bump();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_bump2() {
+        let mut parser = Parser::new("bump normal.png");
+        let expected = Ok(Some("normal.png"));
+        let result = parser.parse_map_bump();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_displacement() {
+        let mut parser = Parser::new("disp roughness.png");
+        let expected = Ok(Some("roughness.png"));
+        let result = parser.parse_map_displacement();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_dissolve() {
+        let mut parser = Parser::new("map_d alpha.png");
+        let expected = Ok(Some("alpha.png"));
+        let result = parser.parse_map_dissolve();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_decal() {
+        let mut parser = Parser::new("decal decal.png");
+        let expected = Ok(Some("decal.png"));
+        let result = parser.parse_map_decal();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_illumination_model0() {
+        let mut parser = Parser::new("illum 0");
+        let expected = Ok(IlluminationModel::Ambient);
+        let result = parser.parse_illumination_model();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_illumination_model1() {
+        let mut parser = Parser::new("illum 1");
+        let expected = Ok(IlluminationModel::AmbientDiffuse);
+        let result = parser.parse_illumination_model();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_illumination_model2() {
+        let mut parser = Parser::new("illum 2");
+        let expected = Ok(IlluminationModel::AmbientDiffuseSpecular);
+        let result = parser.parse_illumination_model();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_illumination_model3() {
+        let mut parser = Parser::new("illum 7");
+        let expected = Ok(IlluminationModel::Other(7));
+        let result = parser.parse_illumination_model();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_illumination_model4() {
+        let mut parser = Parser::new("illum 256");
+        let expected_kind = ErrorKind::UnknownIlluminationModel;
+        let result = parser.parse_illumination_model();
+        assert!(result.is_err());
+
+        let result_kind = result.unwrap_err().kind;
+        assert_eq!(result_kind, expected_kind);
+    }
+
+    #[test]
+    fn test_parse_newmtl1() {
+        let mut parser = Parser::new("newmtl material_name");
+        let expected = Ok("material_name");
+        let result = parser.parse_newmtl();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_newmtl2() {
+        let mut parser = Parser::new("newmtl    ");
+        let result = parser.parse_newmtl();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_map_normal() {
+        let mut parser = Parser::new("norm normal.png");
+        let expected = Ok(Some("normal.png"));
+        let result = parser.parse_map_normal();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_bump_and_normal_maps_are_distinct() {
+        let mut parser = Parser::new("newmtl material\nbump height.png\nnorm normal.png\n");
+        let result = parser.parse_material().unwrap();
+
+        assert_eq!(result.map_bump, Some(String::from("height.png")));
+        assert_eq!(result.map_normal, Some(String::from("normal.png")));
+    }
+
+    #[test]
+    fn test_emissive_low_dynamic_range() {
+        let mut parser = Parser::new("newmtl material\nKe 0.5 0.25 0.0\n");
+        let result = parser.parse_material().unwrap();
+        let expected = Color {
+            r: 0.5,
+            g: 0.25,
+            b: 0.0,
+        };
+
+        assert_eq!(result.emissive_strength, Some(0.5));
+        assert_eq!(result.emissive_color(), expected);
+        assert_eq!(result.emissive_intensity(), 1.0);
+        assert_eq!(result.emissive(), expected);
+    }
+
+    #[test]
+    fn test_emissive_without_emission() {
+        let mut parser = Parser::new("newmtl material\nKd 1.0 1.0 1.0\n");
+        let result = parser.parse_material().unwrap();
+
+        assert_eq!(result.emissive_strength, None);
+        assert_eq!(result.emissive_intensity(), 0.0);
+        assert_eq!(result.emissive(), Color::zero());
+    }
+
+    #[test]
+    fn test_parse_map_aat_on() {
+        let mut parser = Parser::new("map_aat on");
+        let expected = Ok(true);
+        let result = parser.parse_map_aat();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_aat_off() {
+        let mut parser = Parser::new("map_aat off");
+        let expected = Ok(false);
+        let result = parser.parse_map_aat();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map_aat_invalid() {
+        let mut parser = Parser::new("map_aat yes");
+        let result = parser.parse_map_aat();
+
+        assert!(result.is_err());
+    }
+}
+
+
+#[cfg(test)]
+mod mtl_transparency_tests {
+    use super::{
+        DissolveConflict,
+        Parser,
+        ParserOptions,
+    };
+
+
+    fn parse_dissolve(input: &str, dissolve_conflict: DissolveConflict) -> (f64, Option<f64>) {
+        let options = ParserOptions {
+            dissolve_conflict: dissolve_conflict,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(input, options);
+        let material = parser.parse_material().unwrap();
+
+        (material.dissolve, material.transparency)
+    }
+
+    #[test]
+    fn test_parse_transparency_component() {
+        let mut parser = Parser::new("Tr 0.25");
+        assert_eq!(parser.parse_transparency_component(), Ok(0.25));
+    }
+
+    #[test]
+    fn test_transparency_without_dissolve() {
+        let result = parse_dissolve("newmtl glass\nTr 0.25\n", DissolveConflict::PreferDissolve);
+        assert_eq!(result, (0.75, Some(0.25)));
+    }
+
+    #[test]
+    fn test_dissolve_without_transparency() {
+        let result = parse_dissolve("newmtl glass\nd 0.5\n", DissolveConflict::PreferTransparency);
+        assert_eq!(result, (0.5, None));
+    }
+
+    #[test]
+    fn test_conflict_prefer_dissolve() {
+        let result = parse_dissolve("newmtl glass\nTr 0.25\nd 0.5\n", DissolveConflict::PreferDissolve);
+        assert_eq!(result, (0.5, Some(0.25)));
+    }
+
+    #[test]
+    fn test_conflict_prefer_transparency() {
+        let result = parse_dissolve(
+            "newmtl glass\nd 0.5\nTr 0.25\n",
+            DissolveConflict::PreferTransparency,
+        );
+        assert_eq!(result, (0.75, Some(0.25)));
+    }
+
+    #[test]
+    fn test_conflict_last_wins() {
+        let result = parse_dissolve("newmtl glass\nd 0.5\nTr 0.25\n", DissolveConflict::LastWins);
+        assert_eq!(result, (0.75, Some(0.25)));
+
+        let result = parse_dissolve("newmtl glass\nTr 0.25\nd 0.5\n", DissolveConflict::LastWins);
+        assert_eq!(result, (0.5, Some(0.25)));
+    }
+}
+
+
+#[cfg(test)]
+mod material_set_lookup_tests {
+    use super::{
+        Material,
+        Parser,
+    };
+
+
+    const MTL_FILE: &str = "newmtl red\nKd 1 0 0\nnewmtl green\nKd 0 1 0\nnewmtl red\nKd 0.5 0 0\n";
+
+    #[test]
+    fn test_get_finds_the_first_material_with_a_name() {
+        let material_set = Parser::new(MTL_FILE).parse_mtlset().unwrap();
+
+        assert_eq!(material_set.position("red"), Some(0));
+        assert_eq!(material_set.position("green"), Some(1));
+        assert_eq!(
+            material_set.get("red").map(|material| material.color_diffuse.r),
+            Some(1.0)
+        );
+        assert!(material_set.contains("green"));
+        assert!(!material_set.contains("blue"));
+        assert_eq!(material_set.get("blue"), None);
+    }
+
+    #[test]
+    fn test_get_mut_modifies_the_material() {
+        let mut material_set = Parser::new(MTL_FILE).parse_mtlset().unwrap();
+        material_set.get_mut("green").unwrap().specular_exponent = 25.0;
+
+        assert_eq!(material_set.materials[1].specular_exponent, 25.0);
+    }
+
+    #[test]
+    fn test_lookup_after_changing_the_materials() {
+        let mut material_set = Parser::new(MTL_FILE).parse_mtlset().unwrap();
+        assert_eq!(material_set.position("green"), Some(1));
+
+        material_set.materials.remove(0);
+        let mut blue = Material::new();
+        blue.name = String::from("blue");
+        material_set.materials.push(blue);
+        material_set.get_mut("red").unwrap().name = String::from("dark_red");
+
+        assert_eq!(material_set.position("green"), Some(0));
+        assert_eq!(material_set.position("blue"), Some(2));
+        assert_eq!(material_set.position("dark_red"), Some(1));
+        assert_eq!(material_set.position("red"), None);
+    }
+
+    #[test]
+    fn test_lookup_after_renaming_a_material_to_a_later_name() {
+        let mut material_set = Parser::new(MTL_FILE).parse_mtlset().unwrap();
+        assert_eq!(material_set.position("green"), Some(1));
 
-        while let Some("newmtl") = self.peek() {
-            let material = self.parse_material()?;
-            materials.push(material);
-        }
+        material_set.get_mut("red").unwrap().name = String::from("green");
+        assert_eq!(material_set.position("green"), Some(0));
 
-        if let Some(st) = self.peek() {
-            return self.error(
-                ErrorKind::ExpectedEndOfInput,
-                format!("Expected end of input but got `{}`.", st),
-            );
-        }
+        material_set.materials_mut()[0].name = String::from("blue");
+        assert_eq!(material_set.position("green"), Some(1));
+        assert_eq!(material_set.position("red"), Some(2));
+    }
+
+    #[test]
+    fn test_name_index_does_not_affect_equality() {
+        let material_set = Parser::new(MTL_FILE).parse_mtlset().unwrap();
+        let other = material_set.clone();
+        material_set.position("green");
 
-        Ok(MaterialSet { materials: materials })
+        assert_eq!(material_set, other);
+        assert_eq!(material_set.clone().position("green"), Some(1));
     }
 }
 
-
 #[cfg(test)]
-mod mtl_primitive_tests {
+mod material_set_merge_tests {
     use super::{
-        Color,
+        MaterialConflict,
+        MaterialSet,
+        MergeError,
         Parser,
     };
 
 
+    fn parse(input: &str) -> MaterialSet {
+        Parser::new(input).parse_mtlset().unwrap()
+    }
+
+    fn diffuse_reds(material_set: &MaterialSet) -> Vec<(&str, f64)> {
+        material_set
+            .materials
+            .iter()
+            .map(|material| (material.name.as_str(), material.color_diffuse.r))
+            .collect()
+    }
+
+    const FIRST: &str = "newmtl red\nKd 1 0 0\nnewmtl green\nKd 0 1 0\n";
+    const SECOND: &str = "newmtl blue\nKd 0 0 1\nnewmtl red\nKd 0.5 0 0\n";
+
     #[test]
-    fn test_parse_f64() {
-        let mut parser = Parser::new("-1.929448");
-        assert_eq!(parser.parse_f64(), Ok(-1.929448));
+    fn test_merge_prefer_first() {
+        let mut material_set = parse(FIRST);
+        let report = material_set
+            .merge(parse(SECOND), MaterialConflict::PreferFirst)
+            .unwrap();
+
+        assert_eq!(report.conflicts, vec![String::from("red")]);
+        assert_eq!(
+            diffuse_reds(&material_set),
+            vec![("red", 1.0), ("green", 0.0), ("blue", 0.0)]
+        );
     }
 
     #[test]
-    fn test_parse_usize() {
-        let mut parser = Parser::new("    763   ");
-        assert_eq!(parser.parse_usize(), Ok(763));
+    fn test_merge_prefer_last() {
+        let mut material_set = parse(FIRST);
+        let report = material_set
+            .merge(parse(SECOND), MaterialConflict::PreferLast)
+            .unwrap();
+
+        assert_eq!(report.conflicts, vec![String::from("red")]);
+        assert_eq!(
+            diffuse_reds(&material_set),
+            vec![("red", 0.5), ("green", 0.0), ("blue", 0.0)]
+        );
     }
 
     #[test]
-    fn test_parse_color() {
-        let mut parser = Parser::new("    0.1345345 0.63453 0.982430   ");
+    fn test_merge_error_leaves_the_material_set_unchanged() {
+        let mut material_set = parse(FIRST);
+        let error = material_set
+            .merge(parse(SECOND), MaterialConflict::Error)
+            .unwrap_err();
+
         assert_eq!(
-            parser.parse_color(),
-            Ok(Color {
-                r: 0.1345345,
-                g: 0.63453,
-                b: 0.982430
-            })
+            error,
+            MergeError {
+                conflicts: vec![String::from("red")]
+            }
         );
+        assert_eq!(
+            error.to_string(),
+            "The material sets both define the materials `red`."
+        );
+        assert_eq!(material_set, parse(FIRST));
+    }
+
+    #[test]
+    fn test_merge_without_conflicts() {
+        let mut material_set = parse(FIRST);
+        let report = material_set
+            .merge(parse("newmtl blue\n"), MaterialConflict::Error)
+            .unwrap();
+
+        assert!(report.conflicts.is_empty());
+        assert_eq!(material_set.position("blue"), Some(2));
     }
 }
 
 #[cfg(test)]
-mod mtl_illumination_statement_tests {
+mod mtl_pbr_tests {
     use super::{
         Color,
-        ErrorKind,
-        IlluminationModel,
         Parser,
+        ParserOptions,
+        PbrMaterial,
+        StoreExtras,
     };
 
 
-    #[test]
-    fn test_parse_ambient_component() {
-        let mut parser = Parser::new("Ka 0.1345345 0.63453 0.982430");
-        let expected = Ok(Color {
-            r: 0.1345345,
-            g: 0.63453,
-            b: 0.982430,
-        });
-        let result = parser.parse_ambient_component();
+    fn to_pbr(input: &str) -> PbrMaterial {
+        let options = ParserOptions::default().statement_handler(StoreExtras);
+        let material_set = Parser::with_options(input, options).parse_mtlset().unwrap();
 
-        assert_eq!(result, expected);
+        material_set.materials[0].to_pbr()
     }
 
     #[test]
-    fn test_parse_diffuse_component() {
-        let mut parser = Parser::new("Kd 0.1345345 0.63453 0.982430");
-        let expected = Ok(Color {
-            r: 0.1345345,
-            g: 0.63453,
-            b: 0.982430,
-        });
-        let result = parser.parse_diffuse_component();
+    fn test_to_pbr_dielectric() {
+        let pbr = to_pbr("newmtl wood\nKd 0.6 0.4 0.2\nKs 0.2 0.2 0.2\nNs 0\nd 0.5\nbump wood_normal.png\n");
 
-        assert_eq!(result, expected);
+        assert_eq!(pbr.name, "wood");
+        assert_eq!(
+            pbr.base_color,
+            Color {
+                r: 0.6,
+                g: 0.4,
+                b: 0.2
+            }
+        );
+        assert_eq!(pbr.metallic, 0.0);
+        assert_eq!(pbr.roughness, 1.0);
+        assert_eq!(pbr.alpha, 0.5);
+        assert_eq!(pbr.normal_map.as_deref(), Some("wood_normal.png"));
     }
 
     #[test]
-    fn test_parse_specular_component() {
-        let mut parser = Parser::new("Ks 0.1345345 0.63453 0.982430");
-        let expected = Ok(Color {
-            r: 0.1345345,
-            g: 0.63453,
-            b: 0.982430,
-        });
-        let result = parser.parse_specular_component();
+    fn test_to_pbr_metal() {
+        let pbr = to_pbr("newmtl gold\nKd 0.2 0.2 0.2\nKs 1.0 0.8 0.4\nNs 198\n");
 
-        assert_eq!(result, expected);
+        assert_eq!(pbr.metallic, 1.0);
+        assert_eq!(
+            pbr.base_color,
+            Color {
+                r: 1.0,
+                g: 0.8,
+                b: 0.4
+            }
+        );
+        assert_eq!(pbr.roughness, 0.1);
     }
 
     #[test]
-    fn test_parse_emissive_component() {
-        let mut parser = Parser::new("Ke 0.1345345 0.63453 0.982430");
-        let expected = Ok(Color {
-            r: 0.1345345,
-            g: 0.63453,
-            b: 0.982430,
-        });
-        let result = parser.parse_emissive_component();
+    fn test_to_pbr_prefers_stored_pbr_statements() {
+        let pbr = to_pbr("newmtl steel\nKd 0.5 0.5 0.5\nKs 0.5 0.5 0.5\nNs 10\nPr 0.3\nPm 0.9\n");
 
-        assert_eq!(result, expected);
+        assert_eq!(pbr.metallic, 0.9);
+        assert_eq!(pbr.roughness, 0.3);
     }
 
     #[test]
-    fn test_parse_dissolve_component() {
-        let mut parser = Parser::new("d 0.24325634");
-        let expected = Ok(0.24325634);
-        let result = parser.parse_dissolve_component();
+    fn test_to_pbr_emissive() {
+        let pbr = to_pbr("newmtl lamp\nKe 4.0 2.0 0.0\nmap_Ke lamp.png\n");
 
-        assert_eq!(result, expected);
+        assert_eq!(
+            pbr.emissive,
+            Color {
+                r: 4.0,
+                g: 2.0,
+                b: 0.0
+            }
+        );
+        assert_eq!(pbr.emissive_map.as_deref(), Some("lamp.png"));
+        assert_eq!(pbr.base_color_map, None);
     }
+}
 
-    #[test]
-    fn test_parse_specular_exponent() {
-        let mut parser = Parser::new("Ns 3.24325634");
-        let expected = Ok(3.24325634);
-        let result = parser.parse_specular_exponent();
+#[cfg(test)]
+mod mtl_uniform_block_tests {
+    use super::{
+        MaterialUniformBlock,
+        Parser,
+    };
+    use std::mem;
 
-        assert_eq!(result, expected);
+
+    #[test]
+    fn test_uniform_block_layout() {
+        assert_eq!(mem::size_of::<MaterialUniformBlock>(), MaterialUniformBlock::SIZE);
+        assert_eq!(mem::align_of::<MaterialUniformBlock>(), 4);
     }
 
     #[test]
-    fn test_parse_optical_density() {
-        let mut parser = Parser::new("Ni 1.24325634");
-        let expected = Ok(1.24325634);
-        let result = parser.parse_optical_density();
+    fn test_uniform_block_values() {
+        let input = "\
+            newmtl lamp\n\
+            Ka 0.1 0.1 0.1\n\
+            Ke 4 2 0\n\
+            Ni 1.5\n\
+            Tr 0.25\n\
+            illum 5\n\
+            map_Ke lamp.png\n\
+            norm lamp_normal.png\n\
+            map_aat on\n\
+        ";
+        let material_set = Parser::new(input).parse_mtlset().unwrap();
+        let block = material_set.materials[0].uniform_block();
 
-        assert_eq!(result, expected);
+        assert_eq!(block.ambient, [0.1, 0.1, 0.1, 1.0]);
+        assert_eq!(block.diffuse, [0.0, 0.0, 0.0, 0.75]);
+        assert_eq!(block.emissive, [1.0, 0.5, 0.0, 4.0]);
+        assert_eq!(block.parameters, [0.0, 0.75, 1.5, 0.25]);
+        assert_eq!(
+            block.map_flags,
+            MaterialUniformBlock::MAP_EMISSIVE | MaterialUniformBlock::MAP_NORMAL
+        );
+        assert_eq!(block.illumination_model, 5);
+        assert_eq!(block.anti_alias_maps, 1);
     }
 
     #[test]
-    fn test_parse_map_ambient() {
-        let mut parser = Parser::new("map_Ka ambient.png");
-        let expected = Ok(Some("ambient.png"));
-        let result = parser.parse_map_ambient();
+    fn test_uniform_block_to_bytes() {
+        let material_set = Parser::new("newmtl red\nKd 1 0 0\nmap_d mask.png\nillum 2\n")
+            .parse_mtlset()
+            .unwrap();
+        let block = material_set.materials[0].uniform_block();
+        let bytes = block.to_bytes();
+
+        assert_eq!(bytes[16..20], 1_f32.to_ne_bytes());
+        assert_eq!(bytes[28..32], 1_f32.to_ne_bytes());
+        assert_eq!(bytes[80..84], MaterialUniformBlock::MAP_DISSOLVE.to_ne_bytes());
+        assert_eq!(bytes[84..88], 2_u32.to_ne_bytes());
+        assert_eq!(bytes[92..96], [0, 0, 0, 0]);
+    }
+}
 
-        assert_eq!(result, expected);
+#[cfg(test)]
+mod mtl_validation_tests {
+    use super::{
+        Parser,
+        WarningKind,
+    };
+
+
+    fn warning_kinds(input: &str) -> Vec<WarningKind> {
+        let mut parser = Parser::new(input);
+        let material_set = parser.parse_mtlset().unwrap();
+
+        material_set
+            .validate()
+            .iter()
+            .map(|warning| warning.kind)
+            .collect()
     }
 
     #[test]
-    fn test_parse_map_diffuse() {
-        let mut parser = Parser::new("map_Kd diffuse.png");
-        let expected = Ok(Some("diffuse.png"));
-        let result = parser.parse_map_diffuse();
+    fn test_validate_valid_material() {
+        let input = "newmtl valid\nKa 0 0 0\nKd 1 1 1\nKs 0.5 0.5 0.5\nKe 10 10 10\nd 0.5\nNs 10\nNi 1.5\n";
+        assert!(warning_kinds(input).is_empty());
+    }
 
-        assert_eq!(result, expected);
+    #[test]
+    fn test_validate_color_out_of_range() {
+        let input = "newmtl invalid\nKa -0.1 0 0\nKs 0 0 2\n";
+        assert_eq!(
+            warning_kinds(input),
+            vec![WarningKind::ColorOutOfRange, WarningKind::ColorOutOfRange]
+        );
     }
 
     #[test]
-    fn test_parse_map_specular() {
-        let mut parser = Parser::new("map_Ks specular.png");
-        let expected = Ok(Some("specular.png"));
-        let result = parser.parse_map_specular();
+    fn test_validate_dissolve_out_of_range() {
+        let input = "newmtl invalid\nd 1.5\nnewmtl invalid_transparency\nd 0.5\nTr -0.5\n";
+        assert_eq!(
+            warning_kinds(input),
+            vec![WarningKind::DissolveOutOfRange, WarningKind::DissolveOutOfRange]
+        );
+    }
 
-        assert_eq!(result, expected);
+    #[test]
+    fn test_validate_optical_density_below_one() {
+        let input = "newmtl invalid\nNi 0.5\n";
+        assert_eq!(warning_kinds(input), vec![WarningKind::OpticalDensityBelowOne]);
     }
 
     #[test]
-    fn test_parse_map_emissive() {
-        let mut parser = Parser::new("map_Ke emissive.png");
-        let expected = Ok(Some("emissive.png"));
-        let result = parser.parse_map_emissive();
+    fn test_validate_warning_names_material() {
+        let mut parser = Parser::new("newmtl first\nnewmtl second\nNs -1\n");
+        let warnings = parser.parse_mtlset().unwrap().validate();
 
-        assert_eq!(result, expected);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].material, "second");
     }
+}
+
+
+#[cfg(test)]
+mod mtl_statement_handler_tests {
+    use super::{
+        ErrorKind,
+        Material,
+        Parser,
+        ParserOptions,
+        StoreExtras,
+    };
+
 
     #[test]
-    fn test_parse_map_bump1() {
-        let mut parser = Parser::new("map_Bump normal.png");
-        let expected = Ok(Some("normal.png"));
-        let result = parser.parse_map_bump();
+    fn test_unknown_statement_without_handler() {
+        let mut parser = Parser::new("newmtl material\nPr 0.5\n");
+        let result = parser.parse_mtlset();
 
-        assert_eq!(result, expected);
+        assert_eq!(
+            result.map_err(|error| error.kind),
+            Err(ErrorKind::ErrorParsingMaterial)
+        );
     }
 
     #[test]
-    fn test_parse_map_bump2() {
-        let mut parser = Parser::new("bump normal.png");
-        let expected = Ok(Some("normal.png"));
-        let result = parser.parse_map_bump();
-
-        assert_eq!(result, expected);
+    fn test_store_extras() {
+        let options = ParserOptions::default().statement_handler(StoreExtras);
+        let mut parser = Parser::with_options("newmtl material\nPr 0.5\nKd 1 1 1\naniso\n", options);
+        let result = parser.parse_mtlset().unwrap();
+        let material = &result.materials[0];
+
+        assert_eq!(material.extras["Pr"], vec![String::from("0.5")]);
+        assert_eq!(material.extras["aniso"], Vec::<String>::new());
+        assert_eq!(material.extras.len(), 2);
     }
 
     #[test]
-    fn test_parse_map_displacement() {
-        let mut parser = Parser::new("disp roughness.png");
-        let expected = Ok(Some("roughness.png"));
-        let result = parser.parse_map_displacement();
+    fn test_closure_handler() {
+        let handler = |material: &mut Material, keyword: &str, arguments: &[&str]| match keyword {
+            "Ke_strength" => {
+                material.emissive_strength = arguments[0].parse().ok();
+                Ok(())
+            }
+            _ => Err(format!("Unsupported statement `{}`.", keyword)),
+        };
+        let options = ParserOptions::default().statement_handler(handler);
+        let mut parser = Parser::with_options("newmtl material\nKe_strength 4.0\n", options);
+        let result = parser.parse_mtlset().unwrap();
 
-        assert_eq!(result, expected);
+        assert_eq!(result.materials[0].emissive_strength, Some(4.0));
+        assert!(result.materials[0].extras.is_empty());
     }
 
     #[test]
-    fn test_parse_map_dissolve() {
-        let mut parser = Parser::new("map_d alpha.png");
-        let expected = Ok(Some("alpha.png"));
-        let result = parser.parse_map_dissolve();
-
-        assert_eq!(result, expected);
+    fn test_handler_rejects_statement() {
+        let handler = |_: &mut Material, keyword: &str, _: &[&str]| Err(format!("Rejected `{}`.", keyword));
+        let options = ParserOptions::default().statement_handler(handler);
+        let mut parser = Parser::with_options("newmtl material\n\nPr 0.5\n", options);
+        let error = parser.parse_mtlset().unwrap_err();
+
+        assert_eq!(error.line_number, 3);
+        assert_eq!(error.message, "Rejected `Pr`.");
     }
+}
+
+
+#[cfg(test)]
+mod mtl_document_tests {
+    use super::{
+        parse,
+        Document,
+        Span,
+        Statement,
+    };
+
+
+    const MTL_FILE: &str = "\
+        # Materials exported by hand.\r\n\
+        \r\n\
+        newmtl  red   # The first material.\r\n\
+        \tKd 1.0  0.0 0.0\r\n\
+        \tvendor_key 42\r\n\
+        \n\
+        \n\
+        newmtl green\n\
+        \tKd 0.0 1.0 0.0";
 
     #[test]
-    fn test_parse_map_decal() {
-        let mut parser = Parser::new("decal decal.png");
-        let expected = Ok(Some("decal.png"));
-        let result = parser.parse_map_decal();
+    fn test_document_is_lossless() {
+        let document = Document::parse(MTL_FILE);
 
-        assert_eq!(result, expected);
+        assert_eq!(document.statements.len(), 9);
+        assert_eq!(document.to_string(), MTL_FILE);
     }
 
     #[test]
-    fn test_parse_illumination_model0() {
-        let mut parser = Parser::new("illum 0");
-        let expected = Ok(IlluminationModel::Ambient);
-        let result = parser.parse_illumination_model();
-
-        assert_eq!(result, expected);
+    fn test_document_statements() {
+        let document = Document::parse(MTL_FILE);
+        let statement = &document.statements[2];
+
+        assert_eq!(statement.keyword(), Some("newmtl"));
+        assert_eq!(statement.arguments(), &[String::from("red")]);
+        assert_eq!(statement.comment(), Some(" The first material."));
+        assert_eq!(statement.line_number(), Some(3));
+        assert_eq!(document.statements[0].keyword(), None);
+        assert_eq!(document.statements[1].comment(), None);
     }
 
     #[test]
-    fn test_parse_illumination_model1() {
-        let mut parser = Parser::new("illum 1");
-        let expected = Ok(IlluminationModel::AmbientDiffuse);
-        let result = parser.parse_illumination_model();
+    fn test_document_comment_glued_to_token() {
+        let document = Document::parse("map_Kd texture.png#The diffuse map.\n");
+        let statement = &document.statements[0];
 
-        assert_eq!(result, expected);
+        assert_eq!(statement.keyword(), Some("map_Kd"));
+        assert_eq!(statement.arguments(), &[String::from("texture.png")]);
+        assert_eq!(statement.comment(), Some("The diffuse map."));
     }
 
     #[test]
-    fn test_parse_illumination_model2() {
-        let mut parser = Parser::new("illum 2");
-        let expected = Ok(IlluminationModel::AmbientDiffuseSpecular);
-        let result = parser.parse_illumination_model();
-
-        assert_eq!(result, expected);
+    fn test_document_spans() {
+        let document = Document::parse(MTL_FILE);
+        for statement in document.statements.iter() {
+            let Span { start, end } = statement.span().unwrap();
+            assert_eq!(&MTL_FILE[start..end], statement.to_string());
+        }
     }
 
     #[test]
-    fn test_parse_illumination_model3() {
-        let mut parser = Parser::new("illum 3");
-        let expected_kind = ErrorKind::UnknownIlluminationModel;
-        let result = parser.parse_illumination_model();
-        assert!(result.is_err());
+    fn test_document_material_range() {
+        let document = Document::parse(MTL_FILE);
 
-        let result_kind = result.unwrap_err().kind;
-        assert_eq!(result_kind, expected_kind);
+        assert_eq!(document.material_range("red"), Some(2..7));
+        assert_eq!(document.material_range("green"), Some(7..9));
+        assert_eq!(document.material_range("blue"), None);
     }
 
     #[test]
-    fn test_parse_newmtl1() {
-        let mut parser = Parser::new("newmtl material_name");
-        let expected = Ok("material_name");
-        let result = parser.parse_newmtl();
+    fn test_document_rewrites_only_modified_statements() {
+        let mut document = Document::parse("newmtl red\n  Kd   1 0 0\n  Ns 10");
+        document.statements[1].set_arguments(vec![String::from("0.5"), String::from("0"), String::from("0")]);
+        document
+            .statements
+            .push(Statement::new("Ni", vec![String::from("1.5")]));
+
+        assert!(document.statements[1].is_modified());
+        assert!(!document.statements[2].is_modified());
+        assert_eq!(document.to_string(), "newmtl red\nKd 0.5 0 0\n  Ns 10\nNi 1.5\n");
+    }
 
-        assert_eq!(result, expected);
+    #[test]
+    fn test_document_format() {
+        let mut document = Document::parse(MTL_FILE);
+        document.statements.push(Statement::new_comment(" The end."));
+
+        assert_eq!(
+            document.format(),
+            "# Materials exported by hand.\n\
+             \n\
+             newmtl red # The first material.\n\
+             Kd 1.0 0.0 0.0\n\
+             vendor_key 42\n\
+             \n\
+             newmtl green\n\
+             Kd 0.0 1.0 0.0\n\
+             # The end.\n"
+        );
     }
 
     #[test]
-    fn test_parse_newmtl2() {
-        let mut parser = Parser::new("newmtl    ");
-        let result = parser.parse_newmtl();
+    fn test_document_to_material_set() {
+        let document = Document::parse("newmtl red\nKd 1 0 0\n");
+        let expected = parse("newmtl red\nKd 1 0 0\n");
 
-        assert!(result.is_err());
+        assert_eq!(document.to_material_set(), expected);
+        assert!(Document::parse(MTL_FILE).to_material_set().is_err());
     }
 }
 
@@ -1085,30 +3684,35 @@ mod mtlset_parser_tests {
         map_Ks specular.jpg             \
         disp displacement.jpg           \
         ";
-        let expected = Ok(MaterialSet {
-            materials: vec![
-                Material {
-                    name: String::from("Scene_-_Root"),
-                    color_ambient: Color { r: 1_f64, g: 1_f64, b: 1_f64 },
-                    color_diffuse: Color { r: 0.8_f64, g: 0.8_f64, b: 0.8_f64 },
-                    color_specular: Color { r: 0.5_f64, g: 0.5_f64, b: 0.5_f64 },
-                    color_emissive: Color { r: 0_f64, g: 0_f64, b: 0_f64 },
-                    specular_exponent: 225_f64,
-                    dissolve: 1_f64,
-                    optical_density: Some(1.45_f64),
-                    illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                    map_ambient: None,
-                    map_diffuse: Some(String::from("diffuse.jpg")),
-                    map_specular: Some(String::from("specular.jpg")),
-                    map_emissive: None,
-                    map_specular_exponent: None,
-                    map_bump: Some(String::from("normal.png")),
-                    map_displacement: Some(String::from("displacement.jpg")),
-                    map_dissolve: None,
-                    map_decal: None,
-                },
-            ],
-        });
+        let expected = Ok(MaterialSet::new(vec![
+            Material {
+                name: String::from("Scene_-_Root"),
+                color_ambient: Color { r: 1_f64, g: 1_f64, b: 1_f64 },
+                color_diffuse: Color { r: 0.8_f64, g: 0.8_f64, b: 0.8_f64 },
+                color_specular: Color { r: 0.5_f64, g: 0.5_f64, b: 0.5_f64 },
+                color_emissive: Color { r: 0_f64, g: 0_f64, b: 0_f64 },
+                emissive_strength: Some(0_f64),
+                specular_exponent: 225_f64,
+                dissolve: 1_f64,
+                optical_density: Some(1.45_f64),
+                illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                map_ambient: None,
+                map_diffuse: Some(String::from("diffuse.jpg")),
+                map_specular: Some(String::from("specular.jpg")),
+                map_emissive: None,
+                map_specular_exponent: None,
+                map_bump: Some(String::from("normal.png")),
+                map_normal: None,
+                map_displacement: Some(String::from("displacement.jpg")),
+                map_dissolve: None,
+                map_decal: None,
+                anti_alias_maps: false,
+                transparency: None,
+                extras: Default::default(),
+                line_number: 5,
+                end_line_number: 17,
+            },
+        ]));
         let result = super::parse(mtl_file);
 
         assert_eq!(result, expected);
@@ -1164,110 +3768,143 @@ mod mtlset_parser_tests {
         Ns 200.0000                                                            \
         illum 1                                                                \
         ";
-        let expected = MaterialSet {
-            materials: vec![
-                Material {
-                    name: String::from("Scene_-_Root"),
-                    color_ambient: Color { r: 1_f64, g: 1_f64, b: 1_f64 },
-                    color_diffuse: Color { r: 0.8_f64, g: 0.8_f64, b: 0.8_f64 },
-                    color_specular: Color { r: 0.5_f64, g: 0.5_f64, b: 0.5_f64 },
-                    color_emissive: Color { r: 0_f64, g: 0_f64, b: 0_f64 },
-                    specular_exponent: 225_f64,
-                    dissolve: 1_f64,
-                    optical_density: Some(1.45_f64),
-                    illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                    map_ambient: None,
-                    map_diffuse: Some(String::from("diffuse.jpg")),
-                    map_specular: Some(String::from("specular.jpg")),
-                    map_emissive: None,
-                    map_specular_exponent: None,
-                    map_bump: Some(String::from("normal.png")),
-                    map_displacement: Some(String::from("displacement.jpg")),
-                    map_dissolve: None,
-                    map_decal: Some(String::from("decal.jpg")),
-                },
-                Material {
-                    name: String::from("neon_green"),
-                    color_ambient: Color::zero(),
-                    color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_specular: Color::zero(),
-                    color_emissive: Color::zero(),
-                    specular_exponent: 0_f64,
-                    dissolve: 1_f64,
-                    optical_density: None,
-                    illumination_model: IlluminationModel::Ambient,
-                    map_ambient: None,
-                    map_diffuse: None,
-                    map_specular: None,
-                    map_emissive: None,
-                    map_specular_exponent: None,
-                    map_bump: None,
-                    map_displacement: None,
-                    map_dissolve: None,
-                    map_decal: None,
-                },
-                Material {
-                    name: String::from("flat_green"),
-                    color_ambient: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_specular: Color::zero(),
-                    color_emissive: Color::zero(),
-                    specular_exponent: 0_f64,
-                    dissolve: 1_f64,
-                    optical_density: None,
-                    illumination_model: IlluminationModel::AmbientDiffuse,
-                    map_ambient: None,
-                    map_diffuse: None,
-                    map_specular: None,
-                    map_emissive: None,
-                    map_specular_exponent: None,
-                    map_bump: None,
-                    map_displacement: None,
-                    map_dissolve: None,
-                    map_decal: None,
-                },
-                Material {
-                    name: String::from("diss_green"),
-                    color_ambient: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_specular: Color::zero(),
-                    color_emissive: Color::zero(),
-                    specular_exponent: 0_f64,
-                    dissolve: 0.8_f64,
-                    optical_density: None,
-                    illumination_model: IlluminationModel::AmbientDiffuse,
-                    map_ambient: None,
-                    map_diffuse: None,
-                    map_specular: None,
-                    map_emissive: None,
-                    map_specular_exponent: None,
-                    map_bump: None,
-                    map_displacement: None,
-                    map_dissolve: None,
-                    map_decal: None,
-                },
-                Material {
-                    name: String::from("shiny_green"),
-                    color_ambient: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
-                    color_specular: Color { r: 1_f64, g: 1_f64, b: 1_f64 },
-                    color_emissive: Color::zero(),
-                    specular_exponent: 200_f64,
-                    dissolve: 1_f64,
-                    optical_density: None,
-                    illumination_model: IlluminationModel::AmbientDiffuse,
-                    map_ambient: None,
-                    map_diffuse: None,
-                    map_specular: None,
-                    map_emissive: None,
-                    map_specular_exponent: None,
-                    map_bump: None,
-                    map_displacement: None,
-                    map_dissolve: None,
-                    map_decal: None,
-                },
-            ],
-        };
+        let expected = MaterialSet::new(vec![
+            Material {
+                name: String::from("Scene_-_Root"),
+                color_ambient: Color { r: 1_f64, g: 1_f64, b: 1_f64 },
+                color_diffuse: Color { r: 0.8_f64, g: 0.8_f64, b: 0.8_f64 },
+                color_specular: Color { r: 0.5_f64, g: 0.5_f64, b: 0.5_f64 },
+                color_emissive: Color { r: 0_f64, g: 0_f64, b: 0_f64 },
+                emissive_strength: Some(0_f64),
+                specular_exponent: 225_f64,
+                dissolve: 1_f64,
+                optical_density: Some(1.45_f64),
+                illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                map_ambient: None,
+                map_diffuse: Some(String::from("diffuse.jpg")),
+                map_specular: Some(String::from("specular.jpg")),
+                map_emissive: None,
+                map_specular_exponent: None,
+                map_bump: Some(String::from("normal.png")),
+                map_normal: None,
+                map_displacement: Some(String::from("displacement.jpg")),
+                map_dissolve: None,
+                map_decal: Some(String::from("decal.jpg")),
+                anti_alias_maps: false,
+                transparency: None,
+                extras: Default::default(),
+                line_number: 5,
+                end_line_number: 18,
+            },
+            Material {
+                name: String::from("neon_green"),
+                color_ambient: Color::zero(),
+                color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_specular: Color::zero(),
+                color_emissive: Color::zero(),
+                emissive_strength: None,
+                specular_exponent: 0_f64,
+                dissolve: 1_f64,
+                optical_density: None,
+                illumination_model: IlluminationModel::Ambient,
+                map_ambient: None,
+                map_diffuse: None,
+                map_specular: None,
+                map_emissive: None,
+                map_specular_exponent: None,
+                map_bump: None,
+                map_normal: None,
+                map_displacement: None,
+                map_dissolve: None,
+                map_decal: None,
+                anti_alias_maps: false,
+                transparency: None,
+                extras: Default::default(),
+                line_number: 22,
+                end_line_number: 24,
+            },
+            Material {
+                name: String::from("flat_green"),
+                color_ambient: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_specular: Color::zero(),
+                color_emissive: Color::zero(),
+                emissive_strength: None,
+                specular_exponent: 0_f64,
+                dissolve: 1_f64,
+                optical_density: None,
+                illumination_model: IlluminationModel::AmbientDiffuse,
+                map_ambient: None,
+                map_diffuse: None,
+                map_specular: None,
+                map_emissive: None,
+                map_specular_exponent: None,
+                map_bump: None,
+                map_normal: None,
+                map_displacement: None,
+                map_dissolve: None,
+                map_decal: None,
+                anti_alias_maps: false,
+                transparency: None,
+                extras: Default::default(),
+                line_number: 27,
+                end_line_number: 30,
+            },
+            Material {
+                name: String::from("diss_green"),
+                color_ambient: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_specular: Color::zero(),
+                color_emissive: Color::zero(),
+                emissive_strength: None,
+                specular_exponent: 0_f64,
+                dissolve: 0.8_f64,
+                optical_density: None,
+                illumination_model: IlluminationModel::AmbientDiffuse,
+                map_ambient: None,
+                map_diffuse: None,
+                map_specular: None,
+                map_emissive: None,
+                map_specular_exponent: None,
+                map_bump: None,
+                map_normal: None,
+                map_displacement: None,
+                map_dissolve: None,
+                map_decal: None,
+                anti_alias_maps: false,
+                transparency: None,
+                extras: Default::default(),
+                line_number: 33,
+                end_line_number: 37,
+            },
+            Material {
+                name: String::from("shiny_green"),
+                color_ambient: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_diffuse: Color { r: 0_f64, g: 1_f64, b: 0_f64 },
+                color_specular: Color { r: 1_f64, g: 1_f64, b: 1_f64 },
+                color_emissive: Color::zero(),
+                emissive_strength: None,
+                specular_exponent: 200_f64,
+                dissolve: 1_f64,
+                optical_density: None,
+                illumination_model: IlluminationModel::AmbientDiffuse,
+                map_ambient: None,
+                map_diffuse: None,
+                map_specular: None,
+                map_emissive: None,
+                map_specular_exponent: None,
+                map_bump: None,
+                map_normal: None,
+                map_displacement: None,
+                map_dissolve: None,
+                map_decal: None,
+                anti_alias_maps: false,
+                transparency: None,
+                extras: Default::default(),
+                line_number: 41,
+                end_line_number: 46,
+            },
+        ]);
         let result = super::parse(mtl_file);
         assert!(result.is_ok());
         let result = result.unwrap();
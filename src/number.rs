@@ -0,0 +1,109 @@
+//! The numbers of Wavefront OBJ and MTL files.
+//!
+//! The OBJ and MTL parsers accept the same numeric grammar:
+//!
+//! ```text
+//! number   = [ sign ] ( mantissa [ exponent ] | "inf" | "infinity" | "nan" )
+//! mantissa = digits [ point [ digits ] ] | point digits
+//! exponent = ( "e" | "E" ) [ sign ] digits
+//! sign     = "+" | "-"
+//! point    = "." | ","
+//! ```
+//!
+//! so that `.5`, `5.`, `+1.0`, `1e5`, and `-2.5E-3` are all numbers. The
+//! keywords are case insensitive. A comma is only accepted as the decimal
+//! separator when the `decimal_comma` option of the parser is set.
+use std::borrow::Cow;
+
+
+/// The way a parser treats a number that is not finite, i.e. NaN or an
+/// infinity, such as a vertex component or a color component written by a
+/// broken exporter.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// Keep the value as it is.
+    #[default]
+    Accept,
+    /// Fail with a parse error.
+    Reject,
+    /// Keep the value as it is, and record a warning in the parse result.
+    Warn,
+    /// Replace NaN with zero, and each infinity with the finite value of the
+    /// same sign that is largest in magnitude.
+    Clamp,
+}
+
+/// Replace a non-finite value with a finite one, as described by
+/// [`NonFinitePolicy::Clamp`].
+#[inline]
+pub(crate) fn clamp_non_finite(value: f64) -> f64 {
+    if value.is_nan() {
+        0_f64
+    } else {
+        value.clamp(f64::MIN, f64::MAX)
+    }
+}
+
+/// Parse a floating point number from a token, following the grammar in the
+/// documentation of this module.
+#[inline]
+pub(crate) fn parse_f64(token: &str, decimal_comma: bool) -> Option<f64> {
+    let token = if decimal_comma && token.contains(',') {
+        Cow::Owned(token.replacen(',', ".", 1))
+    } else {
+        Cow::Borrowed(token)
+    };
+
+    token.parse::<f64>().ok()
+}
+
+
+#[cfg(test)]
+mod number_tests {
+    use super::{
+        clamp_non_finite,
+        parse_f64,
+    };
+
+
+    #[test]
+    fn test_parse_f64_literal_forms() {
+        assert_eq!(parse_f64("1.5", false), Some(1.5));
+        assert_eq!(parse_f64(".5", false), Some(0.5));
+        assert_eq!(parse_f64("-.5", false), Some(-0.5));
+        assert_eq!(parse_f64("5.", false), Some(5.0));
+        assert_eq!(parse_f64("+1.0", false), Some(1.0));
+        assert_eq!(parse_f64("1e5", false), Some(1e5));
+        assert_eq!(parse_f64("-2.5E-3", false), Some(-2.5e-3));
+        assert_eq!(parse_f64("7", false), Some(7.0));
+    }
+
+    #[test]
+    fn test_parse_f64_rejects_malformed_numbers() {
+        assert_eq!(parse_f64("", false), None);
+        assert_eq!(parse_f64(".", false), None);
+        assert_eq!(parse_f64("e5", false), None);
+        assert_eq!(parse_f64("1e", false), None);
+        assert_eq!(parse_f64("1.0.0", false), None);
+        assert_eq!(parse_f64("++1", false), None);
+        assert_eq!(parse_f64("0x10", false), None);
+    }
+
+    #[test]
+    fn test_parse_f64_decimal_comma() {
+        assert_eq!(parse_f64("1,5", false), None);
+        assert_eq!(parse_f64("1,5", true), Some(1.5));
+        assert_eq!(parse_f64(",5", true), Some(0.5));
+        assert_eq!(parse_f64("-2,5e2", true), Some(-250.0));
+        assert_eq!(parse_f64("1.5", true), Some(1.5));
+        assert_eq!(parse_f64("1,000,5", true), None);
+    }
+
+    #[test]
+    fn test_clamp_non_finite() {
+        assert_eq!(clamp_non_finite(f64::NAN), 0.0);
+        assert_eq!(clamp_non_finite(f64::INFINITY), f64::MAX);
+        assert_eq!(clamp_non_finite(f64::NEG_INFINITY), f64::MIN);
+        assert_eq!(clamp_non_finite(-2.5), -2.5);
+    }
+}
@@ -1,9 +1,40 @@
+use crate::input;
+use crate::lexer;
 use crate::lexer::{
+    is_separator,
+    normalize_line_ending,
     Lexer,
     PeekableLexer,
 };
+use crate::number;
+use std::cmp;
+use std::collections::{
+    BTreeMap,
+    BinaryHeap,
+    HashMap,
+};
 use std::error;
 use std::fmt;
+use std::fs;
+use std::hash::Hash;
+use std::io;
+use std::io::{
+    Read,
+    Seek,
+    Write,
+};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::str;
+use std::sync::Arc;
+use std::thread;
+
+pub use crate::number::NonFinitePolicy;
 
 
 /// Parse a set of objects and material library names from a string.
@@ -19,10 +50,15 @@ use std::fmt;
 /// #      SmoothingGroup,
 /// #      Element,
 /// #      ShapeEntry,
+/// #      ShapeEntryIndex,
+/// #      ElementIndex,
+/// #      GroupIndex,
+/// #      SmoothingGroupIndex,
 /// #      Geometry,
 /// #      VTNIndex,
 /// #      Object,
 /// #      ObjectSet,
+/// #      MtlLibRef,
 /// # };
 /// #
 /// let obj_file = String::from(r"
@@ -56,11 +92,15 @@ use std::fmt;
 /// // let expected = ...;
 /// # let expected = ObjectSet {
 /// #     material_libraries: vec![
-/// #         String::from("material_library.mtl"),
+/// #         MtlLibRef::new("material_library.mtl", 2),
 /// #     ],
+/// #     external_references: vec![],
+/// #     unknown_statements: vec![],
+/// #     warnings: vec![],
 /// #     objects: vec![
 /// #         Object {
 /// #             name: String::from("Object001"),
+/// #             source: None,
 /// #             vertex_set: vec![
 /// #                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
 /// #                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
@@ -68,8 +108,8 @@ use std::fmt;
 /// #                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
 /// #                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
 /// #                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
-/// #             ],
-/// #             texture_vertex_set: vec![],
+/// #             ].into(),
+/// #             texture_vertex_set: vec![].into(),
 /// #             normal_vertex_set: vec![
 /// #                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
 /// #                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
@@ -77,28 +117,32 @@ use std::fmt;
 /// #                 NormalVertex { x: 0.276597, y: 0.000000, z: 0.960986 },
 /// #                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
 /// #                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
-/// #             ],
+/// #             ].into(),
 /// #             group_set: vec![
 /// #                 Group(String::from("all")),
-/// #             ],
+/// #             ].into(),
 /// #             smoothing_group_set: vec![
 /// #                 SmoothingGroup(1),
-/// #             ],
+/// #             ].into(),
 /// #             element_set: vec![
 /// #                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 1), VTNIndex::VN(2, 2)),
 /// #                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(2, 2), VTNIndex::VN(3, 3)),
 /// #                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(2, 2), VTNIndex::VN(4, 4)),
 /// #                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(4, 4), VTNIndex::VN(5, 5)),
-/// #             ],
+/// #             ].into(),
 /// #             shape_set: vec![
-/// #                 ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-/// #                 ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-/// #                 ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-/// #                 ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-/// #             ],
+/// #                 ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+/// #                 ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+/// #                 ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+/// #                 ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+/// #             ].into(),
 /// #             geometry_set: vec![
-/// #                 Geometry { material_name: Some(String::from("material")), shapes: vec![0, 1, 2, 3] },
-/// #             ]
+/// #                 Geometry { material_name: Some(String::from("material")), shapes: (0..4).map(ShapeEntryIndex).collect() },
+/// #             ].into(),
+/// #             element_line_set: vec![],
+/// #             element_text_set: vec![],
+/// #             original_element_set: vec![],
+/// #             numeric_text: Default::default(),
 /// #         }
 /// #     ]
 /// # };
@@ -112,11 +156,561 @@ pub fn parse<T: AsRef<str>>(input: T) -> Result<ObjectSet, ParseError> {
     Parser::new(input.as_ref()).parse_objset()
 }
 
+/// Parse a wavefront object file from a string with non-default parser options.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::ParserOptions;
+/// #
+/// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\n\nf 1 2 3\n";
+/// let options = ParserOptions {
+///     record_element_lines: true,
+///     ..ParserOptions::default()
+/// };
+/// let result = obj::parse_with(obj_file, options).unwrap();
+///
+/// assert_eq!(result.objects[0].element_line_set, vec![5]);
+/// ```
+pub fn parse_with<T: AsRef<str>>(input: T, options: ParserOptions) -> Result<ObjectSet, ParseError> {
+    Parser::with_options(input.as_ref(), options).parse_objset()
+}
+
+/// Parse only the vertices of a Wavefront OBJ file, such as a point cloud
+/// export, skipping every other statement.
+///
+/// See [`Parser::parse_point_cloud`] for details.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::Vertex;
+/// #
+/// let point_cloud = obj::parse_point_cloud("v 0 0 0\nv 1 2 3\nf 1 2 3\n").unwrap();
+///
+/// assert_eq!(point_cloud.vertices[1], Vertex { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+/// assert!(point_cloud.colors.is_empty());
+/// ```
+pub fn parse_point_cloud<T: AsRef<str>>(input: T) -> Result<PointCloud, ParseError> {
+    Parser::new(input.as_ref()).parse_point_cloud()
+}
+
+/// Parse a Wavefront OBJ file, passing each face to a visitor instead of
+/// building an object set.
+///
+/// See [`Parser::parse_with_visitor`] for details.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::{
+/// #     GroupContext,
+/// #     MaterialContext,
+/// #     VTNIndex,
+/// # };
+/// #
+/// let obj_file = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4\nf 1 2 3\n";
+/// let mut corners = 0;
+/// let mut count_corners = |vtn_indices: &[VTNIndex], _: &GroupContext, _: &MaterialContext| {
+///     corners += vtn_indices.len();
+/// };
+/// obj::parse_with_visitor(obj_file, &mut count_corners).unwrap();
+///
+/// assert_eq!(corners, 7);
+/// ```
+pub fn parse_with_visitor<T, V>(input: T, visitor: &mut V) -> Result<(), ParseError>
+where
+    T: AsRef<str>,
+    V: FaceVisitor + ?Sized,
+{
+    Parser::new(input.as_ref()).parse_with_visitor(visitor)
+}
+
+/// Parse a Wavefront OBJ file from a byte slice.
+///
+/// The bytes must be UTF-8 text, or a gzip stream of UTF-8 text when the
+/// crate is built with the `flate2` feature. This function does not touch the
+/// file system, which makes it the entry point for targets without one, such
+/// as `wasm32-unknown-unknown`.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// #
+/// let object_set = obj::parse_slice(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+/// assert_eq!(object_set.objects[0].element_set.len(), 1);
+///
+/// assert!(obj::parse_slice(&[0xff, 0xfe, 0x00]).is_err());
+/// ```
+pub fn parse_slice(bytes: &[u8]) -> Result<ObjectSet, ObjError> {
+    let text = input::decode_slice(bytes).map_err(|error| ObjError::decode(None, error))?;
+
+    parse(text).map_err(|error| ObjError::Parse {
+        path: None,
+        error: error,
+    })
+}
+
+/// Parse a Wavefront OBJ file from disk.
+///
+/// Each object in the resulting object set records the path of the file it
+/// was parsed from in its `source` field. When the crate is built with the
+/// `flate2` feature, gzip compressed files (e.g. `*.obj.gz`) are detected by
+/// their magic number and decompressed transparently.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "info", skip_all, fields(path = %path.as_ref().display()))
+)]
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<ObjectSet, ObjError> {
+    let path = path.as_ref();
+    let buffer = fs::read(path)
+        .map_err(input::DecodeError::Io)
+        .and_then(input::decode)
+        .map_err(|error| ObjError::decode(Some(path.to_path_buf()), error))?;
+    #[cfg(feature = "tracing")]
+    tracing::debug!(bytes = buffer.len(), "Read the file.");
+    let mut object_set = parse(buffer).map_err(|error| ObjError::Parse {
+        path: Some(path.to_path_buf()),
+        error: error,
+    })?;
+    for object in object_set.objects.iter_mut() {
+        object.source = Some(path.to_path_buf());
+    }
+
+    Ok(object_set)
+}
+
+/// Parse a gzip compressed Wavefront OBJ file from a reader.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use flate2::write::GzEncoder;
+/// # use flate2::Compression;
+/// # use std::io::Write;
+/// #
+/// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+/// encoder.write_all(b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nf 1 2 3\n").unwrap();
+/// let compressed = encoder.finish().unwrap();
+///
+/// let object_set = obj::parse_gzip(&compressed[..]).unwrap();
+/// assert_eq!(object_set.objects[0].element_set.len(), 1);
+/// ```
+#[cfg(feature = "flate2")]
+pub fn parse_gzip<R: io::Read>(reader: R) -> Result<ObjectSet, ObjError> {
+    let buffer = input::decompress(reader).map_err(|error| ObjError::decode(None, error))?;
+
+    parse(buffer).map_err(|error| ObjError::Parse {
+        path: None,
+        error: error,
+    })
+}
+
+/// Parse a collection of Wavefront OBJ files and merge them into a single
+/// object set.
+///
+/// The objects appear in the merged object set in the order their files are
+/// given, and each object records the file it was parsed from in its `source`
+/// field. Material library names are merged without duplicates. Parsing stops
+/// at the first file that fails to load.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use wavefront_obj::obj;
+/// # use std::path::PathBuf;
+/// #
+/// let paths = vec![PathBuf::from("chair.obj"), PathBuf::from("table.obj")];
+/// let object_set = obj::parse_files(paths).unwrap();
+///
+/// for object in object_set.objects.iter() {
+///     println!("{} from {:?}", object.name, object.source);
+/// }
+/// ```
+pub fn parse_files<I: IntoIterator<Item = PathBuf>>(paths: I) -> Result<ObjectSet, ObjError> {
+    let mut object_sets = vec![];
+    for path in paths {
+        object_sets.push(parse_file(path)?);
+    }
+
+    Ok(merge_object_sets(object_sets))
+}
+
+/// Parse a collection of Wavefront OBJ files in parallel and merge them into
+/// a single object set.
+///
+/// The files are distributed over at most `max_threads` worker threads. The
+/// result is identical to that of [`parse_files`]: the merged object set
+/// preserves the order of the input paths regardless of which thread parsed
+/// each file. If more than one file fails to load, the error for the earliest
+/// file in the input order is returned.
+pub fn parse_files_parallel<I: IntoIterator<Item = PathBuf>>(
+    paths: I,
+    max_threads: usize,
+) -> Result<ObjectSet, ObjError> {
+    let paths: Vec<PathBuf> = paths.into_iter().collect();
+    if paths.is_empty() {
+        return Ok(merge_object_sets(vec![]));
+    }
+
+    let thread_count = usize::max(1, usize::min(max_threads, paths.len()));
+    let chunk_size = paths.len().div_ceil(thread_count);
+    let results: Vec<Result<ObjectSet, ObjError>> = thread::scope(|scope| {
+        let handles: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(parse_file).collect::<Vec<_>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("OBJ parsing thread panicked."))
+            .collect()
+    });
+
+    let object_sets = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    Ok(merge_object_sets(object_sets))
+}
+
+/// Parse every Wavefront OBJ file in a directory and merge them into a single
+/// object set.
+///
+/// A file is considered a Wavefront OBJ file if its extension is `obj`, compared
+/// case-insensitively. Subdirectories are not searched. The files are parsed
+/// in lexicographic order of their paths so that the result is deterministic.
+pub fn parse_directory<P: AsRef<Path>>(directory: P) -> Result<ObjectSet, ObjError> {
+    let paths = directory_files(directory.as_ref(), |path| {
+        path.extension()
+            .map(|extension| extension.eq_ignore_ascii_case("obj"))
+            .unwrap_or(false)
+    })?;
+
+    parse_files(paths)
+}
+
+/// Parse every file in a directory whose name matches a wildcard pattern and
+/// merge them into a single object set.
+///
+/// In the pattern, `*` matches any sequence of characters, `?` matches any
+/// single character, and every other character matches itself, compared
+/// case-sensitively. The pattern is matched against the whole file name, so
+/// `part_*.obj` matches `part_1.obj` and `part_wheel.obj` but not
+/// `old_part_1.obj`. Subdirectories are not searched, and file names that are
+/// not valid UTF-8 never match. As in [`parse_directory`], the files are
+/// parsed in lexicographic order of their paths.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use wavefront_obj::obj;
+/// #
+/// let object_set = obj::parse_directory_matching("assets/car", "wheel_?.obj").unwrap();
+///
+/// for object in object_set.objects.iter() {
+///     println!("{} from {:?}", object.name, object.source);
+/// }
+/// ```
+pub fn parse_directory_matching<P: AsRef<Path>>(directory: P, pattern: &str) -> Result<ObjectSet, ObjError> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let paths = directory_files(directory.as_ref(), |path| {
+        path.file_name()
+            .and_then(|file_name| file_name.to_str())
+            .map(|file_name| wildcard_matches(&pattern, file_name))
+            .unwrap_or(false)
+    })?;
+
+    parse_files(paths)
+}
+
+/// The files in a directory, not counting subdirectories, that satisfy a
+/// predicate, sorted by path.
+fn directory_files<F: Fn(&Path) -> bool>(directory: &Path, predicate: F) -> Result<Vec<PathBuf>, ObjError> {
+    let io_error = |error| ObjError::Io {
+        path: Some(directory.to_path_buf()),
+        error: error,
+    };
+    let mut paths = vec![];
+    for entry in fs::read_dir(directory).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if predicate(&path) && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Determine whether a name matches a wildcard pattern in which `*` matches
+/// any sequence of characters and `?` matches any single character.
+fn wildcard_matches(pattern: &[char], name: &str) -> bool {
+    let name: Vec<char> = name.chars().collect();
+    let mut pattern_position = 0;
+    let mut name_position = 0;
+    // The position of the last `*` in the pattern, and the position in the
+    // name it was tried against, to backtrack to on a mismatch.
+    let mut backtrack = None;
+    while name_position < name.len() {
+        match pattern.get(pattern_position) {
+            Some('*') => {
+                backtrack = Some((pattern_position, name_position));
+                pattern_position += 1;
+            }
+            Some(&character) if character == '?' || character == name[name_position] => {
+                pattern_position += 1;
+                name_position += 1;
+            }
+            _ => match backtrack {
+                Some((star_position, star_name_position)) => {
+                    backtrack = Some((star_position, star_name_position + 1));
+                    pattern_position = star_position + 1;
+                    name_position = star_name_position + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[pattern_position..]
+        .iter()
+        .all(|&character| character == '*')
+}
+
+/// Merge several object sets into one, preserving the order of the objects.
+fn merge_object_sets(object_sets: Vec<ObjectSet>) -> ObjectSet {
+    let mut material_libraries: Vec<MtlLibRef> = vec![];
+    let mut external_references = vec![];
+    let mut unknown_statements = vec![];
+    let mut warnings = vec![];
+    let mut objects = vec![];
+    for object_set in object_sets.into_iter() {
+        for material_library in object_set.material_libraries.into_iter() {
+            if !material_libraries
+                .iter()
+                .any(|library: &MtlLibRef| library.name == material_library.name)
+            {
+                material_libraries.push(material_library);
+            }
+        }
+        external_references.extend(object_set.external_references);
+        unknown_statements.extend(object_set.unknown_statements);
+        warnings.extend(object_set.warnings);
+        objects.extend(object_set.objects);
+    }
+
+    ObjectSet {
+        material_libraries: material_libraries,
+        external_references: external_references,
+        unknown_statements: unknown_statements,
+        warnings: warnings,
+        objects: objects,
+    }
+}
+
+/// A summary of the contents of a Wavefront OBJ file, made by [`scan`]
+/// without parsing the data of its statements.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjSummary {
+    /// The material libraries named by the `mtllib` statements of the file.
+    pub material_libraries: Vec<MtlLibRef>,
+    /// A summary of each object of the file, in the order of the file.
+    pub objects: Vec<ObjectSummary>,
+}
+
+/// A summary of the contents of one object of a Wavefront OBJ file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjectSummary {
+    /// The name of the object.
+    pub name: String,
+    /// The names of the groups of the object, in order of first appearance.
+    pub groups: Vec<String>,
+    /// The names of the materials used by the object, in order of first
+    /// appearance.
+    pub material_names: Vec<String>,
+    /// The number of `v` statements.
+    pub vertices: usize,
+    /// The number of `vt` statements.
+    pub texture_vertices: usize,
+    /// The number of `vn` statements.
+    pub normal_vertices: usize,
+    /// The number of point elements.
+    pub points: usize,
+    /// The number of line elements.
+    pub lines: usize,
+    /// The number of face elements, after triangulation.
+    pub faces: usize,
+}
+
+impl ObjectSummary {
+    /// The number of elements of the object.
+    pub fn elements(&self) -> usize {
+        self.points + self.lines + self.faces
+    }
+}
+
+/// Push a name onto a list of names unless the list already contains it.
+fn push_distinct(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|other| other == name) {
+        names.push(String::from(name));
+    }
+}
+
+/// Summarize the objects of a Wavefront OBJ file without parsing it.
+///
+/// Only the keywords of the statements are inspected, and the arguments of
+/// element statements are counted rather than parsed, so scanning is much
+/// faster than parsing and allocates nothing per vertex or element. This
+/// suits applications such as asset browsers that list the contents of many
+/// files. The text does not have to parse; for a file that does, the names
+/// and the counts agree with the object set returned by [`parse`] with
+/// default options, where each `o` statement starts a new object.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// #
+/// let obj_file = "\
+///     mtllib scene.mtl\n\
+///     o cube\n\
+///     v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n\
+///     g top\n\
+///     usemtl red\n\
+///     f 1 2 3 4\n\
+/// ";
+/// let summary = obj::scan(obj_file);
+///
+/// assert_eq!(summary.material_libraries[0].name, "scene.mtl");
+/// assert_eq!(summary.objects[0].name, "cube");
+/// assert_eq!(summary.objects[0].groups, vec!["top"]);
+/// assert_eq!(summary.objects[0].material_names, vec!["red"]);
+/// assert_eq!(summary.objects[0].vertices, 4);
+/// assert_eq!(summary.objects[0].faces, 2);
+/// ```
+pub fn scan<T: AsRef<str>>(input: T) -> ObjSummary {
+    let input = input.as_ref();
+    let mut summary = ObjSummary::default();
+    let mut leading_material_name = None;
+    let mut statement: Vec<&str> = vec![];
+    let mut line_number = 1;
+    let mut tokens = PeekableLexer::new(Lexer::new(input));
+    while tokens.peek().is_some() {
+        statement.clear();
+        statement.extend(
+            tokens
+                .by_ref()
+                .map(normalize_line_ending)
+                .take_while(|&token| token != "\n"),
+        );
+        let (keyword, arguments) = match statement.split_first() {
+            Some((&keyword, arguments)) => (keyword, arguments),
+            None => {
+                line_number += 1;
+                continue;
+            }
+        };
+        match keyword {
+            "mtllib" => {
+                for &name in arguments.iter() {
+                    summary.material_libraries.push(MtlLibRef::new(name, line_number));
+                }
+            }
+            "call" | "csh" => {}
+            "usemtl" if summary.objects.is_empty() => {
+                leading_material_name = arguments.first().copied();
+            }
+            _ => {
+                if keyword == "o" || summary.objects.is_empty() {
+                    let name = match (arguments.first(), arguments.last()) {
+                        (Some(first), Some(last)) => {
+                            let start = first.as_ptr() as usize - input.as_ptr() as usize;
+                            let end = last.as_ptr() as usize - input.as_ptr() as usize + last.len();
+                            &input[start..end]
+                        }
+                        _ => "",
+                    };
+                    let mut object = ObjectSummary {
+                        name: String::from(if keyword == "o" { name } else { "" }),
+                        ..ObjectSummary::default()
+                    };
+                    if let Some(material_name) = leading_material_name.take() {
+                        object.material_names.push(String::from(material_name));
+                    }
+                    summary.objects.push(object);
+                }
+                let object = summary.objects.last_mut().unwrap();
+                match keyword {
+                    "v" => object.vertices += 1,
+                    "vt" => object.texture_vertices += 1,
+                    "vn" => object.normal_vertices += 1,
+                    "p" => object.points += arguments.len(),
+                    "l" => object.lines += arguments.len().saturating_sub(1),
+                    "f" => object.faces += arguments.len().saturating_sub(2),
+                    "g" => {
+                        for &group_name in arguments.iter() {
+                            push_distinct(&mut object.groups, group_name);
+                        }
+                    }
+                    "usemtl" => {
+                        if let Some(&material_name) = arguments.first() {
+                            push_distinct(&mut object.material_names, material_name);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        line_number += 1;
+    }
+
+    summary
+}
+
+/// The color of a vertex, written as three extra components of a `v`
+/// statement, e.g. `v 0.0 1.0 2.0 1.0 0.5 0.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VertexColor {
+    /// The red component of a vertex color.
+    pub r: f64,
+    /// The green component of a vertex color.
+    pub g: f64,
+    /// The blue component of a vertex color.
+    pub b: f64,
+}
+
+impl Default for VertexColor {
+    fn default() -> VertexColor {
+        VertexColor {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        }
+    }
+}
+
+/// The vertices of a Wavefront OBJ file, parsed by
+/// [`Parser::parse_point_cloud`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointCloud {
+    /// The vertices of every `v` statement of the file, in order.
+    pub vertices: Vec<Vertex>,
+    /// The color of each vertex. This is empty unless at least one vertex
+    /// has a color, in which case every vertex without one is white.
+    pub colors: Vec<VertexColor>,
+}
+
 
 /// A single three dimensional point in an object, or a single
 /// three-dimensional point of an object in homogeneous coordinates
 /// when the w-component is one.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vertex {
     /// The **x-axis** component of a vertex.
     pub x: f64,
@@ -124,8 +718,9 @@ pub struct Vertex {
     pub y: f64,
     /// The **z-axis** component of a vertex.
     pub z: f64,
-    /// The **w-axis** (homogeneous) component of a vertex. The default value
-    /// of this field is 0 when the w coordinate is not present.
+    /// The **w-axis** (homogeneous) component of a vertex. The parser sets
+    /// this field to [`ParserOptions::default_w`], which is 1 unless configured
+    /// otherwise, when the w coordinate is not present.
     pub w: f64,
 }
 
@@ -135,9 +730,123 @@ impl fmt::Display for Vertex {
     }
 }
 
+impl str::FromStr for Vertex {
+    type Err = ParseError;
+
+    /// Parse a single `v` statement.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::Vertex;
+    /// #
+    /// let vertex: Vertex = "v 1.0 2.0 3.0".parse().unwrap();
+    ///
+    /// assert_eq!(vertex, Vertex { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+    /// ```
+    fn from_str(st: &str) -> Result<Vertex, ParseError> {
+        parse_statement(st, |parser| parser.parse_vertex())
+    }
+}
+
+impl Vertex {
+    /// Compute the dot product of the **xyz** components of two vertices.
+    ///
+    /// The **w** components are ignored.
+    pub fn dot(&self, other: &Vertex) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Compute the cross product of the **xyz** components of two vertices.
+    ///
+    /// The **w** component of the result is the **w** component of `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::Vertex;
+    /// #
+    /// let a = Vertex { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+    /// let b = Vertex { x: 1.0, y: 0.0, z: 0.0, w: 1.0 };
+    /// let c = Vertex { x: 0.0, y: 1.0, z: 0.0, w: 1.0 };
+    /// let normal = (b - a).cross(&(c - a));
+    ///
+    /// assert_eq!(normal, Vertex { x: 0.0, y: 0.0, z: 1.0, w: 1.0 });
+    /// ```
+    pub fn cross(&self, other: &Vertex) -> Vertex {
+        Vertex {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+            w: self.w,
+        }
+    }
+}
+
+/// Add the **xyz** components of two vertices. The **w** component of the
+/// result is the **w** component of the left operand.
+impl ops::Add for Vertex {
+    type Output = Vertex;
+
+    fn add(self, other: Vertex) -> Vertex {
+        Vertex {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w,
+        }
+    }
+}
+
+/// Subtract the **xyz** components of two vertices. The **w** component of
+/// the result is the **w** component of the left operand.
+impl ops::Sub for Vertex {
+    type Output = Vertex;
+
+    fn sub(self, other: Vertex) -> Vertex {
+        Vertex {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w,
+        }
+    }
+}
+
+/// Scale the **xyz** components of a vertex, leaving the **w** component
+/// unchanged.
+impl ops::Mul<f64> for Vertex {
+    type Output = Vertex;
+
+    fn mul(self, scale: f64) -> Vertex {
+        Vertex {
+            x: self.x * scale,
+            y: self.y * scale,
+            z: self.z * scale,
+            w: self.w,
+        }
+    }
+}
+
+/// Negate the **xyz** components of a vertex, leaving the **w** component
+/// unchanged.
+impl ops::Neg for Vertex {
+    type Output = Vertex;
+
+    fn neg(self) -> Vertex {
+        Vertex {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+}
+
 
 /// A single three-dimensional coordinate in a texture.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextureVertex {
     /// The horizontal coordinate of a texture vertex.
     pub u: f64,
@@ -153,9 +862,19 @@ impl fmt::Display for TextureVertex {
     }
 }
 
+impl str::FromStr for TextureVertex {
+    type Err = ParseError;
+
+    /// Parse a single `vt` statement.
+    fn from_str(st: &str) -> Result<TextureVertex, ParseError> {
+        parse_statement(st, |parser| parser.parse_texture_vertex())
+    }
+}
+
 
 /// A normal vector at a vertex in an object.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalVertex {
     /// The **x-axis** component of a normal vector.
     pub x: f64,
@@ -171,51 +890,199 @@ impl fmt::Display for NormalVertex {
     }
 }
 
+impl str::FromStr for NormalVertex {
+    type Err = ParseError;
 
-/// A general vertex/texture/normal index representing the indices
-/// of a vertex, texture vertex, and normal vector in an element
-/// of a geometry figure.
-///
-/// A VTN index has the forms of **vertex**, **vertex/texture**,
-/// **vertex//normal**, or **vertex/texture/normal** indices,
-/// which indicates which data of vertices, texture vertices, and
-/// normal vectors are bound to each vertex in a shape element.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum VTNIndex {
-    V(VertexIndex),
-    VT(VertexIndex, TextureVertexIndex),
-    VN(VertexIndex, NormalVertexIndex),
-    VTN(VertexIndex, TextureVertexIndex, NormalVertexIndex),
+    /// Parse a single `vn` statement.
+    fn from_str(st: &str) -> Result<NormalVertex, ParseError> {
+        parse_statement(st, |parser| parser.parse_normal_vertex())
+    }
 }
 
-impl VTNIndex {
-    /// Determine whether two VTN indices have the same form.
+impl NormalVertex {
+    /// Compute the dot product of two normal vectors.
+    pub fn dot(&self, other: &NormalVertex) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Compute the cross product of two normal vectors.
+    pub fn cross(&self, other: &NormalVertex) -> NormalVertex {
+        NormalVertex {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Compute the length of a normal vector.
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Scale a normal vector to unit length.
     ///
-    /// The function returns true if both VTN indices are of the form
-    /// vertex, vertex/texture/ vertex//normal, or vertex/texture/normal.
-    /// Otherwise, the function returns false.
+    /// A zero length vector is returned unchanged.
     ///
     /// ## Example
     ///
     /// ```
-    /// # use wavefront_obj::obj::{
-    /// #     VTNIndex,
-    /// # };
+    /// # use wavefront_obj::obj::NormalVertex;
     /// #
-    /// let v_index1 = VTNIndex::V(0);
-    /// let v_index2 = VTNIndex::V(1);
-    /// assert!(v_index1.has_same_type_as(&v_index2));
-    ///
-    /// let vt_index1 = VTNIndex::VT(2, 3);
-    /// let vt_index2 = VTNIndex::VT(4, 5);
-    /// assert!(vt_index1.has_same_type_as(&vt_index2));
-    ///
-    /// let vn_index1 = VTNIndex::VN(6, 7);
-    /// let vn_index2 = VTNIndex::VN(8, 9);
-    /// assert!(vn_index1.has_same_type_as(&vn_index2));
+    /// let normal = NormalVertex { x: 0.0, y: 3.0, z: 4.0 }.normalize();
     ///
-    /// let vtn_index1 = VTNIndex::VTN(10, 11, 12);
-    /// let vtn_index2 = VTNIndex::VTN(13, 14, 15);
+    /// assert_eq!(normal, NormalVertex { x: 0.0, y: 0.6, z: 0.8 });
+    /// ```
+    pub fn normalize(&self) -> NormalVertex {
+        let magnitude = self.magnitude();
+        if magnitude == 0_f64 {
+            *self
+        } else {
+            NormalVertex {
+                x: self.x / magnitude,
+                y: self.y / magnitude,
+                z: self.z / magnitude,
+            }
+        }
+    }
+}
+
+impl ops::Add for NormalVertex {
+    type Output = NormalVertex;
+
+    fn add(self, other: NormalVertex) -> NormalVertex {
+        NormalVertex {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl ops::Sub for NormalVertex {
+    type Output = NormalVertex;
+
+    fn sub(self, other: NormalVertex) -> NormalVertex {
+        NormalVertex {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl ops::Mul<f64> for NormalVertex {
+    type Output = NormalVertex;
+
+    fn mul(self, scale: f64) -> NormalVertex {
+        NormalVertex {
+            x: self.x * scale,
+            y: self.y * scale,
+            z: self.z * scale,
+        }
+    }
+}
+
+impl ops::Neg for NormalVertex {
+    type Output = NormalVertex;
+
+    fn neg(self) -> NormalVertex {
+        NormalVertex {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/// A unit tangent vector of a normal vector, as computed by
+/// [`Object::generate_tangents`].
+///
+/// The **xyz** components point along the direction of increasing **u**
+/// texture coordinate, orthogonal to the normal vector. The **w** component
+/// is the handedness of the tangent frame, either `1` or `-1`: the bitangent
+/// vector is `w` times the cross product of the normal and the tangent.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tangent {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+
+/// A general vertex/texture/normal index representing the indices
+/// of a vertex, texture vertex, and normal vector in an element
+/// of a geometry figure.
+///
+/// A VTN index has the forms of **vertex**, **vertex/texture**,
+/// **vertex//normal**, or **vertex/texture/normal** indices,
+/// which indicates which data of vertices, texture vertices, and
+/// normal vectors are bound to each vertex in a shape element.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VTNIndex {
+    V(usize),
+    VT(usize, usize),
+    VN(usize, usize),
+    VTN(usize, usize, usize),
+}
+
+impl VTNIndex {
+    /// The index of the vertex in the vertex set.
+    pub fn vertex_index(&self) -> VertexIndex {
+        match *self {
+            VTNIndex::V(v) | VTNIndex::VT(v, _) | VTNIndex::VN(v, _) | VTNIndex::VTN(v, _, _) => {
+                VertexIndex(v)
+            }
+        }
+    }
+
+    /// The index of the texture vertex in the texture vertex set, if the VTN
+    /// index has one.
+    pub fn texture_vertex_index(&self) -> Option<TextureVertexIndex> {
+        match *self {
+            VTNIndex::VT(_, vt) | VTNIndex::VTN(_, vt, _) => Some(TextureVertexIndex(vt)),
+            VTNIndex::V(_) | VTNIndex::VN(_, _) => None,
+        }
+    }
+
+    /// The index of the normal vector in the normal vertex set, if the VTN
+    /// index has one.
+    pub fn normal_vertex_index(&self) -> Option<NormalVertexIndex> {
+        match *self {
+            VTNIndex::VN(_, vn) | VTNIndex::VTN(_, _, vn) => Some(NormalVertexIndex(vn)),
+            VTNIndex::V(_) | VTNIndex::VT(_, _) => None,
+        }
+    }
+
+    /// Determine whether two VTN indices have the same form.
+    ///
+    /// The function returns true if both VTN indices are of the form
+    /// vertex, vertex/texture/ vertex//normal, or vertex/texture/normal.
+    /// Otherwise, the function returns false.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::{
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let v_index1 = VTNIndex::V(0);
+    /// let v_index2 = VTNIndex::V(1);
+    /// assert!(v_index1.has_same_type_as(&v_index2));
+    ///
+    /// let vt_index1 = VTNIndex::VT(2, 3);
+    /// let vt_index2 = VTNIndex::VT(4, 5);
+    /// assert!(vt_index1.has_same_type_as(&vt_index2));
+    ///
+    /// let vn_index1 = VTNIndex::VN(6, 7);
+    /// let vn_index2 = VTNIndex::VN(8, 9);
+    /// assert!(vn_index1.has_same_type_as(&vn_index2));
+    ///
+    /// let vtn_index1 = VTNIndex::VTN(10, 11, 12);
+    /// let vtn_index2 = VTNIndex::VTN(13, 14, 15);
     /// assert!(vtn_index1.has_same_type_as(&vtn_index2));
     ///
     /// assert!(!v_index1.has_same_type_as(&vt_index1));
@@ -259,14 +1126,420 @@ impl fmt::Display for VTNIndex {
     }
 }
 
+impl str::FromStr for VTNIndex {
+    type Err = ParseError;
+
+    /// Parse a single one-based VTN index such as `1/2/3`.
+    ///
+    /// Relative (negative) indices refer to vertices declared earlier in a
+    /// file, so they cannot be resolved in isolation and are rejected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::VTNIndex;
+    /// #
+    /// assert_eq!("4//5".parse::<VTNIndex>(), Ok(VTNIndex::VN(3, 4)));
+    /// assert!("-1".parse::<VTNIndex>().is_err());
+    /// ```
+    fn from_str(st: &str) -> Result<VTNIndex, ParseError> {
+        parse_statement(st, |parser| {
+            parser.parse_vtn_index(ISOLATED_INDEX_RANGE, ISOLATED_INDEX_RANGE, ISOLATED_INDEX_RANGE)
+        })
+    }
+}
+
+
+/// An index into one of the typed sets of an object.
+///
+/// Each set of an object is indexed by its own index type, so that an index
+/// into one set cannot be used to index another set by mistake.
+pub trait SetIndex: Copy {
+    /// The name of the set the index type refers into.
+    const SET_NAME: &'static str;
+
+    /// Construct an index from its position in the set.
+    fn from_usize(index: usize) -> Self;
+
+    /// The position in the set that the index refers to.
+    fn to_usize(self) -> usize;
+}
+
+/// The index of an element in the element set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementIndex(pub usize);
+
+impl SetIndex for ElementIndex {
+    const SET_NAME: &'static str = "element set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        ElementIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a vertex in the vertex set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VertexIndex(pub usize);
+
+impl SetIndex for VertexIndex {
+    const SET_NAME: &'static str = "vertex set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        VertexIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a texture vertex in the texture vertex set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextureVertexIndex(pub usize);
+
+impl SetIndex for TextureVertexIndex {
+    const SET_NAME: &'static str = "texture vertex set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        TextureVertexIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a normal vector in the normal vertex set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NormalVertexIndex(pub usize);
+
+impl SetIndex for NormalVertexIndex {
+    const SET_NAME: &'static str = "normal vertex set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        NormalVertexIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a group in the group set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupIndex(pub usize);
+
+impl SetIndex for GroupIndex {
+    const SET_NAME: &'static str = "group set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        GroupIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a smoothing group in the smoothing group set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothingGroupIndex(pub usize);
+
+impl SetIndex for SmoothingGroupIndex {
+    const SET_NAME: &'static str = "smoothing group set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        SmoothingGroupIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a shape entry in the shape set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeEntryIndex(pub usize);
+
+impl SetIndex for ShapeEntryIndex {
+    const SET_NAME: &'static str = "shape set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        ShapeEntryIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// The index of a geometry in the geometry set of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeometryIndex(pub usize);
+
+impl SetIndex for GeometryIndex {
+    const SET_NAME: &'static str = "geometry set";
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        GeometryIndex(index)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+}
+
+/// An error returned when an index is out of range for the set it indexes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexError {
+    /// The name of the set that was indexed.
+    pub set: &'static str,
+    /// The index that was out of range.
+    pub index: usize,
+    /// The number of entries in the set.
+    pub length: usize,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Index {} is out of range for a {} with {} entries.",
+            self.index, self.set, self.length
+        )
+    }
+}
+
+impl error::Error for IndexError {}
+
+/// A set of data in an object that can only be indexed by its own index type.
+///
+/// A typed set dereferences to the underlying vector, so it supports the
+/// usual vector and slice operations, but indexing with `[]` only accepts
+/// the index type of the set.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj::{
+/// #     Vertex,
+/// #     VertexIndex,
+/// #     VertexSet,
+/// # };
+/// #
+/// let vertex_set = VertexSet::from(vec![
+///     Vertex { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+///     Vertex { x: 1.0, y: 0.0, z: 0.0, w: 1.0 },
+/// ]);
+///
+/// assert_eq!(vertex_set[VertexIndex(1)].x, 1.0);
+/// assert!(vertex_set.try_get(VertexIndex(2)).is_err());
+/// ```
+pub struct TypedSet<I, T> {
+    /// The entries in the set.
+    items: Vec<T>,
+    /// The index type of the set.
+    index: PhantomData<fn() -> I>,
+}
+
+impl<I: SetIndex, T> TypedSet<I, T> {
+    /// Construct a new empty set.
+    pub fn new() -> Self {
+        TypedSet {
+            items: Vec::new(),
+            index: PhantomData,
+        }
+    }
+
+    /// Fetch an entry from the set, or return an error if the index is out
+    /// of range.
+    pub fn try_get(&self, index: I) -> Result<&T, IndexError> {
+        let length = self.items.len();
+        self.items.get(index.to_usize()).ok_or(IndexError {
+            set: I::SET_NAME,
+            index: index.to_usize(),
+            length: length,
+        })
+    }
+
+    /// Fetch a mutable entry from the set, or return an error if the index is
+    /// out of range.
+    pub fn try_get_mut(&mut self, index: I) -> Result<&mut T, IndexError> {
+        let length = self.items.len();
+        self.items.get_mut(index.to_usize()).ok_or(IndexError {
+            set: I::SET_NAME,
+            index: index.to_usize(),
+            length: length,
+        })
+    }
+
+    /// Iterate over the indices of the set.
+    pub fn indices(&self) -> impl Iterator<Item = I> {
+        (0..self.items.len()).map(I::from_usize)
+    }
+
+    /// Iterate over the entries of the set together with their indices.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (I, &T)> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (I::from_usize(index), item))
+    }
+
+    /// Add an entry to the end of the set, returning its index.
+    pub fn push_indexed(&mut self, item: T) -> I {
+        self.items.push(item);
+
+        I::from_usize(self.items.len() - 1)
+    }
+
+    /// Convert the set into the underlying vector.
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<I, T> ops::Deref for TypedSet<I, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.items
+    }
+}
+
+impl<I, T> ops::DerefMut for TypedSet<I, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+}
+
+impl<I: SetIndex, T> ops::Index<I> for TypedSet<I, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: I) -> &T {
+        &self.items[index.to_usize()]
+    }
+}
+
+impl<I: SetIndex, T> ops::IndexMut<I> for TypedSet<I, T> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut T {
+        &mut self.items[index.to_usize()]
+    }
+}
+
+impl<I, T> From<Vec<T>> for TypedSet<I, T> {
+    fn from(items: Vec<T>) -> Self {
+        TypedSet {
+            items: items,
+            index: PhantomData,
+        }
+    }
+}
+
+impl<I, T> FromIterator<T> for TypedSet<I, T> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        TypedSet::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<I, T> IntoIterator for TypedSet<I, T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, I, T> IntoIterator for &'a TypedSet<I, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<I, T: Clone> Clone for TypedSet<I, T> {
+    fn clone(&self) -> Self {
+        TypedSet::from(self.items.clone())
+    }
+}
+
+impl<I, T: fmt::Debug> fmt::Debug for TypedSet<I, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.items.fmt(formatter)
+    }
+}
+
+impl<I, T> Default for TypedSet<I, T> {
+    fn default() -> Self {
+        TypedSet::from(Vec::new())
+    }
+}
+
+impl<I, T: PartialEq> PartialEq for TypedSet<I, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<I, T: serde::Serialize> serde::Serialize for TypedSet<I, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.items.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I, T: serde::Deserialize<'de>> serde::Deserialize<'de> for TypedSet<I, T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(TypedSet::from)
+    }
+}
 
-type ElementIndex = usize;
-type VertexIndex = usize;
-type TextureVertexIndex = usize;
-type NormalVertexIndex = usize;
-type GroupIndex = usize;
-type SmoothingGroupIndex = usize;
-type ShapeEntryIndex = usize;
+/// The vertices of an object.
+pub type VertexSet = TypedSet<VertexIndex, Vertex>;
+/// The texture vertices of an object.
+pub type TextureVertexSet = TypedSet<TextureVertexIndex, TextureVertex>;
+/// The normal vectors of an object.
+pub type NormalVertexSet = TypedSet<NormalVertexIndex, NormalVertex>;
+/// The group names of an object.
+pub type GroupSet = TypedSet<GroupIndex, Group>;
+/// The smoothing groups of an object.
+pub type SmoothingGroupSet = TypedSet<SmoothingGroupIndex, SmoothingGroup>;
+/// The elements of an object.
+pub type ElementSet = TypedSet<ElementIndex, Element>;
+/// The shape entries of an object.
+pub type ShapeSet = TypedSet<ShapeEntryIndex, ShapeEntry>;
+/// The geometries of an object.
+pub type GeometrySet = TypedSet<GeometryIndex, Geometry>;
 
 
 /// An element is the smallest component of a more complex geometric figure.
@@ -276,6 +1549,7 @@ type ShapeEntryIndex = usize;
 /// are all the same type, i.e. a three-dimensional object is composed of all faces,
 /// or a line is composed of all line elements.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Element {
     Point(VTNIndex),
     Line(VTNIndex, VTNIndex),
@@ -298,12 +1572,63 @@ impl fmt::Display for Element {
     }
 }
 
-/// A group is a label for a collection of elements within an object.
-///
-/// A collection of groups enables one to organize collections of elements
-/// by group.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Group(pub String);
+impl str::FromStr for Element {
+    type Err = ParseError;
+
+    /// Parse a single `p`, `l`, or `f` statement that describes exactly one
+    /// element.
+    ///
+    /// Statements that describe more than one element, such as a polygon with
+    /// more than three vertices, are rejected. Relative (negative) indices
+    /// cannot be resolved in isolation and are rejected as well.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::{
+    /// #     Element,
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let element: Element = "f 1/1 2/2 3/3".parse().unwrap();
+    ///
+    /// assert_eq!(element, Element::Face(VTNIndex::VT(0, 0), VTNIndex::VT(1, 1), VTNIndex::VT(2, 2)));
+    /// assert!("f 1 2 3 4".parse::<Element>().is_err());
+    /// ```
+    fn from_str(st: &str) -> Result<Element, ParseError> {
+        let elements = parse_statement(st, |parser| {
+            let mut elements = vec![];
+            parser.parse_elements(
+                &mut elements,
+                ISOLATED_INDEX_RANGE,
+                ISOLATED_INDEX_RANGE,
+                ISOLATED_INDEX_RANGE,
+            )?;
+
+            Ok(elements)
+        })?;
+
+        match elements[..] {
+            [element] => Ok(element),
+            _ => Err(ParseError::new(
+                1,
+                ErrorKind::ExpectedSingleElement,
+                format!(
+                    "Expected a statement describing one element but it describes {} elements.",
+                    elements.len()
+                ),
+            )),
+        }
+    }
+}
+
+/// A group is a label for a collection of elements within an object.
+///
+/// A collection of groups enables one to organize collections of elements
+/// by group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group(pub String);
 
 impl fmt::Display for Group {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
@@ -321,6 +1646,7 @@ impl Default for Group {
 /// of elements should have their normal vectors interpolated over give
 /// those elements a non-faceted appearance.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SmoothingGroup(pub usize);
 
 impl fmt::Display for SmoothingGroup {
@@ -336,12 +1662,17 @@ impl fmt::Display for SmoothingGroup {
 /// A shape entry is a collection of indices grouping together all the
 /// organizational information about each element in an object.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeEntry {
     /// The index of the element in the element set that the shape entry describes.
     pub element: ElementIndex,
-    /// The groups that a particular element belongs to.
-    pub groups: Vec<GroupIndex>,
-    /// The smoothing group that a particular element belongs to.
+    /// The indices in the group set of the groups that a particular element
+    /// belongs to. Consecutive elements in the same groups share one list.
+    pub groups: Arc<[GroupIndex]>,
+    /// The index in the smoothing group set of the smoothing group that a
+    /// particular element belongs to. This is a position in the set, not the
+    /// value of the `s` statement; use [`Object::smoothing_group_of`] to get
+    /// the smoothing group itself.
     pub smoothing_group: SmoothingGroupIndex,
 }
 
@@ -364,14 +1695,94 @@ pub struct Shape {
 /// material library contains the data for rendering each primitive in the set of
 /// shapes.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Geometry {
     /// The material name that contains the material and lighting properties
     /// of each shape in this collection.
     pub material_name: Option<String>,
-    /// The shapes associated with a particular material and geometry.
+    /// The indices in the shape set of the shapes associated with a particular
+    /// material and geometry.
     pub shapes: Vec<ShapeEntryIndex>,
 }
 
+/// A run of consecutive elements of an object that belong to the same groups,
+/// the same smoothing group, and the same material.
+///
+/// Shape runs describe the organization of an object with one entry per
+/// change of group, smoothing group, or material instead of one shape entry
+/// per element.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeRun<'a> {
+    /// The indices in the element set of the elements in the run.
+    pub elements: ops::Range<usize>,
+    /// The indices in the group set of the groups the elements belong to.
+    pub groups: &'a [GroupIndex],
+    /// The index in the smoothing group set of the smoothing group the
+    /// elements belong to.
+    pub smoothing_group: SmoothingGroupIndex,
+    /// The name of the material of the elements, if they have one.
+    pub material_name: Option<&'a str>,
+}
+
+impl ShapeRun<'_> {
+    /// The number of elements in the run.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Determine whether the run contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+/// An iterator over the shape runs of an object, returned by
+/// [`Object::shape_runs`].
+#[derive(Clone, Debug)]
+pub struct ShapeRuns<'a> {
+    /// The shape entries of the object.
+    shapes: &'a [ShapeEntry],
+    /// The material name of each element of the object.
+    material_names: Vec<Option<&'a str>>,
+    /// The position of the next shape entry to visit.
+    position: usize,
+}
+
+impl<'a> ShapeRuns<'a> {
+    /// The material name of an element.
+    fn material_name(&self, element: ElementIndex) -> Option<&'a str> {
+        self.material_names.get(element.0).copied().flatten()
+    }
+}
+
+impl<'a> Iterator for ShapeRuns<'a> {
+    type Item = ShapeRun<'a>;
+
+    fn next(&mut self) -> Option<ShapeRun<'a>> {
+        let first = self.shapes.get(self.position)?;
+        let mut run = ShapeRun {
+            elements: first.element.0..(first.element.0 + 1),
+            groups: &first.groups,
+            smoothing_group: first.smoothing_group,
+            material_name: self.material_name(first.element),
+        };
+        self.position += 1;
+        while let Some(shape_entry) = self.shapes.get(self.position) {
+            if shape_entry.element.0 != run.elements.end
+                || *shape_entry.groups != *run.groups
+                || shape_entry.smoothing_group != run.smoothing_group
+                || self.material_name(shape_entry.element) != run.material_name
+            {
+                break;
+            }
+            run.elements.end += 1;
+            self.position += 1;
+        }
+
+        Some(run)
+    }
+}
+
 /// A VTN triple contains the actual data of each element in an object.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum VTNTriple<'a> {
@@ -381,33 +1792,419 @@ pub enum VTNTriple<'a> {
     VTN(&'a Vertex, &'a TextureVertex, &'a NormalVertex),
 }
 
+impl VTNTriple<'_> {
+    /// Copy the data the VTN triple refers to out of the object it borrows
+    /// from.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::{
+    /// #     NormalVertex,
+    /// #     VTNData,
+    /// #     VTNTriple,
+    /// #     Vertex,
+    /// # };
+    /// #
+    /// let vertex = Vertex { x: 1.0, y: 2.0, z: 3.0, w: 1.0 };
+    /// let normal_vertex = NormalVertex { x: 0.0, y: 0.0, z: 1.0 };
+    /// let data = VTNTriple::VN(&vertex, &normal_vertex).to_owned();
+    ///
+    /// assert_eq!(data, VTNData {
+    ///     vertex: vertex,
+    ///     texture_vertex: None,
+    ///     normal_vertex: Some(normal_vertex),
+    /// });
+    /// ```
+    pub fn to_owned(&self) -> VTNData {
+        match *self {
+            VTNTriple::V(&vertex) => VTNData {
+                vertex: vertex,
+                texture_vertex: None,
+                normal_vertex: None,
+            },
+            VTNTriple::VT(&vertex, &texture_vertex) => VTNData {
+                vertex: vertex,
+                texture_vertex: Some(texture_vertex),
+                normal_vertex: None,
+            },
+            VTNTriple::VN(&vertex, &normal_vertex) => VTNData {
+                vertex: vertex,
+                texture_vertex: None,
+                normal_vertex: Some(normal_vertex),
+            },
+            VTNTriple::VTN(&vertex, &texture_vertex, &normal_vertex) => VTNData {
+                vertex: vertex,
+                texture_vertex: Some(texture_vertex),
+                normal_vertex: Some(normal_vertex),
+            },
+        }
+    }
+}
+
+/// The vertex, texture vertex, and normal vector bound to one vertex of an
+/// element, held by value.
+///
+/// Unlike a [`VTNTriple`], this does not borrow from the object the data came
+/// from, so it can be stored independently of it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VTNData {
+    /// The position of the vertex.
+    pub vertex: Vertex,
+    /// The texture coordinates of the vertex, if it has any.
+    pub texture_vertex: Option<TextureVertex>,
+    /// The normal vector of the vertex, if it has one.
+    pub normal_vertex: Option<NormalVertex>,
+}
+
 /// An object is a collection of vertices, texture vertices, normal vectors,
 /// and geometric primitives composing a unit of geometry in a scene to
 /// be rendered.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Object {
     /// The name of the object.
     pub name: String,
+    /// The path of the file the object was parsed from, if the object was
+    /// loaded from disk.
+    pub source: Option<PathBuf>,
     /// The set of vertices in an object.
-    pub vertex_set: Vec<Vertex>,
+    pub vertex_set: VertexSet,
     /// The set of texture coordinates in an object for mapping materials onto
     /// an object.
-    pub texture_vertex_set: Vec<TextureVertex>,
+    pub texture_vertex_set: TextureVertexSet,
     /// The set of normal vectors defined at each vertex in an object.
-    pub normal_vertex_set: Vec<NormalVertex>,
+    pub normal_vertex_set: NormalVertexSet,
     /// The set of names of groups of elements in an object.
-    pub group_set: Vec<Group>,
+    pub group_set: GroupSet,
     /// The set of names of smoothing groups of elements in an object.
-    pub smoothing_group_set: Vec<SmoothingGroup>,
+    pub smoothing_group_set: SmoothingGroupSet,
     /// The set of primitives (i.e. points, lines, and faces) in an object.
-    pub element_set: Vec<Element>,
+    pub element_set: ElementSet,
     /// The set of grouping data associated with each element in an object.
-    pub shape_set: Vec<ShapeEntry>,
+    pub shape_set: ShapeSet,
     /// The set of elements associated with each material used in an object.
-    pub geometry_set: Vec<Geometry>,
+    pub geometry_set: GeometrySet,
+    /// The line of the source text each element in the element set was parsed
+    /// from. This is empty unless the parser was asked to record element lines
+    /// with [`ParserOptions::record_element_lines`].
+    pub element_line_set: Vec<usize>,
+    /// The text of the statement each element in the element set was parsed
+    /// from, e.g. `f 1/1 2/2 3/3 4/4` for both triangles of a quad. This is
+    /// empty unless the parser was asked to record element text with
+    /// [`ParserOptions::record_element_text`].
+    pub element_text_set: Vec<String>,
+    /// The elements in the element set with each VTN index given as its
+    /// 1-based position in the whole file, i.e. numbered as in the source
+    /// text. This is empty unless the parser was asked to record original
+    /// indices with [`ParserOptions::record_original_indices`].
+    pub original_element_set: Vec<Element>,
+    /// The numbers of each vertex statement exactly as written in the source
+    /// text. This is empty unless the parser was asked to record them with
+    /// [`ParserOptions::record_numeric_text`].
+    pub numeric_text: NumericText,
+}
+
+/// The numbers of the vertex statements of an object exactly as written in
+/// the source text, e.g. `1.000000 -0.5e-3 2` for the statement
+/// `v 1.000000 -0.5e-3 2`.
+///
+/// Each entry holds the arguments of one statement separated by single
+/// spaces, in the same order as the corresponding set of the object. Writing
+/// an object with [`WriteOptions::preserve_numeric_text`] re-emits the text
+/// of every vertex whose value the text still gives, so untouched vertices
+/// survive parsing and writing unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumericText {
+    /// The text of each `v` statement.
+    pub vertices: Vec<String>,
+    /// The text of each `vt` statement.
+    pub texture_vertices: Vec<String>,
+    /// The text of each `vn` statement.
+    pub normal_vertices: Vec<String>,
+}
+
+/// The vertex data of an object stored as a structure of arrays, with one
+/// array per component instead of one array of vertices.
+///
+/// Numeric post-processing that reads one component of every vertex at a
+/// time, such as computing bounds or applying a scale, touches contiguous
+/// memory in this layout. The arrays also pack directly into the `f32`
+/// attribute arrays that graphics APIs expect.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::ObjectSoA;
+/// #
+/// let object_set = obj::parse("v 1 2 3\nv 4 5 6\nvn 0 0 1\nf 1//1 2//1 1//1\n").unwrap();
+/// let soa = ObjectSoA::from(&object_set.objects[0]);
+///
+/// assert_eq!(soa.vertices.x, vec![1.0, 4.0]);
+/// assert_eq!(soa.vertices.packed(), vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// assert_eq!(soa.normal_vertices.packed(), vec![[0.0, 0.0, 1.0]]);
+/// assert_eq!(soa.element_set, object_set.objects[0].element_set);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectSoA {
+    /// The name of the object.
+    pub name: String,
+    /// The components of the vertices of the object.
+    pub vertices: VertexArrays,
+    /// The components of the texture vertices of the object.
+    pub texture_vertices: TextureVertexArrays,
+    /// The components of the normal vectors of the object.
+    pub normal_vertices: NormalVertexArrays,
+    /// The elements of the object, indexing into the arrays.
+    pub element_set: ElementSet,
+}
+
+impl From<&Object> for ObjectSoA {
+    fn from(object: &Object) -> ObjectSoA {
+        ObjectSoA {
+            name: object.name.clone(),
+            vertices: object.vertex_set.iter().copied().collect(),
+            texture_vertices: object.texture_vertex_set.iter().copied().collect(),
+            normal_vertices: object.normal_vertex_set.iter().copied().collect(),
+            element_set: object.element_set.clone(),
+        }
+    }
+}
+
+/// The components of a collection of vertices, one array per component.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VertexArrays {
+    /// The x-coordinate of each vertex.
+    pub x: Vec<f64>,
+    /// The y-coordinate of each vertex.
+    pub y: Vec<f64>,
+    /// The z-coordinate of each vertex.
+    pub z: Vec<f64>,
+    /// The w-coordinate of each vertex.
+    pub w: Vec<f64>,
+}
+
+impl VertexArrays {
+    /// The number of vertices.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Determine whether there are no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Fetch a vertex. The function returns `None` if the index is out of
+    /// range.
+    pub fn get(&self, index: usize) -> Option<Vertex> {
+        Some(Vertex {
+            x: *self.x.get(index)?,
+            y: *self.y.get(index)?,
+            z: *self.z.get(index)?,
+            w: *self.w.get(index)?,
+        })
+    }
+
+    /// Add a vertex to the end of the arrays.
+    pub fn push(&mut self, vertex: Vertex) {
+        self.x.push(vertex.x);
+        self.y.push(vertex.y);
+        self.z.push(vertex.z);
+        self.w.push(vertex.w);
+    }
+
+    /// Pack the x-, y-, and z-coordinates of each vertex into an array of
+    /// single precision positions.
+    pub fn packed(&self) -> Vec<[f32; 3]> {
+        (0..self.len())
+            .map(|i| [self.x[i] as f32, self.y[i] as f32, self.z[i] as f32])
+            .collect()
+    }
+}
+
+impl FromIterator<Vertex> for VertexArrays {
+    fn from_iter<I: IntoIterator<Item = Vertex>>(iter: I) -> VertexArrays {
+        let mut arrays = VertexArrays::default();
+        for vertex in iter {
+            arrays.push(vertex);
+        }
+
+        arrays
+    }
+}
+
+/// The components of a collection of texture vertices, one array per
+/// component.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextureVertexArrays {
+    /// The u-coordinate of each texture vertex.
+    pub u: Vec<f64>,
+    /// The v-coordinate of each texture vertex.
+    pub v: Vec<f64>,
+    /// The w-coordinate of each texture vertex.
+    pub w: Vec<f64>,
+}
+
+impl TextureVertexArrays {
+    /// The number of texture vertices.
+    pub fn len(&self) -> usize {
+        self.u.len()
+    }
+
+    /// Determine whether there are no texture vertices.
+    pub fn is_empty(&self) -> bool {
+        self.u.is_empty()
+    }
+
+    /// Fetch a texture vertex. The function returns `None` if the index is
+    /// out of range.
+    pub fn get(&self, index: usize) -> Option<TextureVertex> {
+        Some(TextureVertex {
+            u: *self.u.get(index)?,
+            v: *self.v.get(index)?,
+            w: *self.w.get(index)?,
+        })
+    }
+
+    /// Add a texture vertex to the end of the arrays.
+    pub fn push(&mut self, texture_vertex: TextureVertex) {
+        self.u.push(texture_vertex.u);
+        self.v.push(texture_vertex.v);
+        self.w.push(texture_vertex.w);
+    }
+
+    /// Pack the u- and v-coordinates of each texture vertex into an array of
+    /// single precision texture coordinates.
+    pub fn packed(&self) -> Vec<[f32; 2]> {
+        (0..self.len())
+            .map(|i| [self.u[i] as f32, self.v[i] as f32])
+            .collect()
+    }
+}
+
+impl FromIterator<TextureVertex> for TextureVertexArrays {
+    fn from_iter<I: IntoIterator<Item = TextureVertex>>(iter: I) -> TextureVertexArrays {
+        let mut arrays = TextureVertexArrays::default();
+        for texture_vertex in iter {
+            arrays.push(texture_vertex);
+        }
+
+        arrays
+    }
+}
+
+/// The components of a collection of normal vectors, one array per
+/// component.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NormalVertexArrays {
+    /// The x-component of each normal vector.
+    pub x: Vec<f64>,
+    /// The y-component of each normal vector.
+    pub y: Vec<f64>,
+    /// The z-component of each normal vector.
+    pub z: Vec<f64>,
+}
+
+impl NormalVertexArrays {
+    /// The number of normal vectors.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Determine whether there are no normal vectors.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Fetch a normal vector. The function returns `None` if the index is out
+    /// of range.
+    pub fn get(&self, index: usize) -> Option<NormalVertex> {
+        Some(NormalVertex {
+            x: *self.x.get(index)?,
+            y: *self.y.get(index)?,
+            z: *self.z.get(index)?,
+        })
+    }
+
+    /// Add a normal vector to the end of the arrays.
+    pub fn push(&mut self, normal_vertex: NormalVertex) {
+        self.x.push(normal_vertex.x);
+        self.y.push(normal_vertex.y);
+        self.z.push(normal_vertex.z);
+    }
+
+    /// Pack each normal vector into an array of single precision normals.
+    pub fn packed(&self) -> Vec<[f32; 3]> {
+        (0..self.len())
+            .map(|i| [self.x[i] as f32, self.y[i] as f32, self.z[i] as f32])
+            .collect()
+    }
+}
+
+impl FromIterator<NormalVertex> for NormalVertexArrays {
+    fn from_iter<I: IntoIterator<Item = NormalVertex>>(iter: I) -> NormalVertexArrays {
+        let mut arrays = NormalVertexArrays::default();
+        for normal_vertex in iter {
+            arrays.push(normal_vertex);
+        }
+
+        arrays
+    }
 }
 
 impl Object {
+    /// Fetch the text of the statement that an element was parsed from.
+    ///
+    /// The function returns `None` if the element does not exist, or if the
+    /// parser did not record element text.
+    pub fn element_text(&self, element_index: ElementIndex) -> Option<&str> {
+        self.element_text_set.get(element_index.0).map(String::as_str)
+    }
+
+    /// Fetch the line of the source text that an element was parsed from.
+    ///
+    /// The function returns `None` if the element does not exist, or if the
+    /// parser did not record element lines.
+    pub fn element_line(&self, element_index: ElementIndex) -> Option<usize> {
+        self.element_line_set.get(element_index.0).copied()
+    }
+
+    /// Fetch an element with its VTN indices numbered as in the source file.
+    ///
+    /// The function returns `None` if the element does not exist, or if the
+    /// parser did not record original indices.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::{
+    /// #     Element,
+    /// #     ElementIndex,
+    /// #     Parser,
+    /// #     ParserOptions,
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let obj_file = "o first\nv 0 0 0\np 1\no second\nv 1 1 1\nv 2 2 2\nl 2 3\n";
+    /// let options = ParserOptions {
+    ///     record_original_indices: true,
+    ///     ..ParserOptions::default()
+    /// };
+    /// let object_set = Parser::with_options(obj_file, options).parse_objset().unwrap();
+    /// let object = &object_set.objects[1];
+    ///
+    /// assert_eq!(
+    ///     object.original_element(ElementIndex(0)),
+    ///     Some(Element::Line(VTNIndex::V(2), VTNIndex::V(3)))
+    /// );
+    /// ```
+    pub fn original_element(&self, element_index: ElementIndex) -> Option<Element> {
+        self.original_element_set.get(element_index.0).copied()
+    }
+
     /// Fetch the vertex/texture/normal of a vertex in an object.
     ///
     /// The function returns `None` if any of the VTN indices are not found
@@ -491,7 +2288,7 @@ impl Object {
     /// // VTN indices lying outside the ones stored in the oject should return nothing.
     /// assert!(object.get_vtn_triple(VTNIndex::VTN(4, 4, 4)).is_none());
     /// ```
-    pub fn get_vtn_triple(&self, index: VTNIndex) -> Option<VTNTriple> {
+    pub fn get_vtn_triple(&self, index: VTNIndex) -> Option<VTNTriple<'_>> {
         match index {
             VTNIndex::V(v_index) => {
                 let vertex = self.vertex_set.get(v_index)?;
@@ -519,1855 +2316,10625 @@ impl Object {
             }
         }
     }
-}
-
-struct DisplayObjectCompositor {}
-
-impl DisplayObjectCompositor {
-    fn new() -> Self {
-        Self {}
-    }
 
-    fn compose_set<T: fmt::Display>(&self, set: &[T], name: &str) -> String {
-        let mut string = format!("    {} set:\n", name);
-        if set.is_empty() {
-            string += "        data: []\n";
-        } else {
-            string += &format!("        data: [({}) ... ({})]\n", set[0], set[set.len() - 1]);
-        }
-        string += &format!("        length: {}\n", set.len());
-
-        string
+    /// Fetch a copy of the vertex/texture/normal data of a vertex in an object.
+    ///
+    /// This is the owned counterpart of [`Object::get_vtn_triple`]. The
+    /// function returns `None` if any of the VTN indices are not found inside
+    /// the object.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::VTNIndex;
+    /// #
+    /// let object_set = obj::parse("v 1 2 3\nv 4 5 6\nvt 0.5 0.5\nl 1/1 2/1\n").unwrap();
+    /// let object = &object_set.objects[0];
+    /// let data = object.get_vtn_data(VTNIndex::VT(0, 0)).unwrap();
+    ///
+    /// assert_eq!(data.vertex.x, 1.0);
+    /// assert_eq!(data.texture_vertex.map(|texture_vertex| texture_vertex.u), Some(0.5));
+    /// assert!(data.normal_vertex.is_none());
+    /// assert!(object.get_vtn_data(VTNIndex::V(2)).is_none());
+    /// ```
+    pub fn get_vtn_data(&self, index: VTNIndex) -> Option<VTNData> {
+        self.get_vtn_triple(index).map(|triple| triple.to_owned())
     }
 
-    fn compose(&self, object: &Object) -> String {
-        let mut string = String::from("Object {\n");
-
-        string += &format!("    name: {}\n", object.name);
-        string += &self.compose_set(&object.vertex_set, "vertex");
-        string += &self.compose_set(&object.texture_vertex_set, "texture vertex");
-        string += &self.compose_set(&object.normal_vertex_set, "normal vertex");
-        string += &self.compose_set(&object.group_set, "group");
-        string += &self.compose_set(&object.smoothing_group_set, "smoothing group");
-        string += &self.compose_set(&object.element_set, "element");
-        string += "}}\n";
-
-        string
-    }
-}
+    /// Fetch the smoothing group that an element belongs to.
+    ///
+    /// Unlike [`ShapeEntry::smoothing_group`], which is a position in the
+    /// smoothing group set, this returns the value given in the `s` statement.
+    /// The function returns `None` if the element does not exist.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     ElementIndex,
+    /// #     ShapeEntryIndex,
+    /// #     SmoothingGroup,
+    /// #     SmoothingGroupIndex,
+    /// # };
+    /// #
+    /// let object_set = obj::parse("v 0 0 0\ns 16\np 1\ns off\np 1\n").unwrap();
+    /// let object = &object_set.objects[0];
+    ///
+    /// assert_eq!(object.shape_set[ShapeEntryIndex(0)].smoothing_group, SmoothingGroupIndex(0));
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(0)), Some(SmoothingGroup(16)));
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(1)), Some(SmoothingGroup(0)));
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(2)), None);
+    /// ```
+    pub fn smoothing_group_of(&self, element_index: ElementIndex) -> Option<SmoothingGroup> {
+        // The parser emits one shape entry per element in element order, so
+        // the entry is almost always at the same position as the element.
+        let shape_entry = match self.shape_set.as_slice().get(element_index.0) {
+            Some(shape_entry) if shape_entry.element == element_index => shape_entry,
+            _ => self
+                .shape_set
+                .iter()
+                .find(|shape_entry| shape_entry.element == element_index)?,
+        };
 
-impl fmt::Display for Object {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let string = DisplayObjectCompositor::new().compose(self);
-        write!(formatter, "{}", string)
+        self.smoothing_group_set
+            .try_get(shape_entry.smoothing_group)
+            .ok()
+            .copied()
     }
-}
 
-/// An object set is a collection of objects and material library named obtained
-/// from parsing an `*.obj` file. An `*.obj` file may contain more that one object.
-#[derive(Clone, Debug, PartialEq)]
-pub struct ObjectSet {
-    /// The set of material libraries associated with the object set.
-    pub material_libraries: Vec<String>,
-    /// The set of objects in an object set.
-    pub objects: Vec<Object>,
-}
-
-impl fmt::Display for ObjectSet {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let compositor = DisplayObjectCompositor::new();
-        let mut string = String::from("ObjectSet {\n");
-
-        for object in self.objects.iter() {
-            string += &compositor.compose(object);
-            string += &"\n";
+    /// Iterate over the runs of consecutive elements of the object that share
+    /// their groups, smoothing group, and material.
+    ///
+    /// The runs follow the order of the shape set, and the elements of a run
+    /// are consecutive in the element set. The number of runs grows with the
+    /// number of `g`, `s`, and `usemtl` statements rather than with the number
+    /// of faces.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// #
+    /// let obj_file = "\
+    ///     v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n\
+    ///     g top\nusemtl red\nf 1 2 3 4\n\
+    ///     usemtl blue\nf 1 2 3\n\
+    /// ";
+    /// let object_set = obj::parse(obj_file).unwrap();
+    /// let object = &object_set.objects[0];
+    /// let runs: Vec<_> = object.shape_runs().collect();
+    ///
+    /// assert_eq!(object.shape_set.len(), 3);
+    /// assert_eq!(runs.len(), 2);
+    /// assert_eq!(runs[0].elements, 0..2);
+    /// assert_eq!(runs[0].material_name, Some("red"));
+    /// assert_eq!(runs[1].elements, 2..3);
+    /// assert_eq!(runs[1].material_name, Some("blue"));
+    /// ```
+    pub fn shape_runs(&self) -> ShapeRuns<'_> {
+        let mut material_names = vec![None; self.element_set.len()];
+        for geometry in self.geometry_set.iter() {
+            for &shape in geometry.shapes.iter() {
+                let element = match self.shape_set.try_get(shape) {
+                    Ok(shape_entry) => shape_entry.element,
+                    Err(_) => continue,
+                };
+                if let Some(material_name) = material_names.get_mut(element.0) {
+                    *material_name = geometry.material_name.as_deref();
+                }
+            }
         }
 
-        string += &"}\n";
-
-        write!(formatter, "{}", string)
+        ShapeRuns {
+            shapes: self.shape_set.as_slice(),
+            material_names: material_names,
+            position: 0,
+        }
     }
 }
 
-/// A marker indicating the type of error generated during parsing of a
-/// Wavefront OBJ file.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum ErrorKind {
-    /// The parser reached the end of the input early.
-    EndOfFile,
-    /// The parser expected a tag statement that was not present.
-    ExpectedTagStatement,
-    /// The parser expected a floating point number but found something else.
-    ExpectedFloat,
-    /// The parser expected an integer but found something else.
-    ExpectedInteger,
-    /// The parser expected a vertex/texture/normal index but found something else.
-    ExpectedVTNIndex,
-    /// the parser encountered an object element index that is out of range.
-    VTNIndexOutOfRange,
-    /// The parser encountered a face element that did not have enough vertices.
-    EveryFaceElementMustHaveAtLeastThreeVertices,
-    /// An element had VTN indices with different forms.
-    EveryVTNIndexMustHaveTheSameFormForAGivenElement,
-    /// A statement in a wavefront obj file that is either unsupported or does not exist.
-    InvalidObjectStatement,
-    /// The parser encountered an invalid or unsupported element type.
-    ElementMustBeAPointLineOrFace,
-    /// The smoothing group name is something other than an integer or the default
-    /// value `off`.
-    SmoothingGroupNameMustBeOffOrInteger,
-    /// The smoothing group declaration is missing a name.
-    SmoothingGroupDeclarationHasNoName,
-    /// The `usemtl` statement has no corresponding material name.
-    MaterialStatementHasNoName,
-}
+impl Object {
+    /// Add a face to the object, checking its VTN indices first.
+    ///
+    /// The checks are the ones the parser applies to an `f` statement, and a
+    /// failed check is reported with the same [`ErrorKind`]: a face needs at
+    /// least three VTN indices, every VTN index must have the same form, and
+    /// every index must be in range of the corresponding set of the object.
+    /// The indices are zero-based positions in the sets of the object, and
+    /// the error has no line number. On failure the object is unchanged.
+    ///
+    /// The face is triangulated as a triangle fan, and the triangles join the
+    /// groups, the smoothing group, and the geometry of the last element of
+    /// the object, or the default group, no smoothing, and no material if the
+    /// object has no elements yet. The function returns the number of
+    /// triangles added.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     ErrorKind,
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let mut object = obj::parse("v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n").unwrap().objects.remove(0);
+    /// let quad = [VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2), VTNIndex::V(3)];
+    /// assert_eq!(object.try_push_face(&quad), Ok(2));
+    ///
+    /// let error = object.try_push_face(&[VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(4)]).unwrap_err();
+    /// assert_eq!(error.kind(), &ErrorKind::VTNIndexOutOfRange);
+    /// assert_eq!(object.element_set.len(), 2);
+    /// ```
+    pub fn try_push_face(&mut self, vtn_indices: &[VTNIndex]) -> Result<usize, ParseError> {
+        if vtn_indices.len() < 3 {
+            return Err(ParseError::new(
+                0,
+                ErrorKind::EveryFaceElementMustHaveAtLeastThreeVertices,
+                "A face primitive must have at least three vertices.".to_owned(),
+            ));
+        }
+        if !verify_vtn_indices(vtn_indices) {
+            return Err(ParseError::new(
+                0,
+                ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
+                "Every VTN index for a face must have the same form.".to_owned(),
+            ));
+        }
+        for vtn_index in vtn_indices.iter() {
+            let checks = [
+                (Some(vtn_index.vertex_index().0), self.vertex_set.len()),
+                (
+                    vtn_index.texture_vertex_index().map(|index| index.0),
+                    self.texture_vertex_set.len(),
+                ),
+                (
+                    vtn_index.normal_vertex_index().map(|index| index.0),
+                    self.normal_vertex_set.len(),
+                ),
+            ];
+            for (index, length) in checks.iter() {
+                match *index {
+                    Some(index) if index >= *length => {
+                        return Err(ParseError::new(
+                            0,
+                            ErrorKind::VTNIndexOutOfRange,
+                            format!("Expected index in range [0, {}), but got {}.", length, index),
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
 
-/// An error that is returned from parsing an invalid `*.obj` file, or
-/// another kind of error.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ParseError {
-    /// The line number where the error occurred.
-    pub line_number: usize,
-    /// The kind of error that occurred.
-    pub kind: ErrorKind,
-    /// A message describing why the parse error was generated.
-    pub message: String,
-}
+        let (groups, smoothing_group) = match self.shape_set.last() {
+            Some(shape_entry) => (shape_entry.groups.clone(), shape_entry.smoothing_group),
+            None => {
+                if self.group_set.is_empty() {
+                    self.group_set.push(Group::default());
+                }
+                if self.smoothing_group_set.is_empty() {
+                    self.smoothing_group_set.push(SmoothingGroup(0));
+                }
+                (Arc::from(&[GroupIndex(0)][..]), SmoothingGroupIndex(0))
+            }
+        };
+        if self.geometry_set.is_empty() {
+            self.geometry_set.push(Geometry {
+                material_name: None,
+                shapes: vec![],
+            });
+        }
 
-impl ParseError {
-    /// Construct a new parse error.
-    fn new(line_number: usize, kind: ErrorKind, message: String) -> ParseError {
-        ParseError {
-            line_number: line_number,
-            kind: kind,
-            message: message,
+        let first_element = self.element_set.len();
+        let face_count = triangulate(&mut self.element_set, vtn_indices);
+        let first_shape = self.shape_set.len();
+        self.shape_set
+            .extend((first_element..self.element_set.len()).map(|element| ShapeEntry {
+                element: ElementIndex(element),
+                groups: groups.clone(),
+                smoothing_group: smoothing_group,
+            }));
+        let shapes = first_shape..self.shape_set.len();
+        if let Some(geometry) = self.geometry_set.last_mut() {
+            geometry.shapes.extend(shapes.map(ShapeEntryIndex));
         }
-    }
-}
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(
-            formatter,
-            "Parse error at line {}: {}",
-            self.line_number, self.message
-        )
+        Ok(face_count)
     }
 }
 
-impl error::Error for ParseError {}
-
-
-/// A Wavefront OBJ file parser extracts three-dimensional geometric data
-/// from a `*.obj` file.
-#[derive(Clone)]
-pub struct Parser<'a> {
-    /// The current line position of the parser in the input stream.
-    line_number: usize,
-    /// the underlying lexer that generates tokens.
-    lexer: PeekableLexer<'a>,
+/// A reusable list of the VTN indices of a face, which adds the face to an
+/// object with [`Object::try_push_face`].
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::{
+/// #     FaceBuilder,
+/// #     VTNIndex,
+/// # };
+/// #
+/// let mut object = obj::parse("v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n").unwrap().objects.remove(0);
+/// let mut face = FaceBuilder::new();
+/// face.corner(VTNIndex::V(0)).corner(VTNIndex::V(1)).corner(VTNIndex::V(2));
+/// assert_eq!(face.push_to(&mut object), Ok(1));
+/// assert!(face.is_empty());
+///
+/// face.corner(VTNIndex::V(0)).corner(VTNIndex::V(2)).corner(VTNIndex::V(3));
+/// assert_eq!(face.push_to(&mut object), Ok(1));
+/// assert_eq!(object.element_set.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaceBuilder {
+    /// The VTN indices of the corners of the face, in winding order.
+    vtn_indices: Vec<VTNIndex>,
 }
 
-/// Triangulate a polygon with a triangle fan.
-///
-/// NOTE: the OBJ specification assumes that polygons are coplanar, and
-/// consequently the parser does not check this. It is up to the model creator
-/// to ensure this.
-#[inline]
-fn triangulate(elements: &mut Vec<Element>, vtn_indices: &[VTNIndex]) -> usize {
-    let vertex0 = vtn_indices[0];
-    for i in 0..(vtn_indices.len() - 2) {
-        elements.push(Element::Face(vertex0, vtn_indices[i + 1], vtn_indices[i + 2]));
+impl FaceBuilder {
+    /// Construct a new face builder with no corners.
+    pub fn new() -> FaceBuilder {
+        FaceBuilder { vtn_indices: vec![] }
     }
 
-    vtn_indices.len() - 2
-}
-
-/// Verify that each VTN index has the same type and has a valid form.
-#[inline]
-fn verify_vtn_indices(vtn_indices: &[VTNIndex]) -> bool {
-    for i in 1..vtn_indices.len() {
-        if !vtn_indices[i].has_same_type_as(&vtn_indices[0]) {
-            return false;
-        }
+    /// Add a corner to the face.
+    pub fn corner(&mut self, vtn_index: VTNIndex) -> &mut FaceBuilder {
+        self.vtn_indices.push(vtn_index);
+        self
     }
 
-    true
-}
+    /// The VTN indices of the corners added so far.
+    pub fn corners(&self) -> &[VTNIndex] {
+        &self.vtn_indices
+    }
 
-impl<'a> Parser<'a> {
-    /// Construct a new Wavefront OBJ file parser.
-    pub fn new(input: &'a str) -> Parser<'a> {
-        Parser {
-            line_number: 1,
-            lexer: PeekableLexer::new(Lexer::new(input)),
-        }
+    /// Determine whether the face has no corners yet.
+    pub fn is_empty(&self) -> bool {
+        self.vtn_indices.is_empty()
     }
 
-    /// Construct a new parse error.
-    fn error<T>(&self, kind: ErrorKind, message: String) -> Result<T, ParseError> {
-        Err(ParseError::new(self.line_number, kind, message))
+    /// Remove every corner from the face.
+    pub fn clear(&mut self) {
+        self.vtn_indices.clear();
     }
 
-    /// Peek at the currently held token without advancing the token stream.
-    fn peek(&mut self) -> Option<&'a str> {
-        self.lexer.peek()
+    /// Add the face to an object with [`Object::try_push_face`], and clear
+    /// the corners once it succeeds, so the builder can be reused for the
+    /// next face. On failure the corners are kept for inspection.
+    pub fn push_to(&mut self, object: &mut Object) -> Result<usize, ParseError> {
+        let face_count = object.try_push_face(&self.vtn_indices)?;
+        self.vtn_indices.clear();
+
+        Ok(face_count)
     }
+}
 
-    /// Advance the token stream one step returning the currently held string.
-    fn next(&mut self) -> Option<&'a str> {
-        let token = self.lexer.next();
-        if let Some(val) = token {
-            if val == "\n" {
-                self.line_number += 1;
+impl Object {
+    /// Move elements of the object into a single group, replacing the groups
+    /// they belonged to before.
+    ///
+    /// The group is added to the group set if the object does not have it
+    /// yet. Element indices that are out of range are ignored. The function
+    /// returns the index of the group in the group set.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     ElementIndex,
+    /// #     GroupIndex,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ng a\nf 1 2 3\nf 3 2 1\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    ///
+    /// assert_eq!(object.assign_group(&[ElementIndex(1)], "b"), GroupIndex(1));
+    /// assert_eq!(object.groups_of(ElementIndex(0)), vec!["a"]);
+    /// assert_eq!(object.groups_of(ElementIndex(1)), vec!["b"]);
+    /// ```
+    pub fn assign_group(&mut self, elements: &[ElementIndex], group_name: &str) -> GroupIndex {
+        let group_index = match self.group_index(group_name) {
+            Some(group_index) => group_index,
+            None => self.group_set.push_indexed(Group(String::from(group_name))),
+        };
+        let groups: Arc<[GroupIndex]> = Arc::from(&[group_index][..]);
+        let mut selected = vec![false; self.element_set.len()];
+        for &element in elements.iter() {
+            if let Some(is_selected) = selected.get_mut(element.0) {
+                *is_selected = true;
+            }
+        }
+        for shape_entry in self.shape_set.iter_mut() {
+            if selected.get(shape_entry.element.0).copied().unwrap_or(false) {
+                shape_entry.groups = groups.clone();
             }
         }
 
-        token
-    }
-
-    /// Advance the token stream one step without returning the current token.
-    fn advance(&mut self) {
-        self.next();
+        group_index
     }
 
-    /// Advance the token stream one step, returning the next token in the
-    /// stream.
+    /// Rename a group of the object.
     ///
-    /// This function generates an error is it runs out of input.
-    fn next_string(&mut self) -> Result<&'a str, ParseError> {
-        match self.next() {
-            Some(st) => Ok(st),
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Reached the end of the input in the process of getting the next token.".to_owned(),
-            ),
+    /// If the object already has a group with the new name, the two groups
+    /// are merged into it. The function returns `false` if the object has no
+    /// group with the old name.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ElementIndex;
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ng a\nf 1 2 3\ng b\nf 3 2 1\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    ///
+    /// assert!(object.rename_group("a", "b"));
+    /// assert_eq!(object.groups_of(ElementIndex(0)), vec!["b"]);
+    /// assert_eq!(object.groups_of(ElementIndex(1)), vec!["b"]);
+    /// assert!(!object.rename_group("a", "c"));
+    /// ```
+    pub fn rename_group(&mut self, old_name: &str, new_name: &str) -> bool {
+        let renamed = self.group_indices(old_name);
+        if renamed.is_empty() {
+            return false;
+        }
+        for &group_index in renamed.iter() {
+            self.group_set[group_index] = Group(String::from(new_name));
         }
+        self.merge_groups(new_name);
+
+        true
     }
 
-    /// Advance the token stream if the next token in the stream matches the
-    /// input tag.
+    /// Remove a group from the object.
     ///
-    /// This functions returns an error if the expected tag is not present.
-    fn expect_tag(&mut self, tag: &str) -> Result<(), ParseError> {
-        match self.next() {
-            None => self.error(
-                ErrorKind::EndOfFile,
-                "Reached the end of the input in the process of getting the next token.".to_owned(),
-            ),
-            Some(st) if st != tag => self.error(
-                ErrorKind::ExpectedTagStatement,
-                format!("Expected `{}` but got `{}` instead.", tag, st),
-            ),
-            _ => Ok(()),
+    /// The elements of the group leave it, and elements that belonged to no
+    /// other group move to the default group, just as the parser places
+    /// elements that appear before any `g` statement. The function returns
+    /// `false` if the object has no group with the name.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ElementIndex;
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ng a b\nf 1 2 3\ng b\nf 3 2 1\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    ///
+    /// assert!(object.remove_group("b"));
+    /// assert_eq!(object.groups_of(ElementIndex(0)), vec!["a"]);
+    /// assert_eq!(object.groups_of(ElementIndex(1)), vec!["default"]);
+    /// assert!(object.group_set.iter().all(|group| group.0 != "b"));
+    /// ```
+    pub fn remove_group(&mut self, group_name: &str) -> bool {
+        let removed = self.group_indices(group_name);
+        if removed.is_empty() {
+            return false;
         }
+        let orphaned = self
+            .shape_set
+            .iter()
+            .any(|shape_entry| shape_entry.groups.iter().all(|group| removed.contains(group)));
+        let default_index = if orphaned {
+            let default_group = Group::default();
+            let existing = self
+                .group_set
+                .iter_indexed()
+                .find(|&(index, group)| *group == default_group && !removed.contains(&index));
+            match existing {
+                Some((default_index, _)) => default_index,
+                None => self.group_set.push_indexed(default_group),
+            }
+        } else {
+            GroupIndex(0)
+        };
+        self.remap_groups(|groups| {
+            let kept: Vec<GroupIndex> = groups
+                .iter()
+                .copied()
+                .filter(|group| !removed.contains(group))
+                .collect();
+            if kept.is_empty() {
+                vec![default_index]
+            } else {
+                kept
+            }
+        });
+        self.drop_groups(&removed);
+
+        true
     }
 
-    /// Parse a floating point number from the current token in the stream.
-    fn parse_f64(&mut self) -> Result<f64, ParseError> {
-        let st = self.next_string()?;
-        match st.parse::<f64>() {
-            Ok(val) => Ok(val),
-            Err(_) => self.error(
-                ErrorKind::ExpectedFloat,
-                format!("Expected a floating point number but got `{}` instead.", st),
-            ),
-        }
+    /// Fetch the names of the groups that an element belongs to. The list is
+    /// empty if the element does not exist.
+    pub fn groups_of(&self, element_index: ElementIndex) -> Vec<&str> {
+        self.shape_set
+            .iter()
+            .find(|shape_entry| shape_entry.element == element_index)
+            .map(|shape_entry| {
+                shape_entry
+                    .groups
+                    .iter()
+                    .filter_map(|&group_index| self.group_set.try_get(group_index).ok())
+                    .map(|group| group.0.as_str())
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
-    /// Parse an integer from the current token in the stream.
-    fn parse_isize(&mut self) -> Result<isize, ParseError> {
-        let st = self.next_string()?;
-        match st.parse::<isize>() {
-            Ok(val) => Ok(val),
-            Err(_) => self.error(
-                ErrorKind::ExpectedInteger,
-                format!("Expected an integer but got `{}` instead.", st),
-            ),
+    /// The index in the group set of the first group with a given name.
+    fn group_index(&self, group_name: &str) -> Option<GroupIndex> {
+        self.group_set
+            .iter_indexed()
+            .find(|(_, group)| group.0 == group_name)
+            .map(|(index, _)| index)
+    }
+
+    /// The indices in the group set of every group with a given name. The
+    /// parser adds a group to the group set each time a `g` statement names
+    /// it, so a name can appear more than once.
+    fn group_indices(&self, group_name: &str) -> Vec<GroupIndex> {
+        self.group_set
+            .iter_indexed()
+            .filter(|(_, group)| group.0 == group_name)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Replace every group with a given name by the first one with that
+    /// name, and remove the rest from the group set.
+    fn merge_groups(&mut self, group_name: &str) {
+        let merged = self.group_indices(group_name);
+        if merged.len() < 2 {
+            return;
         }
+        self.remap_groups(|groups| {
+            groups
+                .iter()
+                .map(|&group| if merged.contains(&group) { merged[0] } else { group })
+                .collect()
+        });
+        self.drop_groups(&merged[1..]);
     }
 
-    /// Apply a parser to the input stream.
-    ///
-    /// If the parser `parser` fails to parse the current token in the stream,
-    /// it returns nothing and the stream state does not change. Otherwise, the
-    /// stream advances and the corresponding result is returned.
-    fn try_once<P, T>(&mut self, parser: P) -> Option<T>
-    where
-        P: FnOnce(&str) -> Option<T>,
-    {
-        match self.peek() {
-            Some(st) => parser(st).map(|got| {
-                self.advance();
-                got
-            }),
-            None => None,
+    /// Replace the group list of every shape entry, keeping the first of any
+    /// duplicate indices. Consecutive shape entries that shared a group list
+    /// before still share one afterwards.
+    fn remap_groups<F: Fn(&[GroupIndex]) -> Vec<GroupIndex>>(&mut self, f: F) {
+        let mut old_groups: Option<Arc<[GroupIndex]>> = None;
+        let mut new_groups: Arc<[GroupIndex]> = Arc::from(&[][..]);
+        for shape_entry in self.shape_set.iter_mut() {
+            let shared = old_groups
+                .as_ref()
+                .is_some_and(|old_groups| Arc::ptr_eq(old_groups, &shape_entry.groups));
+            if !shared {
+                let mut groups = Vec::with_capacity(shape_entry.groups.len());
+                for group in f(&shape_entry.groups) {
+                    if !groups.contains(&group) {
+                        groups.push(group);
+                    }
+                }
+                old_groups = Some(shape_entry.groups.clone());
+                new_groups = Arc::from(groups);
+            }
+            let groups = new_groups.clone();
+            shape_entry.groups = groups;
         }
     }
 
-    /// Parse a vertex from the input.
-    fn parse_vertex(&mut self) -> Result<Vertex, ParseError> {
-        self.expect_tag("v")?;
-
-        let x = self.parse_f64()?;
-        let y = self.parse_f64()?;
-        let z = self.parse_f64()?;
-        let mw = self.try_once(|st| st.parse::<f64>().ok());
-        let w = mw.unwrap_or(1_f64);
-
-        Ok(Vertex {
-            x: x,
-            y: y,
-            z: z,
-            w: w,
-        })
+    /// Remove groups that no shape entry refers to from the group set,
+    /// shifting the indices of the remaining groups down to match. The
+    /// indices must be in increasing order.
+    fn drop_groups(&mut self, removed: &[GroupIndex]) {
+        let mut index = 0;
+        self.group_set.retain(|_| {
+            let keep = !removed.contains(&GroupIndex(index));
+            index += 1;
+            keep
+        });
+        self.remap_groups(|groups| {
+            groups
+                .iter()
+                .map(|&group| {
+                    GroupIndex(group.0 - removed.iter().filter(|&&removed| removed < group).count())
+                })
+                .collect()
+        });
     }
+}
 
-    /// Parse a texture vertex from the input.
-    fn parse_texture_vertex(&mut self) -> Result<TextureVertex, ParseError> {
-        self.expect_tag("vt")?;
+impl Object {
+    /// Place a range of elements of the object in a smoothing group.
+    ///
+    /// The smoothing group is added to the smoothing group set if the object
+    /// does not have it yet. The range is given in element indices, and the
+    /// part of it past the end of the element set is ignored. The function
+    /// returns the number of elements whose smoothing group changed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     ElementIndex,
+    /// #     SmoothingGroup,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ns 1\nf 1 2 3\nf 3 2 1\nf 2 1 3\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    ///
+    /// assert_eq!(object.set_smoothing_group(ElementIndex(1)..ElementIndex(3), SmoothingGroup(4)), 2);
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(0)), Some(SmoothingGroup(1)));
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(1)), Some(SmoothingGroup(4)));
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(2)), Some(SmoothingGroup(4)));
+    /// ```
+    pub fn set_smoothing_group(
+        &mut self,
+        elements: ops::Range<ElementIndex>,
+        smoothing_group: SmoothingGroup,
+    ) -> usize {
+        let smoothing_group_index = match self
+            .smoothing_group_set
+            .iter()
+            .position(|existing| *existing == smoothing_group)
+        {
+            Some(smoothing_group_index) => SmoothingGroupIndex(smoothing_group_index),
+            None => self.smoothing_group_set.push_indexed(smoothing_group),
+        };
 
-        let u = self.parse_f64()?;
-        let mv = self.try_once(|st| st.parse::<f64>().ok());
-        let v = mv.unwrap_or(0_f64);
-        let mw = self.try_once(|st| st.parse::<f64>().ok());
-        let w = mw.unwrap_or(0_f64);
+        let mut changed = 0;
+        for shape_entry in self.shape_set.iter_mut() {
+            if elements.contains(&shape_entry.element) && shape_entry.smoothing_group != smoothing_group_index
+            {
+                shape_entry.smoothing_group = smoothing_group_index;
+                changed += 1;
+            }
+        }
 
-        Ok(TextureVertex { u: u, v: v, w: w })
+        changed
     }
 
-    /// Parse a normal vector from the input.
-    fn parse_normal_vertex(&mut self) -> Result<NormalVertex, ParseError> {
-        self.expect_tag("vn")?;
+    /// Turn smoothing off for a range of elements of the object, as the
+    /// statement `s off` does. This is the same as placing the elements in
+    /// smoothing group zero with [`Object::set_smoothing_group`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     ElementIndex,
+    /// #     SmoothingGroup,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ns 1\nf 1 2 3\nf 3 2 1\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    /// let elements = ElementIndex(0)..ElementIndex(object.element_set.len());
+    ///
+    /// assert_eq!(object.clear_smoothing_group(elements), 2);
+    /// assert_eq!(object.smoothing_group_of(ElementIndex(1)), Some(SmoothingGroup(0)));
+    /// ```
+    pub fn clear_smoothing_group(&mut self, elements: ops::Range<ElementIndex>) -> usize {
+        self.set_smoothing_group(elements, SmoothingGroup(0))
+    }
+}
 
-        let x = self.parse_f64()?;
-        let y = self.parse_f64()?;
-        let z = self.parse_f64()?;
+/// A compositor that summarizes the contents of objects for human readers.
+struct SummaryCompositor {}
 
-        Ok(NormalVertex { x: x, y: y, z: z })
+impl SummaryCompositor {
+    fn new() -> Self {
+        Self {}
     }
 
-    /// Skip over any number of newlines in the input stream.
-    fn skip_zero_or_more_newlines(&mut self) {
-        while let Some("\n") = self.peek() {
-            self.advance();
+    fn compose_set<T: fmt::Display>(&self, set: &[T], name: &str) -> String {
+        let mut string = format!("    {} set:\n", name);
+        if set.is_empty() {
+            string += "        data: []\n";
+        } else {
+            string += &format!("        data: [({}) ... ({})]\n", set[0], set[set.len() - 1]);
         }
+        string += &format!("        length: {}\n", set.len());
+
+        string
     }
 
-    /// Skip over at least one newline in the input stream.
+    fn compose(&self, object: &Object) -> String {
+        let mut string = String::from("Object {\n");
+
+        string += &format!("    name: {}\n", object.name);
+        string += &self.compose_set(&object.vertex_set, "vertex");
+        string += &self.compose_set(&object.texture_vertex_set, "texture vertex");
+        string += &self.compose_set(&object.normal_vertex_set, "normal vertex");
+        string += &self.compose_set(&object.group_set, "group");
+        string += &self.compose_set(&object.smoothing_group_set, "smoothing group");
+        string += &self.compose_set(&object.element_set, "element");
+        string += "}\n";
+
+        string
+    }
+}
+
+impl Object {
+    /// Summarize the contents of an object for human readers.
     ///
-    /// The function returns an error if no newline tokens are present.
-    fn skip_one_or_more_newlines(&mut self) -> Result<(), ParseError> {
-        self.expect_tag("\n")?;
-        self.skip_zero_or_more_newlines();
-        Ok(())
+    /// The summary lists the name of the object, and the first entry, last
+    /// entry, and length of each of its sets. Use the [`fmt::Display`]
+    /// implementation to get the object as Wavefront OBJ text instead.
+    pub fn summary(&self) -> String {
+        SummaryCompositor::new().compose(self)
     }
+}
 
-    /// Parse the name of an object.
-    fn parse_object_name(&mut self) -> Result<&'a str, ParseError> {
-        match self.peek() {
-            Some("o") => {
-                self.expect_tag("o")?;
-                let object_name = self.next_string();
-                self.skip_one_or_more_newlines()?;
+/// Writes an object as Wavefront OBJ text, as written by the
+/// [`TextObjectSetCompositor`]. The indices in the output are relative to the
+/// start of the object.
+impl fmt::Display for Object {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut bytes = vec![];
+        TextObjectSetCompositor::new()
+            .write_object(&mut bytes, self, (0, 0, 0))
+            .map_err(|_| fmt::Error)?;
 
-                object_name
-            }
-            _ => Ok(""),
-        }
+        formatter.write_str(str::from_utf8(&bytes).map_err(|_| fmt::Error)?)
     }
+}
 
-    #[inline(always)]
-    fn calculate_index(&self, value_range: (usize, usize), parsed_value: isize) -> Result<usize, ParseError> {
-        let (min_value, max_value) = value_range;
-        let actual_value = if parsed_value <= 0 {
-            max_value as isize - parsed_value
-        } else {
-            parsed_value - 1
-        };
+/// The faces around each vertex of an object, and the vertices that share an
+/// edge with it, i.e. its one-ring.
+///
+/// Build it once with [`Object::vertex_adjacency`] and query it as often as
+/// needed. Only face elements contribute; points and lines are ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VertexAdjacency {
+    /// The start of the faces of each vertex in `faces`, followed by the
+    /// total number of faces.
+    face_offsets: Vec<usize>,
+    /// The faces of every vertex, in vertex order.
+    faces: Vec<ElementIndex>,
+    /// The start of the neighbors of each vertex in `neighbors`, followed by
+    /// the total number of neighbors.
+    neighbor_offsets: Vec<usize>,
+    /// The neighbors of every vertex, in vertex order.
+    neighbors: Vec<VertexIndex>,
+}
 
-        if (actual_value >= min_value as isize) && (actual_value < max_value as isize) {
-            debug_assert!(actual_value >= 0);
-            Ok((actual_value - min_value as isize) as usize)
-        } else {
-            self.error(
-                ErrorKind::VTNIndexOutOfRange,
-                format!(
-                    "Expected index in range [{}, {}), but got {}.",
-                    min_value, max_value, actual_value
-                ),
-            )
-        }
+impl VertexAdjacency {
+    /// The number of vertices in the adjacency.
+    pub fn len(&self) -> usize {
+        self.face_offsets.len() - 1
     }
 
-    /// Parse a vertex/texture/normal index.
-    fn parse_vtn_index(
-        &mut self,
-        vertex_index_range: (usize, usize),
-        texture_index_range: (usize, usize),
-        normal_index_range: (usize, usize),
-    ) -> Result<VTNIndex, ParseError> {
-        let st = self.next_string()?;
-        let process_split = |split: &str, value_range: (usize, usize)| -> Result<Option<usize>, ParseError> {
-            if !split.is_empty() {
-                let parsed_value = split.parse::<isize>().or_else(|_| {
-                    self.error(
-                        ErrorKind::ExpectedInteger,
-                        format!("Expected an integer but got `{}` instead.", split),
-                    )
-                })?;
-                let index = self.calculate_index(value_range, parsed_value)?;
-                Ok(Some(index))
-            } else {
-                Ok(None)
-            }
-        };
+    /// Determine whether the adjacency has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
-        let mut splits_iter = st.split('/');
-        let split1 = splits_iter
-            .next()
-            .and_then(|s| process_split(s, vertex_index_range).transpose())
-            .transpose()?;
-        let split2 = splits_iter
-            .next()
-            .and_then(|s| process_split(s, texture_index_range).transpose())
-            .transpose()?;
-        let split3 = splits_iter
-            .next()
-            .and_then(|s| process_split(s, normal_index_range).transpose())
-            .transpose()?;
-        if split1.is_none() || splits_iter.next().is_some() {
-            return self.error(
-                ErrorKind::ExpectedVTNIndex,
-                format!(
-                    "Expected a `vertex/texture/normal` index but got `{}` instead.",
-                    st
-                ),
-            );
+    /// The faces that a vertex is a corner of, in element order.
+    ///
+    /// The slice is empty if the vertex does not exist.
+    pub fn faces(&self, vertex: VertexIndex) -> &[ElementIndex] {
+        if vertex.0 >= self.len() {
+            return &[];
         }
 
-        match (split1, split2, split3) {
-            (Some(v), None, None) => Ok(VTNIndex::V(v)),
-            (Some(v), None, Some(vn)) => Ok(VTNIndex::VN(v, vn)),
-            (Some(v), Some(vt), None) => Ok(VTNIndex::VT(v, vt)),
-            (Some(v), Some(vt), Some(vn)) => Ok(VTNIndex::VTN(v, vt, vn)),
-            _ => self.error(
-                ErrorKind::ExpectedVTNIndex,
-                format!(
-                    "Expected a `vertex/texture/normal` index but got `{}` instead.",
-                    st
-                ),
-            ),
-        }
+        &self.faces[self.face_offsets[vertex.0]..self.face_offsets[vertex.0 + 1]]
     }
 
-    /// Parse one more more VTN indices.
+    /// The vertices that share a face edge with a vertex, in vertex order.
     ///
-    /// Return the number of VTN indices parsed if no errors occurred.
-    fn parse_vtn_indices(
-        &mut self,
-        vtn_indices: &mut Vec<VTNIndex>,
-        vertex_index_range: (usize, usize),
-        texture_index_range: (usize, usize),
-        normal_index_range: (usize, usize),
-    ) -> Result<usize, ParseError> {
-        let mut indices_parsed = 0;
-        while let Ok(vtn_index) =
-            self.parse_vtn_index(vertex_index_range, texture_index_range, normal_index_range)
-        {
-            vtn_indices.push(vtn_index);
-            indices_parsed += 1;
+    /// The slice is empty if the vertex does not exist.
+    pub fn neighbors(&self, vertex: VertexIndex) -> &[VertexIndex] {
+        if vertex.0 >= self.len() {
+            return &[];
         }
 
-        Ok(indices_parsed)
+        &self.neighbors[self.neighbor_offsets[vertex.0]..self.neighbor_offsets[vertex.0 + 1]]
+    }
+}
+
+/// Flatten a list per vertex into one list and the offsets of each vertex in it.
+fn flatten_adjacency<T: Copy + Ord>(lists: Vec<Vec<T>>) -> (Vec<usize>, Vec<T>) {
+    let mut offsets = Vec::with_capacity(lists.len() + 1);
+    let mut items = Vec::with_capacity(lists.iter().map(Vec::len).sum());
+    offsets.push(0);
+    for mut list in lists {
+        list.sort_unstable();
+        list.dedup();
+        items.extend(list);
+        offsets.push(items.len());
     }
 
-    /// Parse one or more point from the current line in the input stream.
-    ///
-    /// There can be more than one point in a single line of input, so
-    /// this parsing rule will attempt to read all of them.
-    fn parse_point(
-        &mut self,
-        elements: &mut Vec<Element>,
-        vertex_index_range: (usize, usize),
-    ) -> Result<usize, ParseError> {
-        self.expect_tag("p")?;
+    (offsets, items)
+}
 
-        let parsed_value = self.parse_isize()?;
-        let v_index = self.calculate_index(vertex_index_range, parsed_value)?;
-        elements.push(Element::Point(VTNIndex::V(v_index)));
-        let mut elements_parsed = 1;
-        loop {
-            match self.next() {
-                Some(st) if st != "\n" => match st.parse::<isize>() {
-                    Ok(val) => {
-                        let v_index = self.calculate_index(vertex_index_range, val)?;
-                        elements.push(Element::Point(VTNIndex::V(v_index)));
-                        elements_parsed += 1;
+impl Object {
+    /// Build the face and vertex adjacency of each vertex in the object.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     ElementIndex,
+    /// #     VertexIndex,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4\n";
+    /// let object_set = obj::parse(obj_file).unwrap();
+    /// let adjacency = object_set.objects[0].vertex_adjacency();
+    ///
+    /// assert_eq!(adjacency.faces(VertexIndex(0)), &[ElementIndex(0), ElementIndex(1)]);
+    /// assert_eq!(adjacency.faces(VertexIndex(1)), &[ElementIndex(0)]);
+    /// assert_eq!(
+    ///     adjacency.neighbors(VertexIndex(0)),
+    ///     &[VertexIndex(1), VertexIndex(2), VertexIndex(3)]
+    /// );
+    /// assert_eq!(adjacency.neighbors(VertexIndex(1)), &[VertexIndex(0), VertexIndex(2)]);
+    /// ```
+    pub fn vertex_adjacency(&self) -> VertexAdjacency {
+        let vertex_count = self.vertex_set.len();
+        let mut vertex_faces: Vec<Vec<ElementIndex>> = vec![vec![]; vertex_count];
+        let mut vertex_neighbors: Vec<Vec<VertexIndex>> = vec![vec![]; vertex_count];
+        for (element_index, element) in self.element_set.iter().enumerate() {
+            if let Element::Face(vtn1, vtn2, vtn3) = *element {
+                let corners = [vtn1.vertex_index(), vtn2.vertex_index(), vtn3.vertex_index()];
+                for i in 0..3 {
+                    let vertex = corners[i];
+                    if vertex.0 >= vertex_count {
+                        continue;
                     }
-                    Err(_) => {
-                        return self.error(
-                            ErrorKind::ExpectedInteger,
-                            format!("Expected an integer but got `{}` instead.", st),
-                        )
+                    vertex_faces[vertex.0].push(ElementIndex(element_index));
+                    for &neighbor in [corners[(i + 1) % 3], corners[(i + 2) % 3]].iter() {
+                        if neighbor != vertex {
+                            vertex_neighbors[vertex.0].push(neighbor);
+                        }
                     }
-                },
-                _ => break,
+                }
             }
         }
 
-        Ok(elements_parsed)
+        let (face_offsets, faces) = flatten_adjacency(vertex_faces);
+        let (neighbor_offsets, neighbors) = flatten_adjacency(vertex_neighbors);
+
+        VertexAdjacency {
+            face_offsets: face_offsets,
+            faces: faces,
+            neighbor_offsets: neighbor_offsets,
+            neighbors: neighbors,
+        }
     }
+}
 
-    /// Parse one more more line elements from a line of text input from the input.
-    ///
-    /// If the parser cannot parse each line element from a line of text input, the
-    /// parser returns an error.
-    fn parse_line(
-        &mut self,
-        elements: &mut Vec<Element>,
-        vertex_index_range: (usize, usize),
-        texture_index_range: (usize, usize),
-        normal_index_range: (usize, usize),
-    ) -> Result<usize, ParseError> {
-        self.expect_tag("l")?;
+/// A symmetric 4x4 matrix measuring the squared distance of a point to a set
+/// of planes, as used by quadric error metric simplification.
+#[derive(Copy, Clone, Debug, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    /// The quadric of the plane `a*x + b*y + c*z + d = 0`, scaled by `weight`.
+    fn from_plane(a: f64, b: f64, c: f64, d: f64, weight: f64) -> Quadric {
+        Quadric([
+            weight * a * a,
+            weight * a * b,
+            weight * a * c,
+            weight * a * d,
+            weight * b * b,
+            weight * b * c,
+            weight * b * d,
+            weight * c * c,
+            weight * c * d,
+            weight * d * d,
+        ])
+    }
 
-        let mut vtn_indices = vec![];
-        vtn_indices.push(self.parse_vtn_index(
-            vertex_index_range,
-            texture_index_range,
-            normal_index_range,
-        )?);
-        vtn_indices.push(self.parse_vtn_index(
-            vertex_index_range,
-            texture_index_range,
-            normal_index_range,
-        )?);
-        self.parse_vtn_indices(
-            &mut vtn_indices,
-            vertex_index_range,
-            texture_index_range,
-            normal_index_range,
-        )?;
-
-        if !verify_vtn_indices(&vtn_indices) {
-            return self.error(
-                ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
-                "Every VTN index for a line must have the same form.".to_owned(),
-            );
+    fn add(&mut self, other: &Quadric) {
+        for i in 0..10 {
+            self.0[i] += other.0[i];
         }
+    }
 
-        // Now that we have verified the indices, build the line elements.
-        for i in 0..(vtn_indices.len() - 1) {
-            elements.push(Element::Line(vtn_indices[i], vtn_indices[i + 1]));
-        }
+    /// The sum of the squared distances of a point to the planes.
+    fn error(&self, vertex: &Vertex) -> f64 {
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, dd] = self.0;
+        let (x, y, z) = (vertex.x, vertex.y, vertex.z);
+
+        aa * x * x
+            + 2_f64 * ab * x * y
+            + 2_f64 * ac * x * z
+            + 2_f64 * ad * x
+            + bb * y * y
+            + 2_f64 * bc * y * z
+            + 2_f64 * bd * y
+            + cc * z * z
+            + 2_f64 * cd * z
+            + dd
+    }
+}
 
-        Ok(vtn_indices.len() - 1)
+/// A candidate edge collapse, ordered so that the cheapest collapse is at the
+/// top of a [`BinaryHeap`].
+#[derive(Copy, Clone, Debug)]
+struct EdgeCollapse {
+    cost: f64,
+    vertices: (usize, usize),
+    stamps: (usize, usize),
+    position: Vertex,
+}
+
+impl PartialEq for EdgeCollapse {
+    fn eq(&self, other: &EdgeCollapse) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
     }
+}
 
-    /// Parse one or more faces from a single line of text input.
-    ///
-    /// All face vertices must have the same vertex/texture/normal form on
-    /// a line of input. If they do not, the parser will return an error. Otherwise,
-    /// it succeeds. The face parser unpacks the face elements by treating the line
-    /// of face indices as a triangle fan.
-    ///
-    /// The parser returns the number of triangles generated.
-    fn parse_face(
-        &mut self,
-        elements: &mut Vec<Element>,
-        vertex_index_range: (usize, usize),
-        texture_index_range: (usize, usize),
-        normal_index_range: (usize, usize),
-    ) -> Result<usize, ParseError> {
-        self.expect_tag("f")?;
+impl Eq for EdgeCollapse {}
+
+impl PartialOrd for EdgeCollapse {
+    fn partial_cmp(&self, other: &EdgeCollapse) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        let mut vtn_indices = vec![];
+impl Ord for EdgeCollapse {
+    fn cmp(&self, other: &EdgeCollapse) -> cmp::Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.vertices.cmp(&self.vertices))
+    }
+}
 
-        self.parse_vtn_indices(
-            &mut vtn_indices,
-            vertex_index_range,
-            texture_index_range,
-            normal_index_range,
-        )?;
+/// The unnormalized normal vector of a triangle.
+fn face_normal(vertex1: &Vertex, vertex2: &Vertex, vertex3: &Vertex) -> Vertex {
+    (*vertex2 - *vertex1).cross(&(*vertex3 - *vertex1))
+}
 
-        // Check that there are enough vtn indices.
-        if vtn_indices.len() < 3 {
-            return self.error(
-                ErrorKind::EveryFaceElementMustHaveAtLeastThreeVertices,
-                "A face primitive must have at least three vertices.".to_owned(),
+/// Replace the vertex index of a VTN index, keeping its texture and normal
+/// vertex indices.
+fn with_vertex_index(vtn_index: VTNIndex, v: usize) -> VTNIndex {
+    match vtn_index {
+        VTNIndex::V(_) => VTNIndex::V(v),
+        VTNIndex::VT(_, vt) => VTNIndex::VT(v, vt),
+        VTNIndex::VN(_, vn) => VTNIndex::VN(v, vn),
+        VTNIndex::VTN(_, vt, vn) => VTNIndex::VTN(v, vt, vn),
+    }
+}
+
+/// Apply a function to each VTN index of an element.
+fn map_vtn_indices<F: FnMut(VTNIndex) -> VTNIndex>(element: Element, mut f: F) -> Element {
+    match element {
+        Element::Point(vtn1) => Element::Point(f(vtn1)),
+        Element::Line(vtn1, vtn2) => Element::Line(f(vtn1), f(vtn2)),
+        Element::Face(vtn1, vtn2, vtn3) => Element::Face(f(vtn1), f(vtn2), f(vtn3)),
+    }
+}
+
+/// Append the geometry of one object to another object, rebasing the indices
+/// of the appended elements, shapes, and geometries.
+///
+/// Groups and smoothing groups that both objects share are stored once.
+fn append_object(object: &mut Object, other: Object) {
+    let vertex_offset = object.vertex_set.len();
+    let texture_vertex_offset = object.texture_vertex_set.len();
+    let normal_vertex_offset = object.normal_vertex_set.len();
+    let element_offset = object.element_set.len();
+    let shape_offset = object.shape_set.len();
+
+    let group_map: Vec<GroupIndex> = other
+        .group_set
+        .into_iter()
+        .map(
+            |group| match object.group_set.iter().position(|existing| *existing == group) {
+                Some(index) => GroupIndex(index),
+                None => object.group_set.push_indexed(group),
+            },
+        )
+        .collect();
+    let smoothing_group_map: Vec<SmoothingGroupIndex> = other
+        .smoothing_group_set
+        .into_iter()
+        .map(|smoothing_group| {
+            match object
+                .smoothing_group_set
+                .iter()
+                .position(|existing| *existing == smoothing_group)
+            {
+                Some(index) => SmoothingGroupIndex(index),
+                None => object.smoothing_group_set.push_indexed(smoothing_group),
+            }
+        })
+        .collect();
+
+    object.vertex_set.extend(other.vertex_set);
+    object.texture_vertex_set.extend(other.texture_vertex_set);
+    object.normal_vertex_set.extend(other.normal_vertex_set);
+    object
+        .element_set
+        .extend(other.element_set.into_iter().map(|element| {
+            map_vtn_indices(element, |vtn_index| match vtn_index {
+                VTNIndex::V(v) => VTNIndex::V(v + vertex_offset),
+                VTNIndex::VT(v, vt) => VTNIndex::VT(v + vertex_offset, vt + texture_vertex_offset),
+                VTNIndex::VN(v, vn) => VTNIndex::VN(v + vertex_offset, vn + normal_vertex_offset),
+                VTNIndex::VTN(v, vt, vn) => VTNIndex::VTN(
+                    v + vertex_offset,
+                    vt + texture_vertex_offset,
+                    vn + normal_vertex_offset,
+                ),
+            })
+        }));
+    object.shape_set.extend(other.shape_set.into_iter().map(|shape| {
+        ShapeEntry {
+            element: ElementIndex(shape.element.0 + element_offset),
+            groups: shape
+                .groups
+                .iter()
+                .map(|&group| group_map.get(group.0).copied().unwrap_or(group))
+                .collect(),
+            smoothing_group: smoothing_group_map
+                .get(shape.smoothing_group.0)
+                .copied()
+                .unwrap_or(shape.smoothing_group),
+        }
+    }));
+    object
+        .geometry_set
+        .extend(other.geometry_set.into_iter().map(|geometry| {
+            Geometry {
+                material_name: geometry.material_name,
+                shapes: geometry
+                    .shapes
+                    .iter()
+                    .map(|&shape| ShapeEntryIndex(shape.0 + shape_offset))
+                    .collect(),
+            }
+        }));
+    object.element_line_set.extend(other.element_line_set);
+    object.element_text_set.extend(other.element_text_set);
+    object.original_element_set.extend(other.original_element_set);
+    object.numeric_text.vertices.extend(other.numeric_text.vertices);
+    object
+        .numeric_text
+        .texture_vertices
+        .extend(other.numeric_text.texture_vertices);
+    object
+        .numeric_text
+        .normal_vertices
+        .extend(other.numeric_text.normal_vertices);
+}
+
+/// Find the first name of the form `name.001`, `name.002`, and so on that no
+/// object uses yet.
+fn free_object_name(name: &str, object_names: &HashMap<String, usize>) -> String {
+    (1..)
+        .map(|suffix| format!("{}.{:03}", name, suffix))
+        .find(|candidate| !object_names.contains_key(candidate))
+        .unwrap()
+}
+
+/// The state of an edge collapse simplification of the faces of an object.
+struct Simplifier {
+    positions: Vec<Vertex>,
+    quadrics: Vec<Quadric>,
+    stamps: Vec<usize>,
+    removed: Vec<bool>,
+    /// The corners of each element, or `None` for points, lines, and faces
+    /// that have collapsed.
+    faces: Vec<Option<[VTNIndex; 3]>>,
+    /// The number of faces whose vertices all exist.
+    face_count: usize,
+    vertex_faces: Vec<Vec<usize>>,
+    heap: BinaryHeap<EdgeCollapse>,
+}
+
+impl Simplifier {
+    fn new(object: &Object) -> Simplifier {
+        let positions: Vec<Vertex> = object.vertex_set.iter().copied().collect();
+        let vertex_count = positions.len();
+        let mut quadrics = vec![Quadric::default(); vertex_count];
+        let mut vertex_faces = vec![vec![]; vertex_count];
+        let mut faces = Vec::with_capacity(object.element_set.len());
+        let mut edge_faces: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut face_count = 0;
+        for (element_index, element) in object.element_set.iter().enumerate() {
+            let face = match *element {
+                Element::Face(vtn1, vtn2, vtn3) => [vtn1, vtn2, vtn3],
+                _ => {
+                    faces.push(None);
+                    continue;
+                }
+            };
+            let corners = face.map(|vtn_index| vtn_index.vertex_index().0);
+            faces.push(Some(face));
+            if corners.iter().any(|&v| v >= vertex_count) {
+                continue;
+            }
+            face_count += 1;
+
+            let normal = face_normal(
+                &positions[corners[0]],
+                &positions[corners[1]],
+                &positions[corners[2]],
             );
+            let length = normal.dot(&normal).sqrt();
+            if length > 0_f64 {
+                let (a, b, c) = (normal.x / length, normal.y / length, normal.z / length);
+                let origin = &positions[corners[0]];
+                let d = -(a * origin.x + b * origin.y + c * origin.z);
+                let quadric = Quadric::from_plane(a, b, c, d, length / 2_f64);
+                for &v in corners.iter() {
+                    quadrics[v].add(&quadric);
+                }
+            }
+            for i in 0..3 {
+                let (v1, v2) = (corners[i], corners[(i + 1) % 3]);
+                *edge_faces.entry((v1.min(v2), v1.max(v2))).or_insert(0) += 1;
+                if !vertex_faces[corners[i]].contains(&element_index) {
+                    vertex_faces[corners[i]].push(element_index);
+                }
+            }
         }
 
-        if !verify_vtn_indices(&vtn_indices) {
-            return self.error(
-                ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
-                "Every VTN index for a face must have the same form.".to_owned(),
+        // Constrain boundary edges with a plane perpendicular to their face so
+        // that open borders do not shrink.
+        for face in faces.iter().flatten() {
+            let corners = face.map(|vtn_index| vtn_index.vertex_index().0);
+            if corners.iter().any(|&v| v >= vertex_count) {
+                continue;
+            }
+            let normal = face_normal(
+                &positions[corners[0]],
+                &positions[corners[1]],
+                &positions[corners[2]],
             );
+            for i in 0..3 {
+                let (v1, v2) = (corners[i], corners[(i + 1) % 3]);
+                if edge_faces.get(&(v1.min(v2), v1.max(v2))) != Some(&1) {
+                    continue;
+                }
+                let edge = positions[v2] - positions[v1];
+                let perpendicular = edge.cross(&normal);
+                let length = perpendicular.dot(&perpendicular).sqrt();
+                if length == 0_f64 {
+                    continue;
+                }
+                let (a, b, c) = (
+                    perpendicular.x / length,
+                    perpendicular.y / length,
+                    perpendicular.z / length,
+                );
+                let origin = &positions[v1];
+                let d = -(a * origin.x + b * origin.y + c * origin.z);
+                let quadric = Quadric::from_plane(a, b, c, d, BOUNDARY_WEIGHT * edge.dot(&edge));
+                quadrics[v1].add(&quadric);
+                quadrics[v2].add(&quadric);
+            }
         }
 
-        let face_count = triangulate(elements, &vtn_indices);
+        let mut simplifier = Simplifier {
+            positions: positions,
+            quadrics: quadrics,
+            stamps: vec![0; vertex_count],
+            removed: vec![false; vertex_count],
+            faces: faces,
+            face_count: face_count,
+            vertex_faces: vertex_faces,
+            heap: BinaryHeap::new(),
+        };
+        let mut edges: Vec<(usize, usize)> = edge_faces.into_keys().collect();
+        edges.sort_unstable();
+        for (v1, v2) in edges {
+            simplifier.push_edge(v1, v2);
+        }
 
-        Ok(face_count)
+        simplifier
     }
 
-    /// Parse all the elements of a givne type from a line of text input.
-    fn parse_elements(
-        &mut self,
-        elements: &mut Vec<Element>,
-        vertex_index_range: (usize, usize),
-        texture_index_range: (usize, usize),
-        normal_index_range: (usize, usize),
-    ) -> Result<usize, ParseError> {
-        match self.peek() {
-            Some("p") => self.parse_point(elements, vertex_index_range),
-            Some("l") => self.parse_line(
-                elements,
-                vertex_index_range,
-                texture_index_range,
-                normal_index_range,
-            ),
-            Some("f") => self.parse_face(
-                elements,
-                vertex_index_range,
-                texture_index_range,
-                normal_index_range,
-            ),
-            _ => self.error(
-                ErrorKind::ElementMustBeAPointLineOrFace,
-                "An element must be a point (`p`), line (`l`), or face (`f`).".to_owned(),
-            ),
-        }
+    /// Queue the collapse of the edge between two vertices at its cheapest
+    /// position.
+    fn push_edge(&mut self, v1: usize, v2: usize) {
+        let mut quadric = self.quadrics[v1];
+        quadric.add(&self.quadrics[v2]);
+        let position1 = self.positions[v1];
+        let position2 = self.positions[v2];
+        let midpoint = Vertex {
+            x: (position1.x + position2.x) / 2_f64,
+            y: (position1.y + position2.y) / 2_f64,
+            z: (position1.z + position2.z) / 2_f64,
+            w: position1.w,
+        };
+        let (cost, position) = [position1, position2, midpoint]
+            .iter()
+            .map(|position| (quadric.error(position), *position))
+            .fold((f64::INFINITY, position1), |best, candidate| {
+                if candidate.0 < best.0 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+
+        self.heap.push(EdgeCollapse {
+            cost: cost,
+            vertices: (v1, v2),
+            stamps: (self.stamps[v1], self.stamps[v2]),
+            position: position,
+        });
     }
 
-    /// Parse group names from a line of text input.
-    fn parse_groups(&mut self, groups: &mut Vec<Group>) -> Result<usize, ParseError> {
-        self.expect_tag("g")?;
-        let mut groups_parsed = 0;
-        loop {
-            match self.next() {
-                Some(name) if name != "\n" => {
-                    groups.push(Group(String::from(name)));
-                    groups_parsed += 1;
+    /// Determine whether moving the vertices of an edge to a new position
+    /// would flip or degenerate any face that survives the collapse.
+    fn collapse_flips_faces(&self, v1: usize, v2: usize, position: &Vertex) -> bool {
+        for &v in [v1, v2].iter() {
+            for &face_index in self.vertex_faces[v].iter() {
+                let face = match self.faces[face_index] {
+                    Some(face) => face,
+                    None => continue,
+                };
+                let corners = face.map(|vtn_index| vtn_index.vertex_index().0);
+                if corners.contains(&v1) && corners.contains(&v2) {
+                    continue;
+                }
+                let before = corners.map(|corner| self.positions[corner]);
+                let after = corners.map(|corner| {
+                    if corner == v {
+                        *position
+                    } else {
+                        self.positions[corner]
+                    }
+                });
+                let normal_before = face_normal(&before[0], &before[1], &before[2]);
+                let normal_after = face_normal(&after[0], &after[1], &after[2]);
+                if normal_before.dot(&normal_after) <= 0_f64 {
+                    return true;
                 }
-                _ => break,
             }
         }
 
-        Ok(groups_parsed)
+        false
     }
 
-    /// Parse a smoothing group name from a line of text input.
-    fn parse_smoothing_group(
-        &mut self,
-        smoothing_groups: &mut Vec<SmoothingGroup>,
-    ) -> Result<usize, ParseError> {
-        self.expect_tag("s")?;
-        if let Some(name) = self.next() {
-            if name == "off" {
-                smoothing_groups.push(SmoothingGroup(0));
-            } else if let Ok(number) = name.parse::<usize>() {
-                smoothing_groups.push(SmoothingGroup(number));
+    /// Collapse the edge between two vertices into the first vertex, and
+    /// return the number of faces removed.
+    fn collapse(&mut self, v1: usize, v2: usize, position: Vertex) -> usize {
+        let mut faces_removed = 0;
+        let v2_faces = mem::take(&mut self.vertex_faces[v2]);
+        for &face_index in v2_faces.iter() {
+            let face = match self.faces[face_index] {
+                Some(face) => face,
+                None => continue,
+            };
+            if face.iter().any(|vtn_index| vtn_index.vertex_index().0 == v1) {
+                self.faces[face_index] = None;
+                faces_removed += 1;
             } else {
-                return self.error(
-                    ErrorKind::SmoothingGroupNameMustBeOffOrInteger,
-                    format!(
-                        "A smoothing group name must either be `off`, which denotes that an \
-                        object has no smoothing groups, or an integer. The parser got `{}` instead.",
-                        name
-                    ),
-                );
+                self.faces[face_index] = Some(face.map(|vtn_index| {
+                    if vtn_index.vertex_index().0 == v2 {
+                        with_vertex_index(vtn_index, v1)
+                    } else {
+                        vtn_index
+                    }
+                }));
+                self.vertex_faces[v1].push(face_index);
             }
-        } else {
-            return self.error(
-                ErrorKind::SmoothingGroupDeclarationHasNoName,
-                "Got a smoothing group declaration without a smoothing group name.".to_owned(),
-            );
         }
 
-        Ok(1)
+        let faces = &self.faces;
+        self.vertex_faces[v1].retain(|&face_index| faces[face_index].is_some());
+        self.positions[v1] = position;
+        let quadric = self.quadrics[v2];
+        self.quadrics[v1].add(&quadric);
+        self.removed[v2] = true;
+        self.stamps[v1] += 1;
+        self.stamps[v2] += 1;
+
+        let mut neighbors = vec![];
+        for &face_index in self.vertex_faces[v1].iter() {
+            if let Some(face) = self.faces[face_index] {
+                for vtn_index in face.iter() {
+                    let v = vtn_index.vertex_index().0;
+                    if v != v1 && !neighbors.contains(&v) {
+                        neighbors.push(v);
+                    }
+                }
+            }
+        }
+        for v in neighbors {
+            self.push_edge(v1.min(v), v1.max(v));
+        }
+
+        faces_removed
     }
+}
 
-    /// Parse a material name from a line of text input.
-    fn parse_material_name(
-        &mut self,
-        material_names: &mut Vec<Option<&'a str>>,
-    ) -> Result<usize, ParseError> {
-        self.expect_tag("usemtl")?;
-        if let Some(name) = self.next() {
-            material_names.push(Some(name));
+/// The weight of the planes that keep boundary edges in place during
+/// simplification, relative to the planes of the faces themselves.
+const BOUNDARY_WEIGHT: f64 = 1000_f64;
+
+impl Object {
+    /// Reduce the number of faces in the object with edge collapse decimation
+    /// guided by quadric error metrics.
+    ///
+    /// The simplified object keeps at most `target_ratio` of the faces of the
+    /// original, unless no further edge can be collapsed without flipping a
+    /// face. Edges are collapsed into one of their endpoints or their
+    /// midpoint, whichever moves the surface the least, and open boundaries
+    /// are kept in place. Points and lines are kept, texture vertex and
+    /// normal vertex indices of the remaining faces are unchanged, and the
+    /// vertices that are no longer used by any element are removed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// #
+    /// let mut obj_file = String::new();
+    /// for j in 0..5 {
+    ///     for i in 0..5 {
+    ///         obj_file += &format!("v {} {} 0\n", i, j);
+    ///     }
+    /// }
+    /// for j in 1..5 {
+    ///     for i in 1..5 {
+    ///         let corner = (j - 1) * 5 + i;
+    ///         obj_file += &format!("f {} {} {} {}\n", corner, corner + 1, corner + 6, corner + 5);
+    ///     }
+    /// }
+    /// let object_set = obj::parse(&obj_file).unwrap();
+    /// let object = &object_set.objects[0];
+    /// let simplified = object.simplify(0.25);
+    ///
+    /// assert_eq!(object.element_set.len(), 32);
+    /// assert!(simplified.element_set.len() <= 8);
+    /// assert!(simplified.vertex_set.len() < object.vertex_set.len());
+    /// ```
+    pub fn simplify(&self, target_ratio: f64) -> Object {
+        let mut simplifier = Simplifier::new(self);
+        let face_count = simplifier.face_count;
+        let target_ratio = if target_ratio.is_nan() {
+            1_f64
         } else {
-            return self.error(
-                ErrorKind::MaterialStatementHasNoName,
-                "Got a `usemtl` material declaration without a material name.".to_owned(),
-            );
+            target_ratio.clamp(0_f64, 1_f64)
+        };
+        let target_face_count = (face_count as f64 * target_ratio).ceil() as usize;
+
+        let mut remaining_face_count = face_count;
+        while remaining_face_count > target_face_count {
+            let collapse = match simplifier.heap.pop() {
+                Some(collapse) => collapse,
+                None => break,
+            };
+            let (v1, v2) = collapse.vertices;
+            if simplifier.removed[v1]
+                || simplifier.removed[v2]
+                || collapse.stamps != (simplifier.stamps[v1], simplifier.stamps[v2])
+            {
+                continue;
+            }
+            if simplifier.collapse_flips_faces(v1, v2, &collapse.position) {
+                continue;
+            }
+
+            remaining_face_count -= simplifier.collapse(v1, v2, collapse.position);
         }
 
-        Ok(1)
+        self.rebuild_with_faces(&simplifier.positions, &simplifier.faces)
     }
 
-    /// Construct a set of shape entries for each element in the element set.
-    #[allow(clippy::type_complexity)]
-    #[allow(clippy::needless_range_loop)]
-    fn parse_shape_entries(
-        &self,
-        shape_entry_table: &mut Vec<ShapeEntry>,
-        elements: &[Element],
-        group_entry_table: &[((usize, usize), (usize, usize))],
-        smoothing_group_entry_table: &[((usize, usize), usize)],
-    ) {
-        for &((min_element_index, max_element_index), (min_group_index, max_group_index)) in group_entry_table
-        {
-            let groups: Vec<usize> = (min_group_index..max_group_index).collect();
-            for i in min_element_index..max_element_index {
-                shape_entry_table.push(ShapeEntry {
-                    element: i,
-                    groups: groups.clone(),
-                    smoothing_group: 0,
-                });
+    /// Construct a copy of the object with new vertex positions and faces,
+    /// dropping collapsed faces and vertices that are no longer used.
+    fn rebuild_with_faces(&self, positions: &[Vertex], faces: &[Option<[VTNIndex; 3]>]) -> Object {
+        let mut elements = Vec::with_capacity(self.element_set.len());
+        let mut element_map = vec![None; self.element_set.len()];
+        for (element_index, element) in self.element_set.iter().enumerate() {
+            let element = match (*element, faces[element_index]) {
+                (Element::Face(..), Some([vtn1, vtn2, vtn3])) => Element::Face(vtn1, vtn2, vtn3),
+                (Element::Face(..), None) => continue,
+                (element, _) => element,
+            };
+            element_map[element_index] = Some(elements.len());
+            elements.push(element);
+        }
+
+        let mut used = vec![false; positions.len()];
+        for element in elements.iter() {
+            map_vtn_indices(*element, |vtn_index| {
+                if let Some(used) = used.get_mut(vtn_index.vertex_index().0) {
+                    *used = true;
+                }
+                vtn_index
+            });
+        }
+        let mut vertex_map: Vec<usize> = (0..positions.len()).collect();
+        let mut vertices = vec![];
+        for (v, position) in positions.iter().enumerate() {
+            if used[v] {
+                vertex_map[v] = vertices.len();
+                vertices.push(*position);
             }
         }
-        debug_assert!(shape_entry_table.len() == elements.len());
+        for element in elements.iter_mut() {
+            *element = map_vtn_indices(*element, |vtn_index| {
+                let v = vtn_index.vertex_index().0;
+                with_vertex_index(vtn_index, vertex_map.get(v).copied().unwrap_or(v))
+            });
+        }
 
-        for &((min_element_index, max_element_index), smoothing_group_index) in smoothing_group_entry_table {
-            for i in min_element_index..max_element_index {
-                shape_entry_table[i].smoothing_group = smoothing_group_index;
+        let mut shapes = vec![];
+        let mut shape_map = vec![None; self.shape_set.len()];
+        for (shape_index, shape_entry) in self.shape_set.iter().enumerate() {
+            if let Some(&Some(element)) = element_map.get(shape_entry.element.0) {
+                shape_map[shape_index] = Some(ShapeEntryIndex(shapes.len()));
+                shapes.push(ShapeEntry {
+                    element: ElementIndex(element),
+                    groups: Arc::clone(&shape_entry.groups),
+                    smoothing_group: shape_entry.smoothing_group,
+                });
             }
         }
-        debug_assert!(shape_entry_table.len() == elements.len());
+
+        let geometries: Vec<Geometry> = self
+            .geometry_set
+            .iter()
+            .map(|geometry| Geometry {
+                material_name: geometry.material_name.clone(),
+                shapes: geometry
+                    .shapes
+                    .iter()
+                    .filter_map(|&shape| shape_map.get(shape.0).copied().flatten())
+                    .collect(),
+            })
+            .collect();
+
+        let keep = |element_index: &usize| element_map[*element_index].is_some();
+        let filter_parallel = |set_length: usize| (0..set_length).filter(keep);
+
+        Object {
+            name: self.name.clone(),
+            source: self.source.clone(),
+            vertex_set: vertices.into(),
+            texture_vertex_set: self.texture_vertex_set.clone(),
+            normal_vertex_set: self.normal_vertex_set.clone(),
+            group_set: self.group_set.clone(),
+            smoothing_group_set: self.smoothing_group_set.clone(),
+            element_set: elements.into(),
+            shape_set: shapes.into(),
+            geometry_set: geometries.into(),
+            element_line_set: filter_parallel(self.element_line_set.len())
+                .map(|element_index| self.element_line_set[element_index])
+                .collect(),
+            element_text_set: filter_parallel(self.element_text_set.len())
+                .map(|element_index| self.element_text_set[element_index].clone())
+                .collect(),
+            original_element_set: filter_parallel(self.original_element_set.len())
+                .map(|element_index| self.original_element_set[element_index])
+                .collect(),
+            numeric_text: NumericText {
+                vertices: vec![],
+                ..self.numeric_text.clone()
+            },
+        }
     }
+}
 
-    /// Construct a set of geometries for reach material in an object.
-    fn parse_geometries(
-        &self,
-        geometries: &mut Vec<Geometry>,
-        material_name_entry_table: &[((usize, usize), usize)],
-        material_names: &[Option<&'a str>],
-    ) {
-        for &((min_element_index, max_element_index), material_name_index) in material_name_entry_table {
-            let shapes: Vec<ShapeEntryIndex> = (min_element_index..max_element_index).collect();
-            let material_name = material_names[material_name_index].map(String::from);
-            let geometry = Geometry {
-                material_name: material_name,
-                shapes: shapes,
-            };
-            geometries.push(geometry);
+/// The midpoints of the edges of an object created while subdividing it,
+/// keyed by the pair of indices at either end so that faces sharing an edge
+/// share its midpoint.
+struct Midpoints {
+    vertices: HashMap<(usize, usize), usize>,
+    texture_vertices: HashMap<(usize, usize), usize>,
+    normal_vertices: HashMap<(usize, usize), usize>,
+}
+
+impl Midpoints {
+    fn new() -> Midpoints {
+        Midpoints {
+            vertices: HashMap::new(),
+            texture_vertices: HashMap::new(),
+            normal_vertices: HashMap::new(),
         }
     }
+}
 
-    /*
-    fn calculate_index_ranges(
-        &self,
-        max_vertex_index:  &mut usize,
-        max_texture_index: &mut usize,
-        max_normal_index:  &mut usize
-    ) {
-        let mut cloned = self.clone();
-        loop {
-            match cloned.peek() {
-                Some("v")  => {
-                    *max_vertex_index += 1;
-                    cloned.advance();
-                }
-                Some("vt") => {
-                    *max_texture_index += 1;
-                    cloned.advance();
-                }
-                Some("vn") => {
-                    *max_normal_index += 1;
-                    cloned.advance();
-                }
-                Some("o") | None => {
-                    break;
-                }
-                _ => {
-                    cloned.advance();
-                }
-            }
-        }
+/// Find or create the entry of a set halfway between two entries.
+///
+/// The function returns `None` if either entry does not exist.
+fn midpoint_index<T, F>(
+    midpoints: &mut HashMap<(usize, usize), usize>,
+    set: &mut Vec<T>,
+    i: usize,
+    j: usize,
+    f: F,
+) -> Option<usize>
+where
+    T: Copy,
+    F: Fn(&T, &T) -> T,
+{
+    let key = (i.min(j), i.max(j));
+    if key.1 >= set.len() {
+        return None;
     }
-    */
-
-    /// Parse one object from a Wavefront OBJ file.
-    fn parse_object(
-        &mut self,
-        min_vertex_index: &mut usize,
-        max_vertex_index: &mut usize,
-        min_texture_index: &mut usize,
-        max_texture_index: &mut usize,
-        min_normal_index: &mut usize,
-        max_normal_index: &mut usize,
-    ) -> Result<Object, ParseError> {
-        let object_name = self.parse_object_name()?;
-
-        let mut vertices: Vec<Vertex> = vec![];
-        let mut texture_vertices = vec![];
-        let mut normal_vertices = vec![];
-        let mut elements = vec![];
-
-        let mut group_entry_table = vec![];
-        let mut groups = vec![];
-        let mut min_element_group_index = 0;
-        let mut max_element_group_index = 0;
-        let mut min_group_index = 0;
-        let mut max_group_index = 0;
 
-        let mut smoothing_group_entry_table = vec![];
-        let mut smoothing_groups = vec![];
-        let mut min_element_smoothing_group_index = 0;
-        let mut max_element_smoothing_group_index = 0;
-        let mut smoothing_group_index = 0;
-
-        let mut material_name_entry_table = vec![];
-        let mut material_names = vec![];
-        let mut min_element_material_name_index = 0;
-        let mut max_element_material_name_index = 0;
-        let mut material_name_index = 0;
+    Some(*midpoints.entry(key).or_insert_with(|| {
+        set.push(f(&set[key.0], &set[key.1]));
+        set.len() - 1
+    }))
+}
 
-        // self.calculate_index_ranges(max_vertex_index, max_texture_index, max_normal_index);
+impl Object {
+    /// Subdivide each face of the object into four faces by splitting its
+    /// edges at their midpoints, repeated `levels` times.
+    ///
+    /// Faces sharing an edge share its midpoint. Texture vertices are
+    /// interpolated linearly and normal vertices are interpolated and
+    /// normalized. Points and lines are kept as they are. The new faces keep
+    /// the groups, smoothing group, and material of the face they were split
+    /// from, as well as its entries in the element line, text, and original
+    /// element sets.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// #
+    /// let object_set = obj::parse("v 0 0 0\nv 2 0 0\nv 0 2 0\nf 1 2 3\n").unwrap();
+    /// let object = object_set.objects[0].subdivide(2);
+    ///
+    /// assert_eq!(object.element_set.len(), 16);
+    /// assert_eq!(object.vertex_set.len(), 15);
+    /// ```
+    pub fn subdivide(&self, levels: usize) -> Object {
+        let mut object = self.clone();
+        for _ in 0..levels {
+            object = object.subdivide_once();
+        }
 
-        loop {
-            match self.peek() {
-                Some("g") if groups.is_empty() => {
-                    let amount_parsed = self.parse_groups(&mut groups)?;
-                    max_group_index += amount_parsed;
-                }
-                Some("g") => {
-                    // Save the shape entry ranges for the current group.
-                    group_entry_table.push((
-                        (min_element_group_index, max_element_group_index),
-                        (min_group_index, max_group_index),
-                    ));
+        object
+    }
 
-                    let amount_parsed = self.parse_groups(&mut groups)?;
-                    min_group_index = max_group_index;
-                    max_group_index += amount_parsed;
-                    min_element_group_index = max_element_group_index;
-                }
-                Some("s") if smoothing_groups.is_empty() => {
-                    self.parse_smoothing_group(&mut smoothing_groups)?;
-                    smoothing_group_index = 0;
-                }
-                Some("s") => {
-                    // Save the shape entry ranges for the current smoothing group.
-                    smoothing_group_entry_table.push((
-                        (
-                            min_element_smoothing_group_index,
-                            max_element_smoothing_group_index,
-                        ),
-                        smoothing_group_index,
-                    ));
+    fn subdivide_once(&self) -> Object {
+        let mut vertices: Vec<Vertex> = self.vertex_set.iter().copied().collect();
+        let mut texture_vertices: Vec<TextureVertex> = self.texture_vertex_set.iter().copied().collect();
+        let mut normal_vertices: Vec<NormalVertex> = self.normal_vertex_set.iter().copied().collect();
+        let mut midpoints = Midpoints::new();
+        let mut midpoint = |vtn1: VTNIndex, vtn2: VTNIndex| -> Option<VTNIndex> {
+            let (v1, v2) = (vtn1.vertex_index().0, vtn2.vertex_index().0);
+            let v = midpoint_index(
+                &mut midpoints.vertices,
+                &mut vertices,
+                v1,
+                v2,
+                |vertex1, vertex2| Vertex {
+                    x: (vertex1.x + vertex2.x) / 2_f64,
+                    y: (vertex1.y + vertex2.y) / 2_f64,
+                    z: (vertex1.z + vertex2.z) / 2_f64,
+                    w: (vertex1.w + vertex2.w) / 2_f64,
+                },
+            )?;
+            let mut vt = |vt1: usize, vt2: usize| {
+                midpoint_index(
+                    &mut midpoints.texture_vertices,
+                    &mut texture_vertices,
+                    vt1,
+                    vt2,
+                    |texture_vertex1, texture_vertex2| TextureVertex {
+                        u: (texture_vertex1.u + texture_vertex2.u) / 2_f64,
+                        v: (texture_vertex1.v + texture_vertex2.v) / 2_f64,
+                        w: (texture_vertex1.w + texture_vertex2.w) / 2_f64,
+                    },
+                )
+            };
+            let mut vn = |vn1: usize, vn2: usize| {
+                midpoint_index(
+                    &mut midpoints.normal_vertices,
+                    &mut normal_vertices,
+                    vn1,
+                    vn2,
+                    |normal_vertex1, normal_vertex2| ((*normal_vertex1 + *normal_vertex2) * 0.5).normalize(),
+                )
+            };
 
-                    self.parse_smoothing_group(&mut smoothing_groups)?;
-                    smoothing_group_index += 1;
-                    min_element_smoothing_group_index = max_element_smoothing_group_index;
+            match (vtn1, vtn2) {
+                (VTNIndex::VT(_, vt1), VTNIndex::VT(_, vt2)) => Some(VTNIndex::VT(v, vt(vt1, vt2)?)),
+                (VTNIndex::VN(_, vn1), VTNIndex::VN(_, vn2)) => Some(VTNIndex::VN(v, vn(vn1, vn2)?)),
+                (VTNIndex::VTN(_, vt1, vn1), VTNIndex::VTN(_, vt2, vn2)) => {
+                    Some(VTNIndex::VTN(v, vt(vt1, vt2)?, vn(vn1, vn2)?))
                 }
-                Some("usemtl") => {
-                    if min_element_material_name_index == max_element_material_name_index {
-                        if material_names.is_empty() {
-                            self.parse_material_name(&mut material_names)?;
-                        } else {
-                            self.parse_material_name(&mut material_names)?;
-                            material_name_index += 1;
-                        }
-                    } else {
-                        material_name_entry_table.push((
-                            (min_element_material_name_index, max_element_material_name_index),
-                            material_name_index,
-                        ));
+                _ => Some(VTNIndex::V(v)),
+            }
+        };
 
-                        if material_names.is_empty() {
-                            self.parse_material_name(&mut material_names)?;
-                        } else {
-                            self.parse_material_name(&mut material_names)?;
-                            material_name_index += 1;
-                        }
+        let mut elements = Vec::with_capacity(self.element_set.len() * 4);
+        // The range of new elements that each element was split into.
+        let mut element_ranges = Vec::with_capacity(self.element_set.len());
+        for element in self.element_set.iter() {
+            let start = elements.len();
+            let children = match *element {
+                Element::Face(vtn1, vtn2, vtn3) => {
+                    match (midpoint(vtn1, vtn2), midpoint(vtn2, vtn3), midpoint(vtn3, vtn1)) {
+                        (Some(vtn12), Some(vtn23), Some(vtn31)) => Some([
+                            Element::Face(vtn1, vtn12, vtn31),
+                            Element::Face(vtn12, vtn2, vtn23),
+                            Element::Face(vtn31, vtn23, vtn3),
+                            Element::Face(vtn12, vtn23, vtn31),
+                        ]),
+                        _ => None,
                     }
-
-                    min_element_material_name_index = max_element_material_name_index;
-                }
-                Some("v") => {
-                    let vertex = self.parse_vertex()?;
-                    vertices.push(vertex);
-                    *max_vertex_index += 1;
-                }
-                Some("vt") => {
-                    let texture_vertex = self.parse_texture_vertex()?;
-                    texture_vertices.push(texture_vertex);
-                    *max_texture_index += 1;
-                }
-                Some("vn") => {
-                    let normal_vertex = self.parse_normal_vertex()?;
-                    normal_vertices.push(normal_vertex);
-                    *max_normal_index += 1;
                 }
-                Some("p") | Some("l") | Some("f") => {
-                    if groups.is_empty() {
-                        groups.push(Default::default());
-                        min_group_index = 0;
-                        max_group_index = 1;
-                    }
+                _ => None,
+            };
+            match children {
+                Some(children) => elements.extend(children),
+                None => elements.push(*element),
+            }
+            element_ranges.push(start..elements.len());
+        }
 
-                    if smoothing_groups.is_empty() {
-                        smoothing_groups.push(Default::default());
-                        smoothing_group_index = 0;
-                    }
+        let mut shapes = Vec::with_capacity(elements.len());
+        let mut shape_ranges = Vec::with_capacity(self.shape_set.len());
+        for shape_entry in self.shape_set.iter() {
+            let start = shapes.len();
+            let element_range = element_ranges.get(shape_entry.element.0).cloned().unwrap_or(0..0);
+            for element in element_range {
+                shapes.push(ShapeEntry {
+                    element: ElementIndex(element),
+                    groups: Arc::clone(&shape_entry.groups),
+                    smoothing_group: shape_entry.smoothing_group,
+                });
+            }
+            shape_ranges.push(start..shapes.len());
+        }
 
-                    if material_names.is_empty() {
-                        material_names.push(None);
-                        material_name_index = 0;
-                    }
+        let geometries: Vec<Geometry> = self
+            .geometry_set
+            .iter()
+            .map(|geometry| Geometry {
+                material_name: geometry.material_name.clone(),
+                shapes: geometry
+                    .shapes
+                    .iter()
+                    .flat_map(|&shape| shape_ranges.get(shape.0).cloned().unwrap_or(0..0))
+                    .map(ShapeEntryIndex)
+                    .collect(),
+            })
+            .collect();
 
-                    let elements_parsed = self.parse_elements(
-                        &mut elements,
-                        (*min_vertex_index, *max_vertex_index),
-                        (*min_texture_index, *max_texture_index),
-                        (*min_normal_index, *max_normal_index),
-                    )?;
-                    max_element_group_index += elements_parsed;
-                    max_element_smoothing_group_index += elements_parsed;
-                    max_element_material_name_index += elements_parsed;
-                }
-                Some("\n") => {
-                    self.skip_one_or_more_newlines()?;
-                }
-                Some("o") | None => {
-                    // At the end of file or object, collect any remaining shapes.
-                    group_entry_table.push((
-                        (min_element_group_index, max_element_group_index),
-                        (min_group_index, max_group_index),
-                    ));
+        fn split<T: Clone>(set: &[T], element_ranges: &[ops::Range<usize>]) -> Vec<T> {
+            set.iter()
+                .zip(element_ranges.iter())
+                .flat_map(|(item, range)| std::iter::repeat_n(item.clone(), range.len()))
+                .collect()
+        }
 
-                    smoothing_group_entry_table.push((
-                        (
-                            min_element_smoothing_group_index,
-                            max_element_smoothing_group_index,
-                        ),
-                        smoothing_group_index,
-                    ));
+        Object {
+            name: self.name.clone(),
+            source: self.source.clone(),
+            vertex_set: vertices.into(),
+            texture_vertex_set: texture_vertices.into(),
+            normal_vertex_set: normal_vertices.into(),
+            group_set: self.group_set.clone(),
+            smoothing_group_set: self.smoothing_group_set.clone(),
+            element_set: elements.into(),
+            shape_set: shapes.into(),
+            geometry_set: geometries.into(),
+            element_line_set: split(&self.element_line_set, &element_ranges),
+            element_text_set: split(&self.element_text_set, &element_ranges),
+            original_element_set: split(&self.original_element_set, &element_ranges),
+            numeric_text: NumericText::default(),
+        }
+    }
+}
 
-                    material_name_entry_table.push((
-                        (min_element_material_name_index, max_element_material_name_index),
-                        material_name_index,
-                    ));
+/// The new positions of the entries of a set after removing the entries that
+/// are not used, and the entries that are kept.
+fn compact_set<T: Copy>(set: &[T], used: &[bool]) -> (Vec<usize>, Vec<T>) {
+    let mut index_map: Vec<usize> = (0..set.len()).collect();
+    let mut items = Vec::with_capacity(set.len());
+    for (index, item) in set.iter().enumerate() {
+        if used[index] {
+            index_map[index] = items.len();
+            items.push(*item);
+        }
+    }
 
-                    break;
+    (index_map, items)
+}
+
+impl Object {
+    /// Remove the vertices, texture vertices, and normal vertices that no
+    /// element refers to, and renumber the elements to match.
+    ///
+    /// The remaining entries keep their order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     Element,
+    /// #     ElementIndex,
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0\nv 9 9 9\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 1\nf 1/2 3/2 4/2\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    /// object.compact();
+    ///
+    /// assert_eq!(object.vertex_set.len(), 3);
+    /// assert_eq!(object.texture_vertex_set.len(), 1);
+    /// assert_eq!(
+    ///     object.element_set[ElementIndex(0)],
+    ///     Element::Face(VTNIndex::VT(0, 0), VTNIndex::VT(1, 0), VTNIndex::VT(2, 0))
+    /// );
+    /// ```
+    pub fn compact(&mut self) {
+        let mut used_vertices = vec![false; self.vertex_set.len()];
+        let mut used_texture_vertices = vec![false; self.texture_vertex_set.len()];
+        let mut used_normal_vertices = vec![false; self.normal_vertex_set.len()];
+        let mark = |used: &mut [bool], index: usize| {
+            if let Some(used) = used.get_mut(index) {
+                *used = true;
+            }
+        };
+        for element in self.element_set.iter() {
+            map_vtn_indices(*element, |vtn_index| {
+                mark(&mut used_vertices, vtn_index.vertex_index().0);
+                if let Some(vt) = vtn_index.texture_vertex_index() {
+                    mark(&mut used_texture_vertices, vt.0);
                 }
-                Some(other_st) => {
-                    return self.error(
-                        ErrorKind::InvalidObjectStatement,
-                        format!("Unsupported or invalid object statement `{}`.", other_st),
-                    );
+                if let Some(vn) = vtn_index.normal_vertex_index() {
+                    mark(&mut used_normal_vertices, vn.0);
                 }
-            }
+                vtn_index
+            });
         }
 
-        let mut shape_entries = vec![];
-        self.parse_shape_entries(
-            &mut shape_entries,
-            &elements,
-            &group_entry_table,
-            &smoothing_group_entry_table,
-        );
+        let (vertex_map, vertices) = compact_set(&self.vertex_set, &used_vertices);
+        let (texture_vertex_map, texture_vertices) =
+            compact_set(&self.texture_vertex_set, &used_texture_vertices);
+        let (normal_vertex_map, normal_vertices) =
+            compact_set(&self.normal_vertex_set, &used_normal_vertices);
+        let remap = |index_map: &[usize], index: usize| index_map.get(index).copied().unwrap_or(index);
+        for element in self.element_set.iter_mut() {
+            *element = map_vtn_indices(*element, |vtn_index| match vtn_index {
+                VTNIndex::V(v) => VTNIndex::V(remap(&vertex_map, v)),
+                VTNIndex::VT(v, vt) => VTNIndex::VT(remap(&vertex_map, v), remap(&texture_vertex_map, vt)),
+                VTNIndex::VN(v, vn) => VTNIndex::VN(remap(&vertex_map, v), remap(&normal_vertex_map, vn)),
+                VTNIndex::VTN(v, vt, vn) => VTNIndex::VTN(
+                    remap(&vertex_map, v),
+                    remap(&texture_vertex_map, vt),
+                    remap(&normal_vertex_map, vn),
+                ),
+            });
+        }
 
-        let mut geometries = vec![];
-        self.parse_geometries(&mut geometries, &material_name_entry_table, &material_names);
+        self.vertex_set = vertices.into();
+        self.texture_vertex_set = texture_vertices.into();
+        self.normal_vertex_set = normal_vertices.into();
+    }
+}
 
-        *min_vertex_index += vertices.len();
-        *min_texture_index += texture_vertices.len();
-        *min_normal_index += normal_vertices.len();
+/// Apply a function to each index in `0..count` in at most `thread_count`
+/// chunks on the `rayon` global thread pool, collecting the results in index
+/// order.
+#[cfg(feature = "rayon")]
+fn map_parallel<U, F>(count: usize, thread_count: usize, f: F) -> Vec<U>
+where
+    U: Send,
+    F: Fn(usize) -> U + Sync,
+{
+    use rayon::prelude::*;
+
+    let thread_count = usize::max(1, usize::min(thread_count, count));
+    if thread_count == 1 {
+        return (0..count).map(f).collect();
+    }
 
-        Ok(Object {
-            name: object_name.into(),
-            vertex_set: vertices,
-            texture_vertex_set: texture_vertices,
-            normal_vertex_set: normal_vertices,
-            group_set: groups,
-            smoothing_group_set: smoothing_groups,
-            element_set: elements,
-            shape_set: shape_entries,
-            geometry_set: geometries,
-        })
+    let chunk_size = count.div_ceil(thread_count);
+    (0..count)
+        .into_par_iter()
+        .with_min_len(chunk_size)
+        .map(&f)
+        .collect()
+}
+
+/// Apply a function to each index in `0..count` on at most `thread_count`
+/// worker threads, collecting the results in index order.
+#[cfg(not(feature = "rayon"))]
+fn map_parallel<U, F>(count: usize, thread_count: usize, f: F) -> Vec<U>
+where
+    U: Send,
+    F: Fn(usize) -> U + Sync,
+{
+    let thread_count = usize::max(1, usize::min(thread_count, count));
+    if thread_count == 1 {
+        return (0..count).map(f).collect();
     }
 
-    /// Parse a set of objects in a wavefront OBJ file.
-    fn parse_objects(&mut self) -> Result<Vec<Object>, ParseError> {
-        let mut result = Vec::new();
+    let chunk_size = count.div_ceil(thread_count);
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..count)
+            .step_by(chunk_size)
+            .map(|start| {
+                let end = usize::min(start + chunk_size, count);
+                scope.spawn(move || (start..end).map(f).collect::<Vec<_>>())
+            })
+            .collect();
 
-        let mut min_vertex_index = 0;
-        let mut max_vertex_index = 0;
-        let mut min_texture_index = 0;
-        let mut max_texture_index = 0;
-        let mut min_normal_index = 0;
-        let mut max_normal_index = 0;
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Worker thread panicked."))
+            .collect()
+    })
+}
 
-        self.skip_zero_or_more_newlines();
-        while self.peek().is_some() {
-            result.push(self.parse_object(
-                &mut min_vertex_index,
-                &mut max_vertex_index,
-                &mut min_texture_index,
-                &mut max_texture_index,
-                &mut min_normal_index,
-                &mut max_normal_index,
-            )?);
-            self.skip_zero_or_more_newlines();
+/// The area weighted normal of an element, i.e. the cross product of two
+/// edges of a face. The normal is zero for points, lines, and faces referring
+/// to missing vertices.
+fn element_normal(vertices: &[Vertex], element: Element) -> [f64; 3] {
+    let (vtn1, vtn2, vtn3) = match element {
+        Element::Face(vtn1, vtn2, vtn3) => (vtn1, vtn2, vtn3),
+        _ => return [0_f64; 3],
+    };
+    let vertex = |vtn_index: VTNIndex| vertices.get(vtn_index.vertex_index().0);
+    match (vertex(vtn1), vertex(vtn2), vertex(vtn3)) {
+        (Some(vertex1), Some(vertex2), Some(vertex3)) => {
+            let normal = face_normal(vertex1, vertex2, vertex3);
+            [normal.x, normal.y, normal.z]
         }
-
-        Ok(result)
+        _ => [0_f64; 3],
     }
+}
 
-    /// Parse a set of material library file names from a line of text input.
-    fn parse_material_library_line(
-        &mut self,
-        material_libraries: &mut Vec<String>,
-    ) -> Result<usize, ParseError> {
-        self.expect_tag("mtllib")?;
-        let mut number_of_libraries_found = 0;
-        loop {
-            match self.next() {
-                Some(st) if st != "\n" => {
-                    material_libraries.push(String::from(st));
-                    number_of_libraries_found += 1;
-                }
-                _ => break,
-            }
-        }
+/// Scale a sum of face normals to unit length and store it as a normal vertex.
+fn unit_normal(normal: [f64; 3]) -> NormalVertex {
+    let magnitude = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+    let scale = if magnitude == 0_f64 { 1_f64 } else { magnitude };
 
-        Ok(number_of_libraries_found)
+    NormalVertex {
+        x: normal[0] / scale,
+        y: normal[1] / scale,
+        z: normal[2] / scale,
     }
+}
 
-    /// Parse a set of material library names from a Wavefront OBJ file.
-    fn parse_material_libraries(&mut self) -> Result<Vec<String>, ParseError> {
-        let mut material_libraries = vec![];
-        self.skip_zero_or_more_newlines();
-        while let Some("mtllib") = self.peek() {
-            self.parse_material_library_line(&mut material_libraries)?;
-            self.skip_zero_or_more_newlines();
-        }
+/// The tangent and bitangent vectors of a face, i.e. the directions of
+/// increasing **u** and **v** texture coordinates in the plane of the face,
+/// scaled by the inverse of the area of the face in texture space. There are
+/// none for points, lines, faces without texture vertices, faces referring
+/// to missing vertices, and faces whose texture coordinates are degenerate.
+fn element_tangent(
+    vertices: &[Vertex],
+    texture_vertices: &[TextureVertex],
+    element: Element,
+) -> Option<(NormalVertex, NormalVertex)> {
+    let (vtn1, vtn2, vtn3) = match element {
+        Element::Face(vtn1, vtn2, vtn3) => (vtn1, vtn2, vtn3),
+        _ => return None,
+    };
+    let corner = |vtn_index: VTNIndex| match vtn_index {
+        VTNIndex::VT(v, vt) | VTNIndex::VTN(v, vt, _) => Some((vertices.get(v)?, texture_vertices.get(vt)?)),
+        _ => None,
+    };
+    let (vertex1, texture_vertex1) = corner(vtn1)?;
+    let (vertex2, texture_vertex2) = corner(vtn2)?;
+    let (vertex3, texture_vertex3) = corner(vtn3)?;
+    let edge = |vertex: &Vertex| NormalVertex {
+        x: vertex.x - vertex1.x,
+        y: vertex.y - vertex1.y,
+        z: vertex.z - vertex1.z,
+    };
+    let (edge1, edge2) = (edge(vertex2), edge(vertex3));
+    let (du1, dv1) = (
+        texture_vertex2.u - texture_vertex1.u,
+        texture_vertex2.v - texture_vertex1.v,
+    );
+    let (du2, dv2) = (
+        texture_vertex3.u - texture_vertex1.u,
+        texture_vertex3.v - texture_vertex1.v,
+    );
+    let determinant = du1 * dv2 - du2 * dv1;
+    if determinant == 0_f64 {
+        return None;
+    }
 
-        Ok(material_libraries)
+    let tangent = (edge1 * dv2 - edge2 * dv1) * (1_f64 / determinant);
+    let bitangent = (edge2 * du1 - edge1 * du2) * (1_f64 / determinant);
+
+    Some((tangent, bitangent))
+}
+
+/// Orthogonalize a sum of face tangents against a normal vector and scale it
+/// to unit length. The handedness is taken from the sum of the bitangents.
+/// When the sum of the tangents is parallel to the normal vector, e.g. when no
+/// face contributed to it, an arbitrary unit vector orthogonal to the normal
+/// vector is chosen instead.
+fn unit_tangent(normal: &NormalVertex, tangent: NormalVertex, bitangent: NormalVertex) -> Tangent {
+    let normal = normal.normalize();
+    let orthogonal = |vector: NormalVertex| vector - normal * normal.dot(&vector);
+    let mut direction = orthogonal(tangent);
+    if direction.magnitude() == 0_f64 {
+        let axis = if normal.x.abs() <= normal.y.abs() && normal.x.abs() <= normal.z.abs() {
+            NormalVertex {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            }
+        } else if normal.y.abs() <= normal.z.abs() {
+            NormalVertex {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            }
+        } else {
+            NormalVertex {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            }
+        };
+        direction = orthogonal(axis);
     }
+    let direction = direction.normalize();
+    let handedness = if normal.cross(&direction).dot(&bitangent) < 0_f64 {
+        -1_f64
+    } else {
+        1_f64
+    };
 
-    /// Parse the object set in the wavefront obj file.
+    Tangent {
+        x: direction.x,
+        y: direction.y,
+        z: direction.z,
+        w: handedness,
+    }
+}
+
+impl Object {
+    /// Replace the normal vectors of the object with normal vectors computed
+    /// from the positions of the vertices of its faces.
+    ///
+    /// The corners of the faces of a smoothing group that share a vertex get
+    /// the same normal vector, the area weighted average of the normals of
+    /// those faces, so the group is shaded smoothly. The corners of a face
+    /// with smoothing off get the normal of the face itself. Texture vertex
+    /// indices are kept. Points and lines lose their normal indices, since
+    /// the normal vectors they referred to are discarded.
     ///
     /// ## Example
     ///
     /// ```
     /// # use wavefront_obj::obj;
     /// # use wavefront_obj::obj::{
-    /// #      Vertex,
-    /// #      NormalVertex,
-    /// #      Group,
-    /// #      SmoothingGroup,
-    /// #      Element,
-    /// #      ShapeEntry,
-    /// #      Geometry,
-    /// #      VTNIndex,
-    /// #      Object,
-    /// #      ObjectSet,
-    /// #      Parser,
+    /// #     Element,
+    /// #     ElementIndex,
+    /// #     NormalVertex,
+    /// #     VTNIndex,
     /// # };
     /// #
-    /// let obj_file = String::from(r"
-    ///     mtllib material_library.mtl    \
-    ///     o object1                      \
-    ///     v 0.000000  2.000000  0.000000 \
-    ///     v 0.000000  0.000000  0.000000 \
-    ///     v 2.000000  0.000000  0.000000 \
-    ///     v 2.000000  2.000000  0.000000 \
-    ///     v 4.000000  0.000000 -1.255298 \
-    ///     v 4.000000  2.000000 -1.255298 \
-    ///     ## 6 vertices                  \
-    ///                                    \
-    ///     g all                          \
-    ///     s 1                            \
-    ///     usemtl material1               \
-    ///     f 1 2 3 4                      \
-    ///     f 4 3 5 6                      \
-    ///     ## 2 elements                  \
-    ///                                    \
-    ///     o object2                      \
-    ///     v 0.000000  2.000000  0.000000 \
-    ///     v 0.000000  0.000000  0.000000 \
-    ///     v 2.000000  0.000000  0.000000 \
-    ///     v 2.000000  2.000000  0.000000 \
-    ///     v 4.000000  0.000000 -1.255298 \
-    ///     v 4.000000  2.000000 -1.255298 \
-    ///     ## 6 vertices                  \
-    ///                                    \
-    ///     g all                          \
-    ///     s 1                            \
-    ///     usemtl material2               \
-    ///     f 7 8 9 10                     \
-    ///     f 10 9 11 12                   \
-    ///     ## 2 elements                  \
-    ///                                    \
-    /// ");
-    /// // let expected = ...;
-    /// # let expected = ObjectSet {
-    /// #     material_libraries: vec![
-    /// #         String::from("material_library.mtl"),
-    /// #     ],
-    /// #     objects: vec![
-    /// #         Object {
-    /// #             name: String::from("object1"),
-    /// #             vertex_set: vec![
-    /// #                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 2.000000, y: 0.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
-    /// #                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
-    /// #             ],
-    /// #             texture_vertex_set: vec![],
-    /// #             normal_vertex_set: vec![],
-    /// #             group_set: vec![
-    /// #                 Group(String::from("all")),
-    /// #             ],
-    /// #             smoothing_group_set: vec![
-    /// #                 SmoothingGroup(1),
-    /// #             ],
-    /// #             element_set: vec![
-    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
-    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
-    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(2), VTNIndex::V(4)),
-    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(4), VTNIndex::V(5)),
-    /// #             ],
-    /// #             shape_set: vec![
-    /// #                 ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-    /// #                 ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-    /// #                 ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-    /// #                 ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-    /// #             ],
-    /// #             geometry_set: vec![
-    /// #                 Geometry { material_name: Some(String::from("material1")), shapes: vec![0, 1, 2, 3] },
-    /// #             ]
-    /// #         },
-    /// #         Object {
-    /// #             name: String::from("object2"),
-    /// #             vertex_set: vec![
-    /// #                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 2.000000, y: 0.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
-    /// #                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
-    /// #                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
-    /// #             ],
-    /// #             texture_vertex_set: vec![],
-    /// #             normal_vertex_set: vec![],
-    /// #             group_set: vec![
-    /// #                 Group(String::from("all")),
-    /// #             ],
-    /// #             smoothing_group_set: vec![
-    /// #                 SmoothingGroup(1),
-    /// #             ],
-    /// #             element_set: vec![
-    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
-    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
-    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(2), VTNIndex::V(4)),
-    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(4), VTNIndex::V(5)),
-    /// #             ],
-    /// #             shape_set: vec![
-    /// #                 ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-    /// #                 ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-    /// #                 ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-    /// #                 ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-    /// #             ],
-    /// #             geometry_set: vec![
-    /// #                 Geometry { material_name: Some(String::from("material2")), shapes: vec![0, 1, 2, 3] },
-    /// #             ]
-    /// #         }
-    /// #     ]
-    /// # };
-    /// let mut parser = Parser::new(&obj_file);
-    /// let result = parser.parse_objset();
-    /// assert!(result.is_ok());
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\ns 1\nf 1 2 3\nf 2 4 3\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    /// object.generate_normals();
     ///
-    /// let result = result.unwrap();
-    /// assert_eq!(result, expected)
+    /// assert_eq!(object.normal_vertex_set.len(), 4);
+    /// assert!(object
+    ///     .normal_vertex_set
+    ///     .iter()
+    ///     .all(|normal| *normal == NormalVertex { x: 0.0, y: 0.0, z: 1.0 }));
+    /// assert_eq!(
+    ///     object.element_set[ElementIndex(1)],
+    ///     Element::Face(VTNIndex::VN(1, 1), VTNIndex::VN(3, 3), VTNIndex::VN(2, 2))
+    /// );
     /// ```
-    pub fn parse_objset(&mut self) -> Result<ObjectSet, ParseError> {
-        let material_libraries = self.parse_material_libraries()?;
-        let objects = self.parse_objects()?;
+    pub fn generate_normals(&mut self) {
+        self.generate_normals_parallel(1);
+    }
 
-        Ok(ObjectSet {
-            material_libraries: material_libraries,
-            objects: objects,
-        })
+    /// Replace the normal vectors of the object like
+    /// [`Object::generate_normals`], distributing the work over at most
+    /// `max_threads` worker threads.
+    ///
+    /// The face normals are computed over ranges of elements, and the normals
+    /// of the smoothing groups are summed over ranges of vertices, so that
+    /// each vertex normal is summed by one thread in element order. The
+    /// result is therefore identical to that of [`Object::generate_normals`]
+    /// regardless of the number of threads.
+    ///
+    /// With the optional `rayon` feature, the work runs in at most
+    /// `max_threads` tasks on the `rayon` global thread pool. Without it, the
+    /// worker threads are scoped standard library threads, so that the crate
+    /// keeps no required dependencies.
+    pub fn generate_normals_parallel(&mut self, max_threads: usize) {
+        let thread_count = usize::max(1, max_threads);
+        let elements = self.element_set.as_slice();
+        let vertices = self.vertex_set.as_slice();
+
+        let mut smoothing_groups = vec![0; elements.len()];
+        for shape_entry in self.shape_set.iter() {
+            let smoothing_group = self.smoothing_group_set.try_get(shape_entry.smoothing_group);
+            if let (Some(slot), Ok(smoothing_group)) =
+                (smoothing_groups.get_mut(shape_entry.element.0), smoothing_group)
+            {
+                *slot = smoothing_group.0;
+            }
+        }
+        let face_normals = map_parallel(elements.len(), thread_count, |element_index| {
+            element_normal(vertices, elements[element_index])
+        });
+
+        // Each range of vertices is owned by one thread, and the last range
+        // also owns any vertex index past the end of the vertex set. The
+        // corners of the smooth faces are handed to the ranges owning their
+        // vertices before the threads start, so that each thread only visits
+        // its own corners.
+        let range_size = usize::max(1, vertices.len().div_ceil(thread_count));
+        let range_count = usize::max(1, vertices.len().div_ceil(range_size));
+        let owner = |vertex: usize| usize::min(vertex / range_size, range_count - 1);
+        let mut corners = vec![Vec::new(); range_count];
+        for (element_index, element) in elements.iter().enumerate() {
+            let smoothing_group = smoothing_groups[element_index];
+            if let (Element::Face(vtn1, vtn2, vtn3), true) = (*element, smoothing_group != 0) {
+                for vtn_index in [vtn1, vtn2, vtn3] {
+                    let vertex = vtn_index.vertex_index().0;
+                    corners[owner(vertex)].push((vertex, smoothing_group, element_index));
+                }
+            }
+        }
+        let sums = map_parallel(range_count, range_count, |range| {
+            let mut sums: BTreeMap<(usize, usize), [f64; 3]> = BTreeMap::new();
+            for &(vertex, smoothing_group, element_index) in corners[range].iter() {
+                let normal = face_normals[element_index];
+                let sum = sums.entry((vertex, smoothing_group)).or_insert([0_f64; 3]);
+                sum[0] += normal[0];
+                sum[1] += normal[1];
+                sum[2] += normal[2];
+            }
+
+            sums
+        });
+
+        let mut normal_vertices = vec![];
+        let mut smooth_indices = Vec::with_capacity(sums.len());
+        for range_sums in sums {
+            let mut indices = BTreeMap::new();
+            for (key, sum) in range_sums {
+                indices.insert(key, normal_vertices.len());
+                normal_vertices.push(unit_normal(sum));
+            }
+            smooth_indices.push(indices);
+        }
+        let mut flat_indices = vec![0; elements.len()];
+        for (element_index, element) in elements.iter().enumerate() {
+            if smoothing_groups[element_index] == 0 && matches!(*element, Element::Face(..)) {
+                flat_indices[element_index] = normal_vertices.len();
+                normal_vertices.push(unit_normal(face_normals[element_index]));
+            }
+        }
+
+        let new_elements = map_parallel(elements.len(), thread_count, |element_index| {
+            let element = elements[element_index];
+            let smoothing_group = smoothing_groups[element_index];
+            if !matches!(element, Element::Face(..)) {
+                return map_vtn_indices(element, |vtn_index| match vtn_index {
+                    VTNIndex::VN(v, _) => VTNIndex::V(v),
+                    VTNIndex::VTN(v, vt, _) => VTNIndex::VT(v, vt),
+                    other => other,
+                });
+            }
+
+            map_vtn_indices(element, |vtn_index| {
+                let vertex = vtn_index.vertex_index().0;
+                let vn = if smoothing_group == 0 {
+                    flat_indices[element_index]
+                } else {
+                    smooth_indices[owner(vertex)][&(vertex, smoothing_group)]
+                };
+                match vtn_index {
+                    VTNIndex::V(v) | VTNIndex::VN(v, _) => VTNIndex::VN(v, vn),
+                    VTNIndex::VT(v, vt) | VTNIndex::VTN(v, vt, _) => VTNIndex::VTN(v, vt, vn),
+                }
+            })
+        });
+
+        self.element_set = new_elements.into();
+        self.normal_vertex_set = normal_vertices.into();
+    }
+
+    /// Compute a tangent vector for each normal vector of the object, for
+    /// normal mapping.
+    ///
+    /// The tangent of a normal vector is the sum of the tangents of the faces
+    /// with a corner referring to both a texture vertex and that normal
+    /// vector, orthogonalized against the normal vector and scaled to unit
+    /// length. The returned tangents line up with the normal vertex set, so a
+    /// corner finds its tangent through its normal vertex index. Call
+    /// [`Object::generate_normals`] first if the object has no normal
+    /// vectors, or if they are shared between corners that should not share
+    /// a tangent.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::Tangent;
+    /// #
+    /// let obj_file = "\
+    ///     v 0 0 0\n v 1 0 0\n v 0 1 0\n\
+    ///     vt 0 0\n vt 1 0\n vt 0 1\n\
+    ///     vn 0 0 1\n\
+    ///     f 1/1/1 2/2/1 3/3/1\n\
+    /// ";
+    /// let object = obj::parse(obj_file).unwrap().objects.remove(0);
+    /// let tangents = object.generate_tangents();
+    ///
+    /// assert_eq!(tangents, vec![Tangent { x: 1.0, y: 0.0, z: 0.0, w: 1.0 }]);
+    /// ```
+    pub fn generate_tangents(&self) -> Vec<Tangent> {
+        self.generate_tangents_parallel(1)
+    }
+
+    /// Compute the tangent vectors of the object like
+    /// [`Object::generate_tangents`], distributing the work over at most
+    /// `max_threads` worker threads.
+    ///
+    /// As in [`Object::generate_normals_parallel`], the face tangents are
+    /// computed over ranges of elements, and the corners are handed to the
+    /// threads owning the ranges of normal vectors they refer to, so the
+    /// result does not depend on the number of threads.
+    /// The `rayon` feature runs the work on the `rayon` global thread pool in
+    /// the same way.
+    pub fn generate_tangents_parallel(&self, max_threads: usize) -> Vec<Tangent> {
+        let thread_count = usize::max(1, max_threads);
+        let elements = self.element_set.as_slice();
+        let vertices = self.vertex_set.as_slice();
+        let texture_vertices = self.texture_vertex_set.as_slice();
+        let normals = self.normal_vertex_set.as_slice();
+
+        let face_tangents = map_parallel(elements.len(), thread_count, |element_index| {
+            element_tangent(vertices, texture_vertices, elements[element_index])
+        });
+
+        let range_size = usize::max(1, normals.len().div_ceil(thread_count));
+        let range_count = usize::max(1, normals.len().div_ceil(range_size));
+        let mut corners = vec![Vec::new(); range_count];
+        for (element_index, element) in elements.iter().enumerate() {
+            if let (Element::Face(vtn1, vtn2, vtn3), Some(_)) = (*element, face_tangents[element_index]) {
+                for vtn_index in [vtn1, vtn2, vtn3] {
+                    if let VTNIndex::VTN(_, _, vn) = vtn_index {
+                        if vn < normals.len() {
+                            corners[vn / range_size].push((vn, element_index));
+                        }
+                    }
+                }
+            }
+        }
+        let tangents = map_parallel(range_count, range_count, |range| {
+            let start = usize::min(range * range_size, normals.len());
+            let end = usize::min(start + range_size, normals.len());
+            let zero = NormalVertex {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            };
+            let mut sums = vec![(zero, zero); end - start];
+            for &(vn, element_index) in corners[range].iter() {
+                if let Some((tangent, bitangent)) = face_tangents[element_index] {
+                    let sum = &mut sums[vn - start];
+                    sum.0 = sum.0 + tangent;
+                    sum.1 = sum.1 + bitangent;
+                }
+            }
+
+            sums.into_iter()
+                .zip(normals[start..end].iter())
+                .map(|((tangent, bitangent), normal)| unit_tangent(normal, tangent, bitangent))
+                .collect::<Vec<_>>()
+        });
+
+        tangents.into_iter().flatten().collect()
     }
 }
 
+/// The version of the JSON schema written by [`ObjectSet::to_json`].
+#[cfg(feature = "serde")]
+pub const JSON_SCHEMA_VERSION: u32 = 1;
 
-#[cfg(test)]
-mod primitive_tests {
-    use super::Parser;
+/// The document written by [`ObjectSet::to_json`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonDocumentRef<'a> {
+    version: u32,
+    object_set: &'a ObjectSet,
+}
 
+/// The document read by [`ObjectSet::from_json`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct JsonDocument {
+    version: u32,
+    object_set: ObjectSet,
+}
 
-    #[test]
-    fn test_parse_f64() {
-        let mut parser = Parser::new("-1.929448");
-        assert_eq!(parser.parse_f64(), Ok(-1.929448));
+#[cfg(feature = "serde")]
+impl ObjectSet {
+    /// Serialize the object set as JSON.
+    ///
+    /// The document is an object with two fields: `version`, the version of
+    /// the schema, currently [`JSON_SCHEMA_VERSION`], and `object_set`. Within
+    /// `object_set`, every struct becomes a JSON object with the same field
+    /// names as the Rust struct, and every set becomes an array. In particular:
+    ///
+    /// * Vertices are `{"x", "y", "z", "w"}`, texture vertices `{"u", "v", "w"}`,
+    ///   and normal vertices `{"x", "y", "z"}`.
+    /// * VTN indices are 0-based positions in the sets of the object, written
+    ///   as `{"V": v}`, `{"VT": [v, t]}`, `{"VN": [v, n]}`, or `{"VTN": [v, t, n]}`.
+    /// * Elements are `{"Point": vtn}`, `{"Line": [vtn, vtn]}`, or
+    ///   `{"Face": [vtn, vtn, vtn]}`.
+    /// * Groups are strings and smoothing groups are numbers, with `0` meaning
+    ///   smoothing is off.
+    /// * Shape entries are `{"element", "groups", "smoothing_group"}` and
+    ///   geometries are `{"material_name", "shapes"}`, all holding 0-based
+    ///   positions in the sets of the object.
+    /// * Optional values are `null` when absent.
+    ///
+    /// Fields are only added to the schema in new versions, never renamed or
+    /// removed without a change to the version.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ObjectSet;
+    /// #
+    /// let object_set = obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+    /// let json = object_set.to_json().unwrap();
+    /// assert!(json.starts_with(r#"{"version":1,"object_set":"#));
+    /// assert!(json.contains(r#""element_set":[{"Face":[{"V":0},{"V":1},{"V":2}]}]"#));
+    ///
+    /// assert_eq!(ObjectSet::from_json(&json).unwrap(), object_set);
+    /// ```
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&JsonDocumentRef {
+            version: JSON_SCHEMA_VERSION,
+            object_set: self,
+        })
     }
 
-    #[test]
-    fn test_parse_isize() {
-        let mut parser = Parser::new("    763   ");
-        assert_eq!(parser.parse_isize(), Ok(763));
+    /// Deserialize an object set from JSON written by [`ObjectSet::to_json`].
+    ///
+    /// The function fails if the JSON does not match the schema, or if it was
+    /// written with a newer version of the schema than this one.
+    pub fn from_json(json: &str) -> Result<ObjectSet, serde_json::Error> {
+        let document: JsonDocument = serde_json::from_str(json)?;
+        if document.version > JSON_SCHEMA_VERSION {
+            return Err(<serde_json::Error as serde::de::Error>::custom(format!(
+                "Unsupported JSON schema version {}; expected at most {}.",
+                document.version, JSON_SCHEMA_VERSION
+            )));
+        }
+
+        Ok(document.object_set)
+    }
+}
+
+/// A reference from a Wavefront OBJ file to something outside of it.
+///
+/// Older Wavefront tooling emits `call` and `csh` statements. The parser
+/// does not follow either of them; it records them so that the caller can
+/// decide what to do with them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExternalReference {
+    /// A `call` statement that reads the contents of another `*.obj` or
+    /// `*.mod` file into the current one.
+    Call {
+        /// The name of the file to read.
+        file_name: String,
+        /// The arguments to substitute into the called file.
+        arguments: Vec<String>,
+    },
+    /// A `csh` statement that executes a UNIX shell command.
+    Shell {
+        /// The command to execute. The tokens of the command are separated by
+        /// single spaces.
+        command: String,
+        /// Whether errors from the command should be ignored. The statement
+        /// `csh -command` ignores errors.
+        ignore_errors: bool,
+    },
+}
+
+/// A reference to a material library from an `mtllib` statement.
+///
+/// Besides the name of the library, the reference records where the
+/// statement appears, so that a loader can report which statement named a
+/// library it failed to load.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MtlLibRef {
+    /// The name of the material library as written in the `mtllib` statement.
+    pub name: String,
+    /// The line number of the `mtllib` statement in the source text.
+    pub line_number: usize,
+    /// The path or name that a loader resolved the library to. The parser
+    /// leaves this empty.
+    pub resolved_path: Option<PathBuf>,
+}
+
+impl MtlLibRef {
+    /// Construct a new unresolved material library reference.
+    pub fn new<S: Into<String>>(name: S, line_number: usize) -> MtlLibRef {
+        MtlLibRef {
+            name: name.into(),
+            line_number: line_number,
+            resolved_path: None,
+        }
     }
 }
 
+impl fmt::Display for MtlLibRef {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.name)
+    }
+}
+
+/// A statement that the parser skipped in lenient mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnknownStatement {
+    /// The line number of the statement in the source text.
+    pub line_number: usize,
+    /// The text of the statement as it appears in the source text, without
+    /// comments or the line terminator.
+    pub text: String,
+}
+
+/// A marker indicating the type of suspicious input the parser accepted with a
+/// warning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WarningKind {
+    /// A number is NaN or an infinity, and the parser kept it under
+    /// [`NonFinitePolicy::Warn`].
+    NonFiniteValue,
+}
+
+/// Suspicious input that the parser accepted, but that is likely to be a
+/// mistake.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseWarning {
+    /// The line number of the suspicious input in the source text.
+    pub line_number: usize,
+    /// The kind of suspicious input.
+    pub kind: WarningKind,
+    /// A message describing the suspicious input.
+    pub message: String,
+}
+
+/// An object set is a collection of objects and material library named obtained
+/// from parsing an `*.obj` file. An `*.obj` file may contain more that one object.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectSet {
+    /// The material libraries referenced by the `mtllib` statements of the
+    /// object set, in the order they appear.
+    pub material_libraries: Vec<MtlLibRef>,
+    /// The `call` and `csh` statements in the file, in the order they appear.
+    pub external_references: Vec<ExternalReference>,
+    /// The statements the parser skipped because it did not recognize them,
+    /// in the order they appear. This is empty unless the parser runs in
+    /// lenient mode with [`ParserOptions::lenient`].
+    pub unknown_statements: Vec<UnknownStatement>,
+    /// The suspicious input the parser accepted, in the order it appears.
+    pub warnings: Vec<ParseWarning>,
+    /// The set of objects in an object set.
+    pub objects: Vec<Object>,
+}
+
+impl ObjectSet {
+    /// Summarize the contents of each object in an object set for human
+    /// readers.
+    ///
+    /// Use the [`fmt::Display`] implementation to get the object set as
+    /// Wavefront OBJ text instead.
+    pub fn summary(&self) -> String {
+        let compositor = SummaryCompositor::new();
+        let mut string = String::from("ObjectSet {\n");
+
+        for object in self.objects.iter() {
+            string += &compositor.compose(object);
+            string += "\n";
+        }
+
+        string += "}\n";
+
+        string
+    }
+
+    /// Report the materials whose texture coordinates reach further than
+    /// `margin` outside the range `[0, 1]`.
+    ///
+    /// Texture coordinates somewhat outside the unit square are common when a
+    /// texture tiles, so the report gives the extents of the coordinates of
+    /// each material to help tell intentional tiling from a broken export.
+    /// Each texture vertex is counted once per material of each object that
+    /// uses it, and faces without a material are reported under `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// #
+    /// let obj_file = "\
+    ///     v 0 0 0\nv 1 0 0\nv 0 1 0\n\
+    ///     vt 0 0\nvt 1 0\nvt 0 1\nvt 250 -3\n\
+    ///     usemtl good\nf 1/1 2/2 3/3\n\
+    ///     usemtl broken\nf 1/1 2/4 3/3\n\
+    /// ";
+    /// let object_set = obj::parse(obj_file).unwrap();
+    /// let report = object_set.audit_texture_coordinates(1.0);
+    ///
+    /// assert_eq!(report.len(), 1);
+    /// assert_eq!(report[0].material_name.as_deref(), Some("broken"));
+    /// assert_eq!(report[0].out_of_range_count, 1);
+    /// assert_eq!(report[0].max_u, 250.0);
+    /// ```
+    pub fn audit_texture_coordinates(&self, margin: f64) -> Vec<TextureCoordinateAudit> {
+        let mut audits: Vec<TextureCoordinateAudit> = vec![];
+        for object in self.objects.iter() {
+            for geometry in object.geometry_set.iter() {
+                let mut texture_vertex_indices = vec![];
+                for shape_entry in geometry
+                    .shapes
+                    .iter()
+                    .filter_map(|&shape| object.shape_set.try_get(shape).ok())
+                {
+                    if let Ok(element) = object.element_set.try_get(shape_entry.element) {
+                        map_vtn_indices(*element, |vtn_index| {
+                            texture_vertex_indices.extend(vtn_index.texture_vertex_index());
+                            vtn_index
+                        });
+                    }
+                }
+                texture_vertex_indices.sort_unstable();
+                texture_vertex_indices.dedup();
+                if texture_vertex_indices.is_empty() {
+                    continue;
+                }
+
+                let position = audits
+                    .iter()
+                    .position(|audit| audit.material_name == geometry.material_name);
+                let audit = match position {
+                    Some(position) => &mut audits[position],
+                    None => {
+                        audits.push(TextureCoordinateAudit::new(geometry.material_name.clone()));
+                        audits.last_mut().unwrap()
+                    }
+                };
+                let texture_vertices = texture_vertex_indices
+                    .into_iter()
+                    .filter_map(|index| object.texture_vertex_set.try_get(index).ok());
+                for texture_vertex in texture_vertices {
+                    audit.add(texture_vertex, margin);
+                }
+            }
+        }
+        audits.retain(|audit| audit.out_of_range_count > 0);
+
+        audits
+    }
+}
+
+/// A convention for the directions of the coordinate axes of a model.
+///
+/// In every convention the x axis points to the right of the viewer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axes {
+    /// The y axis points up and the z axis points towards the viewer. This is
+    /// the convention of OpenGL, Maya, and most OBJ exporters.
+    YUpRightHanded,
+    /// The z axis points up and the y axis points away from the viewer. This
+    /// is the convention of Blender and 3ds Max scenes.
+    ZUpRightHanded,
+    /// The y axis points up and the z axis points away from the viewer. This
+    /// is the convention of Direct3D and Unity.
+    YUpLeftHanded,
+    /// The z axis points up and the y axis points towards the viewer.
+    ZUpLeftHanded,
+}
+
+impl Axes {
+    /// The matrix taking coordinates in this convention to the
+    /// [`Axes::YUpRightHanded`] convention.
+    fn to_y_up_right_handed(self) -> [[f64; 3]; 3] {
+        match self {
+            Axes::YUpRightHanded => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            Axes::ZUpRightHanded => [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, -1.0, 0.0]],
+            Axes::YUpLeftHanded => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]],
+            Axes::ZUpLeftHanded => [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]],
+        }
+    }
+
+    /// The matrix taking coordinates in the `from` convention to the `to`
+    /// convention. Each matrix is orthogonal, so its inverse is its transpose.
+    fn conversion(from: Axes, to: Axes) -> [[f64; 3]; 3] {
+        let from = from.to_y_up_right_handed();
+        let to = to.to_y_up_right_handed();
+        let mut matrix = [[0_f64; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = (0..3).map(|k| to[k][i] * from[k][j]).sum();
+            }
+        }
+
+        matrix
+    }
+}
+
+impl ObjectSet {
+    /// Convert the coordinates of every object from one axis convention to
+    /// another.
+    ///
+    /// Vertices and normal vertices are rotated, or mirrored when the
+    /// handedness changes. Mirroring turns faces inside out, so the winding
+    /// of every face is reversed in that case to keep the faces pointing the
+    /// same way.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     Axes,
+    /// #     NormalVertex,
+    /// #     NormalVertexIndex,
+    /// #     Vertex,
+    /// #     VertexIndex,
+    /// # };
+    /// #
+    /// let mut object_set = obj::parse("v 1 2 3\nvn 0 1 0\np 1\n").unwrap();
+    /// object_set.convert_axes(Axes::YUpRightHanded, Axes::ZUpRightHanded);
+    /// let object = &object_set.objects[0];
+    ///
+    /// assert_eq!(object.vertex_set[VertexIndex(0)], Vertex { x: 1.0, y: -3.0, z: 2.0, w: 1.0 });
+    /// assert_eq!(object.normal_vertex_set[NormalVertexIndex(0)], NormalVertex { x: 0.0, y: 0.0, z: 1.0 });
+    /// ```
+    pub fn convert_axes(&mut self, from: Axes, to: Axes) {
+        if from == to {
+            return;
+        }
+
+        let matrix = Axes::conversion(from, to);
+        let transform = |x: f64, y: f64, z: f64| {
+            let mut result = [0_f64; 3];
+            for (i, row) in matrix.iter().enumerate() {
+                result[i] = row[0] * x + row[1] * y + row[2] * z;
+            }
+
+            result
+        };
+        let determinant = matrix[0][0] * (matrix[1][1] * matrix[2][2] - matrix[1][2] * matrix[2][1])
+            - matrix[0][1] * (matrix[1][0] * matrix[2][2] - matrix[1][2] * matrix[2][0])
+            + matrix[0][2] * (matrix[1][0] * matrix[2][1] - matrix[1][1] * matrix[2][0]);
+
+        for object in self.objects.iter_mut() {
+            for vertex in object.vertex_set.iter_mut() {
+                let [x, y, z] = transform(vertex.x, vertex.y, vertex.z);
+                vertex.x = x;
+                vertex.y = y;
+                vertex.z = z;
+            }
+            for normal_vertex in object.normal_vertex_set.iter_mut() {
+                let [x, y, z] = transform(normal_vertex.x, normal_vertex.y, normal_vertex.z);
+                normal_vertex.x = x;
+                normal_vertex.y = y;
+                normal_vertex.z = z;
+            }
+            if determinant < 0_f64 {
+                object.reverse_winding();
+            }
+        }
+    }
+}
+
+/// A coordinate axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Object {
+    /// Mirror the object across the plane perpendicular to an axis through
+    /// the origin.
+    ///
+    /// The coordinate along the axis of every vertex and normal vertex is
+    /// negated, and the winding of every face is reversed so that the faces
+    /// of the mirrored object still point outwards.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     Axis,
+    /// #     Element,
+    /// #     ElementIndex,
+    /// #     NormalVertex,
+    /// #     NormalVertexIndex,
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let obj_file = "v 1 0 0\nv 2 0 0\nv 1 1 0\nvn 1 0 0\nf 1//1 2//1 3//1\n";
+    /// let mut object = obj::parse(obj_file).unwrap().objects.remove(0);
+    /// object.mirror(Axis::X);
+    ///
+    /// assert_eq!(object.vertex_set.iter().map(|vertex| vertex.x).collect::<Vec<_>>(), vec![-1.0, -2.0, -1.0]);
+    /// assert_eq!(object.normal_vertex_set[NormalVertexIndex(0)], NormalVertex { x: -1.0, y: 0.0, z: 0.0 });
+    /// assert_eq!(
+    ///     object.element_set[ElementIndex(0)],
+    ///     Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(2, 0), VTNIndex::VN(1, 0))
+    /// );
+    /// ```
+    pub fn mirror(&mut self, axis: Axis) {
+        for vertex in self.vertex_set.iter_mut() {
+            match axis {
+                Axis::X => vertex.x = -vertex.x,
+                Axis::Y => vertex.y = -vertex.y,
+                Axis::Z => vertex.z = -vertex.z,
+            }
+        }
+        for normal_vertex in self.normal_vertex_set.iter_mut() {
+            match axis {
+                Axis::X => normal_vertex.x = -normal_vertex.x,
+                Axis::Y => normal_vertex.y = -normal_vertex.y,
+                Axis::Z => normal_vertex.z = -normal_vertex.z,
+            }
+        }
+        self.reverse_winding();
+    }
+
+    /// Reverse the order of the corners of every face, turning it inside out.
+    fn reverse_winding(&mut self) {
+        for element in self.element_set.iter_mut() {
+            if let Element::Face(vtn1, vtn2, vtn3) = *element {
+                *element = Element::Face(vtn1, vtn3, vtn2);
+            }
+        }
+    }
+}
+
+/// A unit of length that model coordinates may be given in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Units {
+    Millimeters,
+    Centimeters,
+    Meters,
+    Kilometers,
+    Inches,
+    Feet,
+}
+
+impl Units {
+    /// The length of one unit in tenths of a millimeter, which measures
+    /// every unit exactly so that conversion factors have no rounding error.
+    fn in_tenths_of_millimeters(self) -> f64 {
+        match self {
+            Units::Millimeters => 10.0,
+            Units::Centimeters => 100.0,
+            Units::Meters => 10_000.0,
+            Units::Kilometers => 10_000_000.0,
+            Units::Inches => 254.0,
+            Units::Feet => 3048.0,
+        }
+    }
+
+    /// The length of one unit in meters.
+    pub fn in_meters(self) -> f64 {
+        self.in_tenths_of_millimeters() / Units::Meters.in_tenths_of_millimeters()
+    }
+
+    /// The factor that converts lengths in the `from` unit to the `to` unit,
+    /// for use with [`ObjectSet::scale_units`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::Units;
+    /// #
+    /// assert_eq!(Units::conversion_factor(Units::Meters, Units::Centimeters), 100.0);
+    /// ```
+    pub fn conversion_factor(from: Units, to: Units) -> f64 {
+        from.in_tenths_of_millimeters() / to.in_tenths_of_millimeters()
+    }
+}
+
+impl ObjectSet {
+    /// Scale the positions of every vertex by a factor, e.g. to convert a
+    /// model between units of length.
+    ///
+    /// Normal vertices and texture vertices are left alone, since a uniform
+    /// scale does not change either. The factor should be positive; a
+    /// negative factor mirrors the model without reversing its faces.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #     Units,
+    /// #     Vertex,
+    /// #     VertexIndex,
+    /// # };
+    /// #
+    /// let mut object_set = obj::parse("v 1 2 0.5\np 1\n").unwrap();
+    /// object_set.scale_units(Units::conversion_factor(Units::Meters, Units::Centimeters));
+    ///
+    /// assert_eq!(
+    ///     object_set.objects[0].vertex_set[VertexIndex(0)],
+    ///     Vertex { x: 100.0, y: 200.0, z: 50.0, w: 1.0 }
+    /// );
+    /// ```
+    pub fn scale_units(&mut self, factor: f64) {
+        for object in self.objects.iter_mut() {
+            for vertex in object.vertex_set.iter_mut() {
+                vertex.x *= factor;
+                vertex.y *= factor;
+                vertex.z *= factor;
+            }
+        }
+    }
+
+    /// Rename materials and material libraries using a table that maps old
+    /// names to new names.
+    ///
+    /// The material name of every geometry that appears in the table is
+    /// replaced, as is the name of every `mtllib` entry that appears in it,
+    /// so a single table can swap both placeholder materials and the
+    /// libraries that define them. A renamed library loses its resolved
+    /// path. Names that are not in the table, and the geometry itself, are
+    /// left alone.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::GeometryIndex;
+    /// #
+    /// let mut object_set = obj::parse(
+    ///     "mtllib placeholder.mtl\nv 0 0 0\nusemtl grey\np 1\nusemtl red\np 1\n",
+    /// ).unwrap();
+    /// let table: HashMap<String, String> = [
+    ///     ("placeholder.mtl", "final.mtl"),
+    ///     ("grey", "brushed_steel"),
+    /// ]
+    /// .iter()
+    /// .map(|&(old, new)| (String::from(old), String::from(new)))
+    /// .collect();
+    /// object_set.remap_materials(&table);
+    ///
+    /// let geometry_set = &object_set.objects[0].geometry_set;
+    /// assert_eq!(object_set.material_libraries[0].name, "final.mtl");
+    /// assert_eq!(geometry_set[GeometryIndex(0)].material_name.as_deref(), Some("brushed_steel"));
+    /// assert_eq!(geometry_set[GeometryIndex(1)].material_name.as_deref(), Some("red"));
+    /// ```
+    pub fn remap_materials(&mut self, table: &HashMap<String, String>) {
+        for material_library in self.material_libraries.iter_mut() {
+            if let Some(new_name) = table.get(&material_library.name) {
+                material_library.name = new_name.clone();
+                material_library.resolved_path = None;
+            }
+        }
+        for object in self.objects.iter_mut() {
+            for geometry in object.geometry_set.iter_mut() {
+                if let Some(new_name) = geometry.material_name.as_ref().and_then(|name| table.get(name)) {
+                    geometry.material_name = Some(new_name.clone());
+                }
+            }
+        }
+    }
+}
+
+/// The texture coordinates used by one material, as reported by
+/// [`ObjectSet::audit_texture_coordinates`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextureCoordinateAudit {
+    /// The name of the material, or `None` for faces without a material.
+    pub material_name: Option<String>,
+    /// The number of texture vertices the material uses.
+    pub texture_vertex_count: usize,
+    /// The number of those texture vertices that lie further than the margin
+    /// outside the range `[0, 1]`.
+    pub out_of_range_count: usize,
+    /// The smallest u coordinate the material uses.
+    pub min_u: f64,
+    /// The largest u coordinate the material uses.
+    pub max_u: f64,
+    /// The smallest v coordinate the material uses.
+    pub min_v: f64,
+    /// The largest v coordinate the material uses.
+    pub max_v: f64,
+}
+
+impl TextureCoordinateAudit {
+    fn new(material_name: Option<String>) -> TextureCoordinateAudit {
+        TextureCoordinateAudit {
+            material_name: material_name,
+            texture_vertex_count: 0,
+            out_of_range_count: 0,
+            min_u: f64::INFINITY,
+            max_u: f64::NEG_INFINITY,
+            min_v: f64::INFINITY,
+            max_v: f64::NEG_INFINITY,
+        }
+    }
+
+    fn add(&mut self, texture_vertex: &TextureVertex, margin: f64) {
+        let in_range = |coordinate: f64| coordinate >= -margin && coordinate <= 1_f64 + margin;
+        self.texture_vertex_count += 1;
+        if !in_range(texture_vertex.u) || !in_range(texture_vertex.v) {
+            self.out_of_range_count += 1;
+        }
+        self.min_u = f64::min(self.min_u, texture_vertex.u);
+        self.max_u = f64::max(self.max_u, texture_vertex.u);
+        self.min_v = f64::min(self.min_v, texture_vertex.v);
+        self.max_v = f64::max(self.max_v, texture_vertex.v);
+    }
+}
+
+impl fmt::Display for TextureCoordinateAudit {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.material_name {
+            Some(ref material_name) => write!(formatter, "Material `{}`: ", material_name)?,
+            None => write!(formatter, "No material: ")?,
+        }
+        write!(
+            formatter,
+            "{} of {} texture vertices out of range, u in [{}, {}], v in [{}, {}]",
+            self.out_of_range_count,
+            self.texture_vertex_count,
+            self.min_u,
+            self.max_u,
+            self.min_v,
+            self.max_v
+        )
+    }
+}
+
+/// Writes an object set as Wavefront OBJ text, as written by the
+/// [`TextObjectSetCompositor`].
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// #
+/// let object_set = obj::parse("o triangle\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+/// let text = object_set.to_string();
+///
+/// assert_eq!(text, "o triangle\nv 0 0 0\nv 1 0 0\nv 0 1 0\ng default\ns off\nf 1 2 3\n");
+/// assert_eq!(obj::parse(&text).unwrap(), object_set);
+/// ```
+impl fmt::Display for ObjectSet {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        formatter.write_str(&TextObjectSetCompositor::new().compose(self))
+    }
+}
+
+/// A compositor converts parsed data into text.
+///
+/// The data is an object set unless stated otherwise, e.g. the
+/// [`TextMaterialSetCompositor`](crate::mtl::TextMaterialSetCompositor)
+/// composes a [`MaterialSet`](crate::mtl::MaterialSet).
+pub trait Compositor<T: ?Sized = ObjectSet> {
+    /// Write the data to a writer.
+    ///
+    /// Implementations should stream their output to the writer instead of
+    /// building the whole output in memory.
+    fn compose_to(&self, data: &T, writer: &mut dyn io::Write) -> io::Result<()>;
+
+    /// Convert the data into a string.
+    fn compose(&self, data: &T) -> String {
+        let mut bytes = vec![];
+        self.compose_to(data, &mut bytes)
+            .expect("Writing to a vector cannot fail.");
+
+        String::from_utf8(bytes).expect("A compositor must produce UTF-8 text.")
+    }
+}
+
+/// Options that control how a [`TextObjectSetCompositor`] writes an object set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WriteOptions {
+    /// The value of the w component of a vertex that readers assume when it
+    /// is absent. The default is 1.
+    pub default_w: f64,
+    /// Write the w component of a vertex even when it equals `default_w`.
+    pub write_default_w: bool,
+    /// The size in bytes of the chunks the output is flushed to the writer
+    /// in. The default is 64 KiB.
+    pub buffer_capacity: usize,
+    /// Write pairs of consecutive faces that the parser produced by
+    /// triangulating a coplanar quad as a single quad again, so that writing
+    /// a parsed file does not double its number of quads.
+    pub reconstruct_quads: bool,
+    /// Write the numbers of each vertex statement exactly as the parser
+    /// recorded them in [`Object::numeric_text`], as long as they still give
+    /// the values of the vertex. Vertices that were modified after parsing,
+    /// or that have no recorded text, are formatted as usual.
+    pub preserve_numeric_text: bool,
+    /// Leave out the `vt` statements, and the texture vertex indices of the
+    /// elements, e.g. writing `f 1/1/1 2/2/1 3/3/1` as `f 1//1 2//1 3//1`.
+    pub omit_texture_vertices: bool,
+    /// Leave out the `vn` statements, and the normal vertex indices of the
+    /// elements, e.g. writing `f 1/1/1 2/2/1 3/3/1` as `f 1/1 2/2 3/3`.
+    pub omit_normals: bool,
+    /// Leave out the `g` statements, so that every element of an object is
+    /// in the default group when the output is parsed.
+    pub flatten_groups: bool,
+    /// The statement that starts each named object. The default is `o`.
+    pub object_delimiter: ObjectDelimiter,
+}
+
+/// The statement a writer starts each named object with, as chosen by
+/// [`WriteOptions::object_delimiter`].
+///
+/// Some tools split a file into objects at `o` statements and others at `g`
+/// statements. When objects are delimited by groups, the group statements of
+/// the elements of an object are replaced by a single group named after the
+/// object, so that tools reading either statement see the same objects.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ObjectDelimiter {
+    /// Start each object with an `o` statement, and write the groups of its
+    /// elements as they are.
+    #[default]
+    Object,
+    /// Start each object with a `g` statement instead of an `o` statement.
+    Group,
+    /// Start each object with both an `o` statement and a `g` statement.
+    Both,
+}
+
+impl Default for WriteOptions {
+    fn default() -> WriteOptions {
+        WriteOptions {
+            default_w: 1_f64,
+            write_default_w: false,
+            buffer_capacity: 64 * 1024,
+            reconstruct_quads: false,
+            preserve_numeric_text: false,
+            omit_texture_vertices: false,
+            omit_normals: false,
+            flatten_groups: false,
+            object_delimiter: ObjectDelimiter::Object,
+        }
+    }
+}
+
+/// Determine whether the recorded text of a vertex statement still gives the
+/// values of the vertex.
+///
+/// A component missing from the text takes its default value, and a
+/// component without a default must be present. Values compare bit for bit,
+/// so that e.g. `-0` does not stand in for zero, except that any NaN matches
+/// any other.
+fn numeric_text_matches(text: &str, values: &[f64], defaults: &[Option<f64>]) -> bool {
+    let mut tokens = text.split(lexer::is_separator).filter(|token| !token.is_empty());
+    for (&value, &default) in values.iter().zip(defaults.iter()) {
+        let parsed = match tokens.next() {
+            Some(token) => number::parse_f64(token, true),
+            None => default,
+        };
+        match parsed {
+            Some(parsed) if parsed.to_bits() == value.to_bits() || (parsed.is_nan() && value.is_nan()) => {}
+            _ => return false,
+        }
+    }
+
+    tokens.next().is_none()
+}
+
+/// Recover the quad that the parser triangulated into two consecutive faces.
+///
+/// The faces must come from the triangle fan of a quad, i.e. the second face
+/// shares the first and last corners of the first one, use the same VTN
+/// index form, and lie in the same plane with the same orientation.
+fn triangulated_quad(object: &Object, first: &Element, second: &Element) -> Option<[VTNIndex; 4]> {
+    let (vtn1, vtn2, vtn3, vtn4) = match (*first, *second) {
+        (Element::Face(vtn1, vtn2, vtn3), Element::Face(vtn1_next, vtn3_next, vtn4))
+            if vtn1_next == vtn1 && vtn3_next == vtn3 =>
+        {
+            (vtn1, vtn2, vtn3, vtn4)
+        }
+        _ => return None,
+    };
+    if !vtn1.has_same_type_as(&vtn2) || !vtn1.has_same_type_as(&vtn3) || !vtn1.has_same_type_as(&vtn4) {
+        return None;
+    }
+
+    let vertex = |vtn_index: VTNIndex| object.vertex_set.try_get(vtn_index.vertex_index()).ok().copied();
+    let (vertex1, vertex2, vertex3, vertex4) = (vertex(vtn1)?, vertex(vtn2)?, vertex(vtn3)?, vertex(vtn4)?);
+    let normal1 = (vertex2 - vertex1).cross(&(vertex3 - vertex1));
+    let normal2 = (vertex3 - vertex1).cross(&(vertex4 - vertex1));
+    let parallel = normal1.cross(&normal2);
+    let tolerance = 1e-12 * normal1.dot(&normal1) * normal2.dot(&normal2);
+    if normal1.dot(&normal2) <= 0_f64 || parallel.dot(&parallel) > tolerance {
+        return None;
+    }
+
+    Some([vtn1, vtn2, vtn3, vtn4])
+}
+
+/// A compositor that writes an object set as Wavefront OBJ text.
+///
+/// Parsing the output of this compositor yields an object set equivalent to
+/// the original one. Objects without a name are written without an `o`
+/// statement, so only the first object in an object set may be unnamed.
+///
+/// The output is streamed to the writer in chunks of
+/// [`WriteOptions::buffer_capacity`] bytes, so writing a large object set does
+/// not hold a second copy of it in memory.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::{
+/// #     Compositor,
+/// #     TextObjectSetCompositor,
+/// # };
+/// #
+/// let obj_file = "o triangle\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+/// let object_set = obj::parse(obj_file).unwrap();
+/// let text = TextObjectSetCompositor::new().compose(&object_set);
+///
+/// assert!(text.contains("f 1 2 3\n"));
+/// assert_eq!(obj::parse(&text).unwrap().objects, object_set.objects);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TextObjectSetCompositor {
+    /// The options controlling the output.
+    options: WriteOptions,
+}
+
+impl TextObjectSetCompositor {
+    /// Construct a new Wavefront OBJ text compositor.
+    pub fn new() -> Self {
+        Self::with_options(WriteOptions::default())
+    }
+
+    /// Construct a new Wavefront OBJ text compositor with non-default options.
+    pub fn with_options(options: WriteOptions) -> Self {
+        Self { options: options }
+    }
+
+    /// Write a VTN index using the one-based global indices of an `*.obj` file.
+    fn write_vtn_index<W: io::Write>(
+        &self,
+        writer: &mut W,
+        vtn_index: &VTNIndex,
+        offsets: (usize, usize, usize),
+    ) -> io::Result<()> {
+        let (v_offset, vt_offset, vn_offset) = offsets;
+        let vtn_index = match (
+            *vtn_index,
+            self.options.omit_texture_vertices,
+            self.options.omit_normals,
+        ) {
+            (VTNIndex::VT(v, _), true, _) | (VTNIndex::VN(v, _), _, true) => VTNIndex::V(v),
+            (VTNIndex::VTN(v, _, _), true, true) => VTNIndex::V(v),
+            (VTNIndex::VTN(v, _, vn), true, false) => VTNIndex::VN(v, vn),
+            (VTNIndex::VTN(v, vt, _), false, true) => VTNIndex::VT(v, vt),
+            (vtn_index, _, _) => vtn_index,
+        };
+        match vtn_index {
+            VTNIndex::V(v) => write!(writer, "{}", v + v_offset + 1),
+            VTNIndex::VT(v, vt) => write!(writer, "{}/{}", v + v_offset + 1, vt + vt_offset + 1),
+            VTNIndex::VN(v, vn) => write!(writer, "{}//{}", v + v_offset + 1, vn + vn_offset + 1),
+            VTNIndex::VTN(v, vt, vn) => write!(
+                writer,
+                "{}/{}/{}",
+                v + v_offset + 1,
+                vt + vt_offset + 1,
+                vn + vn_offset + 1
+            ),
+        }
+    }
+
+    fn write_element<W: io::Write>(
+        &self,
+        writer: &mut W,
+        element: &Element,
+        offsets: (usize, usize, usize),
+    ) -> io::Result<()> {
+        let (tag, vtn_indices) = match *element {
+            Element::Point(vtn) => ("p", [Some(vtn), None, None]),
+            Element::Line(vtn1, vtn2) => ("l", [Some(vtn1), Some(vtn2), None]),
+            Element::Face(vtn1, vtn2, vtn3) => ("f", [Some(vtn1), Some(vtn2), Some(vtn3)]),
+        };
+        writer.write_all(tag.as_bytes())?;
+        for vtn_index in vtn_indices.iter().flatten() {
+            writer.write_all(b" ")?;
+            self.write_vtn_index(writer, vtn_index, offsets)?;
+        }
+
+        writer.write_all(b"\n")
+    }
+
+    fn write_quad<W: io::Write>(
+        &self,
+        writer: &mut W,
+        quad: &[VTNIndex; 4],
+        offsets: (usize, usize, usize),
+    ) -> io::Result<()> {
+        writer.write_all(b"f")?;
+        for vtn_index in quad.iter() {
+            writer.write_all(b" ")?;
+            self.write_vtn_index(writer, vtn_index, offsets)?;
+        }
+
+        writer.write_all(b"\n")
+    }
+
+    /// The recorded text of a vertex statement, if it should be written
+    /// instead of the values of the vertex.
+    fn numeric_text<'t>(
+        &self,
+        texts: &'t [String],
+        index: usize,
+        values: &[f64],
+        defaults: &[Option<f64>],
+    ) -> Option<&'t str> {
+        if !self.options.preserve_numeric_text {
+            return None;
+        }
+
+        texts
+            .get(index)
+            .map(String::as_str)
+            .filter(|text| numeric_text_matches(text, values, defaults))
+    }
+
+    fn write_object<W: io::Write>(
+        &self,
+        writer: &mut W,
+        object: &Object,
+        offsets: (usize, usize, usize),
+    ) -> io::Result<()> {
+        let object_delimiter = if object.name.is_empty() {
+            None
+        } else {
+            Some(self.options.object_delimiter)
+        };
+        if let Some(ObjectDelimiter::Object) | Some(ObjectDelimiter::Both) = object_delimiter {
+            writeln!(writer, "o {}", object.name)?;
+        }
+        let group_is_object = matches!(
+            object_delimiter,
+            Some(ObjectDelimiter::Group) | Some(ObjectDelimiter::Both)
+        );
+        if group_is_object {
+            writeln!(writer, "g {}", object.name)?;
+        }
+        let write_groups = !self.options.flatten_groups && !group_is_object;
+
+        let default_w = if self.options.write_default_w {
+            None
+        } else {
+            Some(self.options.default_w)
+        };
+        for (index, vertex) in object.vertex_set.iter().enumerate() {
+            let values = [vertex.x, vertex.y, vertex.z, vertex.w];
+            if let Some(text) = self.numeric_text(
+                &object.numeric_text.vertices,
+                index,
+                &values,
+                &[None, None, None, default_w],
+            ) {
+                writeln!(writer, "v {}", text)?;
+            } else if vertex.w == self.options.default_w && !self.options.write_default_w {
+                writeln!(writer, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+            } else {
+                writeln!(writer, "v {} {} {} {}", vertex.x, vertex.y, vertex.z, vertex.w)?;
+            }
+        }
+        let texture_vertices = if self.options.omit_texture_vertices {
+            &[]
+        } else {
+            object.texture_vertex_set.as_slice()
+        };
+        for (index, texture_vertex) in texture_vertices.iter().enumerate() {
+            let values = [texture_vertex.u, texture_vertex.v, texture_vertex.w];
+            let defaults = [None, Some(0_f64), Some(0_f64)];
+            if let Some(text) =
+                self.numeric_text(&object.numeric_text.texture_vertices, index, &values, &defaults)
+            {
+                writeln!(writer, "vt {}", text)?;
+            } else if texture_vertex.w == 0_f64 {
+                writeln!(writer, "vt {} {}", texture_vertex.u, texture_vertex.v)?;
+            } else {
+                writeln!(
+                    writer,
+                    "vt {} {} {}",
+                    texture_vertex.u, texture_vertex.v, texture_vertex.w
+                )?;
+            }
+        }
+        let normal_vertices = if self.options.omit_normals {
+            &[]
+        } else {
+            object.normal_vertex_set.as_slice()
+        };
+        for (index, normal_vertex) in normal_vertices.iter().enumerate() {
+            let values = [normal_vertex.x, normal_vertex.y, normal_vertex.z];
+            if let Some(text) =
+                self.numeric_text(&object.numeric_text.normal_vertices, index, &values, &[None; 3])
+            {
+                writeln!(writer, "vn {}", text)?;
+                continue;
+            }
+            writeln!(
+                writer,
+                "vn {} {} {}",
+                normal_vertex.x, normal_vertex.y, normal_vertex.z
+            )?;
+        }
+
+        let mut current_groups: Option<&[GroupIndex]> = None;
+        let mut current_smoothing_group = None;
+        for geometry in object.geometry_set.iter() {
+            if geometry.shapes.is_empty() {
+                continue;
+            }
+            if let Some(ref material_name) = geometry.material_name {
+                writeln!(writer, "usemtl {}", material_name)?;
+            }
+
+            let mut shape_indices = geometry.shapes.iter().copied().peekable();
+            while let Some(shape_index) = shape_indices.next() {
+                let shape_entry = &object.shape_set[shape_index];
+                if write_groups && current_groups != Some(&shape_entry.groups[..]) {
+                    writer.write_all(b"g")?;
+                    for &group_index in shape_entry.groups.iter() {
+                        write!(writer, " {}", object.group_set[group_index])?;
+                    }
+                    writer.write_all(b"\n")?;
+                    current_groups = Some(&shape_entry.groups[..]);
+                }
+
+                let smoothing_group = object.smoothing_group_set[shape_entry.smoothing_group];
+                if current_smoothing_group != Some(smoothing_group) {
+                    writeln!(writer, "s {}", smoothing_group)?;
+                    current_smoothing_group = Some(smoothing_group);
+                }
+
+                let element = &object.element_set[shape_entry.element];
+                if self.options.reconstruct_quads {
+                    let quad = shape_indices.peek().and_then(|&next_shape_index| {
+                        let next_shape_entry = &object.shape_set[next_shape_index];
+                        if next_shape_entry.groups != shape_entry.groups
+                            || next_shape_entry.smoothing_group != shape_entry.smoothing_group
+                        {
+                            return None;
+                        }
+
+                        triangulated_quad(object, element, &object.element_set[next_shape_entry.element])
+                    });
+                    if let Some(quad) = quad {
+                        self.write_quad(writer, &quad, offsets)?;
+                        shape_indices.next();
+                        continue;
+                    }
+                }
+
+                self.write_element(writer, element, offsets)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write an object set whose vertex indices start after the vertices
+    /// already in the output, and return the offsets after it.
+    fn write_object_set<W: io::Write>(
+        &self,
+        writer: &mut W,
+        object_set: &ObjectSet,
+        offsets: IndexOffsets,
+    ) -> io::Result<IndexOffsets> {
+        if !object_set.material_libraries.is_empty() {
+            writer.write_all(b"mtllib")?;
+            for material_library in object_set.material_libraries.iter() {
+                write!(writer, " {}", material_library.name)?;
+            }
+            writer.write_all(b"\n")?;
+        }
+        for external_reference in object_set.external_references.iter() {
+            match *external_reference {
+                ExternalReference::Call {
+                    ref file_name,
+                    ref arguments,
+                } => {
+                    write!(writer, "call {}", file_name)?;
+                    for argument in arguments.iter() {
+                        write!(writer, " {}", argument)?;
+                    }
+                    writer.write_all(b"\n")?;
+                }
+                ExternalReference::Shell {
+                    ref command,
+                    ignore_errors,
+                } => {
+                    let prefix = if ignore_errors { "-" } else { "" };
+                    writeln!(writer, "csh {}{}", prefix, command)?;
+                }
+            }
+        }
+
+        let mut offsets = offsets;
+        for object in object_set.objects.iter() {
+            let object_offsets = (
+                offsets.vertices,
+                offsets.texture_vertices,
+                offsets.normal_vertices,
+            );
+            self.write_object(writer, object, object_offsets)?;
+            offsets.vertices += object.vertex_set.len();
+            if !self.options.omit_texture_vertices {
+                offsets.texture_vertices += object.texture_vertex_set.len();
+            }
+            if !self.options.omit_normals {
+                offsets.normal_vertices += object.normal_vertex_set.len();
+            }
+        }
+
+        Ok(offsets)
+    }
+}
+
+impl Compositor for TextObjectSetCompositor {
+    fn compose_to(&self, object_set: &ObjectSet, writer: &mut dyn io::Write) -> io::Result<()> {
+        let mut writer = io::BufWriter::with_capacity(self.options.buffer_capacity, writer);
+        self.write_object_set(&mut writer, object_set, IndexOffsets::default())?;
+
+        writer.flush()
+    }
+}
+
+/// The number of each kind of vertex in a Wavefront OBJ output stream, which
+/// the indices of the elements appended to the stream are offset by.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IndexOffsets {
+    /// The number of `v` statements.
+    pub vertices: usize,
+    /// The number of `vt` statements.
+    pub texture_vertices: usize,
+    /// The number of `vn` statements.
+    pub normal_vertices: usize,
+}
+
+impl IndexOffsets {
+    /// Count the vertex statements of existing Wavefront OBJ text.
+    ///
+    /// Only the keywords of the statements are inspected, so the text does
+    /// not have to parse, and a large file is read one line at a time. Lines
+    /// end with `"\n"`, `"\r\n"`, or a lone `"\r"`, as they do for the
+    /// parser.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::IndexOffsets;
+    /// #
+    /// let text = "v 0 0 0\nv 1 0 0\n  vn 0 0 1\nf 1//1 2//1 1//1\n";
+    /// let offsets = IndexOffsets::count(text.as_bytes()).unwrap();
+    /// assert_eq!(offsets, IndexOffsets { vertices: 2, texture_vertices: 0, normal_vertices: 1 });
+    /// ```
+    pub fn count<R: io::BufRead>(mut reader: R) -> io::Result<IndexOffsets> {
+        let mut offsets = IndexOffsets::default();
+        let mut line = Vec::new();
+        loop {
+            let buffer = match reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            if buffer.is_empty() {
+                break;
+            }
+
+            let length = lexer::find_line_end(buffer);
+            line.extend_from_slice(&buffer[..length]);
+            if length < buffer.len() {
+                offsets.count_line(&line);
+                line.clear();
+                reader.consume(length + 1);
+            } else {
+                reader.consume(length);
+            }
+        }
+        offsets.count_line(&line);
+
+        Ok(offsets)
+    }
+
+    /// Count the statement on a single line without its terminator.
+    fn count_line(&mut self, line: &[u8]) {
+        match lexer::first_token(line) {
+            Some(b"v") => self.vertices += 1,
+            Some(b"vt") => self.texture_vertices += 1,
+            Some(b"vn") => self.normal_vertices += 1,
+            _ => {}
+        }
+    }
+}
+
+/// A writer that appends object sets one at a time to a single Wavefront OBJ
+/// output stream.
+///
+/// Wavefront OBJ files index vertices globally, so the indices of each object
+/// set are offset by the number of vertices already written to the stream.
+/// This assembles a scene incrementally, without holding all of it in memory
+/// or merging the object sets first. Each object set is written as the
+/// [`TextObjectSetCompositor`] writes it, including its `mtllib` statements.
+/// An unnamed object is written without an `o` statement, so it merges into
+/// the object written before it when the output is parsed.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::ObjectSetWriter;
+/// #
+/// let mut writer = ObjectSetWriter::new(vec![]);
+/// writer.append(&obj::parse("o first\nv 0 0 0\nv 1 0 0\nl 1 2\n").unwrap()).unwrap();
+/// writer.append(&obj::parse("o second\nv 0 1 0\nv 0 0 1\nl 1 2\n").unwrap()).unwrap();
+/// let text = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+///
+/// assert!(text.contains("l 3 4\n"));
+/// assert_eq!(obj::parse(&text).unwrap().objects.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct ObjectSetWriter<W: io::Write> {
+    /// The output stream.
+    writer: io::BufWriter<W>,
+    /// The compositor that writes each object set.
+    compositor: TextObjectSetCompositor,
+    /// The number of vertices in the output stream so far.
+    offsets: IndexOffsets,
+}
+
+impl<W: io::Write> ObjectSetWriter<W> {
+    /// Construct a writer for a new, empty output stream.
+    pub fn new(writer: W) -> ObjectSetWriter<W> {
+        ObjectSetWriter::resume(writer, WriteOptions::default(), IndexOffsets::default())
+    }
+
+    /// Construct a writer for a new, empty output stream with non-default
+    /// write options.
+    pub fn with_options(writer: W, options: WriteOptions) -> ObjectSetWriter<W> {
+        ObjectSetWriter::resume(writer, options, IndexOffsets::default())
+    }
+
+    /// Construct a writer for an output stream that already holds the given
+    /// number of vertices, e.g. as counted by [`IndexOffsets::count`].
+    pub fn resume(writer: W, options: WriteOptions, offsets: IndexOffsets) -> ObjectSetWriter<W> {
+        ObjectSetWriter {
+            writer: io::BufWriter::with_capacity(options.buffer_capacity, writer),
+            compositor: TextObjectSetCompositor::with_options(options),
+            offsets: offsets,
+        }
+    }
+
+    /// Append an object set to the output stream.
+    pub fn append(&mut self, object_set: &ObjectSet) -> io::Result<()> {
+        self.offsets = self
+            .compositor
+            .write_object_set(&mut self.writer, object_set, self.offsets)?;
+
+        Ok(())
+    }
+
+    /// The number of vertices in the output stream so far.
+    pub fn offsets(&self) -> IndexOffsets {
+        self.offsets
+    }
+
+    /// Flush the buffered output to the output stream.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Flush the buffered output and return the output stream.
+    pub fn into_inner(self) -> io::Result<W> {
+        self.writer.into_inner().map_err(io::IntoInnerError::into_error)
+    }
+}
+
+impl ObjectSetWriter<fs::File> {
+    /// Open a Wavefront OBJ file for appending, creating it if it does not
+    /// exist.
+    ///
+    /// The vertex statements already in the file are counted first, so that
+    /// the indices of the appended object sets follow on from them. A line
+    /// terminator is appended first when the file does not end with one.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ObjectSetWriter;
+    /// #
+    /// let mut writer = ObjectSetWriter::append_to_file("scene.obj").unwrap();
+    /// writer.append(&obj::parse("o extra\nv 0 0 0\np 1\n").unwrap()).unwrap();
+    /// writer.flush().unwrap();
+    /// ```
+    pub fn append_to_file<P: AsRef<Path>>(path: P) -> Result<ObjectSetWriter<fs::File>, ObjError> {
+        let path = path.as_ref();
+        let io_error = |error| ObjError::Io {
+            path: Some(path.to_path_buf()),
+            error: error,
+        };
+        let offsets = match fs::File::open(path) {
+            Ok(file) => IndexOffsets::count(io::BufReader::new(file)).map_err(io_error)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => IndexOffsets::default(),
+            Err(error) => return Err(io_error(error)),
+        };
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        // Terminate the last line of the file, so that the first appended
+        // statement does not run onto it.
+        let mut last_byte = [0_u8];
+        if file.seek(io::SeekFrom::End(0)).map_err(io_error)? > 0 {
+            file.seek(io::SeekFrom::End(-1)).map_err(io_error)?;
+            file.read_exact(&mut last_byte).map_err(io_error)?;
+            if !matches!(last_byte[0], b'\n' | b'\r') {
+                file.write_all(b"\n").map_err(io_error)?;
+            }
+        }
+
+        Ok(ObjectSetWriter::resume(file, WriteOptions::default(), offsets))
+    }
+}
+
+/// Write an object set to a Wavefront OBJ file using a compositor.
+///
+/// The output is streamed to the file as it is composed.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::TextObjectSetCompositor;
+/// #
+/// let object_set = obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+/// obj::write_file("triangle.obj", &object_set, &TextObjectSetCompositor::new()).unwrap();
+/// ```
+pub fn write_file<P: AsRef<Path>, C: Compositor + ?Sized>(
+    path: P,
+    object_set: &ObjectSet,
+    compositor: &C,
+) -> Result<(), ObjError> {
+    let path = path.as_ref();
+    let io_error = |error| ObjError::Io {
+        path: Some(path.to_path_buf()),
+        error: error,
+    };
+    let mut file = fs::File::create(path).map_err(io_error)?;
+
+    compositor.compose_to(object_set, &mut file).map_err(io_error)
+}
+
+/// Write an object set as a gzip compressed stream using a compositor, and
+/// return the writer once the stream is finished.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::TextObjectSetCompositor;
+/// #
+/// let object_set = obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+/// let compressed = obj::write_gzip(vec![], &object_set, &TextObjectSetCompositor::new()).unwrap();
+///
+/// assert_eq!(obj::parse_gzip(&compressed[..]).unwrap(), object_set);
+/// ```
+#[cfg(feature = "flate2")]
+pub fn write_gzip<W: io::Write, C: Compositor + ?Sized>(
+    writer: W,
+    object_set: &ObjectSet,
+    compositor: &C,
+) -> io::Result<W> {
+    let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+    compositor.compose_to(object_set, &mut encoder)?;
+
+    encoder.finish()
+}
+
+/// Write an object set to a gzip compressed Wavefront OBJ file, e.g. a
+/// `*.obj.gz` file, using a compositor.
+///
+/// The file can be read back with [`parse_file`], which decompresses it
+/// transparently.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::obj::TextObjectSetCompositor;
+/// #
+/// let object_set = obj::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+/// obj::write_gzip_file("triangle.obj.gz", &object_set, &TextObjectSetCompositor::new()).unwrap();
+/// ```
+#[cfg(feature = "flate2")]
+pub fn write_gzip_file<P: AsRef<Path>, C: Compositor + ?Sized>(
+    path: P,
+    object_set: &ObjectSet,
+    compositor: &C,
+) -> Result<(), ObjError> {
+    let path = path.as_ref();
+    let io_error = |error| ObjError::Io {
+        path: Some(path.to_path_buf()),
+        error: error,
+    };
+    let file = fs::File::create(path).map_err(io_error)?;
+    let mut file = write_gzip(file, object_set, compositor).map_err(io_error)?;
+
+    file.flush().map_err(io_error)
+}
+
+/// A marker indicating the type of error generated during parsing of a
+/// Wavefront OBJ file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The parser reached the end of the input early.
+    EndOfFile,
+    /// The parser expected a tag statement that was not present.
+    ExpectedTagStatement,
+    /// The parser expected a floating point number but found something else.
+    ExpectedFloat,
+    /// The parser expected an integer but found something else.
+    ExpectedInteger,
+    /// The parser expected a vertex/texture/normal index but found something else.
+    ExpectedVTNIndex,
+    /// the parser encountered an object element index that is out of range.
+    VTNIndexOutOfRange,
+    /// The parser encountered a face element that did not have enough vertices.
+    EveryFaceElementMustHaveAtLeastThreeVertices,
+    /// An element had VTN indices with different forms.
+    EveryVTNIndexMustHaveTheSameFormForAGivenElement,
+    /// A statement in a wavefront obj file that is either unsupported or does not exist.
+    InvalidObjectStatement,
+    /// The parser encountered an invalid or unsupported element type.
+    ElementMustBeAPointLineOrFace,
+    /// The smoothing group name is something other than an integer or the default
+    /// value `off`.
+    SmoothingGroupNameMustBeOffOrInteger,
+    /// The smoothing group declaration is missing a name.
+    SmoothingGroupDeclarationHasNoName,
+    /// The `usemtl` statement has no corresponding material name.
+    MaterialStatementHasNoName,
+    /// The `call` statement has no file name.
+    CallStatementHasNoFileName,
+    /// The `csh` statement has no command.
+    ShellStatementHasNoCommand,
+    /// A statement parsed in isolation was followed by more input.
+    ExpectedEndOfStatement,
+    /// An element statement parsed in isolation describes more than one element.
+    ExpectedSingleElement,
+    /// The input is binary data rather than text, e.g. it contains a NUL byte
+    /// or invalid UTF-8.
+    NotATextFile,
+    /// A line of the input is longer than
+    /// [`ParserOptions::max_line_length`] allows.
+    LineTooLong,
+    /// A number is NaN or an infinity, and the parser rejects those under
+    /// [`NonFinitePolicy::Reject`].
+    NonFiniteValue,
+    /// An object has the same name as an earlier object, and the parser
+    /// rejects those under [`DuplicateObjectNames::Reject`].
+    DuplicateObjectName,
+}
+
+impl ErrorKind {
+    /// The stable code of the error kind, e.g. `OBJ0006`.
+    ///
+    /// Codes never change meaning between versions of the crate, and new
+    /// error kinds get new codes, so applications can match on codes or use
+    /// them as keys for localized messages.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::ErrorKind;
+    /// #
+    /// assert_eq!(ErrorKind::VTNIndexOutOfRange.code(), "OBJ0006");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ErrorKind::EndOfFile => "OBJ0001",
+            ErrorKind::ExpectedTagStatement => "OBJ0002",
+            ErrorKind::ExpectedFloat => "OBJ0003",
+            ErrorKind::ExpectedInteger => "OBJ0004",
+            ErrorKind::ExpectedVTNIndex => "OBJ0005",
+            ErrorKind::VTNIndexOutOfRange => "OBJ0006",
+            ErrorKind::EveryFaceElementMustHaveAtLeastThreeVertices => "OBJ0007",
+            ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement => "OBJ0008",
+            ErrorKind::InvalidObjectStatement => "OBJ0009",
+            ErrorKind::ElementMustBeAPointLineOrFace => "OBJ0010",
+            ErrorKind::SmoothingGroupNameMustBeOffOrInteger => "OBJ0011",
+            ErrorKind::SmoothingGroupDeclarationHasNoName => "OBJ0012",
+            ErrorKind::MaterialStatementHasNoName => "OBJ0013",
+            ErrorKind::CallStatementHasNoFileName => "OBJ0014",
+            ErrorKind::ShellStatementHasNoCommand => "OBJ0015",
+            ErrorKind::ExpectedEndOfStatement => "OBJ0016",
+            ErrorKind::ExpectedSingleElement => "OBJ0017",
+            ErrorKind::NotATextFile => "OBJ0018",
+            ErrorKind::LineTooLong => "OBJ0019",
+            ErrorKind::NonFiniteValue => "OBJ0020",
+            ErrorKind::DuplicateObjectName => "OBJ0021",
+        }
+    }
+}
+
+/// An error that is returned from parsing an invalid `*.obj` file, or
+/// another kind of error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line number where the error occurred.
+    pub line_number: usize,
+    /// The kind of error that occurred.
+    pub kind: ErrorKind,
+    /// A message describing why the parse error was generated.
+    pub message: String,
+    /// The byte range in the input of the token the parser was looking at
+    /// when it detected the error, if known.
+    pub span: Option<ops::Range<usize>>,
+}
+
+impl ParseError {
+    /// Construct a new parse error.
+    fn new(line_number: usize, kind: ErrorKind, message: String) -> ParseError {
+        ParseError {
+            line_number: line_number,
+            kind: kind,
+            message: message,
+            span: None,
+        }
+    }
+
+    /// Construct a parse error for an input that is binary data rather than
+    /// text.
+    pub(crate) fn not_a_text_file(not_text: input::NotText) -> ParseError {
+        let mut error = ParseError::new(not_text.line_number, ErrorKind::NotATextFile, not_text.message());
+        error.span = Some(not_text.offset..(not_text.offset + 1));
+
+        error
+    }
+
+    /// The line number where the error occurred.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// The kind of error that occurred.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// A message describing why the parse error was generated.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The stable code of the kind of error that occurred.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// The byte range in the input of the token the parser was looking at
+    /// when it detected the error, if known.
+    pub fn span(&self) -> Option<ops::Range<usize>> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "Parse error {} at line {}: {}",
+            self.code(),
+            self.line_number,
+            self.message
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A parse error is a diagnostic whose code is the stable code of the error
+/// and whose label underlines the token where the parser detected the error.
+/// Attach the parsed text to render the error as an annotated snippet.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// #
+/// let text = "v 0 0 0\nv 0 x 0\n";
+/// let error = obj::parse(text).unwrap_err();
+/// let report = miette::Report::new(error).with_source_code(text);
+///
+/// let label = report.labels().unwrap().next().unwrap();
+/// assert_eq!(label.offset()..(label.offset() + label.len()), 12..13);
+/// ```
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.code()))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let span = self.span.clone()?;
+        let label = miette::LabeledSpan::new_with_span(Some(self.message.clone()), span);
+
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+/// An error that is returned from loading a Wavefront OBJ file from disk or
+/// from a reader.
+#[derive(Debug)]
+pub enum ObjError {
+    /// The input could not be read, or the output could not be written.
+    Io {
+        /// The path of the file that could not be read, if the input came from a file.
+        path: Option<PathBuf>,
+        /// The underlying input/output error.
+        error: io::Error,
+    },
+    /// The input was read, but its contents are not a valid Wavefront OBJ file.
+    Parse {
+        /// The path of the file that failed to parse, if the input came from a file.
+        path: Option<PathBuf>,
+        /// The underlying parse error.
+        error: ParseError,
+    },
+}
+
+impl ObjError {
+    /// The path of the file that generated the error, if the input came from a file.
+    pub fn path(&self) -> Option<&Path> {
+        match *self {
+            ObjError::Io { ref path, .. } => path.as_deref(),
+            ObjError::Parse { ref path, .. } => path.as_deref(),
+        }
+    }
+
+    /// Construct an error for an input that could not be decoded into text.
+    fn decode(path: Option<PathBuf>, error: input::DecodeError) -> ObjError {
+        match error {
+            input::DecodeError::Io(error) => ObjError::Io {
+                path: path,
+                error: error,
+            },
+            input::DecodeError::NotText(not_text) => ObjError::Parse {
+                path: path,
+                error: ParseError::not_a_text_file(not_text),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match (self, self.path()) {
+            (ObjError::Io { error, .. }, Some(path)) => {
+                write!(formatter, "Could not read `{}`: {}", path.display(), error)
+            }
+            (ObjError::Io { error, .. }, None) => {
+                write!(formatter, "Could not read input: {}", error)
+            }
+            (ObjError::Parse { error, .. }, Some(path)) => {
+                write!(formatter, "In `{}`: {}", path.display(), error)
+            }
+            (ObjError::Parse { error, .. }, None) => {
+                write!(formatter, "{}", error)
+            }
+        }
+    }
+}
+
+impl error::Error for ObjError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ObjError::Io { ref error, .. } => Some(error),
+            ObjError::Parse { ref error, .. } => Some(error),
+        }
+    }
+}
+
+
+/// A difference between two object sets found by [`diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Difference {
+    /// The index of the object the difference was found in, or `None` if the
+    /// difference is in the object set itself.
+    pub object: Option<usize>,
+    /// A description of the difference.
+    pub message: String,
+}
+
+impl Difference {
+    fn new(object: Option<usize>, message: String) -> Difference {
+        Difference {
+            object: object,
+            message: message,
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.object {
+            Some(object) => write!(formatter, "In object {}: {}", object, self.message),
+            None => write!(formatter, "{}", self.message),
+        }
+    }
+}
+
+/// Determine whether two floating point numbers agree up to a tolerance.
+#[inline]
+fn approx_eq(x: f64, y: f64, tolerance: f64) -> bool {
+    x == y || (x - y).abs() <= tolerance || (x.is_nan() && y.is_nan())
+}
+
+/// Compare two sets element by element, recording the first mismatch.
+fn diff_sets<T, F>(
+    differences: &mut Vec<Difference>,
+    object: usize,
+    name: &str,
+    expected: &[T],
+    result: &[T],
+    eq: F,
+) where
+    T: fmt::Display,
+    F: Fn(&T, &T) -> bool,
+{
+    if expected.len() != result.len() {
+        differences.push(Difference::new(
+            Some(object),
+            format!(
+                "Expected {} {} entries but got {}.",
+                expected.len(),
+                name,
+                result.len()
+            ),
+        ));
+        return;
+    }
+
+    let mismatch = expected
+        .iter()
+        .zip(result.iter())
+        .position(|(expected_value, result_value)| !eq(expected_value, result_value));
+    if let Some(index) = mismatch {
+        differences.push(Difference::new(
+            Some(object),
+            format!(
+                "The {} entry {} differs: expected `{}` but got `{}`.",
+                name, index, expected[index], result[index]
+            ),
+        ));
+    }
+}
+
+/// The groups, smoothing group, and material of each element in an object,
+/// indexed by element.
+fn element_attributes(object: &Object) -> Vec<(Vec<&str>, SmoothingGroup, Option<&str>)> {
+    let mut attributes = vec![(vec![], SmoothingGroup(0), None); object.element_set.len()];
+    for geometry in object.geometry_set.iter() {
+        for &shape_index in geometry.shapes.iter() {
+            let shape_entry = &object.shape_set[shape_index];
+            let groups = shape_entry
+                .groups
+                .iter()
+                .map(|&group_index| object.group_set[group_index].0.as_str())
+                .collect();
+            let smoothing_group = object.smoothing_group_set[shape_entry.smoothing_group];
+            attributes[shape_entry.element.0] = (groups, smoothing_group, geometry.material_name.as_deref());
+        }
+    }
+
+    attributes
+}
+
+/// Compare two object sets, tolerating differences in floating point values
+/// up to `tolerance` and differences in how the elements are organized.
+///
+/// Two objects compare equal when they have the same name, vertex data, and
+/// elements, and each element belongs to the same groups, smoothing group, and
+/// material in both objects. The material libraries are compared by name, and
+/// the external references as they are. Everything that depends on the source
+/// text rather than on the geometry is ignored:
+///
+/// * the `source`, `element_line_set`, `element_text_set`,
+///   `original_element_set`, and `numeric_text` fields of each object,
+/// * the line numbers and resolved paths of the material libraries, and
+/// * the `unknown_statements` and `warnings` of the object sets.
+///
+/// The function returns the list of differences found, which is empty when
+/// the object sets are equivalent.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// #
+/// let expected = obj::parse("v 0.1 0.2 0.3\np 1\n").unwrap();
+/// let result = obj::parse("v 0.1000001 0.2 0.3\np 1\n").unwrap();
+///
+/// assert!(obj::diff(&expected, &result, 1e-6).is_empty());
+/// assert_eq!(obj::diff(&expected, &result, 0.0).len(), 1);
+/// ```
+pub fn diff(expected: &ObjectSet, result: &ObjectSet, tolerance: f64) -> Vec<Difference> {
+    let mut differences = vec![];
+    // Only the names of the material libraries are compared, since their line
+    // numbers depend on the layout of the source text.
+    let material_library_names = |object_set: &ObjectSet| -> Vec<String> {
+        object_set
+            .material_libraries
+            .iter()
+            .map(|library| library.name.clone())
+            .collect()
+    };
+    let expected_material_libraries = material_library_names(expected);
+    let result_material_libraries = material_library_names(result);
+    if expected_material_libraries != result_material_libraries {
+        differences.push(Difference::new(
+            None,
+            format!(
+                "Expected the material libraries {:?} but got {:?}.",
+                expected_material_libraries, result_material_libraries
+            ),
+        ));
+    }
+    if expected.external_references != result.external_references {
+        differences.push(Difference::new(
+            None,
+            format!(
+                "Expected the external references {:?} but got {:?}.",
+                expected.external_references, result.external_references
+            ),
+        ));
+    }
+    if expected.objects.len() != result.objects.len() {
+        differences.push(Difference::new(
+            None,
+            format!(
+                "Expected {} objects but got {}.",
+                expected.objects.len(),
+                result.objects.len()
+            ),
+        ));
+    }
+
+    for (i, (expected_object, result_object)) in
+        expected.objects.iter().zip(result.objects.iter()).enumerate()
+    {
+        if expected_object.name != result_object.name {
+            differences.push(Difference::new(
+                Some(i),
+                format!(
+                    "Expected the name `{}` but got `{}`.",
+                    expected_object.name, result_object.name
+                ),
+            ));
+        }
+        diff_sets(
+            &mut differences,
+            i,
+            "vertex",
+            &expected_object.vertex_set,
+            &result_object.vertex_set,
+            |v1, v2| {
+                approx_eq(v1.x, v2.x, tolerance)
+                    && approx_eq(v1.y, v2.y, tolerance)
+                    && approx_eq(v1.z, v2.z, tolerance)
+                    && approx_eq(v1.w, v2.w, tolerance)
+            },
+        );
+        diff_sets(
+            &mut differences,
+            i,
+            "texture vertex",
+            &expected_object.texture_vertex_set,
+            &result_object.texture_vertex_set,
+            |vt1, vt2| {
+                approx_eq(vt1.u, vt2.u, tolerance)
+                    && approx_eq(vt1.v, vt2.v, tolerance)
+                    && approx_eq(vt1.w, vt2.w, tolerance)
+            },
+        );
+        diff_sets(
+            &mut differences,
+            i,
+            "normal vertex",
+            &expected_object.normal_vertex_set,
+            &result_object.normal_vertex_set,
+            |vn1, vn2| {
+                approx_eq(vn1.x, vn2.x, tolerance)
+                    && approx_eq(vn1.y, vn2.y, tolerance)
+                    && approx_eq(vn1.z, vn2.z, tolerance)
+            },
+        );
+        diff_sets(
+            &mut differences,
+            i,
+            "element",
+            &expected_object.element_set,
+            &result_object.element_set,
+            |e1, e2| e1 == e2,
+        );
+
+        let expected_attributes = element_attributes(expected_object);
+        let result_attributes = element_attributes(result_object);
+        let mismatch = expected_attributes
+            .iter()
+            .zip(result_attributes.iter())
+            .position(|(expected_value, result_value)| expected_value != result_value);
+        if let Some(index) = mismatch {
+            differences.push(Difference::new(
+                Some(i),
+                format!(
+                    "The element {} has the groups, smoothing group, and material {:?} but expected {:?}.",
+                    index, result_attributes[index], expected_attributes[index]
+                ),
+            ));
+        }
+    }
+
+    differences
+}
+
+/// The reason a round trip check failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RoundTripReport {
+    /// The input failed to parse.
+    InvalidInput(ParseError),
+    /// The text written for the parsed input failed to parse.
+    InvalidOutput {
+        /// The text written for the parsed input.
+        output: String,
+        /// The error from parsing the written text.
+        error: ParseError,
+    },
+    /// The text written for the parsed input parsed into a different object set.
+    Mismatch {
+        /// The text written for the parsed input.
+        output: String,
+        /// The differences between the two object sets.
+        differences: Vec<Difference>,
+    },
+}
+
+impl fmt::Display for RoundTripReport {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            RoundTripReport::InvalidInput(ref error) => {
+                write!(formatter, "The input failed to parse: {}", error)
+            }
+            RoundTripReport::InvalidOutput { ref error, .. } => {
+                write!(formatter, "The written output failed to parse: {}", error)
+            }
+            RoundTripReport::Mismatch { ref differences, .. } => {
+                write!(formatter, "The written output differs from the input:")?;
+                for difference in differences.iter() {
+                    write!(formatter, "\n    {}", difference)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for RoundTripReport {}
+
+/// The tolerance used by [`roundtrip_check`] when comparing floating point values.
+const ROUNDTRIP_TOLERANCE: f64 = 1e-9;
+
+/// Check that a Wavefront OBJ file survives a round trip through the parser
+/// and the [`TextObjectSetCompositor`].
+///
+/// The input is parsed, written back out as text, and parsed again. The two
+/// object sets are then compared with [`diff`]. This gives authors of OBJ
+/// exporters a one call conformance check.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::obj;
+/// #
+/// let obj_file = r"
+///     mtllib material_library.mtl
+///     o quad
+///     v 0.0 0.0 0.0
+///     v 1.0 0.0 0.0
+///     v 1.0 1.0 0.0
+///     v 0.0 1.0 0.0
+///     g front
+///     s 1
+///     usemtl material
+///     f 1 2 3 4
+/// ";
+///
+/// assert!(obj::roundtrip_check(obj_file).is_ok());
+/// assert!(obj::roundtrip_check("v 1.0 2.0\n").is_err());
+/// ```
+pub fn roundtrip_check(input: &str) -> Result<(), RoundTripReport> {
+    let expected = parse(input).map_err(RoundTripReport::InvalidInput)?;
+    let output = TextObjectSetCompositor::new().compose(&expected);
+    let result = match parse(&output) {
+        Ok(result) => result,
+        Err(error) => {
+            return Err(RoundTripReport::InvalidOutput {
+                output: output,
+                error: error,
+            });
+        }
+    };
+
+    let differences = diff(&expected, &result, ROUNDTRIP_TOLERANCE);
+    if differences.is_empty() {
+        Ok(())
+    } else {
+        Err(RoundTripReport::Mismatch {
+            output: output,
+            differences: differences,
+        })
+    }
+}
+
+/// The rule for handling an object whose name is already used by an earlier
+/// object in the same file, e.g. a file with two `o Cube` statements.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuplicateObjectNames {
+    /// Keep each object as it is, so several objects share the name.
+    #[default]
+    Keep,
+    /// Give each later object the first free name with a numeric suffix,
+    /// e.g. `Cube.001`, `Cube.002`, and so on, as Blender does.
+    Rename,
+    /// Append the geometry of each later object to the first object with the
+    /// same name.
+    Merge,
+    /// Fail with a parse error.
+    Reject,
+}
+
+/// A handler for statements that the OBJ parser does not recognize.
+///
+/// Applications implement this trait to support vendor extensions without
+/// forking the parser. Closures with the same signature as
+/// [`StatementHandler::handle`] implement the trait.
+pub trait StatementHandler {
+    /// Handle an unrecognized statement.
+    ///
+    /// The handler receives the keyword of the statement and the remaining
+    /// tokens on the line. Returning an error message rejects the statement,
+    /// and the parser fails with that message.
+    fn handle(&mut self, keyword: &str, arguments: &[&str]) -> Result<(), String>;
+}
+
+impl<F> StatementHandler for F
+where
+    F: FnMut(&str, &[&str]) -> Result<(), String>,
+{
+    fn handle(&mut self, keyword: &str, arguments: &[&str]) -> Result<(), String> {
+        self(keyword, arguments)
+    }
+}
+
+/// A filter that selects the objects of a file to parse by name, set with
+/// [`ParserOptions::object_filter`].
+///
+/// Closures with the same signature as [`ObjectFilter::accept`] implement the
+/// trait.
+pub trait ObjectFilter {
+    /// Whether to parse the object with the given name.
+    fn accept(&mut self, object_name: &str) -> bool;
+}
+
+impl<F> ObjectFilter for F
+where
+    F: FnMut(&str) -> bool,
+{
+    fn accept(&mut self, object_name: &str) -> bool {
+        self(object_name)
+    }
+}
+
+/// A filter that selects the elements of a file to parse by group name, set
+/// with [`ParserOptions::group_filter`].
+///
+/// Closures with the same signature as [`GroupFilter::accept`] implement the
+/// trait.
+pub trait GroupFilter {
+    /// Whether to parse the elements of the group with the given name.
+    fn accept(&mut self, group_name: &str) -> bool;
+}
+
+impl<F> GroupFilter for F
+where
+    F: FnMut(&str) -> bool,
+{
+    fn accept(&mut self, group_name: &str) -> bool {
+        self(group_name)
+    }
+}
+
+/// The object, groups, and smoothing group of a face passed to a
+/// [`FaceVisitor`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GroupContext<'a> {
+    /// The name of the object the face belongs to.
+    pub object_name: &'a str,
+    /// The groups the face belongs to.
+    pub groups: &'a [Group],
+    /// The smoothing group the face belongs to.
+    pub smoothing_group: SmoothingGroup,
+}
+
+/// The material of a face passed to a [`FaceVisitor`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MaterialContext<'a> {
+    /// The name of the material of the face, if it has one.
+    pub material_name: Option<&'a str>,
+}
+
+/// A consumer of the faces of a Wavefront OBJ file, called by
+/// [`Parser::parse_with_visitor`] for each face as it is parsed.
+///
+/// Closures with the same signature as [`FaceVisitor::face`] implement the
+/// trait.
+pub trait FaceVisitor {
+    /// Visit one face.
+    ///
+    /// The VTN indices are the corners of the face as written, before
+    /// triangulation, numbered from zero within the object of the face as in
+    /// the element set of an [`Object`].
+    fn face(&mut self, vtn_indices: &[VTNIndex], groups: &GroupContext, material: &MaterialContext);
+}
+
+impl<F> FaceVisitor for F
+where
+    F: FnMut(&[VTNIndex], &GroupContext, &MaterialContext),
+{
+    fn face(&mut self, vtn_indices: &[VTNIndex], groups: &GroupContext, material: &MaterialContext) {
+        self(vtn_indices, groups, material)
+    }
+}
+
+/// Options that control the behavior of a Wavefront OBJ file parser.
+pub struct ParserOptions {
+    /// Record the source line each element was parsed from in
+    /// [`Object::element_line_set`].
+    pub record_element_lines: bool,
+    /// Record the text of the statement each element was parsed from in
+    /// [`Object::element_text_set`]. This is useful for tracing surprising
+    /// triangulation or index rebasing results back to the source.
+    /// Each element stores its own copy of the text, so a face statement with
+    /// many vertices is copied once per triangle.
+    pub record_element_text: bool,
+    /// Record each element with its VTN indices numbered as in the source file
+    /// in [`Object::original_element_set`]. The element set itself is always
+    /// numbered from zero at the start of each object.
+    pub record_original_indices: bool,
+    /// Record the numbers of each vertex statement exactly as written in
+    /// [`Object::numeric_text`], so that writing the object with
+    /// [`WriteOptions::preserve_numeric_text`] reproduces them.
+    pub record_numeric_text: bool,
+    /// The value of the w component of a vertex that does not state one.
+    /// The default is 1.
+    pub default_w: f64,
+    /// Divide the x, y, and z components of each vertex by its w component,
+    /// and set the w component to 1. Vertices whose w component is 0 are
+    /// points at infinity and are left unchanged.
+    pub normalize_w: bool,
+    /// The handler for unrecognized statements. Without a handler, an
+    /// unrecognized statement is an error unless the parser is lenient.
+    pub statement_handler: Option<Box<dyn StatementHandler>>,
+    /// Skip statements the parser does not recognize instead of failing, and
+    /// record them in [`ObjectSet::unknown_statements`]. A statement handler
+    /// takes precedence over lenient mode.
+    pub lenient: bool,
+    /// Accept a comma as the decimal separator of a number, e.g. `1,5`, as
+    /// written by tools that format numbers for a European locale.
+    ///
+    /// Numbers otherwise follow the grammar described in the
+    /// [`number`] module.
+    pub decimal_comma: bool,
+    /// The maximum length of a line of the input in bytes, not counting its
+    /// line terminator. A longer line, e.g. a face statement with millions of
+    /// vertices, is an error. The default is no limit.
+    ///
+    /// The parser takes linear time and memory in the size of the input
+    /// regardless, but capping the line length bounds the work spent on a
+    /// single statement of an untrusted file.
+    pub max_line_length: Option<usize>,
+    /// The treatment of vertex, texture vertex, and normal components that are
+    /// NaN or an infinity. The default is to accept them.
+    pub non_finite: NonFinitePolicy,
+    /// The handling of objects that share a name with an earlier object. The
+    /// default is to keep them as they are.
+    pub duplicate_object_names: DuplicateObjectNames,
+    /// Collapse each texture vertex and normal vector that repeats the one
+    /// declared immediately before it in the same object, and renumber the
+    /// elements to match. Exporters that write one `vn` statement per face
+    /// corner repeat the same normal for every corner of a flat face, and
+    /// this keeps one copy of it. Components are compared bit for bit, so
+    /// `0` and `-0` are kept apart and repeated NaNs are collapsed. The default
+    /// is to keep every statement.
+    pub collapse_repeated_attributes: bool,
+    /// Store each vertex, texture vertex, and normal vector of an object only
+    /// the first time its exact value appears in the object, and renumber the
+    /// elements to refer to that copy. Values are compared bit for bit as they
+    /// are parsed, so `0` and `-0` stay distinct. This costs a hash lookup per
+    /// statement, and can shrink exports that write every attribute once per
+    /// face corner severalfold. The default is to keep every statement.
+    pub deduplicate_vertices: bool,
+    /// The filter that decides which objects to parse, given the name of
+    /// each object. An object the filter rejects is skipped without checking
+    /// its statements, except that the material libraries it names are still
+    /// recorded, and it is left out of the result. The objects after it keep
+    /// their vertex numbering. An object without an `o` statement at the
+    /// start of a file has the empty name. Without a filter, every object is
+    /// parsed.
+    pub object_filter: Option<Box<dyn ObjectFilter>>,
+    /// The filter that decides which elements to parse, given the names of
+    /// the groups of each element. A point, line, or face statement is kept
+    /// if the filter accepts any of its groups, and skipped without checking
+    /// it otherwise. An element outside of any `g` statement is in the group
+    /// `default`. The groups themselves, and the vertex data, are kept either
+    /// way. Without a filter, every element is parsed.
+    pub group_filter: Option<Box<dyn GroupFilter>>,
+}
+
+impl ParserOptions {
+    /// Set the handler for unrecognized statements.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ParserOptions;
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// #
+    /// let obj_file = "v 0 0 0\nvendor_tag 42 blue\np 1\n";
+    /// let statements = Rc::new(RefCell::new(vec![]));
+    /// let captured = statements.clone();
+    /// let handler = move |keyword: &str, arguments: &[&str]| {
+    ///     captured.borrow_mut().push(format!("{} {}", keyword, arguments.join(" ")));
+    ///     Ok(())
+    /// };
+    /// let options = ParserOptions::default().statement_handler(handler);
+    /// let result = obj::parse_with(obj_file, options);
+    ///
+    /// assert!(result.is_ok());
+    /// assert_eq!(*statements.borrow(), vec![String::from("vendor_tag 42 blue")]);
+    /// ```
+    pub fn statement_handler<H: StatementHandler + 'static>(mut self, handler: H) -> ParserOptions {
+        self.statement_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the filter that decides which objects to parse by name.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ParserOptions;
+    /// #
+    /// let obj_file = "o low\nv 0 0 0\np 1\no high\nv 1 1 1\nv 2 2 2\np 2 3\n";
+    /// let options = ParserOptions::default().object_filter(|name: &str| name == "high");
+    /// let result = obj::parse_with(obj_file, options).unwrap();
+    ///
+    /// assert_eq!(result.objects.len(), 1);
+    /// assert_eq!(result.objects[0].name, "high");
+    /// assert_eq!(result.objects[0].vertex_set.len(), 2);
+    /// ```
+    pub fn object_filter<F: ObjectFilter + 'static>(mut self, filter: F) -> ParserOptions {
+        self.object_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Set the filter that decides which elements to parse by group name.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::ParserOptions;
+    /// #
+    /// let obj_file = "\
+    ///     v 0 0 0\n\
+    ///     v 1 0 0\n\
+    ///     v 0 1 0\n\
+    ///     g body\n\
+    ///     f 1 2 3\n\
+    ///     g collision_hull\n\
+    ///     f 3 2 1\n\
+    /// ";
+    /// let options = ParserOptions::default()
+    ///     .group_filter(|name: &str| name.starts_with("collision_"));
+    /// let result = obj::parse_with(obj_file, options).unwrap();
+    ///
+    /// assert_eq!(result.objects[0].element_set.len(), 1);
+    /// ```
+    pub fn group_filter<F: GroupFilter + 'static>(mut self, filter: F) -> ParserOptions {
+        self.group_filter = Some(Box::new(filter));
+        self
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> ParserOptions {
+        ParserOptions {
+            record_element_lines: false,
+            record_element_text: false,
+            record_original_indices: false,
+            record_numeric_text: false,
+            default_w: 1_f64,
+            normalize_w: false,
+            statement_handler: None,
+            lenient: false,
+            decimal_comma: false,
+            max_line_length: None,
+            non_finite: NonFinitePolicy::Accept,
+            duplicate_object_names: DuplicateObjectNames::Keep,
+            collapse_repeated_attributes: false,
+            deduplicate_vertices: false,
+            object_filter: None,
+            group_filter: None,
+        }
+    }
+}
+
+impl fmt::Debug for ParserOptions {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("ParserOptions")
+            .field("record_element_lines", &self.record_element_lines)
+            .field("record_element_text", &self.record_element_text)
+            .field("record_original_indices", &self.record_original_indices)
+            .field("record_numeric_text", &self.record_numeric_text)
+            .field("default_w", &self.default_w)
+            .field("normalize_w", &self.normalize_w)
+            .field(
+                "statement_handler",
+                &self.statement_handler.as_ref().map(|_| "StatementHandler"),
+            )
+            .field("lenient", &self.lenient)
+            .field("decimal_comma", &self.decimal_comma)
+            .field("max_line_length", &self.max_line_length)
+            .field("non_finite", &self.non_finite)
+            .field("duplicate_object_names", &self.duplicate_object_names)
+            .field("collapse_repeated_attributes", &self.collapse_repeated_attributes)
+            .field("deduplicate_vertices", &self.deduplicate_vertices)
+            .field(
+                "object_filter",
+                &self.object_filter.as_ref().map(|_| "ObjectFilter"),
+            )
+            .field("group_filter", &self.group_filter.as_ref().map(|_| "GroupFilter"))
+            .finish()
+    }
+}
+
+/// The index range used to resolve VTN indices in a statement that is parsed
+/// in isolation, where no vertices have been declared.
+const ISOLATED_INDEX_RANGE: (usize, usize) = (0, isize::MAX as usize);
+
+/// Parse a single statement in isolation using one of the parser rules.
+///
+/// The statement may be surrounded by newlines, but must not be followed by
+/// any other input.
+fn parse_statement<'a, T, F>(input: &'a str, rule: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&mut Parser<'a>) -> Result<T, ParseError>,
+{
+    let mut parser = Parser::new(input);
+    parser.skip_zero_or_more_newlines();
+    let value = rule(&mut parser)?;
+    parser.skip_zero_or_more_newlines();
+    match parser.peek() {
+        None => Ok(value),
+        Some(st) => parser.error(
+            ErrorKind::ExpectedEndOfStatement,
+            format!("Expected the end of the statement but got `{}` instead.", st),
+        ),
+    }
+}
+
+/// The number of each kind of statement in a Wavefront OBJ file, counted by
+/// [`Parser::count`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StatementCounts {
+    /// The number of statements of any kind.
+    pub statements: usize,
+    /// The number of `o` statements.
+    pub objects: usize,
+    /// The number of `v` statements.
+    pub vertices: usize,
+    /// The number of `vt` statements.
+    pub texture_vertices: usize,
+    /// The number of `vn` statements.
+    pub normal_vertices: usize,
+    /// The number of `p` statements.
+    pub points: usize,
+    /// The number of `l` statements.
+    pub lines: usize,
+    /// The number of `f` statements.
+    pub faces: usize,
+    /// The number of `g` statements.
+    pub groups: usize,
+    /// The number of `s` statements.
+    pub smoothing_groups: usize,
+    /// The number of `usemtl` statements.
+    pub material_uses: usize,
+    /// The number of `mtllib` statements.
+    pub material_libraries: usize,
+}
+
+/// A Wavefront OBJ file parser extracts three-dimensional geometric data
+/// from a `*.obj` file.
+pub struct Parser<'a> {
+    /// The input text.
+    input: &'a str,
+    /// The options controlling the parser.
+    options: ParserOptions,
+    /// The current line position of the parser in the input stream.
+    line_number: usize,
+    /// the underlying lexer that generates tokens.
+    lexer: PeekableLexer<'a>,
+    /// The token most recently taken from the token stream, which parse
+    /// errors point at.
+    last_token: Option<&'a str>,
+    /// The external references encountered so far.
+    external_references: Vec<ExternalReference>,
+    /// The material libraries named by `mtllib` statements inside objects so
+    /// far.
+    material_libraries: Vec<MtlLibRef>,
+    /// The statements skipped in lenient mode so far.
+    unknown_statements: Vec<UnknownStatement>,
+    /// The warnings recorded so far.
+    warnings: Vec<ParseWarning>,
+    /// A scratch buffer for the VTN indices of the line or face element
+    /// statement being parsed. It is reused across statements so that
+    /// parsing elements does not allocate once the buffer has grown to fit
+    /// the longest statement.
+    vtn_index_buffer: Vec<VTNIndex>,
+    /// The progress through the objects of the input made by
+    /// [`Parser::next_object`], once it has been called.
+    object_stream: Option<ObjectStream<'a>>,
+}
+
+/// The state carried from one object to the next while parsing the objects
+/// of a Wavefront OBJ file.
+struct ObjectStream<'a> {
+    /// The material of a `usemtl` statement preceding the first object.
+    leading_material_name: Option<&'a str>,
+    /// The bounds of the indices into each kind of vertex declared before the
+    /// next object.
+    min_vertex_index: usize,
+    max_vertex_index: usize,
+    min_texture_index: usize,
+    max_texture_index: usize,
+    min_normal_index: usize,
+    max_normal_index: usize,
+    /// The position of the first object with each name.
+    object_names: HashMap<String, usize>,
+    /// The number of objects kept so far, not counting merged objects.
+    object_count: usize,
+    /// Whether the input is exhausted or an error has been returned.
+    finished: bool,
+}
+
+impl<'a> ObjectStream<'a> {
+    fn new(leading_material_name: Option<&'a str>) -> ObjectStream<'a> {
+        ObjectStream {
+            leading_material_name: leading_material_name,
+            min_vertex_index: 0,
+            max_vertex_index: 0,
+            min_texture_index: 0,
+            max_texture_index: 0,
+            min_normal_index: 0,
+            max_normal_index: 0,
+            object_names: HashMap::new(),
+            object_count: 0,
+            finished: false,
+        }
+    }
+}
+
+/// Triangulate a polygon with a triangle fan.
+///
+/// NOTE: the OBJ specification assumes that polygons are coplanar, and
+/// consequently the parser does not check this. It is up to the model creator
+/// to ensure this.
+#[inline]
+fn triangulate(elements: &mut Vec<Element>, vtn_indices: &[VTNIndex]) -> usize {
+    let vertex0 = vtn_indices[0];
+    for i in 0..(vtn_indices.len() - 2) {
+        elements.push(Element::Face(vertex0, vtn_indices[i + 1], vtn_indices[i + 2]));
+    }
+
+    vtn_indices.len() - 2
+}
+
+/// Number the VTN indices of an element as in the source file, given the
+/// number of each kind of vertex preceding the element's object.
+fn to_file_indices(element: Element, offsets: (usize, usize, usize)) -> Element {
+    let (v_offset, vt_offset, vn_offset) = offsets;
+    let to_file_index = |vtn_index: VTNIndex| match vtn_index {
+        VTNIndex::V(v) => VTNIndex::V(v + v_offset + 1),
+        VTNIndex::VT(v, vt) => VTNIndex::VT(v + v_offset + 1, vt + vt_offset + 1),
+        VTNIndex::VN(v, vn) => VTNIndex::VN(v + v_offset + 1, vn + vn_offset + 1),
+        VTNIndex::VTN(v, vt, vn) => VTNIndex::VTN(v + v_offset + 1, vt + vt_offset + 1, vn + vn_offset + 1),
+    };
+
+    match element {
+        Element::Point(vtn1) => Element::Point(to_file_index(vtn1)),
+        Element::Line(vtn1, vtn2) => Element::Line(to_file_index(vtn1), to_file_index(vtn2)),
+        Element::Face(vtn1, vtn2, vtn3) => {
+            Element::Face(to_file_index(vtn1), to_file_index(vtn2), to_file_index(vtn3))
+        }
+    }
+}
+
+/// The stored index of every statement of one kind in an object, for
+/// deduplicating exact repeats as they are parsed.
+struct StoredIndex<K> {
+    /// The stored index of each distinct value, keyed by its bits.
+    stored: HashMap<K, usize>,
+    /// The stored index of each statement, in statement order.
+    statement_map: Vec<usize>,
+}
+
+impl<K: Hash + Eq> StoredIndex<K> {
+    fn new() -> StoredIndex<K> {
+        StoredIndex {
+            stored: HashMap::new(),
+            statement_map: Vec::new(),
+        }
+    }
+
+    /// Record the next statement, returning whether its value is new and must
+    /// be stored.
+    fn insert(&mut self, key: K) -> bool {
+        let next = self.stored.len();
+        let index = *self.stored.entry(key).or_insert(next);
+        self.statement_map.push(index);
+
+        index == next
+    }
+}
+
+/// The bits of each component of a value, so that values are equal as keys
+/// exactly when they are written to the same bits.
+fn bit_key<const N: usize>(components: [f64; N]) -> [u64; N] {
+    components.map(f64::to_bits)
+}
+
+/// Collapse each item whose key equals the key of the item immediately before
+/// it into that item, returning the new index of every original item. The
+/// recorded text of the items, if there is any, is collapsed along with them.
+fn collapse_repeats<T, K, F>(items: &mut Vec<T>, texts: &mut Vec<String>, key: F) -> Vec<usize>
+where
+    T: Copy,
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    let mut index_map = Vec::with_capacity(items.len());
+    let mut kept = 0;
+    for index in 0..items.len() {
+        // A collapsed item equals the last kept item, so comparing with the
+        // last kept item compares with the item before.
+        if kept > 0 && key(&items[index]) == key(&items[kept - 1]) {
+            index_map.push(kept - 1);
+            continue;
+        }
+        items[kept] = items[index];
+        if !texts.is_empty() {
+            texts.swap(kept, index);
+        }
+        index_map.push(kept);
+        kept += 1;
+    }
+    items.truncate(kept);
+    texts.truncate(kept);
+
+    index_map
+}
+
+/// Verify that each VTN index has the same type and has a valid form.
+#[inline]
+fn verify_vtn_indices(vtn_indices: &[VTNIndex]) -> bool {
+    for i in 1..vtn_indices.len() {
+        if !vtn_indices[i].has_same_type_as(&vtn_indices[0]) {
+            return false;
+        }
+    }
+
+    true
+}
+
+impl<'a> Parser<'a> {
+    /// Construct a new Wavefront OBJ file parser.
+    pub fn new(input: &'a str) -> Parser<'a> {
+        Parser::with_options(input, ParserOptions::default())
+    }
+
+    /// Construct a new Wavefront OBJ file parser with non-default options.
+    pub fn with_options(input: &'a str, options: ParserOptions) -> Parser<'a> {
+        Parser {
+            input: input,
+            options: options,
+            line_number: 1,
+            lexer: PeekableLexer::new(Lexer::new(input)),
+            last_token: None,
+            external_references: vec![],
+            material_libraries: vec![],
+            unknown_statements: vec![],
+            warnings: vec![],
+            vtn_index_buffer: vec![],
+            object_stream: None,
+        }
+    }
+
+    /// Construct a new parse error.
+    #[cold]
+    fn error<T>(&self, kind: ErrorKind, message: String) -> Result<T, ParseError> {
+        let mut error = ParseError::new(self.line_number, kind, message);
+        error.span = self.last_token.map(|token| {
+            let start = token.as_ptr() as usize - self.input.as_ptr() as usize;
+            start..(start + token.len())
+        });
+
+        Err(error)
+    }
+
+    /// Peek at the currently held token without advancing the token stream.
+    fn peek(&mut self) -> Option<&'a str> {
+        self.lexer.peek().map(normalize_line_ending)
+    }
+
+    /// Advance the token stream one step returning the currently held string.
+    ///
+    /// Line terminators are returned as `"\n"` regardless of the line ending
+    /// convention of the input.
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.lexer.next();
+        if let Some(val) = token {
+            self.last_token = token;
+            let val = normalize_line_ending(val);
+            if val == "\n" {
+                self.line_number += 1;
+            }
+
+            return Some(val);
+        }
+
+        None
+    }
+
+    /// Advance the token stream one step without returning the current token.
+    fn advance(&mut self) {
+        self.next();
+    }
+
+    /// Advance the token stream one step, returning the next token in the
+    /// stream.
+    ///
+    /// This function generates an error is it runs out of input.
+    fn next_string(&mut self) -> Result<&'a str, ParseError> {
+        match self.next() {
+            Some(st) => Ok(st),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Reached the end of the input in the process of getting the next token.".to_owned(),
+            ),
+        }
+    }
+
+    /// Advance the token stream if the next token in the stream matches the
+    /// input tag.
+    ///
+    /// This functions returns an error if the expected tag is not present.
+    fn expect_tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        match self.next() {
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Reached the end of the input in the process of getting the next token.".to_owned(),
+            ),
+            Some(st) if st != tag => self.error(
+                ErrorKind::ExpectedTagStatement,
+                format!("Expected `{}` but got `{}` instead.", tag, st),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse a floating point number from the current token in the stream.
+    fn parse_f64(&mut self) -> Result<f64, ParseError> {
+        let st = self.next_string()?;
+        match number::parse_f64(st, self.options.decimal_comma) {
+            Some(val) => self.check_finite(val),
+            None => self.error(
+                ErrorKind::ExpectedFloat,
+                format!("Expected a floating point number but got `{}` instead.", st),
+            ),
+        }
+    }
+
+    /// Apply the non-finite value policy to the number most recently taken
+    /// from the token stream.
+    fn check_finite(&mut self, value: f64) -> Result<f64, ParseError> {
+        if value.is_finite() {
+            return Ok(value);
+        }
+
+        match self.options.non_finite {
+            NonFinitePolicy::Accept => Ok(value),
+            NonFinitePolicy::Reject => self.error(
+                ErrorKind::NonFiniteValue,
+                format!("Expected a finite number but got `{}` instead.", value),
+            ),
+            NonFinitePolicy::Warn => {
+                self.warnings.push(ParseWarning {
+                    line_number: self.line_number,
+                    kind: WarningKind::NonFiniteValue,
+                    message: format!("The number `{}` is not finite.", value),
+                });
+                Ok(value)
+            }
+            NonFinitePolicy::Clamp => Ok(number::clamp_non_finite(value)),
+        }
+    }
+
+    /// Apply a parser to the input stream.
+    ///
+    /// If the parser `parser` fails to parse the current token in the stream,
+    /// it returns nothing and the stream state does not change. Otherwise, the
+    /// stream advances and the corresponding result is returned.
+    fn try_once<P, T>(&mut self, parser: P) -> Option<T>
+    where
+        P: FnOnce(&str) -> Option<T>,
+    {
+        match self.peek() {
+            Some(st) => parser(st).inspect(|_| {
+                self.advance();
+            }),
+            None => None,
+        }
+    }
+
+    /// Parse a vertex from the input.
+    fn parse_vertex(&mut self) -> Result<Vertex, ParseError> {
+        self.expect_tag("v")?;
+
+        let x = self.parse_f64()?;
+        let y = self.parse_f64()?;
+        let z = self.parse_f64()?;
+        let decimal_comma = self.options.decimal_comma;
+        let mw = self.try_once(|st| number::parse_f64(st, decimal_comma));
+        let w = match mw {
+            Some(w) => self.check_finite(w)?,
+            None => self.options.default_w,
+        };
+
+        Ok(self.vertex(x, y, z, w))
+    }
+
+    /// Construct a vertex from its parsed components, normalizing it if the
+    /// parser options ask for that.
+    fn vertex(&self, x: f64, y: f64, z: f64, w: f64) -> Vertex {
+        if self.options.normalize_w && w != 0_f64 {
+            Vertex {
+                x: x / w,
+                y: y / w,
+                z: z / w,
+                w: 1_f64,
+            }
+        } else {
+            Vertex {
+                x: x,
+                y: y,
+                z: z,
+                w: w,
+            }
+        }
+    }
+
+    /// Parse a vertex from the input, with either an optional **w** component
+    /// or a color.
+    fn parse_colored_vertex(&mut self) -> Result<(Vertex, Option<VertexColor>), ParseError> {
+        self.expect_tag("v")?;
+
+        let x = self.parse_f64()?;
+        let y = self.parse_f64()?;
+        let z = self.parse_f64()?;
+        let decimal_comma = self.options.decimal_comma;
+        let mut extra = [0_f64; 3];
+        let mut extra_count = 0;
+        while extra_count < extra.len() {
+            match self.try_once(|st| number::parse_f64(st, decimal_comma)) {
+                Some(value) => {
+                    extra[extra_count] = self.check_finite(value)?;
+                    extra_count += 1;
+                }
+                None => break,
+            }
+        }
+
+        match extra_count {
+            0 => Ok((self.vertex(x, y, z, self.options.default_w), None)),
+            1 => Ok((self.vertex(x, y, z, extra[0]), None)),
+            2 => self.error(
+                ErrorKind::ExpectedFloat,
+                String::from("Expected the blue component of the vertex color."),
+            ),
+            _ => {
+                let color = VertexColor {
+                    r: extra[0],
+                    g: extra[1],
+                    b: extra[2],
+                };
+
+                Ok((self.vertex(x, y, z, self.options.default_w), Some(color)))
+            }
+        }
+    }
+
+    /// Parse a texture vertex from the input.
+    fn parse_texture_vertex(&mut self) -> Result<TextureVertex, ParseError> {
+        self.expect_tag("vt")?;
+
+        let u = self.parse_f64()?;
+        let decimal_comma = self.options.decimal_comma;
+        let mv = self.try_once(|st| number::parse_f64(st, decimal_comma));
+        let v = match mv {
+            Some(v) => self.check_finite(v)?,
+            None => 0_f64,
+        };
+        let mw = self.try_once(|st| number::parse_f64(st, decimal_comma));
+        let w = match mw {
+            Some(w) => self.check_finite(w)?,
+            None => 0_f64,
+        };
+
+        Ok(TextureVertex { u: u, v: v, w: w })
+    }
+
+    /// Parse a normal vector from the input.
+    fn parse_normal_vertex(&mut self) -> Result<NormalVertex, ParseError> {
+        self.expect_tag("vn")?;
+
+        let x = self.parse_f64()?;
+        let y = self.parse_f64()?;
+        let z = self.parse_f64()?;
+
+        Ok(NormalVertex { x: x, y: y, z: z })
+    }
+
+    /// Skip over any number of newlines in the input stream.
+    fn skip_zero_or_more_newlines(&mut self) {
+        while let Some("\n") = self.peek() {
+            self.advance();
+        }
+    }
+
+    /// Skip over at least one newline in the input stream.
+    ///
+    /// The function returns an error if no newline tokens are present.
+    fn skip_one_or_more_newlines(&mut self) -> Result<(), ParseError> {
+        self.expect_tag("\n")?;
+        self.skip_zero_or_more_newlines();
+        Ok(())
+    }
+
+    /// Parse the name of an object. The name is the rest of the `o` line,
+    /// so it may contain spaces.
+    fn parse_object_name(&mut self) -> Result<&'a str, ParseError> {
+        match self.peek() {
+            Some("o") => {
+                self.expect_tag("o")?;
+                let arguments = self.parse_rest_of_line();
+                let object_name = match (arguments.first(), arguments.last()) {
+                    (Some(&first), Some(&last)) => self.source_text(first, last),
+                    _ => "",
+                };
+                self.skip_one_or_more_newlines()?;
+
+                Ok(object_name)
+            }
+            _ => Ok(""),
+        }
+    }
+
+    #[inline(always)]
+    fn calculate_index(&self, value_range: (usize, usize), parsed_value: isize) -> Result<usize, ParseError> {
+        let (min_value, max_value) = value_range;
+        if parsed_value <= 0 && value_range == ISOLATED_INDEX_RANGE {
+            return self.error(
+                ErrorKind::VTNIndexOutOfRange,
+                format!(
+                    "The relative index {} cannot be resolved without any declared vertices.",
+                    parsed_value
+                ),
+            );
+        }
+        let actual_value = if parsed_value <= 0 {
+            (max_value as isize).saturating_add(parsed_value)
+        } else {
+            parsed_value - 1
+        };
+
+        if (actual_value >= min_value as isize) && (actual_value < max_value as isize) {
+            debug_assert!(actual_value >= 0);
+            Ok((actual_value - min_value as isize) as usize)
+        } else {
+            self.error(
+                ErrorKind::VTNIndexOutOfRange,
+                format!(
+                    "Expected index in range [{}, {}), but got {}.",
+                    min_value, max_value, actual_value
+                ),
+            )
+        }
+    }
+
+    /// Parse a vertex/texture/normal index.
+    ///
+    /// An empty texture or normal component is treated as absent, so that the
+    /// trailing slash forms `1/` and `1//` are the vertex index `1`, and `1/2/`
+    /// is the vertex/texture index `1/2`. The vertex component cannot be empty,
+    /// and an index has at most three components.
+    fn parse_vtn_index(
+        &mut self,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<VTNIndex, ParseError> {
+        let st = self.next_string()?;
+        let process_split = |split: &str, value_range: (usize, usize)| -> Result<Option<usize>, ParseError> {
+            if !split.is_empty() {
+                let parsed_value = split.parse::<isize>().or_else(|_| {
+                    self.error(
+                        ErrorKind::ExpectedInteger,
+                        format!("Expected an integer but got `{}` instead.", split),
+                    )
+                })?;
+                let index = self.calculate_index(value_range, parsed_value)?;
+                Ok(Some(index))
+            } else {
+                Ok(None)
+            }
+        };
+
+        let mut splits_iter = st.split('/');
+        let split1 = splits_iter
+            .next()
+            .and_then(|s| process_split(s, vertex_index_range).transpose())
+            .transpose()?;
+        let split2 = splits_iter
+            .next()
+            .and_then(|s| process_split(s, texture_index_range).transpose())
+            .transpose()?;
+        let split3 = splits_iter
+            .next()
+            .and_then(|s| process_split(s, normal_index_range).transpose())
+            .transpose()?;
+        if split1.is_none() || splits_iter.next().is_some() {
+            return self.error(
+                ErrorKind::ExpectedVTNIndex,
+                format!(
+                    "Expected a `vertex/texture/normal` index but got `{}` instead.",
+                    st
+                ),
+            );
+        }
+
+        match (split1, split2, split3) {
+            (Some(v), None, None) => Ok(VTNIndex::V(v)),
+            (Some(v), None, Some(vn)) => Ok(VTNIndex::VN(v, vn)),
+            (Some(v), Some(vt), None) => Ok(VTNIndex::VT(v, vt)),
+            (Some(v), Some(vt), Some(vn)) => Ok(VTNIndex::VTN(v, vt, vn)),
+            _ => self.error(
+                ErrorKind::ExpectedVTNIndex,
+                format!(
+                    "Expected a `vertex/texture/normal` index but got `{}` instead.",
+                    st
+                ),
+            ),
+        }
+    }
+
+    /// Parse one more more VTN indices.
+    ///
+    /// The parser consumes VTN indices up to and including the end of the
+    /// line. The end of the line is checked for before parsing each index, so
+    /// that reaching the end of a statement does not construct an error
+    /// that would be thrown away.
+    ///
+    /// Return the number of VTN indices parsed if no errors occurred.
+    fn parse_vtn_indices(
+        &mut self,
+        vtn_indices: &mut Vec<VTNIndex>,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<usize, ParseError> {
+        let mut indices_parsed = 0;
+        loop {
+            match self.peek() {
+                Some("\n") | None => {
+                    self.advance();
+                    break;
+                }
+                _ => {
+                    let vtn_index =
+                        self.parse_vtn_index(vertex_index_range, texture_index_range, normal_index_range)?;
+                    vtn_indices.push(vtn_index);
+                    indices_parsed += 1;
+                }
+            }
+        }
+
+        Ok(indices_parsed)
+    }
+
+    /// Parse one or more point from the current line in the input stream.
+    ///
+    /// There can be more than one point in a single line of input, so
+    /// this parsing rule will attempt to read all of them.
+    fn parse_point(
+        &mut self,
+        elements: &mut Vec<Element>,
+        vertex_index_range: (usize, usize),
+    ) -> Result<usize, ParseError> {
+        self.expect_tag("p")?;
+
+        let st = self.next_string()?;
+        let parsed_value = self.parse_point_index(st)?;
+        let v_index = self.calculate_index(vertex_index_range, parsed_value)?;
+        elements.push(Element::Point(VTNIndex::V(v_index)));
+        let mut elements_parsed = 1;
+        loop {
+            match self.next() {
+                Some(st) if st != "\n" => {
+                    let parsed_value = self.parse_point_index(st)?;
+                    let v_index = self.calculate_index(vertex_index_range, parsed_value)?;
+                    elements.push(Element::Point(VTNIndex::V(v_index)));
+                    elements_parsed += 1;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(elements_parsed)
+    }
+
+    /// Parse the vertex index of a point.
+    ///
+    /// A point refers to a vertex only, but the trailing slash forms `1/` and
+    /// `1//` are accepted as the vertex index `1`, as they are in lines and
+    /// faces.
+    fn parse_point_index(&self, st: &str) -> Result<isize, ParseError> {
+        let index = st
+            .strip_suffix("//")
+            .or_else(|| st.strip_suffix('/'))
+            .unwrap_or(st);
+
+        match index.parse::<isize>() {
+            Ok(val) => Ok(val),
+            Err(_) => self.error(
+                ErrorKind::ExpectedInteger,
+                format!("Expected an integer but got `{}` instead.", st),
+            ),
+        }
+    }
+
+    /// Parse one more more line elements from a line of text input from the input.
+    ///
+    /// If the parser cannot parse each line element from a line of text input, the
+    /// parser returns an error.
+    fn parse_line(
+        &mut self,
+        elements: &mut Vec<Element>,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<usize, ParseError> {
+        self.expect_tag("l")?;
+
+        let mut vtn_indices = mem::take(&mut self.vtn_index_buffer);
+        vtn_indices.clear();
+        let result = self
+            .parse_line_corners(
+                &mut vtn_indices,
+                vertex_index_range,
+                texture_index_range,
+                normal_index_range,
+            )
+            .map(|_| {
+                for i in 0..(vtn_indices.len() - 1) {
+                    elements.push(Element::Line(vtn_indices[i], vtn_indices[i + 1]));
+                }
+
+                vtn_indices.len() - 1
+            });
+        self.vtn_index_buffer = vtn_indices;
+
+        result
+    }
+
+    /// Parse the VTN indices of the vertices of a line into a buffer,
+    /// checking that there are at least two of them and that they have the
+    /// same form.
+    fn parse_line_corners(
+        &mut self,
+        vtn_indices: &mut Vec<VTNIndex>,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<(), ParseError> {
+        vtn_indices.push(self.parse_vtn_index(
+            vertex_index_range,
+            texture_index_range,
+            normal_index_range,
+        )?);
+        vtn_indices.push(self.parse_vtn_index(
+            vertex_index_range,
+            texture_index_range,
+            normal_index_range,
+        )?);
+        self.parse_vtn_indices(
+            vtn_indices,
+            vertex_index_range,
+            texture_index_range,
+            normal_index_range,
+        )?;
+
+        if !verify_vtn_indices(vtn_indices) {
+            return self.error(
+                ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
+                "Every VTN index for a line must have the same form.".to_owned(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Parse one or more faces from a single line of text input.
+    ///
+    /// All face vertices must have the same vertex/texture/normal form on
+    /// a line of input. If they do not, the parser will return an error. Otherwise,
+    /// it succeeds. The face parser unpacks the face elements by treating the line
+    /// of face indices as a triangle fan.
+    ///
+    /// The parser returns the number of triangles generated.
+    fn parse_face(
+        &mut self,
+        elements: &mut Vec<Element>,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<usize, ParseError> {
+        let vtn_indices =
+            self.parse_face_corners(vertex_index_range, texture_index_range, normal_index_range)?;
+        let face_count = triangulate(elements, &vtn_indices);
+        self.vtn_index_buffer = vtn_indices;
+
+        Ok(face_count)
+    }
+
+    /// Parse the VTN indices of the corners of a face, checking that there
+    /// are at least three of them and that they have the same form.
+    ///
+    /// The indices are returned in the scratch buffer, which the caller
+    /// should put back for reuse. On an error, the buffer is put back here.
+    fn parse_face_corners(
+        &mut self,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<Vec<VTNIndex>, ParseError> {
+        self.expect_tag("f")?;
+
+        let mut vtn_indices = mem::take(&mut self.vtn_index_buffer);
+        vtn_indices.clear();
+        match self.parse_face_corner_indices(
+            &mut vtn_indices,
+            vertex_index_range,
+            texture_index_range,
+            normal_index_range,
+        ) {
+            Ok(()) => Ok(vtn_indices),
+            Err(error) => {
+                self.vtn_index_buffer = vtn_indices;
+                Err(error)
+            }
+        }
+    }
+
+    /// Parse the VTN indices of the corners of a face into a buffer.
+    fn parse_face_corner_indices(
+        &mut self,
+        vtn_indices: &mut Vec<VTNIndex>,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<(), ParseError> {
+        self.parse_vtn_indices(
+            vtn_indices,
+            vertex_index_range,
+            texture_index_range,
+            normal_index_range,
+        )?;
+
+        // Check that there are enough vtn indices.
+        if vtn_indices.len() < 3 {
+            return self.error(
+                ErrorKind::EveryFaceElementMustHaveAtLeastThreeVertices,
+                "A face primitive must have at least three vertices.".to_owned(),
+            );
+        }
+
+        if !verify_vtn_indices(vtn_indices) {
+            return self.error(
+                ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
+                "Every VTN index for a face must have the same form.".to_owned(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Parse all the elements of a givne type from a line of text input.
+    fn parse_elements(
+        &mut self,
+        elements: &mut Vec<Element>,
+        vertex_index_range: (usize, usize),
+        texture_index_range: (usize, usize),
+        normal_index_range: (usize, usize),
+    ) -> Result<usize, ParseError> {
+        match self.peek() {
+            Some("p") => self.parse_point(elements, vertex_index_range),
+            Some("l") => self.parse_line(
+                elements,
+                vertex_index_range,
+                texture_index_range,
+                normal_index_range,
+            ),
+            Some("f") => self.parse_face(
+                elements,
+                vertex_index_range,
+                texture_index_range,
+                normal_index_range,
+            ),
+            _ => self.error(
+                ErrorKind::ElementMustBeAPointLineOrFace,
+                "An element must be a point (`p`), line (`l`), or face (`f`).".to_owned(),
+            ),
+        }
+    }
+
+    /// Parse group names from a line of text input. A `g` statement without
+    /// any names resets to the default group.
+    fn parse_groups(&mut self, groups: &mut Vec<Group>) -> Result<usize, ParseError> {
+        self.expect_tag("g")?;
+        let mut groups_parsed = 0;
+        loop {
+            match self.next() {
+                Some(name) if name != "\n" => {
+                    groups.push(Group(String::from(name)));
+                    groups_parsed += 1;
+                }
+                _ => break,
+            }
+        }
+
+        if groups_parsed == 0 {
+            groups.push(Default::default());
+            groups_parsed = 1;
+        }
+
+        Ok(groups_parsed)
+    }
+
+    /// Parse a smoothing group name from a line of text input.
+    fn parse_smoothing_group(
+        &mut self,
+        smoothing_groups: &mut Vec<SmoothingGroup>,
+    ) -> Result<usize, ParseError> {
+        self.expect_tag("s")?;
+        if let Some(name) = self.next() {
+            if name == "off" {
+                smoothing_groups.push(SmoothingGroup(0));
+            } else if let Ok(number) = name.parse::<usize>() {
+                smoothing_groups.push(SmoothingGroup(number));
+            } else {
+                return self.error(
+                    ErrorKind::SmoothingGroupNameMustBeOffOrInteger,
+                    format!(
+                        "A smoothing group name must either be `off`, which denotes that an \
+                        object has no smoothing groups, or an integer. The parser got `{}` instead.",
+                        name
+                    ),
+                );
+            }
+        } else {
+            return self.error(
+                ErrorKind::SmoothingGroupDeclarationHasNoName,
+                "Got a smoothing group declaration without a smoothing group name.".to_owned(),
+            );
+        }
+
+        Ok(1)
+    }
+
+    /// Parse a material name from a line of text input.
+    fn parse_material_name(
+        &mut self,
+        material_names: &mut Vec<Option<&'a str>>,
+    ) -> Result<usize, ParseError> {
+        self.expect_tag("usemtl")?;
+        if let Some(name) = self.next() {
+            material_names.push(Some(name));
+        } else {
+            return self.error(
+                ErrorKind::MaterialStatementHasNoName,
+                "Got a `usemtl` material declaration without a material name.".to_owned(),
+            );
+        }
+
+        Ok(1)
+    }
+
+    /// Parse a statement the parser does not recognize. It is passed to the
+    /// statement handler if there is one, recorded in lenient mode, and
+    /// rejected otherwise.
+    fn parse_unknown_statement(&mut self, keyword: &'a str) -> Result<(), ParseError> {
+        if self.options.statement_handler.is_some() {
+            self.advance();
+            let arguments = self.parse_rest_of_line();
+            if let Some(ref mut handler) = self.options.statement_handler {
+                if let Err(message) = handler.handle(keyword, &arguments) {
+                    return self.error(ErrorKind::InvalidObjectStatement, message);
+                }
+            }
+
+            Ok(())
+        } else if self.options.lenient {
+            let line_number = self.line_number;
+            self.advance();
+            let arguments = self.parse_rest_of_line();
+            let last_st = arguments.last().copied().unwrap_or(keyword);
+            self.unknown_statements.push(UnknownStatement {
+                line_number: line_number,
+                text: String::from(self.source_text(keyword, last_st)),
+            });
+
+            Ok(())
+        } else {
+            self.error(
+                ErrorKind::InvalidObjectStatement,
+                format!("Unsupported or invalid object statement `{}`.", keyword),
+            )
+        }
+    }
+
+    /// Collect the remaining tokens on the current line, leaving the newline
+    /// in the token stream.
+    fn parse_rest_of_line(&mut self) -> Vec<&'a str> {
+        let mut tokens = vec![];
+        while let Some(st) = self.peek() {
+            if st == "\n" {
+                break;
+            }
+            tokens.push(st);
+            self.advance();
+        }
+
+        tokens
+    }
+
+    /// The source text spanning from the start of the token `first` to the end
+    /// of the token `last`, where both tokens are slices of the input.
+    fn source_text(&self, first: &'a str, last: &'a str) -> &'a str {
+        let start = first.as_ptr() as usize - self.input.as_ptr() as usize;
+        let end = last.as_ptr() as usize - self.input.as_ptr() as usize + last.len();
+
+        &self.input[start..end]
+    }
+
+    /// The source text of the statement starting at the token `first`, which
+    /// is a slice of the input, without comments or the line terminator.
+    ///
+    /// As in the lexer, a comment starts at any `#`, even one that is not
+    /// preceded by whitespace.
+    fn source_line(&self, first: &'a str) -> &'a str {
+        let start = first.as_ptr() as usize - self.input.as_ptr() as usize;
+        let rest = &self.input[start..];
+        let end = rest.find(['\n', '\r', '#']).unwrap_or(rest.len());
+
+        rest[..end].trim_end_matches(is_separator)
+    }
+
+    /// The arguments of the statement at the current token, separated by
+    /// single spaces.
+    fn statement_arguments(&mut self) -> String {
+        let keyword = self.peek().unwrap_or("");
+        let statement = self.source_line(keyword);
+        let arguments: Vec<&str> = statement
+            .split(is_separator)
+            .filter(|st| !st.is_empty())
+            .skip(1)
+            .collect();
+
+        arguments.join(" ")
+    }
+
+    /// Parse a `call` or `csh` statement.
+    fn parse_external_reference(&mut self) -> Result<ExternalReference, ParseError> {
+        match self.next() {
+            Some("call") => {
+                let file_name = match self.next() {
+                    Some(st) if st != "\n" => String::from(st),
+                    _ => {
+                        return self.error(
+                            ErrorKind::CallStatementHasNoFileName,
+                            "Got a `call` statement without a file name.".to_owned(),
+                        );
+                    }
+                };
+                let arguments = self.parse_rest_of_line().into_iter().map(String::from).collect();
+
+                Ok(ExternalReference::Call {
+                    file_name: file_name,
+                    arguments: arguments,
+                })
+            }
+            Some("csh") => {
+                let mut command = self.parse_rest_of_line().join(" ");
+                let ignore_errors = command.starts_with('-');
+                if ignore_errors {
+                    command.remove(0);
+                }
+                if command.is_empty() {
+                    return self.error(
+                        ErrorKind::ShellStatementHasNoCommand,
+                        "Got a `csh` statement without a command.".to_owned(),
+                    );
+                }
+
+                Ok(ExternalReference::Shell {
+                    command: command,
+                    ignore_errors: ignore_errors,
+                })
+            }
+            Some(st) => self.error(
+                ErrorKind::ExpectedTagStatement,
+                format!("Expected `call` or `csh` but got `{}` instead.", st),
+            ),
+            None => self.error(
+                ErrorKind::EndOfFile,
+                "Reached the end of the input in the process of getting the next token.".to_owned(),
+            ),
+        }
+    }
+
+    /// Construct a set of shape entries for each element in the element set.
+    #[allow(clippy::type_complexity)]
+    #[allow(clippy::needless_range_loop)]
+    fn parse_shape_entries(
+        &self,
+        shape_entry_table: &mut Vec<ShapeEntry>,
+        elements: &[Element],
+        group_entry_table: &[((usize, usize), (usize, usize))],
+        smoothing_group_entry_table: &[((usize, usize), usize)],
+    ) {
+        for &((min_element_index, max_element_index), (min_group_index, max_group_index)) in group_entry_table
+        {
+            let groups: Arc<[GroupIndex]> = (min_group_index..max_group_index).map(GroupIndex).collect();
+            for i in min_element_index..max_element_index {
+                shape_entry_table.push(ShapeEntry {
+                    element: ElementIndex(i),
+                    groups: Arc::clone(&groups),
+                    smoothing_group: SmoothingGroupIndex(0),
+                });
+            }
+        }
+        debug_assert!(shape_entry_table.len() == elements.len());
+
+        for &((min_element_index, max_element_index), smoothing_group_index) in smoothing_group_entry_table {
+            for i in min_element_index..max_element_index {
+                shape_entry_table[i].smoothing_group = SmoothingGroupIndex(smoothing_group_index);
+            }
+        }
+        debug_assert!(shape_entry_table.len() == elements.len());
+    }
+
+    /// Construct a set of geometries for reach material in an object.
+    fn parse_geometries(
+        &self,
+        geometries: &mut Vec<Geometry>,
+        material_name_entry_table: &[((usize, usize), usize)],
+        material_names: &[Option<&'a str>],
+    ) {
+        for &((min_element_index, max_element_index), material_name_index) in material_name_entry_table {
+            let shapes: Vec<ShapeEntryIndex> = (min_element_index..max_element_index)
+                .map(ShapeEntryIndex)
+                .collect();
+            // An object without elements never names a material, not even
+            // the default one.
+            let material_name = material_names
+                .get(material_name_index)
+                .copied()
+                .flatten()
+                .map(String::from);
+            let geometry = Geometry {
+                material_name: material_name,
+                shapes: shapes,
+            };
+            geometries.push(geometry);
+        }
+    }
+
+    /// Parse the `usemtl` statements that appear before the first object or
+    /// element in a Wavefront OBJ file. The last one wins, and its material
+    /// carries over to the first geometry of the first object.
+    fn parse_leading_material_name(&mut self) -> Result<Option<&'a str>, ParseError> {
+        let mut material_names = vec![];
+        while let Some("usemtl") = self.peek() {
+            self.parse_material_name(&mut material_names)?;
+            self.skip_zero_or_more_newlines();
+        }
+
+        Ok(material_names.pop().flatten())
+    }
+
+    /// Parse one object from a Wavefront OBJ file.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_object(
+        &mut self,
+        object_name: &'a str,
+        leading_material_name: Option<&'a str>,
+        min_vertex_index: &mut usize,
+        max_vertex_index: &mut usize,
+        min_texture_index: &mut usize,
+        max_texture_index: &mut usize,
+        min_normal_index: &mut usize,
+        max_normal_index: &mut usize,
+    ) -> Result<Object, ParseError> {
+        let mut vertices: Vec<Vertex> = vec![];
+        let mut texture_vertices = vec![];
+        let mut normal_vertices = vec![];
+        let mut elements = vec![];
+        let mut element_lines = vec![];
+        let mut element_texts = vec![];
+        let mut original_elements = vec![];
+        let mut numeric_text = NumericText::default();
+        let mut vertex_index = StoredIndex::new();
+        let mut texture_vertex_index = StoredIndex::new();
+        let mut normal_vertex_index = StoredIndex::new();
+
+        let mut group_entry_table = vec![];
+        let mut groups = vec![];
+        let mut min_element_group_index = 0;
+        let mut max_element_group_index = 0;
+        let mut min_group_index = 0;
+        let mut max_group_index = 0;
+
+        let mut smoothing_group_entry_table = vec![];
+        let mut smoothing_groups = vec![];
+        let mut min_element_smoothing_group_index = 0;
+        let mut max_element_smoothing_group_index = 0;
+        let mut smoothing_group_index = 0;
+
+        let mut material_name_entry_table = vec![];
+        let mut material_names = match leading_material_name {
+            Some(material_name) => vec![Some(material_name)],
+            None => vec![],
+        };
+        let mut min_element_material_name_index = 0;
+        let mut max_element_material_name_index = 0;
+        let mut material_name_index = 0;
+
+        loop {
+            match self.peek() {
+                Some("g") if groups.is_empty() => {
+                    let amount_parsed = self.parse_groups(&mut groups)?;
+                    max_group_index += amount_parsed;
+                }
+                Some("g") => {
+                    // Save the shape entry ranges for the current group.
+                    group_entry_table.push((
+                        (min_element_group_index, max_element_group_index),
+                        (min_group_index, max_group_index),
+                    ));
+
+                    let amount_parsed = self.parse_groups(&mut groups)?;
+                    min_group_index = max_group_index;
+                    max_group_index += amount_parsed;
+                    min_element_group_index = max_element_group_index;
+                }
+                Some("s") if smoothing_groups.is_empty() => {
+                    self.parse_smoothing_group(&mut smoothing_groups)?;
+                    smoothing_group_index = 0;
+                }
+                Some("s") => {
+                    // Save the shape entry ranges for the current smoothing group.
+                    smoothing_group_entry_table.push((
+                        (
+                            min_element_smoothing_group_index,
+                            max_element_smoothing_group_index,
+                        ),
+                        smoothing_group_index,
+                    ));
+
+                    self.parse_smoothing_group(&mut smoothing_groups)?;
+                    smoothing_group_index += 1;
+                    min_element_smoothing_group_index = max_element_smoothing_group_index;
+                }
+                Some("usemtl") => {
+                    if min_element_material_name_index == max_element_material_name_index {
+                        if material_names.is_empty() {
+                            self.parse_material_name(&mut material_names)?;
+                        } else {
+                            self.parse_material_name(&mut material_names)?;
+                            material_name_index += 1;
+                        }
+                    } else {
+                        material_name_entry_table.push((
+                            (min_element_material_name_index, max_element_material_name_index),
+                            material_name_index,
+                        ));
+
+                        if material_names.is_empty() {
+                            self.parse_material_name(&mut material_names)?;
+                        } else {
+                            self.parse_material_name(&mut material_names)?;
+                            material_name_index += 1;
+                        }
+                    }
+
+                    min_element_material_name_index = max_element_material_name_index;
+                }
+                Some("v") => {
+                    let text = self
+                        .options
+                        .record_numeric_text
+                        .then(|| self.statement_arguments());
+                    let vertex = self.parse_vertex()?;
+                    let key = bit_key([vertex.x, vertex.y, vertex.z, vertex.w]);
+                    if !self.options.deduplicate_vertices || vertex_index.insert(key) {
+                        vertices.push(vertex);
+                        numeric_text.vertices.extend(text);
+                    }
+                    *max_vertex_index += 1;
+                }
+                Some("vt") => {
+                    let text = self
+                        .options
+                        .record_numeric_text
+                        .then(|| self.statement_arguments());
+                    let texture_vertex = self.parse_texture_vertex()?;
+                    let key = bit_key([texture_vertex.u, texture_vertex.v, texture_vertex.w]);
+                    if !self.options.deduplicate_vertices || texture_vertex_index.insert(key) {
+                        texture_vertices.push(texture_vertex);
+                        numeric_text.texture_vertices.extend(text);
+                    }
+                    *max_texture_index += 1;
+                }
+                Some("vn") => {
+                    let text = self
+                        .options
+                        .record_numeric_text
+                        .then(|| self.statement_arguments());
+                    let normal_vertex = self.parse_normal_vertex()?;
+                    let key = bit_key([normal_vertex.x, normal_vertex.y, normal_vertex.z]);
+                    if !self.options.deduplicate_vertices || normal_vertex_index.insert(key) {
+                        normal_vertices.push(normal_vertex);
+                        numeric_text.normal_vertices.extend(text);
+                    }
+                    *max_normal_index += 1;
+                }
+                Some("p") | Some("l") | Some("f") => {
+                    if groups.is_empty() {
+                        groups.push(Default::default());
+                        min_group_index = 0;
+                        max_group_index = 1;
+                    }
+
+                    if smoothing_groups.is_empty() {
+                        smoothing_groups.push(Default::default());
+                        smoothing_group_index = 0;
+                    }
+
+                    if material_names.is_empty() {
+                        material_names.push(None);
+                        material_name_index = 0;
+                    }
+
+                    if !self.accepts_groups(&groups[min_group_index..max_group_index]) {
+                        self.skip_rest_of_line();
+                        continue;
+                    }
+
+                    let line_number = self.line_number;
+                    let statement = self.peek().unwrap_or("");
+                    let elements_parsed = self.parse_elements(
+                        &mut elements,
+                        (*min_vertex_index, *max_vertex_index),
+                        (*min_texture_index, *max_texture_index),
+                        (*min_normal_index, *max_normal_index),
+                    )?;
+                    if self.options.record_element_lines {
+                        element_lines.resize(elements.len(), line_number);
+                    }
+                    if self.options.record_element_text {
+                        let text = String::from(self.source_line(statement));
+                        element_texts.resize(elements.len(), text);
+                    }
+                    if self.options.record_original_indices {
+                        let offsets = (*min_vertex_index, *min_texture_index, *min_normal_index);
+                        let new_elements = &elements[original_elements.len()..];
+                        original_elements.extend(
+                            new_elements
+                                .iter()
+                                .map(|&element| to_file_indices(element, offsets)),
+                        );
+                    }
+                    max_element_group_index += elements_parsed;
+                    max_element_smoothing_group_index += elements_parsed;
+                    max_element_material_name_index += elements_parsed;
+                }
+                Some("call") | Some("csh") => {
+                    let external_reference = self.parse_external_reference()?;
+                    self.external_references.push(external_reference);
+                }
+                Some("mtllib") => {
+                    let mut material_libraries = mem::take(&mut self.material_libraries);
+                    let result = self.parse_material_library_line(&mut material_libraries);
+                    self.material_libraries = material_libraries;
+                    result?;
+                }
+                Some("\n") => {
+                    self.skip_one_or_more_newlines()?;
+                }
+                Some("o") | None => {
+                    // At the end of file or object, collect any remaining shapes.
+                    group_entry_table.push((
+                        (min_element_group_index, max_element_group_index),
+                        (min_group_index, max_group_index),
+                    ));
+
+                    smoothing_group_entry_table.push((
+                        (
+                            min_element_smoothing_group_index,
+                            max_element_smoothing_group_index,
+                        ),
+                        smoothing_group_index,
+                    ));
+
+                    material_name_entry_table.push((
+                        (min_element_material_name_index, max_element_material_name_index),
+                        material_name_index,
+                    ));
+
+                    break;
+                }
+                Some(other_st) => {
+                    self.parse_unknown_statement(other_st)?;
+                }
+            }
+        }
+
+        let mut shape_entries = vec![];
+        self.parse_shape_entries(
+            &mut shape_entries,
+            &elements,
+            &group_entry_table,
+            &smoothing_group_entry_table,
+        );
+
+        let mut geometries = vec![];
+        self.parse_geometries(&mut geometries, &material_name_entry_table, &material_names);
+
+        // The index bases count statements, which deduplication may have dropped.
+        *min_vertex_index = *max_vertex_index;
+        *min_texture_index = *max_texture_index;
+        *min_normal_index = *max_normal_index;
+
+        if self.options.deduplicate_vertices {
+            let vertex_map = vertex_index.statement_map;
+            let texture_vertex_map = texture_vertex_index.statement_map;
+            let normal_vertex_map = normal_vertex_index.statement_map;
+            for element in elements.iter_mut() {
+                *element = map_vtn_indices(*element, |vtn_index| match vtn_index {
+                    VTNIndex::V(v) => VTNIndex::V(vertex_map[v]),
+                    VTNIndex::VT(v, vt) => VTNIndex::VT(vertex_map[v], texture_vertex_map[vt]),
+                    VTNIndex::VN(v, vn) => VTNIndex::VN(vertex_map[v], normal_vertex_map[vn]),
+                    VTNIndex::VTN(v, vt, vn) => {
+                        VTNIndex::VTN(vertex_map[v], texture_vertex_map[vt], normal_vertex_map[vn])
+                    }
+                });
+            }
+        }
+
+        if self.options.collapse_repeated_attributes {
+            let texture_vertex_map = collapse_repeats(
+                &mut texture_vertices,
+                &mut numeric_text.texture_vertices,
+                |texture_vertex| bit_key([texture_vertex.u, texture_vertex.v, texture_vertex.w]),
+            );
+            let normal_vertex_map = collapse_repeats(
+                &mut normal_vertices,
+                &mut numeric_text.normal_vertices,
+                |normal_vertex| bit_key([normal_vertex.x, normal_vertex.y, normal_vertex.z]),
+            );
+            for element in elements.iter_mut() {
+                *element = map_vtn_indices(*element, |vtn_index| match vtn_index {
+                    VTNIndex::V(v) => VTNIndex::V(v),
+                    VTNIndex::VT(v, vt) => VTNIndex::VT(v, texture_vertex_map[vt]),
+                    VTNIndex::VN(v, vn) => VTNIndex::VN(v, normal_vertex_map[vn]),
+                    VTNIndex::VTN(v, vt, vn) => {
+                        VTNIndex::VTN(v, texture_vertex_map[vt], normal_vertex_map[vn])
+                    }
+                });
+            }
+        }
+
+        Ok(Object {
+            name: object_name.into(),
+            source: None,
+            vertex_set: vertices.into(),
+            texture_vertex_set: texture_vertices.into(),
+            normal_vertex_set: normal_vertices.into(),
+            group_set: groups.into(),
+            smoothing_group_set: smoothing_groups.into(),
+            element_set: elements.into(),
+            shape_set: shape_entries.into(),
+            geometry_set: geometries.into(),
+            element_line_set: element_lines,
+            element_text_set: element_texts,
+            original_element_set: original_elements,
+            numeric_text: numeric_text,
+        })
+    }
+
+    /// Start parsing the objects of a Wavefront OBJ file, after its leading
+    /// material library statements.
+    fn begin_objects(&mut self) -> Result<ObjectStream<'a>, ParseError> {
+        self.skip_zero_or_more_newlines();
+        let leading_material_name = self.parse_leading_material_name()?;
+
+        Ok(ObjectStream::new(leading_material_name))
+    }
+
+    /// Parse the next object of a Wavefront OBJ file and apply the rule for
+    /// duplicate object names to it.
+    ///
+    /// Under [`DuplicateObjectNames::Merge`], the position of the earlier
+    /// object with the same name is returned with an object that should be
+    /// merged into it. Objects the object filter rejects are skipped, and
+    /// `None` is returned if the filter rejects every remaining object.
+    fn parse_next_object(
+        &mut self,
+        stream: &mut ObjectStream<'a>,
+    ) -> Result<Option<(Object, Option<usize>)>, ParseError> {
+        let (line_number, object_name) = loop {
+            if self.peek().is_none() {
+                return Ok(None);
+            }
+            let line_number = self.line_number;
+            let object_name = self.parse_object_name()?;
+            if !self.rejects_object(object_name) {
+                break (line_number, object_name);
+            }
+            stream.leading_material_name = None;
+            self.skip_object_body(
+                &mut stream.max_vertex_index,
+                &mut stream.max_texture_index,
+                &mut stream.max_normal_index,
+            )?;
+            stream.min_vertex_index = stream.max_vertex_index;
+            stream.min_texture_index = stream.max_texture_index;
+            stream.min_normal_index = stream.max_normal_index;
+        };
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("parse_object", line_number = line_number).entered();
+        let mut object = self.parse_object(
+            object_name,
+            stream.leading_material_name.take(),
+            &mut stream.min_vertex_index,
+            &mut stream.max_vertex_index,
+            &mut stream.min_texture_index,
+            &mut stream.max_texture_index,
+            &mut stream.min_normal_index,
+            &mut stream.max_normal_index,
+        )?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            name = %object.name,
+            vertices = object.vertex_set.len(),
+            texture_vertices = object.texture_vertex_set.len(),
+            normal_vertices = object.normal_vertex_set.len(),
+            elements = object.element_set.len(),
+            groups = object.group_set.len(),
+            smoothing_groups = object.smoothing_group_set.len(),
+            geometries = object.geometry_set.len(),
+            "Parsed an object."
+        );
+        self.skip_zero_or_more_newlines();
+        if let Some(&first) = stream.object_names.get(&object.name) {
+            match self.options.duplicate_object_names {
+                DuplicateObjectNames::Keep => {}
+                DuplicateObjectNames::Rename => {
+                    object.name = free_object_name(&object.name, &stream.object_names);
+                }
+                DuplicateObjectNames::Merge => {
+                    return Ok(Some((object, Some(first))));
+                }
+                DuplicateObjectNames::Reject => {
+                    return Err(ParseError::new(
+                        line_number,
+                        ErrorKind::DuplicateObjectName,
+                        format!("The object name `{}` is already in use.", object.name),
+                    ));
+                }
+            }
+        }
+        stream
+            .object_names
+            .entry(object.name.clone())
+            .or_insert(stream.object_count);
+        stream.object_count += 1;
+
+        Ok(Some((object, None)))
+    }
+
+    /// Whether the object filter rejects the object with the given name.
+    fn rejects_object(&mut self, object_name: &str) -> bool {
+        self.options
+            .object_filter
+            .as_mut()
+            .is_some_and(|filter| !filter.accept(object_name))
+    }
+
+    /// Whether the group filter accepts an element in the given groups, i.e.
+    /// whether it accepts any of them. An element in no group is in the
+    /// default group.
+    fn accepts_groups(&mut self, groups: &[Group]) -> bool {
+        match self.options.group_filter {
+            Some(ref mut filter) if groups.is_empty() => filter.accept(&Group::default().0),
+            Some(ref mut filter) => groups.iter().any(|group| filter.accept(&group.0)),
+            None => true,
+        }
+    }
+
+    /// Skip the tokens of the current statement up to its line terminator
+    /// without checking them.
+    fn skip_rest_of_line(&mut self) {
+        while !matches!(self.peek(), None | Some("\n")) {
+            self.advance();
+        }
+    }
+
+    /// Skip the statements of an object up to the next `o` statement without
+    /// checking them. The vertex statements are counted so that the objects
+    /// after it resolve their indices, and the material libraries are
+    /// recorded.
+    fn skip_object_body(
+        &mut self,
+        max_vertex_index: &mut usize,
+        max_texture_index: &mut usize,
+        max_normal_index: &mut usize,
+    ) -> Result<(), ParseError> {
+        loop {
+            match self.peek() {
+                Some("o") | None => break,
+                Some("\n") => self.skip_one_or_more_newlines()?,
+                Some("mtllib") => {
+                    let mut material_libraries = mem::take(&mut self.material_libraries);
+                    let result = self.parse_material_library_line(&mut material_libraries);
+                    self.material_libraries = material_libraries;
+                    result?;
+                }
+                Some(keyword) => {
+                    match keyword {
+                        "v" => *max_vertex_index += 1,
+                        "vt" => *max_texture_index += 1,
+                        "vn" => *max_normal_index += 1,
+                        _ => {}
+                    }
+                    self.skip_rest_of_line();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a set of objects in a wavefront OBJ file.
+    fn parse_objects(&mut self) -> Result<Vec<Object>, ParseError> {
+        let mut result: Vec<Object> = Vec::new();
+        let mut stream = self.begin_objects()?;
+        while self.peek().is_some() {
+            match self.parse_next_object(&mut stream)? {
+                Some((object, Some(first))) => append_object(&mut result[first], object),
+                Some((object, None)) => result.push(object),
+                None => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parse a set of material library file names from a line of text input.
+    fn parse_material_library_line(
+        &mut self,
+        material_libraries: &mut Vec<MtlLibRef>,
+    ) -> Result<usize, ParseError> {
+        self.expect_tag("mtllib")?;
+        let line_number = self.line_number;
+        let mut number_of_libraries_found = 0;
+        loop {
+            match self.next() {
+                Some(st) if st != "\n" => {
+                    material_libraries.push(MtlLibRef::new(st, line_number));
+                    number_of_libraries_found += 1;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(number_of_libraries_found)
+    }
+
+    /// Parse the set of material library names declared at the top of a
+    /// Wavefront OBJ file. Libraries declared later in the file are collected
+    /// while parsing objects.
+    fn parse_material_libraries(&mut self) -> Result<Vec<MtlLibRef>, ParseError> {
+        let mut material_libraries = vec![];
+        self.skip_zero_or_more_newlines();
+        loop {
+            match self.peek() {
+                Some("mtllib") => {
+                    self.parse_material_library_line(&mut material_libraries)?;
+                }
+                Some("call") | Some("csh") => {
+                    let external_reference = self.parse_external_reference()?;
+                    self.external_references.push(external_reference);
+                }
+                _ => break,
+            }
+            self.skip_zero_or_more_newlines();
+        }
+
+        Ok(material_libraries)
+    }
+
+    /// Check the input as a whole before parsing it: it must be text, and no
+    /// line may exceed [`ParserOptions::max_line_length`].
+    fn check_input(&self) -> Result<(), ParseError> {
+        if let Some(not_text) = input::NotText::find_nul(self.input) {
+            return Err(ParseError::not_a_text_file(not_text));
+        }
+        if let Some(limit) = self.options.max_line_length {
+            if let Some((line_number, span)) = lexer::find_long_line(self.input, limit) {
+                let message = format!(
+                    "The line is {} bytes long, which exceeds the limit of {} bytes.",
+                    span.len(),
+                    limit
+                );
+                let mut error = ParseError::new(line_number, ErrorKind::LineTooLong, message);
+                error.span = Some(span);
+
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse the object set in the wavefront obj file.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj;
+    /// # use wavefront_obj::obj::{
+    /// #      Vertex,
+    /// #      NormalVertex,
+    /// #      Group,
+    /// #      SmoothingGroup,
+    /// #      Element,
+    /// #      ShapeEntry,
+    /// #      ShapeEntryIndex,
+    /// #      ElementIndex,
+    /// #      GroupIndex,
+    /// #      SmoothingGroupIndex,
+    /// #      Geometry,
+    /// #      VTNIndex,
+    /// #      Object,
+    /// #      ObjectSet,
+    /// #      MtlLibRef,
+    /// #      Parser,
+    /// # };
+    /// #
+    /// let obj_file = String::from(r"
+    ///     mtllib material_library.mtl    \
+    ///     o object1                      \
+    ///     v 0.000000  2.000000  0.000000 \
+    ///     v 0.000000  0.000000  0.000000 \
+    ///     v 2.000000  0.000000  0.000000 \
+    ///     v 2.000000  2.000000  0.000000 \
+    ///     v 4.000000  0.000000 -1.255298 \
+    ///     v 4.000000  2.000000 -1.255298 \
+    ///     ## 6 vertices                  \
+    ///                                    \
+    ///     g all                          \
+    ///     s 1                            \
+    ///     usemtl material1               \
+    ///     f 1 2 3 4                      \
+    ///     f 4 3 5 6                      \
+    ///     ## 2 elements                  \
+    ///                                    \
+    ///     o object2                      \
+    ///     v 0.000000  2.000000  0.000000 \
+    ///     v 0.000000  0.000000  0.000000 \
+    ///     v 2.000000  0.000000  0.000000 \
+    ///     v 2.000000  2.000000  0.000000 \
+    ///     v 4.000000  0.000000 -1.255298 \
+    ///     v 4.000000  2.000000 -1.255298 \
+    ///     ## 6 vertices                  \
+    ///                                    \
+    ///     g all                          \
+    ///     s 1                            \
+    ///     usemtl material2               \
+    ///     f 7 8 9 10                     \
+    ///     f 10 9 11 12                   \
+    ///     ## 2 elements                  \
+    ///                                    \
+    /// ");
+    /// // let expected = ...;
+    /// # let expected = ObjectSet {
+    /// #     material_libraries: vec![
+    /// #         MtlLibRef::new("material_library.mtl", 2),
+    /// #     ],
+    /// #     external_references: vec![],
+    /// #     unknown_statements: vec![],
+    /// #     warnings: vec![],
+    /// #     objects: vec![
+    /// #         Object {
+    /// #             name: String::from("object1"),
+    /// #             source: None,
+    /// #             vertex_set: vec![
+    /// #                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 2.000000, y: 0.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
+    /// #                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
+    /// #             ].into(),
+    /// #             texture_vertex_set: vec![].into(),
+    /// #             normal_vertex_set: vec![].into(),
+    /// #             group_set: vec![
+    /// #                 Group(String::from("all")),
+    /// #             ].into(),
+    /// #             smoothing_group_set: vec![
+    /// #                 SmoothingGroup(1),
+    /// #             ].into(),
+    /// #             element_set: vec![
+    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
+    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(2), VTNIndex::V(4)),
+    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(4), VTNIndex::V(5)),
+    /// #             ].into(),
+    /// #             shape_set: vec![
+    /// #                 ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #                 ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #                 ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #                 ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #             ].into(),
+    /// #             geometry_set: vec![
+    /// #                 Geometry { material_name: Some(String::from("material1")), shapes: (0..4).map(ShapeEntryIndex).collect() },
+    /// #             ].into(),
+    /// #             element_line_set: vec![],
+    /// #             element_text_set: vec![],
+    /// #             original_element_set: vec![],
+    /// #             numeric_text: Default::default(),
+    /// #         },
+    /// #         Object {
+    /// #             name: String::from("object2"),
+    /// #             source: None,
+    /// #             vertex_set: vec![
+    /// #                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 2.000000, y: 0.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
+    /// #                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
+    /// #                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
+    /// #             ].into(),
+    /// #             texture_vertex_set: vec![].into(),
+    /// #             normal_vertex_set: vec![].into(),
+    /// #             group_set: vec![
+    /// #                 Group(String::from("all")),
+    /// #             ].into(),
+    /// #             smoothing_group_set: vec![
+    /// #                 SmoothingGroup(1),
+    /// #             ].into(),
+    /// #             element_set: vec![
+    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+    /// #                 Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
+    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(2), VTNIndex::V(4)),
+    /// #                 Element::Face(VTNIndex::V(3), VTNIndex::V(4), VTNIndex::V(5)),
+    /// #             ].into(),
+    /// #             shape_set: vec![
+    /// #                 ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #                 ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #                 ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #                 ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+    /// #             ].into(),
+    /// #             geometry_set: vec![
+    /// #                 Geometry { material_name: Some(String::from("material2")), shapes: (0..4).map(ShapeEntryIndex).collect() },
+    /// #             ].into(),
+    /// #             element_line_set: vec![],
+    /// #             element_text_set: vec![],
+    /// #             original_element_set: vec![],
+    /// #             numeric_text: Default::default(),
+    /// #         }
+    /// #     ]
+    /// # };
+    /// let mut parser = Parser::new(&obj_file);
+    /// let result = parser.parse_objset();
+    /// assert!(result.is_ok());
+    ///
+    /// let result = result.unwrap();
+    /// assert_eq!(result, expected)
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn parse_objset(&mut self) -> Result<ObjectSet, ParseError> {
+        self.check_input()?;
+        let mut material_libraries = self.parse_material_libraries()?;
+        let objects = self.parse_objects()?;
+        material_libraries.append(&mut self.material_libraries);
+        let external_references = std::mem::take(&mut self.external_references);
+        let unknown_statements = std::mem::take(&mut self.unknown_statements);
+        let warnings = std::mem::take(&mut self.warnings);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            lines = self.line_number,
+            objects = objects.len(),
+            material_libraries = material_libraries.len(),
+            external_references = external_references.len(),
+            unknown_statements = unknown_statements.len(),
+            "Parsed an object set."
+        );
+
+        Ok(ObjectSet {
+            material_libraries: material_libraries,
+            external_references: external_references,
+            unknown_statements: unknown_statements,
+            warnings: warnings,
+            objects: objects,
+        })
+    }
+
+    /// Parse the next object of the input, returning `None` once every object
+    /// has been parsed.
+    ///
+    /// This is a pull parser over the objects of a Wavefront OBJ file: each
+    /// call parses exactly one object, so an application can process and
+    /// drop the objects of a large file one at a time instead of holding the
+    /// whole object set in memory. The objects are the same as the ones
+    /// [`Parser::parse_objset`] returns, except that under
+    /// [`DuplicateObjectNames::Merge`] an object with a duplicate name is
+    /// returned separately, since the earlier object has already been
+    /// handed out. After an error, every later call returns `None`.
+    ///
+    /// The material libraries of the file are available from
+    /// [`Parser::material_libraries`] as they are encountered. A parser
+    /// should be used either with this method or with
+    /// [`Parser::parse_objset`], not both.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::Parser;
+    /// #
+    /// let obj_file = "o a\nv 0 0 0\np 1\no b\nv 1 1 1\np 2\n";
+    /// let mut parser = Parser::new(obj_file);
+    /// let mut names = vec![];
+    /// while let Some(object) = parser.next_object() {
+    ///     let object = object.unwrap();
+    ///     names.push(object.name);
+    /// }
+    ///
+    /// assert_eq!(names, vec!["a", "b"]);
+    /// ```
+    pub fn next_object(&mut self) -> Option<Result<Object, ParseError>> {
+        let mut stream = match self.object_stream.take() {
+            Some(stream) => stream,
+            None => {
+                let stream = self.check_input().and_then(|_| {
+                    let mut material_libraries = self.parse_material_libraries()?;
+                    material_libraries.append(&mut self.material_libraries);
+                    self.material_libraries = material_libraries;
+                    self.begin_objects()
+                });
+                match stream {
+                    Ok(stream) => stream,
+                    Err(error) => {
+                        let mut stream = ObjectStream::new(None);
+                        stream.finished = true;
+                        self.object_stream = Some(stream);
+
+                        return Some(Err(error));
+                    }
+                }
+            }
+        };
+        if stream.finished || self.peek().is_none() {
+            stream.finished = true;
+            self.object_stream = Some(stream);
+
+            return None;
+        }
+
+        let result = self.parse_next_object(&mut stream);
+        stream.finished = !matches!(result, Ok(Some(_)));
+        self.object_stream = Some(stream);
+
+        result.map(|parsed| parsed.map(|(object, _)| object)).transpose()
+    }
+
+    /// The material libraries named by the `mtllib` statements the parser
+    /// has encountered so far.
+    pub fn material_libraries(&self) -> &[MtlLibRef] {
+        &self.material_libraries
+    }
+
+    /// Count the statements of the input by kind without parsing them.
+    ///
+    /// Only the keyword of each statement is inspected, and nothing is
+    /// allocated, so counting is a cheap first pass over a large file, e.g.
+    /// to estimate the progress of parsing it or to size buffers before
+    /// parsing. Counting does not advance the parser.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::Parser;
+    /// #
+    /// let obj_file = "o quad\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nusemtl red\nf 1 2 3 4\n";
+    /// let counts = Parser::new(obj_file).count();
+    ///
+    /// assert_eq!(counts.statements, 7);
+    /// assert_eq!(counts.objects, 1);
+    /// assert_eq!(counts.vertices, 4);
+    /// assert_eq!(counts.faces, 1);
+    /// assert_eq!(counts.material_uses, 1);
+    /// ```
+    pub fn count(&self) -> StatementCounts {
+        let mut counts = StatementCounts::default();
+        let mut at_statement_start = true;
+        for token in Lexer::new(self.input) {
+            if matches!(token, b"\n" | b"\r\n" | b"\r") {
+                at_statement_start = true;
+                continue;
+            }
+            if !at_statement_start {
+                continue;
+            }
+            at_statement_start = false;
+            counts.statements += 1;
+            match token {
+                b"o" => counts.objects += 1,
+                b"v" => counts.vertices += 1,
+                b"vt" => counts.texture_vertices += 1,
+                b"vn" => counts.normal_vertices += 1,
+                b"p" => counts.points += 1,
+                b"l" => counts.lines += 1,
+                b"f" => counts.faces += 1,
+                b"g" => counts.groups += 1,
+                b"s" => counts.smoothing_groups += 1,
+                b"usemtl" => counts.material_uses += 1,
+                b"mtllib" => counts.material_libraries += 1,
+                _ => {}
+            }
+        }
+
+        counts
+    }
+
+    /// Parse only the vertices of the input, skipping every other statement.
+    ///
+    /// This suits point cloud exports, which consist of `v` statements, and
+    /// any application that only needs the positions of a file. Only `v`
+    /// statements are parsed; every other statement is skipped token by token
+    /// without being checked, so a file whose elements are invalid still
+    /// yields its vertices. A `v` statement may have three extra components
+    /// after **z**, which are the red, green, and blue components of the color
+    /// of the vertex, e.g. `v 0.0 1.0 2.0 1.0 0.5 0.0`. The parser options
+    /// for numbers and the **w** component apply as when parsing objects.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::{
+    /// #     Parser,
+    /// #     VertexColor,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0 1 0 0\nv 1 0 0\ng ignored\nf 1 2 9\n";
+    /// let point_cloud = Parser::new(obj_file).parse_point_cloud().unwrap();
+    ///
+    /// assert_eq!(point_cloud.vertices.len(), 2);
+    /// assert_eq!(point_cloud.colors[0], VertexColor { r: 1.0, g: 0.0, b: 0.0 });
+    /// assert_eq!(point_cloud.colors[1], VertexColor::default());
+    /// ```
+    pub fn parse_point_cloud(&mut self) -> Result<PointCloud, ParseError> {
+        self.check_input()?;
+        let mut vertices = vec![];
+        let mut colors = vec![];
+        loop {
+            match self.peek() {
+                None => break,
+                Some("\n") => self.advance(),
+                Some("v") => {
+                    let (vertex, color) = self.parse_colored_vertex()?;
+                    if let Some(color) = color {
+                        colors.resize(vertices.len(), VertexColor::default());
+                        colors.push(color);
+                    } else if !colors.is_empty() {
+                        colors.push(VertexColor::default());
+                    }
+                    vertices.push(vertex);
+                    match self.peek() {
+                        None | Some("\n") => {}
+                        Some(st) => {
+                            return self.error(
+                                ErrorKind::ExpectedEndOfStatement,
+                                format!("Expected the end of the statement but got `{}` instead.", st),
+                            );
+                        }
+                    }
+                }
+                Some(_) => self.skip_rest_of_line(),
+            }
+        }
+
+        Ok(PointCloud {
+            vertices: vertices,
+            colors: colors,
+        })
+    }
+
+    /// Parse the input, passing each face to a visitor instead of building an
+    /// object set.
+    ///
+    /// The statements are checked as [`Parser::parse_objset`] checks them,
+    /// but vertex data is discarded after it is checked, and nothing is kept
+    /// per element, so a consumer can compute statistics or build its own
+    /// data structures in a single pass over a large file. Points and lines
+    /// are checked and skipped. The visitor also receives the object, groups,
+    /// smoothing group, and material of each face, which follow the same
+    /// rules as in the object set: each object starts in the default group,
+    /// with smoothing off and no material, except that a `usemtl` statement
+    /// before the first object applies to it. If the input fails to parse,
+    /// the faces before the error have already been visited.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::obj::{
+    /// #     GroupContext,
+    /// #     MaterialContext,
+    /// #     Parser,
+    /// #     VTNIndex,
+    /// # };
+    /// #
+    /// let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl red\nf 1 2 3\nusemtl blue\nf 3 2 1\n";
+    /// let mut materials = vec![];
+    /// let mut visitor = |_: &[VTNIndex], _: &GroupContext, material: &MaterialContext| {
+    ///     materials.push(material.material_name.map(String::from));
+    /// };
+    /// Parser::new(obj_file).parse_with_visitor(&mut visitor).unwrap();
+    ///
+    /// assert_eq!(materials, vec![Some(String::from("red")), Some(String::from("blue"))]);
+    /// ```
+    pub fn parse_with_visitor<V: FaceVisitor + ?Sized>(&mut self, visitor: &mut V) -> Result<(), ParseError> {
+        self.check_input()?;
+        let mut object_name = "";
+        let mut object_started = false;
+        let mut groups: Vec<Group> = vec![];
+        let mut smoothing_groups = vec![];
+        let mut material_names = vec![];
+        let mut elements = vec![];
+        let (mut min_vertex_index, mut max_vertex_index) = (0, 0);
+        let (mut min_texture_index, mut max_texture_index) = (0, 0);
+        let (mut min_normal_index, mut max_normal_index) = (0, 0);
+        while let Some(statement) = self.peek() {
+            match statement {
+                "\n" => self.skip_one_or_more_newlines()?,
+                "mtllib" => {
+                    let mut material_libraries = mem::take(&mut self.material_libraries);
+                    let result = self.parse_material_library_line(&mut material_libraries);
+                    self.material_libraries = material_libraries;
+                    result?;
+                }
+                "call" | "csh" => {
+                    let external_reference = self.parse_external_reference()?;
+                    self.external_references.push(external_reference);
+                }
+                "usemtl" => {
+                    self.parse_material_name(&mut material_names)?;
+                }
+                _ => {
+                    if statement == "o" {
+                        if object_started {
+                            groups.clear();
+                            smoothing_groups.clear();
+                            material_names.clear();
+                            min_vertex_index = max_vertex_index;
+                            min_texture_index = max_texture_index;
+                            min_normal_index = max_normal_index;
+                        }
+                        object_name = self.parse_object_name()?;
+                        object_started = true;
+                        if self.rejects_object(object_name) {
+                            self.skip_object_body(
+                                &mut max_vertex_index,
+                                &mut max_texture_index,
+                                &mut max_normal_index,
+                            )?;
+                        }
+                        continue;
+                    }
+                    if !object_started && self.rejects_object(object_name) {
+                        object_started = true;
+                        self.skip_object_body(
+                            &mut max_vertex_index,
+                            &mut max_texture_index,
+                            &mut max_normal_index,
+                        )?;
+                        continue;
+                    }
+                    object_started = true;
+                    match statement {
+                        "g" => {
+                            groups.clear();
+                            self.parse_groups(&mut groups)?;
+                        }
+                        "s" => {
+                            self.parse_smoothing_group(&mut smoothing_groups)?;
+                        }
+                        "v" => {
+                            self.parse_vertex()?;
+                            max_vertex_index += 1;
+                        }
+                        "vt" => {
+                            self.parse_texture_vertex()?;
+                            max_texture_index += 1;
+                        }
+                        "vn" => {
+                            self.parse_normal_vertex()?;
+                            max_normal_index += 1;
+                        }
+                        "p" | "l" | "f" if !self.accepts_groups(&groups) => {
+                            self.skip_rest_of_line();
+                        }
+                        "p" | "l" => {
+                            elements.clear();
+                            self.parse_elements(
+                                &mut elements,
+                                (min_vertex_index, max_vertex_index),
+                                (min_texture_index, max_texture_index),
+                                (min_normal_index, max_normal_index),
+                            )?;
+                        }
+                        "f" => {
+                            let vtn_indices = self.parse_face_corners(
+                                (min_vertex_index, max_vertex_index),
+                                (min_texture_index, max_texture_index),
+                                (min_normal_index, max_normal_index),
+                            )?;
+                            if groups.is_empty() {
+                                groups.push(Default::default());
+                            }
+                            let group_context = GroupContext {
+                                object_name: object_name,
+                                groups: &groups,
+                                smoothing_group: smoothing_groups.last().copied().unwrap_or_default(),
+                            };
+                            let material_context = MaterialContext {
+                                material_name: material_names.last().copied().flatten(),
+                            };
+                            visitor.face(&vtn_indices, &group_context, &material_context);
+                            self.vtn_index_buffer = vtn_indices;
+                        }
+                        _ => self.parse_unknown_statement(statement)?,
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod primitive_tests {
+    use super::Parser;
+
+
+    #[test]
+    fn test_parse_f64() {
+        let mut parser = Parser::new("-1.929448");
+        assert_eq!(parser.parse_f64(), Ok(-1.929448));
+    }
+
+    #[test]
+    fn test_parse_point_index() {
+        let mut parser = Parser::new("    763   ");
+        let st = parser.next_string().unwrap();
+        assert_eq!(parser.parse_point_index(st), Ok(763));
+    }
+}
+
+#[cfg(test)]
+mod vertex_tests {
+    use super::{
+        Parser,
+        ParserOptions,
+        Vertex,
+    };
+
+
+    #[test]
+    fn test_parse_vertex1() {
+        let mut parser = Parser::new("v -1.929448 13.329624 -5.221914\n");
+        let vertex = Vertex {
+            x: -1.929448,
+            y: 13.329624,
+            z: -5.221914,
+            w: 1.0,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex2() {
+        let mut parser = Parser::new("v -1.929448 13.329624 -5.221914 1.329624\n");
+        let vertex = Vertex {
+            x: -1.929448,
+            y: 13.329624,
+            z: -5.221914,
+            w: 1.329624,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_tab_delimited() {
+        let mut parser = Parser::new("v\t-1.929448\t13.329624\t\t-5.221914\t\n");
+        let vertex = Vertex {
+            x: -1.929448,
+            y: 13.329624,
+            z: -5.221914,
+            w: 1.0,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_form_feed_delimited() {
+        let mut parser = Parser::new("v\x0C-1.929448 \x0C13.329624\x0B-5.221914\x0C1.329624\n");
+        let vertex = Vertex {
+            x: -1.929448,
+            y: 13.329624,
+            z: -5.221914,
+            w: 1.329624,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_numeric_literal_forms() {
+        let mut parser = Parser::new("v .5 5. +1.0 1e5\n");
+        let vertex = Vertex {
+            x: 0.5,
+            y: 5.0,
+            z: 1.0,
+            w: 1e5,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_decimal_comma() {
+        let options = ParserOptions {
+            decimal_comma: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options("v 1,5 -2,25 ,5 0,5\n", options);
+        let vertex = Vertex {
+            x: 1.5,
+            y: -2.25,
+            z: 0.5,
+            w: 0.5,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_decimal_comma_requires_option() {
+        let mut parser = Parser::new("v 1,5 -2,25 0,5\n");
+        assert!(parser.parse_vertex().is_err());
+    }
+
+    #[test]
+    fn test_parse_vertex_default_w() {
+        let options = ParserOptions {
+            default_w: 0.0,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options("v 1.0 2.0 3.0\n", options);
+        let vertex = Vertex {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 0.0,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_normalize_w() {
+        let options = ParserOptions {
+            normalize_w: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options("v 1.0 2.0 3.0 2.0\n", options);
+        let vertex = Vertex {
+            x: 0.5,
+            y: 1.0,
+            z: 1.5,
+            w: 1.0,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex_normalize_w_at_infinity() {
+        let options = ParserOptions {
+            normalize_w: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options("v 1.0 2.0 3.0 0.0\n", options);
+        let vertex = Vertex {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 0.0,
+        };
+        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    }
+
+    #[test]
+    fn test_parse_vertex3() {
+        let mut parser = Parser::new("v -1.929448 13.329624 \n");
+        assert!(parser.parse_vertex().is_err());
+    }
+
+    #[test]
+    fn test_parse_vertex4() {
+        let mut parser = Parser::new("v -1.929448 13.329624 -5.221914 1.329624\n v");
+        assert!(parser.parse_vertex().is_ok());
+    }
+
+    #[test]
+    fn test_parse_vertex5() {
+        let mut parser = Parser::new(
+            "v -6.207583 1.699077 8.466142
+              v -14.299248 1.700244 8.468981 1.329624",
+        );
+        assert_eq!(
+            parser.parse_vertex(),
+            Ok(Vertex {
+                x: -6.207583,
+                y: 1.699077,
+                z: 8.466142,
+                w: 1.0,
+            })
+        );
+        assert_eq!(parser.next(), Some("\n"));
+        assert_eq!(
+            parser.parse_vertex(),
+            Ok(Vertex {
+                x: -14.299248,
+                y: 1.700244,
+                z: 8.468981,
+                w: 1.329624,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vertex6() {
+        let mut parser = Parser::new("v -6.207583 1.699077 8.466142 v -14.299248 1.700244 8.468981 1.329624");
+        assert_eq!(
+            parser.parse_vertex(),
+            Ok(Vertex {
+                x: -6.207583,
+                y: 1.699077,
+                z: 8.466142,
+                w: 1.0,
+            })
+        );
+        assert_eq!(parser.peek(), Some("v"));
+        assert_eq!(
+            parser.parse_vertex(),
+            Ok(Vertex {
+                x: -14.299248,
+                y: 1.700244,
+                z: 8.468981,
+                w: 1.329624,
+            })
+        );
+    }
+
+    #[test]
+    fn test_vertex_arithmetic_preserves_w() {
+        let a = Vertex {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 0.5,
+        };
+        let b = Vertex {
+            x: 4.0,
+            y: 5.0,
+            z: 6.0,
+            w: 1.0,
+        };
+
+        assert_eq!(
+            a + b,
+            Vertex {
+                x: 5.0,
+                y: 7.0,
+                z: 9.0,
+                w: 0.5
+            }
+        );
+        assert_eq!(
+            b - a,
+            Vertex {
+                x: 3.0,
+                y: 3.0,
+                z: 3.0,
+                w: 1.0
+            }
+        );
+        assert_eq!(
+            a * 2.0,
+            Vertex {
+                x: 2.0,
+                y: 4.0,
+                z: 6.0,
+                w: 0.5
+            }
+        );
+        assert_eq!(
+            -a,
+            Vertex {
+                x: -1.0,
+                y: -2.0,
+                z: -3.0,
+                w: 0.5
+            }
+        );
+        assert_eq!(a.dot(&b), 32.0);
+        assert_eq!(
+            a.cross(&b),
+            Vertex {
+                x: -3.0,
+                y: 6.0,
+                z: -3.0,
+                w: 0.5
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod texture_vertex_tests {
+    use super::{
+        Parser,
+        TextureVertex,
+    };
+
+
+    #[test]
+    fn test_parse_texture_vertex1() {
+        let mut parser = Parser::new("vt -1.929448");
+        let vt = TextureVertex {
+            u: -1.929448,
+            v: 0.0,
+            w: 0.0,
+        };
+        assert_eq!(parser.parse_texture_vertex(), Ok(vt));
+    }
+
+    #[test]
+    fn test_parse_texture_vertex2() {
+        let mut parser = Parser::new("vt -1.929448 13.329624 -5.221914");
+        let vt = TextureVertex {
+            u: -1.929448,
+            v: 13.329624,
+            w: -5.221914,
+        };
+        assert_eq!(parser.parse_texture_vertex(), Ok(vt));
+    }
+
+    #[test]
+    fn test_parse_texture_vertex3() {
+        let mut parser = Parser::new(
+            "vt -1.929448 13.329624 -5.221914
+             vt -27.6068  31.1438    27.2099",
+        );
+        assert_eq!(
+            parser.parse_texture_vertex(),
+            Ok(TextureVertex {
+                u: -1.929448,
+                v: 13.329624,
+                w: -5.221914,
+            })
+        );
+        assert_eq!(parser.next(), Some("\n"));
+        assert_eq!(
+            parser.parse_texture_vertex(),
+            Ok(TextureVertex {
+                u: -27.6068,
+                v: 31.1438,
+                w: 27.2099,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_texture_vertex4() {
+        let mut parser = Parser::new("vt -1.929448 13.329624 -5.221914 vt -27.6068  31.1438    27.2099");
+        assert_eq!(
+            parser.parse_texture_vertex(),
+            Ok(TextureVertex {
+                u: -1.929448,
+                v: 13.329624,
+                w: -5.221914,
+            })
+        );
+        assert_eq!(parser.peek(), Some("vt"));
+        assert_eq!(
+            parser.parse_texture_vertex(),
+            Ok(TextureVertex {
+                u: -27.6068,
+                v: 31.1438,
+                w: 27.2099,
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod normal_vertex_tests {
+    use super::{
+        NormalVertex,
+        Parser,
+    };
+
+
+    #[test]
+    fn test_parse_normal_vertex1() {
+        let mut parser = Parser::new("vn  -0.966742  -0.255752  9.97231e-09");
+        let vn = NormalVertex {
+            x: -0.966742,
+            y: -0.255752,
+            z: 9.97231e-09,
+        };
+        assert_eq!(parser.parse_normal_vertex(), Ok(vn));
+    }
+
+    #[test]
+    fn test_parse_normal_vertex2() {
+        let mut parser = Parser::new(
+            "vn -1.929448 13.329624 -5.221914
+             vn -27.6068  31.1438    27.2099",
+        );
+        assert_eq!(
+            parser.parse_normal_vertex(),
+            Ok(NormalVertex {
+                x: -1.929448,
+                y: 13.329624,
+                z: -5.221914,
+            })
+        );
+        assert_eq!(parser.next(), Some("\n"));
+        assert_eq!(
+            parser.parse_normal_vertex(),
+            Ok(NormalVertex {
+                x: -27.6068,
+                y: 31.1438,
+                z: 27.2099,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_normal_vertex3() {
+        let mut parser = Parser::new("vn -1.929448 13.329624 -5.221914 vn -27.6068  31.1438    27.2099");
+        assert_eq!(
+            parser.parse_normal_vertex(),
+            Ok(NormalVertex {
+                x: -1.929448,
+                y: 13.329624,
+                z: -5.221914,
+            })
+        );
+        assert_eq!(parser.peek(), Some("vn"));
+        assert_eq!(
+            parser.parse_normal_vertex(),
+            Ok(NormalVertex {
+                x: -27.6068,
+                y: 31.1438,
+                z: 27.2099,
+            })
+        );
+    }
+
+    #[test]
+    fn test_normal_vertex_arithmetic() {
+        let a = NormalVertex {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let b = NormalVertex {
+            x: 4.0,
+            y: 5.0,
+            z: 6.0,
+        };
+
+        assert_eq!(
+            a + b,
+            NormalVertex {
+                x: 5.0,
+                y: 7.0,
+                z: 9.0
+            }
+        );
+        assert_eq!(
+            b - a,
+            NormalVertex {
+                x: 3.0,
+                y: 3.0,
+                z: 3.0
+            }
+        );
+        assert_eq!(
+            a * 2.0,
+            NormalVertex {
+                x: 2.0,
+                y: 4.0,
+                z: 6.0
+            }
+        );
+        assert_eq!(
+            -a,
+            NormalVertex {
+                x: -1.0,
+                y: -2.0,
+                z: -3.0
+            }
+        );
+        assert_eq!(a.dot(&b), 32.0);
+        assert_eq!(
+            a.cross(&b),
+            NormalVertex {
+                x: -3.0,
+                y: 6.0,
+                z: -3.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_normal_vertex_normalize_zero_length() {
+        let zero = NormalVertex {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        assert_eq!(zero.normalize(), zero);
+    }
+}
+
+#[cfg(test)]
+mod object_tests {
+    use super::{
+        Element,
+        ElementIndex,
+        Parser,
+        ParserOptions,
+        VTNIndex,
+    };
+
+
+    #[test]
+    fn test_parse_object_name1() {
+        let mut parser = Parser::new("o object_name \n\n");
+        assert_eq!(parser.parse_object_name(), Ok("object_name"));
+    }
+
+    #[test]
+    fn test_parse_object_name2() {
+        let mut parser = Parser::new("o object_name");
+        assert!(parser.parse_object_name().is_err());
+    }
+
+    #[test]
+    fn test_parse_object_name_with_spaces() {
+        let mut parser = Parser::new("o  Left  Front Wheel \t# the wheel\n");
+        assert_eq!(parser.parse_object_name(), Ok("Left  Front Wheel"));
+    }
+
+    #[test]
+    fn test_parse_object_name_empty() {
+        let mut parser = Parser::new("o\nv 0 0 0\n");
+        assert_eq!(parser.parse_object_name(), Ok(""));
+    }
+
+    #[test]
+    fn test_parse_objset_multi_word_object_name() {
+        let mut parser = Parser::new("o first object\nv 0 0 0\np 1\no second object\nv 1 1 1\np 2\n");
+        let result = parser.parse_objset().unwrap();
+        let names: Vec<&str> = result.objects.iter().map(|object| object.name.as_str()).collect();
+
+        assert_eq!(names, vec!["first object", "second object"]);
+    }
+
+    #[test]
+    fn test_element_lines_are_not_recorded_by_default() {
+        let mut parser = Parser::new("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n");
+        let result = parser.parse_objset().unwrap();
+
+        assert!(result.objects[0].element_line_set.is_empty());
+        assert_eq!(result.objects[0].element_line(ElementIndex(0)), None);
+    }
+
+    #[test]
+    fn test_element_lines() {
+        let obj_file = "\
+            o first\n\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 1 1 0\n\
+            v 0 1 0\n\
+            # A quad that is triangulated into two faces.\n\
+            f 1 2 3 4\n\
+            \n\
+            l 1 2\n\
+            o second\n\
+            v 0 0 1\n\
+            p 5\n\
+        ";
+        let options = ParserOptions {
+            record_element_lines: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(obj_file, options);
+        let result = parser.parse_objset().unwrap();
+
+        assert_eq!(result.objects[0].element_line_set, vec![7, 7, 9]);
+        assert_eq!(result.objects[0].element_line(ElementIndex(2)), Some(9));
+        assert_eq!(result.objects[1].element_line_set, vec![12]);
+    }
+
+    #[test]
+    fn test_element_text() {
+        let obj_file = "\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 1 1 0\n\
+            v 0 1 0\n\
+            f  1 2 3   4  # A quad.\n\
+            l 1 2\r\n\
+            p 3#A point.\
+        ";
+        let options = ParserOptions {
+            record_element_text: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(obj_file, options);
+        let result = parser.parse_objset().unwrap();
+        let expected = vec![
+            String::from("f  1 2 3   4"),
+            String::from("f  1 2 3   4"),
+            String::from("l 1 2"),
+            String::from("p 3"),
+        ];
+
+        assert_eq!(result.objects[0].element_text_set, expected);
+        assert_eq!(result.objects[0].element_text(ElementIndex(2)), Some("l 1 2"));
+        assert!(result.objects[0].element_line_set.is_empty());
+    }
+
+    #[test]
+    fn test_original_indices() {
+        let obj_file = "\
+            o first\n\
+            v 0 0 0\n\
+            v 0 0 1\n\
+            vt 0 0\n\
+            l 1/1 2/1\n\
+            o second\n\
+            v 1 0 0\n\
+            v 1 1 0\n\
+            v 0 1 0\n\
+            vt 1 0\n\
+            vn 0 0 1\n\
+            f 3//1 4//1 5//1\n\
+            f 3/2 4/2 5/2\n\
+        ";
+        let options = ParserOptions {
+            record_original_indices: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(obj_file, options);
+        let result = parser.parse_objset().unwrap();
+        let expected = vec![
+            Element::Face(VTNIndex::VN(3, 1), VTNIndex::VN(4, 1), VTNIndex::VN(5, 1)),
+            Element::Face(VTNIndex::VT(3, 2), VTNIndex::VT(4, 2), VTNIndex::VT(5, 2)),
+        ];
+
+        assert_eq!(
+            result.objects[0].original_element_set,
+            vec![Element::Line(VTNIndex::VT(1, 1), VTNIndex::VT(2, 1))]
+        );
+        assert_eq!(result.objects[1].original_element_set, expected);
+        assert_eq!(
+            result.objects[1].element_set[ElementIndex(0)],
+            Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 0), VTNIndex::VN(2, 0))
+        );
+    }
+}
+
+#[cfg(test)]
+mod face_builder_tests {
+    use super::{
+        ErrorKind,
+        FaceBuilder,
+        Object,
+        VTNIndex,
+    };
+
+
+    fn object(obj_file: &str) -> Object {
+        super::parse(obj_file).unwrap().objects.remove(0)
+    }
+
+    #[test]
+    fn test_try_push_face_matches_the_parser() {
+        let vertices = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\n";
+        let mut result = object(vertices);
+        let quad = [
+            VTNIndex::VTN(0, 0, 0),
+            VTNIndex::VTN(1, 0, 0),
+            VTNIndex::VTN(2, 0, 0),
+            VTNIndex::VTN(3, 0, 0),
+        ];
+        assert_eq!(result.try_push_face(&quad), Ok(2));
+        let expected = object(&format!("{}f 1/1/1 2/1/1 3/1/1 4/1/1\n", vertices));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_push_face_joins_the_last_element() {
+        let mut result = object("v 0 0 0\nv 1 0 0\nv 0 1 0\ng a b\ns 2\nusemtl red\np 1\n");
+        result
+            .try_push_face(&[VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)])
+            .unwrap();
+        let expected = object("v 0 0 0\nv 1 0 0\nv 0 1 0\ng a b\ns 2\nusemtl red\np 1\nf 1 2 3\n");
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_try_push_face_rejects_invalid_faces() {
+        let original = object("v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\n");
+        let cases = [
+            (
+                vec![VTNIndex::V(0), VTNIndex::V(1)],
+                ErrorKind::EveryFaceElementMustHaveAtLeastThreeVertices,
+            ),
+            (
+                vec![VTNIndex::V(0), VTNIndex::VT(1, 0), VTNIndex::V(2)],
+                ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
+            ),
+            (
+                vec![VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(3)],
+                ErrorKind::VTNIndexOutOfRange,
+            ),
+            (
+                vec![VTNIndex::VT(0, 0), VTNIndex::VT(1, 1), VTNIndex::VT(2, 0)],
+                ErrorKind::VTNIndexOutOfRange,
+            ),
+            (
+                vec![VTNIndex::VN(0, 0), VTNIndex::VN(1, 0), VTNIndex::VN(2, 0)],
+                ErrorKind::VTNIndexOutOfRange,
+            ),
+        ];
+        for (vtn_indices, kind) in cases.iter() {
+            let mut result = original.clone();
+            let error = result.try_push_face(vtn_indices).unwrap_err();
+
+            assert_eq!(error.kind(), kind);
+            assert_eq!(result, original);
+        }
+    }
+
+    #[test]
+    fn test_face_builder_keeps_corners_on_failure() {
+        let mut result = object("v 0 0 0\nv 1 0 0\nv 0 1 0\n");
+        let mut face = FaceBuilder::new();
+        face.corner(VTNIndex::V(0))
+            .corner(VTNIndex::V(5))
+            .corner(VTNIndex::V(2));
+
+        assert!(face.push_to(&mut result).is_err());
+        assert_eq!(face.corners().len(), 3);
+        face.clear();
+        assert!(face.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod group_editing_tests {
+    use super::{
+        ElementIndex,
+        Group,
+        GroupIndex,
+        Object,
+        ShapeEntryIndex,
+    };
+    use std::sync::Arc;
+
+
+    fn object() -> Object {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ng a\nf 1 2 3\nf 3 2 1\ng b c\nf 1 3 2\ng c\nf 2 1 3\n";
+
+        super::parse(obj_file).unwrap().objects.remove(0)
+    }
+
+    fn all_groups(object: &Object) -> Vec<Vec<&str>> {
+        object
+            .element_set
+            .indices()
+            .map(|element| object.groups_of(element))
+            .collect()
+    }
+
+    #[test]
+    fn test_assign_group_adds_the_group_once() {
+        let mut result = object();
+        let elements = [ElementIndex(0), ElementIndex(2), ElementIndex(3), ElementIndex(9)];
+
+        assert_eq!(result.assign_group(&elements, "d"), GroupIndex(4));
+        assert_eq!(
+            result.assign_group(&[ElementIndex(0), ElementIndex(1)], "d"),
+            GroupIndex(4)
+        );
+        assert_eq!(
+            all_groups(&result),
+            vec![vec!["d"], vec!["d"], vec!["d"], vec!["d"]]
+        );
+        assert_eq!(result.group_set.iter().filter(|group| group.0 == "d").count(), 1);
+    }
+
+    #[test]
+    fn test_rename_group() {
+        let mut result = object();
+
+        assert!(result.rename_group("c", "e"));
+        assert_eq!(
+            all_groups(&result),
+            vec![vec!["a"], vec!["a"], vec!["b", "e"], vec!["e"]]
+        );
+        assert_eq!(result.group_set.len(), 3);
+    }
+
+    #[test]
+    fn test_rename_group_merges_into_an_existing_group() {
+        let mut result = object();
+
+        assert!(result.rename_group("b", "c"));
+        assert_eq!(
+            all_groups(&result),
+            vec![vec!["a"], vec!["a"], vec!["c"], vec!["c"]]
+        );
+        assert_eq!(
+            result.group_set.as_slice(),
+            &[Group(String::from("a")), Group(String::from("c"))]
+        );
+        assert!(Arc::ptr_eq(
+            &result.shape_set[ShapeEntryIndex(0)].groups,
+            &result.shape_set[ShapeEntryIndex(1)].groups
+        ));
+    }
+
+    #[test]
+    fn test_remove_group() {
+        let mut result = object();
+
+        assert!(result.remove_group("c"));
+        assert_eq!(
+            all_groups(&result),
+            vec![vec!["a"], vec!["a"], vec!["b"], vec!["default"]]
+        );
+        assert!(result.remove_group("a"));
+        assert_eq!(
+            all_groups(&result),
+            vec![vec!["default"], vec!["default"], vec!["b"], vec!["default"]]
+        );
+        assert_eq!(result.group_set.len(), 2);
+        assert!(!result.remove_group("a"));
+    }
+
+    #[test]
+    fn test_group_edits_survive_writing() {
+        let mut object_set =
+            super::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\ng a b\nf 1 2 3\ng b\nf 3 2 1\n").unwrap();
+        object_set.objects[0].rename_group("a", "front");
+        object_set.objects[0].remove_group("b");
+        let reparsed = super::parse(object_set.to_string()).unwrap();
+
+        assert_eq!(
+            all_groups(&reparsed.objects[0]),
+            all_groups(&object_set.objects[0])
+        );
+        assert_eq!(
+            all_groups(&reparsed.objects[0]),
+            vec![vec!["front"], vec!["default"]]
+        );
+    }
+}
+
+#[cfg(test)]
+mod smoothing_group_editing_tests {
+    use super::{
+        ElementIndex,
+        Object,
+        SmoothingGroup,
+    };
+
+
+    fn object() -> Object {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ns 1\nf 1 2 3\nf 3 2 1\ns off\nf 1 3 2\nf 2 1 3\n";
+
+        super::parse(obj_file).unwrap().objects.remove(0)
+    }
+
+    fn all_smoothing_groups(object: &Object) -> Vec<usize> {
+        object
+            .element_set
+            .indices()
+            .map(|element| object.smoothing_group_of(element).unwrap().0)
+            .collect()
+    }
+
+    #[test]
+    fn test_set_smoothing_group_reuses_an_existing_smoothing_group() {
+        let mut result = object();
+        let smoothing_group_count = result.smoothing_group_set.len();
+
+        assert_eq!(
+            result.set_smoothing_group(ElementIndex(1)..ElementIndex(3), SmoothingGroup(1)),
+            1
+        );
+        assert_eq!(all_smoothing_groups(&result), vec![1, 1, 1, 0]);
+        assert_eq!(result.smoothing_group_set.len(), smoothing_group_count);
+    }
+
+    #[test]
+    fn test_set_smoothing_group_ignores_elements_out_of_range() {
+        let mut result = object();
+
+        assert_eq!(
+            result.set_smoothing_group(ElementIndex(2)..ElementIndex(10), SmoothingGroup(7)),
+            2
+        );
+        assert_eq!(all_smoothing_groups(&result), vec![1, 1, 7, 7]);
+        assert_eq!(
+            result.set_smoothing_group(ElementIndex(10)..ElementIndex(12), SmoothingGroup(7)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_clear_smoothing_group_survives_writing() {
+        let mut object_set = super::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\ns 3\nf 1 2 3\nf 3 2 1\n").unwrap();
+        object_set.objects[0].clear_smoothing_group(ElementIndex(0)..ElementIndex(1));
+        let reparsed = super::parse(object_set.to_string()).unwrap();
+
+        assert_eq!(all_smoothing_groups(&reparsed.objects[0]), vec![0, 3]);
+    }
+}
+
+#[cfg(test)]
+mod object_soa_tests {
+    use super::{
+        NormalVertex,
+        ObjectSoA,
+        TextureVertex,
+        Vertex,
+    };
+
+
+    #[test]
+    fn test_object_soa_from_object() {
+        let obj_file = "o box\nv 1 2 3 0.5\nv 4 5 6\nvt 0.25 0.75 1\nvn 0 1 0\nf 1/1/1 2/1/1 1/1/1\n";
+        let object_set = super::parse(obj_file).unwrap();
+        let object = &object_set.objects[0];
+        let result = ObjectSoA::from(object);
+
+        assert_eq!(result.name, "box");
+        assert_eq!(result.vertices.len(), object.vertex_set.len());
+        for (i, vertex) in object.vertex_set.iter().enumerate() {
+            assert_eq!(result.vertices.get(i), Some(*vertex));
+        }
+        assert_eq!(result.vertices.w, vec![0.5, 1.0]);
+        assert_eq!(
+            result.texture_vertices.get(0),
+            Some(object.texture_vertex_set.as_slice()[0])
+        );
+        assert_eq!(
+            result.normal_vertices.get(0),
+            Some(object.normal_vertex_set.as_slice()[0])
+        );
+        assert_eq!(result.vertices.get(2), None);
+        assert_eq!(result.element_set, object.element_set);
+    }
+
+    #[test]
+    fn test_object_soa_packed_arrays() {
+        let result = ObjectSoA {
+            vertices: vec![Vertex {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                w: 4.0,
+            }]
+            .into_iter()
+            .collect(),
+            texture_vertices: vec![TextureVertex {
+                u: 0.5,
+                v: 0.25,
+                w: 1.0,
+            }]
+            .into_iter()
+            .collect(),
+            normal_vertices: vec![NormalVertex {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            }]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        assert_eq!(result.vertices.packed(), vec![[1.0, 2.0, 3.0]]);
+        assert_eq!(result.texture_vertices.packed(), vec![[0.5, 0.25]]);
+        assert_eq!(result.normal_vertices.packed(), vec![[0.0, 0.0, -1.0]]);
+        assert!(ObjectSoA::default().vertices.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod generate_normals_tests {
+    use super::{
+        Element,
+        ElementIndex,
+        NormalVertex,
+        NormalVertexIndex,
+        Object,
+        Tangent,
+        VTNIndex,
+    };
+
+
+    fn bent_quad(smoothing: &str) -> Object {
+        let obj_file = format!("v 0 0 0\nv 1 0 0\nv 0 1 0\nv 0 0 1\n{}", smoothing);
+
+        super::parse(obj_file).unwrap().objects.remove(0)
+    }
+
+    fn grid(size: usize) -> Object {
+        let mut obj_file = String::new();
+        for j in 0..=size {
+            for i in 0..=size {
+                obj_file += &format!("v {} {} {}\n", i, j, (i * j) % 3);
+            }
+        }
+        for j in 0..size {
+            obj_file += &format!("s {}\n", j % 3);
+            for i in 0..size {
+                let corner = j * (size + 1) + i + 1;
+                obj_file += &format!(
+                    "f {} {} {} {}\n",
+                    corner,
+                    corner + 1,
+                    corner + size + 2,
+                    corner + size + 1
+                );
+            }
+        }
+        obj_file += "vt 0 0\np 1\nl 1/1 2/1\n";
+
+        super::parse(obj_file).unwrap().objects.remove(0)
+    }
+
+    #[test]
+    fn test_generate_normals_flat_faces_get_face_normals() {
+        let mut object = bent_quad("s off\nf 1 2 3\nf 1 4 2\n");
+        object.generate_normals();
+
+        assert_eq!(
+            object.normal_vertex_set.as_slice(),
+            &[
+                NormalVertex {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 1.0
+                },
+                NormalVertex {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 0.0
+                },
+            ]
+        );
+        assert_eq!(
+            object.element_set.as_slice(),
+            &[
+                Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 0), VTNIndex::VN(2, 0)),
+                Element::Face(VTNIndex::VN(0, 1), VTNIndex::VN(3, 1), VTNIndex::VN(1, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_normals_averages_shared_vertices_in_a_smoothing_group() {
+        let mut object = bent_quad("s 1\nf 1 2 3\nf 1 4 2\n");
+        object.generate_normals();
+        let half = 0.5_f64.sqrt();
+        let shared = object.normal_vertex_set[NormalVertexIndex(0)];
+
+        assert_eq!(object.normal_vertex_set.len(), 4);
+        assert_eq!(shared, object.normal_vertex_set[NormalVertexIndex(1)]);
+        assert!(shared.x == 0.0 && (shared.y - half).abs() < 1e-12 && (shared.z - half).abs() < 1e-12);
+        assert_eq!(
+            object.normal_vertex_set[NormalVertexIndex(2)],
+            NormalVertex {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0
+            }
+        );
+        assert_eq!(
+            object.normal_vertex_set[NormalVertexIndex(3)],
+            NormalVertex {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0
+            }
+        );
+        assert_eq!(
+            object.element_set[ElementIndex(1)],
+            Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(3, 3), VTNIndex::VN(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_generate_normals_keeps_smoothing_groups_apart() {
+        let mut object = bent_quad("s 1\nf 1 2 3\ns 2\nf 1 4 2\n");
+        object.generate_normals();
+
+        assert_eq!(object.normal_vertex_set.len(), 6);
+        assert_eq!(
+            object.element_set.as_slice(),
+            &[
+                Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 2), VTNIndex::VN(2, 4)),
+                Element::Face(VTNIndex::VN(0, 1), VTNIndex::VN(3, 5), VTNIndex::VN(1, 3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_normals_keeps_texture_vertices_and_strips_lines() {
+        let mut object = grid(2);
+        let element_count = object.element_set.len();
+        object.element_set[ElementIndex(element_count - 1)] =
+            Element::Line(VTNIndex::VTN(0, 0, 0), VTNIndex::VTN(1, 0, 0));
+        object.generate_normals();
+
+        assert_eq!(
+            object.element_set[ElementIndex(element_count - 2)],
+            Element::Point(VTNIndex::V(0))
+        );
+        assert_eq!(
+            object.element_set[ElementIndex(element_count - 1)],
+            Element::Line(VTNIndex::VT(0, 0), VTNIndex::VT(1, 0))
+        );
+        assert!(object.element_set.iter().all(|element| match *element {
+            Element::Face(vtn1, vtn2, vtn3) => [vtn1, vtn2, vtn3].iter().all(|vtn| vtn
+                .normal_vertex_index()
+                .is_some_and(|vn| vn.0 < object.normal_vertex_set.len())),
+            _ => true,
+        }));
+    }
+
+    #[test]
+    fn test_generate_normals_parallel_matches_serial() {
+        let mut expected = grid(9);
+        expected.generate_normals();
+        for max_threads in [0, 2, 3, 7, 64] {
+            let mut result = grid(9);
+            result.generate_normals_parallel(max_threads);
+
+            assert_eq!(result, expected, "max_threads = {}", max_threads);
+        }
+    }
+
+    #[test]
+    fn test_generate_normals_without_faces() {
+        let mut object = super::parse("v 0 0 0\nvn 0 0 1\np 1\n")
+            .unwrap()
+            .objects
+            .remove(0);
+        object.element_set[ElementIndex(0)] = Element::Point(VTNIndex::VN(0, 0));
+        object.generate_normals_parallel(4);
+
+        assert!(object.normal_vertex_set.is_empty());
+        assert_eq!(
+            object.element_set[ElementIndex(0)],
+            Element::Point(VTNIndex::V(0))
+        );
+    }
+
+    fn textured_grid(size: usize) -> Object {
+        let mut obj_file = String::new();
+        for j in 0..=size {
+            for i in 0..=size {
+                obj_file += &format!("v {} {} {}\nvt {} {}\n", i, j, (i * j) % 3, i, j);
+            }
+        }
+        obj_file += "s 1\n";
+        for j in 0..size {
+            for i in 0..size {
+                let corner = j * (size + 1) + i + 1;
+                obj_file += &format!(
+                    "f {0}/{0} {1}/{1} {2}/{2}\n",
+                    corner,
+                    corner + 1,
+                    corner + size + 2
+                );
+            }
+        }
+        let mut object = super::parse(obj_file).unwrap().objects.remove(0);
+        object.generate_normals();
+
+        object
+    }
+
+    #[test]
+    fn test_generate_tangents_follow_the_texture_coordinates() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 0 1\nvt 1 0\nvn 0 0 1\n\
+            f 1/1/1 2/2/1 3/3/1\n";
+        let object = super::parse(obj_file).unwrap().objects.remove(0);
+
+        assert_eq!(
+            object.generate_tangents(),
+            vec![Tangent {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+                w: -1.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_generate_tangents_without_texture_vertices_are_orthogonal() {
+        let object = super::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1\n")
+            .unwrap()
+            .objects
+            .remove(0);
+
+        assert_eq!(
+            object.generate_tangents(),
+            vec![Tangent {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_generate_tangents_parallel_matches_serial() {
+        let object = textured_grid(9);
+        let expected = object.generate_tangents();
+
+        assert_eq!(expected.len(), object.normal_vertex_set.len());
+        for (tangent, normal) in expected.iter().zip(object.normal_vertex_set.iter()) {
+            let dot = tangent.x * normal.x + tangent.y * normal.y + tangent.z * normal.z;
+            let magnitude = (tangent.x * tangent.x + tangent.y * tangent.y + tangent.z * tangent.z).sqrt();
+            assert!(dot.abs() < 1e-12);
+            assert!((magnitude - 1.0).abs() < 1e-12);
+        }
+        for max_threads in [0, 2, 3, 7, 64] {
+            let result = object.generate_tangents_parallel(max_threads);
+
+            assert_eq!(result, expected, "max_threads = {}", max_threads);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod shape_run_tests {
+    use super::{
+        GroupIndex,
+        ShapeRun,
+        SmoothingGroupIndex,
+    };
+
+
+    #[test]
+    fn test_shape_runs_split_on_groups_smoothing_groups_and_materials() {
+        let obj_file = "\
+            v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n\
+            g a\nf 1 2 3 4\nf 1 2 3\n\
+            s 1\nf 1 2 3\n\
+            g a b\nf 1 2 3\n\
+            usemtl red\nf 1 2 3\nl 1 2\n\
+        ";
+        let object_set = super::parse(obj_file).unwrap();
+        let object = &object_set.objects[0];
+        let runs: Vec<ShapeRun> = object.shape_runs().collect();
+
+        assert_eq!(
+            runs,
+            vec![
+                ShapeRun {
+                    elements: 0..3,
+                    groups: &[GroupIndex(0)],
+                    smoothing_group: SmoothingGroupIndex(0),
+                    material_name: None,
+                },
+                ShapeRun {
+                    elements: 3..4,
+                    groups: &[GroupIndex(0)],
+                    smoothing_group: SmoothingGroupIndex(1),
+                    material_name: None,
+                },
+                ShapeRun {
+                    elements: 4..5,
+                    groups: &[GroupIndex(1), GroupIndex(2)],
+                    smoothing_group: SmoothingGroupIndex(1),
+                    material_name: None,
+                },
+                ShapeRun {
+                    elements: 5..7,
+                    groups: &[GroupIndex(1), GroupIndex(2)],
+                    smoothing_group: SmoothingGroupIndex(1),
+                    material_name: Some("red"),
+                },
+            ]
+        );
+        assert_eq!(
+            runs.iter().map(ShapeRun::len).sum::<usize>(),
+            object.element_set.len()
+        );
+    }
+
+    #[test]
+    fn test_shape_runs_of_object_without_elements() {
+        let object_set = super::parse("v 0 0 0\n").unwrap();
+
+        assert_eq!(object_set.objects[0].shape_runs().count(), 0);
+    }
+
+    #[test]
+    fn test_shape_runs_split_on_gaps_in_the_element_set() {
+        let mut object = super::parse("v 0 0 0\np 1\np 1\np 1\n")
+            .unwrap()
+            .objects
+            .remove(0);
+        object.shape_set.remove(1);
+        let runs: Vec<_> = object.shape_runs().map(|run| run.elements).collect();
+
+        assert_eq!(runs, vec![0..1, 2..3]);
+    }
+}
+
+
+#[cfg(test)]
+mod next_object_tests {
+    use super::{
+        DuplicateObjectNames,
+        ErrorKind,
+        MtlLibRef,
+        Object,
+        Parser,
+        ParserOptions,
+    };
+
+
+    const OBJ_FILE: &str = "\
+        mtllib first.mtl\n\
+        usemtl red\n\
+        o a\n\
+        v 0 0 0\nv 1 0 0\nv 0 1 0\n\
+        f 1 2 3\n\
+        mtllib second.mtl\n\
+        o b\n\
+        v 0 0 1\nv 1 0 1\nv 0 1 1\n\
+        f -3 -2 -1\n\
+        o a\n\
+        v 0 0 2\n\
+        p 7\n\
+    ";
+
+    fn collect_objects(parser: &mut Parser) -> Vec<Result<Object, super::ParseError>> {
+        let mut objects = vec![];
+        while let Some(object) = parser.next_object() {
+            objects.push(object);
+        }
+
+        objects
+    }
+
+    #[test]
+    fn test_next_object_matches_parse_objset() {
+        let expected = Parser::new(OBJ_FILE).parse_objset().unwrap();
+        let mut parser = Parser::new(OBJ_FILE);
+        let result: Vec<Object> = collect_objects(&mut parser)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(result, expected.objects);
+        assert_eq!(
+            parser.material_libraries(),
+            expected.material_libraries.as_slice()
+        );
+        assert_eq!(
+            parser.material_libraries(),
+            &[MtlLibRef::new("first.mtl", 1), MtlLibRef::new("second.mtl", 8)]
+        );
+    }
+
+    #[test]
+    fn test_next_object_returns_none_once_exhausted() {
+        let mut parser = Parser::new("o a\nv 0 0 0\np 1\n");
+
+        assert!(parser.next_object().is_some());
+        assert!(parser.next_object().is_none());
+        assert!(parser.next_object().is_none());
+    }
+
+    #[test]
+    fn test_next_object_of_empty_input() {
+        let mut parser = Parser::new("\n\n");
+
+        assert!(parser.next_object().is_none());
+    }
+
+    #[test]
+    fn test_next_object_stops_after_an_error() {
+        let mut parser = Parser::new("o a\nv 0 0 0\np 1\no b\np 5\no c\nv 0 0 0\np 3\n");
+
+        assert!(parser.next_object().unwrap().is_ok());
+        assert_eq!(
+            parser.next_object().unwrap().unwrap_err().kind(),
+            &ErrorKind::VTNIndexOutOfRange
+        );
+        assert!(parser.next_object().is_none());
+    }
+
+    #[test]
+    fn test_next_object_reports_binary_input() {
+        let mut parser = Parser::new("v 0 0 0\0\n");
+
+        assert!(parser.next_object().unwrap().is_err());
+        assert!(parser.next_object().is_none());
+    }
+
+    #[test]
+    fn test_next_object_duplicate_object_names() {
+        let names = |policy| {
+            let options = ParserOptions {
+                duplicate_object_names: policy,
+                ..ParserOptions::default()
+            };
+            let mut parser = Parser::with_options(OBJ_FILE, options);
+
+            collect_objects(&mut parser)
+                .into_iter()
+                .map(|object| object.map(|object| object.name))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(DuplicateObjectNames::Rename),
+            vec![
+                Ok(String::from("a")),
+                Ok(String::from("b")),
+                Ok(String::from("a.001"))
+            ]
+        );
+        assert_eq!(
+            names(DuplicateObjectNames::Merge),
+            vec![
+                Ok(String::from("a")),
+                Ok(String::from("b")),
+                Ok(String::from("a"))
+            ]
+        );
+        let rejected = names(DuplicateObjectNames::Reject);
+        assert_eq!(rejected.len(), 3);
+        assert_eq!(
+            rejected[2].as_ref().unwrap_err().kind(),
+            &ErrorKind::DuplicateObjectName
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod scan_tests {
+    use super::{
+        MtlLibRef,
+        ObjSummary,
+        ObjectSet,
+        ObjectSummary,
+    };
+    use crate::testing::Generator;
+
+
+    fn summarize(object_set: &ObjectSet) -> Vec<(String, usize, usize, usize, usize)> {
+        object_set
+            .objects
+            .iter()
+            .map(|object| {
+                (
+                    object.name.clone(),
+                    object.vertex_set.len(),
+                    object.texture_vertex_set.len(),
+                    object.normal_vertex_set.len(),
+                    object.element_set.len(),
+                )
+            })
+            .collect()
+    }
+
+    fn summarize_scan(summary: &ObjSummary) -> Vec<(String, usize, usize, usize, usize)> {
+        summary
+            .objects
+            .iter()
+            .map(|object| {
+                (
+                    object.name.clone(),
+                    object.vertices,
+                    object.texture_vertices,
+                    object.normal_vertices,
+                    object.elements(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_scan() {
+        let obj_file = "\
+            mtllib a.mtl b.mtl\n\
+            # A comment.\n\
+            usemtl leading\n\
+            v 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\n\
+            p 1 2\n\
+            o big box\n\
+            v 0 0 1\nv 1 0 1\nv 0 1 1\nv 1 1 1\n\
+            vt 0 0\nvn 0 0 1\n\
+            g a b\ng b\n\
+            usemtl red\nl 5 6 7\nusemtl blue\nusemtl red\n\
+            f 5 6 7 8 # A quad.\n\
+            mtllib c.mtl\n\
+        ";
+        let summary = super::scan(obj_file);
+
+        assert_eq!(
+            summary,
+            ObjSummary {
+                material_libraries: vec![
+                    MtlLibRef::new("a.mtl", 1),
+                    MtlLibRef::new("b.mtl", 1),
+                    MtlLibRef::new("c.mtl", 23),
+                ],
+                objects: vec![
+                    ObjectSummary {
+                        name: String::from(""),
+                        material_names: vec![String::from("leading")],
+                        vertices: 4,
+                        points: 2,
+                        ..ObjectSummary::default()
+                    },
+                    ObjectSummary {
+                        name: String::from("big box"),
+                        groups: vec![String::from("a"), String::from("b")],
+                        material_names: vec![String::from("red"), String::from("blue")],
+                        vertices: 4,
+                        texture_vertices: 1,
+                        normal_vertices: 1,
+                        lines: 2,
+                        faces: 2,
+                        ..ObjectSummary::default()
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            summarize_scan(&summary),
+            summarize(&super::parse(obj_file).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_scan_of_empty_input() {
+        assert_eq!(super::scan(""), ObjSummary::default());
+        assert_eq!(super::scan("mtllib a.mtl\n\n").objects, vec![]);
+    }
+
+    #[test]
+    fn test_scan_agrees_with_parse_on_generated_files() {
+        let mut generator = Generator::new(1725);
+        for _ in 0..50 {
+            let generated = generator.obj();
+            let summary = super::scan(&generated.text);
+
+            assert_eq!(summarize_scan(&summary), summarize(&generated.expected));
+            assert_eq!(summary.material_libraries, generated.expected.material_libraries);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod count_tests {
+    use super::{
+        Parser,
+        StatementCounts,
+    };
+
+
+    #[test]
+    fn test_count() {
+        let obj_file = "\
+            mtllib a.mtl b.mtl\r\n\
+            # A comment.\r\n\
+            o box\r\n\
+            v 0 0 0\r\nv 1 0 0\rv 0 1 0\n\
+            vt 0 0\nvn 0 0 1\n\
+            g a b\ns 1\nusemtl red\n\
+            p 1 2 3\nl 1 2 3\nf 1 2 3\n\
+            bevel on\n\
+            \n\
+        ";
+
+        assert_eq!(
+            Parser::new(obj_file).count(),
+            StatementCounts {
+                statements: 14,
+                objects: 1,
+                vertices: 3,
+                texture_vertices: 1,
+                normal_vertices: 1,
+                points: 1,
+                lines: 1,
+                faces: 1,
+                groups: 1,
+                smoothing_groups: 1,
+                material_uses: 1,
+                material_libraries: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_count_of_empty_input() {
+        assert_eq!(Parser::new("").count(), StatementCounts::default());
+        assert_eq!(Parser::new("# Nothing.\n\n").count(), StatementCounts::default());
+    }
+
+    #[test]
+    fn test_count_does_not_advance_the_parser() {
+        let obj_file = "o a\nv 0 0 0\np 1\no b\nv 0 0 0\np 2\n";
+        let mut parser = Parser::new(obj_file);
+        let first = parser.next_object().unwrap().unwrap();
+
+        assert_eq!(parser.count().objects, 2);
+        assert_eq!(first.name, "a");
+        assert_eq!(parser.next_object().unwrap().unwrap().name, "b");
+    }
+}
+
+
+#[cfg(test)]
+mod point_cloud_tests {
+    use super::{
+        ErrorKind,
+        Parser,
+        ParserOptions,
+        Vertex,
+        VertexColor,
+    };
+    use crate::testing::Generator;
+
+
+    #[test]
+    fn test_parse_point_cloud_matches_vertices_of_parse() {
+        let mut generator = Generator::new(1727);
+        for _ in 0..50 {
+            let generated = generator.obj();
+            let point_cloud = super::parse_point_cloud(&generated.text).unwrap();
+            let expected: Vec<Vertex> = generated
+                .expected
+                .objects
+                .iter()
+                .flat_map(|object| object.vertex_set.iter().copied())
+                .collect();
+
+            assert_eq!(point_cloud.vertices, expected);
+            assert!(point_cloud.colors.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_parse_point_cloud_colors() {
+        let obj_file = "v 0 0 0\nv 1 0 0 0.5\nv 0 1 0 1 0.5 0\nv 0 0 1\n";
+        let point_cloud = super::parse_point_cloud(obj_file).unwrap();
+
+        assert_eq!(point_cloud.vertices[1].w, 0.5);
+        assert_eq!(point_cloud.vertices[2].w, 1.0);
+        assert_eq!(
+            point_cloud.colors,
+            vec![
+                VertexColor::default(),
+                VertexColor::default(),
+                VertexColor {
+                    r: 1.0,
+                    g: 0.5,
+                    b: 0.0
+                },
+                VertexColor::default(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_point_cloud_skips_other_statements_unchecked() {
+        let obj_file = "o cloud\ng a b\nf 1 2 99\nbogus statement\nvt 0 0\nv 1 2 3\n";
+        let point_cloud = super::parse_point_cloud(obj_file).unwrap();
+
+        assert_eq!(
+            point_cloud.vertices,
+            vec![Vertex {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                w: 1.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_point_cloud_applies_parser_options() {
+        let options = ParserOptions {
+            normalize_w: true,
+            decimal_comma: true,
+            ..ParserOptions::default()
+        };
+        let point_cloud = Parser::with_options("v 1 2 4,5 0,5\n", options)
+            .parse_point_cloud()
+            .unwrap();
+
+        assert_eq!(
+            point_cloud.vertices,
+            vec![Vertex {
+                x: 2.0,
+                y: 4.0,
+                z: 9.0,
+                w: 1.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_point_cloud_rejects_malformed_vertices() {
+        let kind = |obj_file| super::parse_point_cloud(obj_file).unwrap_err().kind().clone();
+
+        assert_eq!(kind("v 0 0 0 1 0\n"), ErrorKind::ExpectedFloat);
+        assert_eq!(kind("v 0 0 0 1 0 0 1\n"), ErrorKind::ExpectedEndOfStatement);
+        assert_eq!(kind("v 0 0\n"), ErrorKind::ExpectedFloat);
+        assert_eq!(kind("v 0 0 zero\n"), ErrorKind::ExpectedFloat);
+        assert_eq!(
+            super::parse_point_cloud("f 1 2 3\nv 0 0\n")
+                .unwrap_err()
+                .line_number(),
+            super::parse("v 0 0 0\nv 0 0\n").unwrap_err().line_number()
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod face_visitor_tests {
+    use super::{
+        Element,
+        ErrorKind,
+        GroupContext,
+        MaterialContext,
+        ObjectSet,
+        Parser,
+        ParserOptions,
+        SmoothingGroup,
+        VTNIndex,
+    };
+    use crate::testing::Generator;
+
+
+    type VisitedFace = (String, Element, Vec<String>, SmoothingGroup, Option<String>);
+
+    fn visit(obj_file: &str) -> Vec<VisitedFace> {
+        let mut faces = vec![];
+        let mut visitor = |vtn_indices: &[VTNIndex], groups: &GroupContext, material: &MaterialContext| {
+            for i in 1..(vtn_indices.len() - 1) {
+                faces.push((
+                    String::from(groups.object_name),
+                    Element::Face(vtn_indices[0], vtn_indices[i], vtn_indices[i + 1]),
+                    groups.groups.iter().map(|group| group.0.clone()).collect(),
+                    groups.smoothing_group,
+                    material.material_name.map(String::from),
+                ));
+            }
+        };
+        super::parse_with_visitor(obj_file, &mut visitor).unwrap();
+
+        faces
+    }
+
+    fn faces_of(object_set: &ObjectSet) -> Vec<VisitedFace> {
+        let mut faces = vec![];
+        for object in object_set.objects.iter() {
+            for run in object.shape_runs() {
+                for element in run.elements {
+                    let face = object.element_set.as_slice()[element];
+                    if let Element::Face(..) = face {
+                        faces.push((
+                            object.name.clone(),
+                            face,
+                            run.groups
+                                .iter()
+                                .map(|&group| object.group_set[group].0.clone())
+                                .collect(),
+                            object.smoothing_group_set[run.smoothing_group],
+                            run.material_name.map(String::from),
+                        ));
+                    }
+                }
+            }
+        }
+
+        faces
+    }
+
+    #[test]
+    fn test_parse_with_visitor_matches_parse() {
+        let obj_file = "\
+            usemtl leading\n\
+            o a\n\
+            v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n\
+            f 1 2 3 4\n\
+            g x y\ns 2\nusemtl red\n\
+            p 1\nl 1 2\nf 4 3 2\n\
+            o b\n\
+            v 0 0 1\nv 1 0 1\nv 0 1 1\n\
+            f -3 -2 -1\n\
+            g z\ns off\n\
+            f 5 6 7\n\
+        ";
+        let expected = faces_of(&super::parse(obj_file).unwrap());
+
+        assert_eq!(visit(obj_file), expected);
+        assert_eq!(expected.len(), 5);
+    }
+
+    #[test]
+    fn test_parse_with_visitor_matches_parse_on_generated_files() {
+        let mut generator = Generator::new(1728);
+        for _ in 0..50 {
+            let generated = generator.obj();
+
+            assert_eq!(visit(&generated.text), faces_of(&generated.expected));
+        }
+    }
+
+    #[test]
+    fn test_parse_with_visitor_reports_errors_after_visiting_earlier_faces() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\nf 1 2 4\nf 3 2 1\n";
+        let mut face_count = 0;
+        let mut visitor = |_: &[VTNIndex], _: &GroupContext, _: &MaterialContext| {
+            face_count += 1;
+        };
+        let result = Parser::new(obj_file).parse_with_visitor(&mut visitor);
+
+        assert_eq!(result, Err(super::parse(obj_file).unwrap_err()));
+        assert_eq!(face_count, 1);
+    }
+
+    #[test]
+    fn test_parse_with_visitor_records_material_libraries_and_unknown_statements() {
+        let obj_file = "mtllib a.mtl\nv 0 0 0\nbevel on\nmtllib b.mtl\n";
+        let options = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(obj_file, options);
+        let mut visitor = |_: &[VTNIndex], _: &GroupContext, _: &MaterialContext| {};
+        parser.parse_with_visitor(&mut visitor).unwrap();
+        let names: Vec<&str> = parser
+            .material_libraries()
+            .iter()
+            .map(|library| library.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["a.mtl", "b.mtl"]);
+        assert_eq!(
+            Parser::new(obj_file)
+                .parse_with_visitor(&mut visitor)
+                .unwrap_err()
+                .kind(),
+            &ErrorKind::InvalidObjectStatement
+        );
+    }
+}
+
+
+#[cfg(test)]
+mod collapse_repeated_attributes_tests {
+    use super::{
+        Element,
+        NormalVertex,
+        ObjectSet,
+        Parser,
+        ParserOptions,
+        VTNIndex,
+    };
+
+
+    fn parse_with(text: &str, collapse: bool, record_numeric_text: bool) -> ObjectSet {
+        let options = ParserOptions {
+            collapse_repeated_attributes: collapse,
+            record_numeric_text: record_numeric_text,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(text, options);
+
+        parser.parse_objset().unwrap()
+    }
+
+    #[test]
+    fn test_collapse_normals_repeated_per_corner() {
+        let text = "\
+            o Quad\n\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 1 1 0\n\
+            v 0 1 0\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            f 1//1 2//2 3//3\n\
+            f 1//4 3//5 4//6\n\
+        ";
+        let result = parse_with(text, true, false);
+        let object = &result.objects[0];
+        let expected_elements = vec![
+            Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 0), VTNIndex::VN(2, 0)),
+            Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(2, 0), VTNIndex::VN(3, 0)),
+        ];
+
+        assert_eq!(
+            *object.normal_vertex_set,
+            vec![NormalVertex {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0
+            }]
+        );
+        assert_eq!(*object.element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_collapse_compares_the_bits_of_the_components() {
+        let text = "\
+            v 0 0 0\n\
+            vn 0 0 1\n\
+            vn -0 0 1\n\
+            vn nan 0 1\n\
+            vn nan 0 1\n\
+            f 1//1 1//2 1//3\n\
+            f 1//4 1//3 1//2\n\
+        ";
+        let result = parse_with(text, true, false);
+        let object = &result.objects[0];
+        let expected_elements = vec![
+            Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(0, 1), VTNIndex::VN(0, 2)),
+            Element::Face(VTNIndex::VN(0, 2), VTNIndex::VN(0, 2), VTNIndex::VN(0, 1)),
+        ];
+
+        assert_eq!(object.normal_vertex_set.len(), 3);
+        assert_eq!(
+            object.normal_vertex_set.as_slice()[1].x.to_bits(),
+            (-0_f64).to_bits()
+        );
+        assert!(object.normal_vertex_set.as_slice()[2].x.is_nan());
+        assert_eq!(*object.element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_collapse_keeps_repeats_that_are_not_consecutive() {
+        let text = "\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            vt 0 0\n\
+            vt 1 0\n\
+            vt 1 0\n\
+            vt 0 0\n\
+            f 1/1 2/3 3/4\n\
+        ";
+        let result = parse_with(text, true, false);
+        let object = &result.objects[0];
+        let expected_elements = vec![Element::Face(
+            VTNIndex::VT(0, 0),
+            VTNIndex::VT(1, 1),
+            VTNIndex::VT(2, 2),
+        )];
+
+        assert_eq!(object.texture_vertex_set.len(), 3);
+        assert_eq!(*object.element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_collapse_preserves_index_bases_of_later_objects() {
+        let text = "\
+            o a\n\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            f 1//1 2//2 3//3\n\
+            o b\n\
+            v 0 0 1\n\
+            v 1 0 1\n\
+            v 0 1 1\n\
+            vn 1 0 0\n\
+            vn 0 1 0\n\
+            vn 0 1 0\n\
+            f 4//4 5//5 6//6\n\
+        ";
+        let result = parse_with(text, true, false);
+        let object = &result.objects[1];
+        let expected_elements = vec![Element::Face(
+            VTNIndex::VN(0, 0),
+            VTNIndex::VN(1, 1),
+            VTNIndex::VN(2, 1),
+        )];
+
+        assert_eq!(result.objects[0].normal_vertex_set.len(), 1);
+        assert_eq!(object.normal_vertex_set.len(), 2);
+        assert_eq!(*object.element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_collapse_keeps_numeric_text_aligned() {
+        let text = "\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            vn 0 0 1\n\
+            vn 0 0 1.0\n\
+            vn 0 1 0\n\
+            f 1//1 2//2 3//3\n\
+        ";
+        let result = parse_with(text, true, true);
+        let object = &result.objects[0];
+
+        assert_eq!(object.numeric_text.normal_vertices, vec!["0 0 1", "0 1 0"]);
+    }
+
+    #[test]
+    fn test_collapse_off_keeps_every_statement() {
+        let text = "\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            f 1//1 2//2 3//3\n\
+        ";
+        let collapsed = parse_with(text, false, false);
+        let expected = super::parse(text).unwrap();
+
+        assert_eq!(collapsed, expected);
+    }
+}
+
+#[cfg(test)]
+mod deduplicate_vertices_tests {
+    use super::{
+        map_vtn_indices,
+        Element,
+        NormalVertex,
+        Object,
+        ObjectSet,
+        Parser,
+        ParserOptions,
+        TextureVertex,
+        VTNIndex,
+        Vertex,
+    };
+
+
+    fn parse_with(text: &str, record_numeric_text: bool) -> ObjectSet {
+        let options = ParserOptions {
+            deduplicate_vertices: true,
+            record_numeric_text: record_numeric_text,
+            ..ParserOptions::default()
+        };
+        let mut parser = Parser::with_options(text, options);
+
+        parser.parse_objset().unwrap()
+    }
+
+    fn resolve(object: &Object) -> Vec<(Vertex, Option<TextureVertex>, Option<NormalVertex>)> {
+        let mut values = vec![];
+        for &element in object.element_set.iter() {
+            map_vtn_indices(element, |vtn_index| {
+                values.push((
+                    object.vertex_set[vtn_index.vertex_index()],
+                    vtn_index
+                        .texture_vertex_index()
+                        .map(|index| object.texture_vertex_set[index]),
+                    vtn_index
+                        .normal_vertex_index()
+                        .map(|index| object.normal_vertex_set[index]),
+                ));
+
+                vtn_index
+            });
+        }
+
+        values
+    }
+
+    #[test]
+    fn test_deduplicate_attributes_written_per_corner() {
+        let text = "\
+            o Quad\n\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 1 1 0\n\
+            v 0 0 0\n\
+            v 1 1 0\n\
+            v 0 1 0\n\
+            vt 0 0\n\
+            vt 1 0\n\
+            vt 1 1\n\
+            vt 0 0\n\
+            vt 1 1\n\
+            vt 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            vn 0 0 1\n\
+            f 1/1/1 2/2/2 3/3/3\n\
+            f 4/4/4 5/5/5 6/6/6\n\
+        ";
+        let result = parse_with(text, false);
+        let object = &result.objects[0];
+        let expected_elements = vec![
+            Element::Face(
+                VTNIndex::VTN(0, 0, 0),
+                VTNIndex::VTN(1, 1, 0),
+                VTNIndex::VTN(2, 2, 0),
+            ),
+            Element::Face(
+                VTNIndex::VTN(0, 0, 0),
+                VTNIndex::VTN(2, 2, 0),
+                VTNIndex::VTN(3, 3, 0),
+            ),
+        ];
+
+        assert_eq!(object.vertex_set.len(), 4);
+        assert_eq!(object.texture_vertex_set.len(), 4);
+        assert_eq!(object.normal_vertex_set.len(), 1);
+        assert_eq!(*object.element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_deduplicate_preserves_index_bases_of_later_objects() {
+        let text = "\
+            o a\n\
+            v 0 0 0\n\
+            v 0 0 0\n\
+            p 1 2\n\
+            o b\n\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 0 0\n\
+            p 3 4 5\n\
+        ";
+        let result = parse_with(text, false);
+        let expected_elements = vec![
+            Element::Point(VTNIndex::V(0)),
+            Element::Point(VTNIndex::V(1)),
+            Element::Point(VTNIndex::V(0)),
+        ];
+
+        assert_eq!(result.objects[0].vertex_set.len(), 1);
+        assert_eq!(result.objects[1].vertex_set.len(), 2);
+        assert_eq!(*result.objects[1].element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_deduplicate_compares_bits() {
+        let text = "\
+            v 0 0 0\n\
+            v -0 0 0\n\
+            v 0.0 0 0\n\
+            v 0 0 0 1\n\
+            v 0 0 0 2\n\
+            p 1 2 3 4 5\n\
+        ";
+        let result = parse_with(text, false);
+        let expected_elements = vec![
+            Element::Point(VTNIndex::V(0)),
+            Element::Point(VTNIndex::V(1)),
+            Element::Point(VTNIndex::V(0)),
+            Element::Point(VTNIndex::V(0)),
+            Element::Point(VTNIndex::V(2)),
+        ];
+
+        assert_eq!(*result.objects[0].element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_deduplicate_keeps_numeric_text_of_the_stored_copy() {
+        let text = "\
+            v 1 0 0\n\
+            v 1.0 0 0\n\
+            v 0 1 0\n\
+            p 1 2 3\n\
+        ";
+        let result = parse_with(text, true);
+
+        assert_eq!(result.objects[0].numeric_text.vertices, vec!["1 0 0", "0 1 0"]);
+    }
+
+    #[test]
+    fn test_deduplicate_resolves_to_the_same_values() {
+        for seed in 0..64 {
+            let generated = crate::testing::Generator::new(seed).obj();
+            let result = parse_with(&generated.text, false);
+
+            assert_eq!(result.objects.len(), generated.expected.objects.len());
+            for (object, expected) in result.objects.iter().zip(generated.expected.objects.iter()) {
+                assert!(object.vertex_set.len() <= expected.vertex_set.len());
+                assert_eq!(resolve(object), resolve(expected));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod object_filter_tests {
+    use super::{
+        GroupContext,
+        MaterialContext,
+        ObjectSet,
+        Parser,
+        ParserOptions,
+        VTNIndex,
+    };
+    use crate::testing::Generator;
+
+
+    const OBJ_FILE: &str = "\
+        mtllib base.mtl\n\
+        usemtl red\n\
+        o low\n\
+        v 0 0 0\n\
+        v 1 0 0\n\
+        v 0 1 0\n\
+        f 1 2 3\n\
+        o medium\n\
+        mtllib medium.mtl\n\
+        v 0 0 1\n\
+        v 1 0 1\n\
+        v 0 1 1\n\
+        vn 0 0 1\n\
+        f 4//1 5//1 6//1\n\
+        o high\n\
+        v 0 0 2\n\
+        v 1 0 2\n\
+        v 0 1 2\n\
+        vn 0 0 -1\n\
+        f 7//2 8//2 -1//-1\n\
+    ";
+
+    fn parse_with_filter<F>(text: &str, filter: F) -> ObjectSet
+    where
+        F: FnMut(&str) -> bool + 'static,
+    {
+        let options = ParserOptions::default().object_filter(filter);
+        let mut parser = Parser::with_options(text, options);
+
+        parser.parse_objset().unwrap()
+    }
+
+    #[test]
+    fn test_object_filter_keeps_matching_objects() {
+        let expected = super::parse(OBJ_FILE).unwrap();
+        let result = parse_with_filter(OBJ_FILE, |name: &str| name == "high");
+
+        assert_eq!(result.objects, vec![expected.objects[2].clone()]);
+    }
+
+    #[test]
+    fn test_object_filter_matches_filtering_the_parsed_objects() {
+        for seed in 0..64 {
+            let generated = Generator::new(seed).obj();
+            let keep = |name: &str| name.len().is_multiple_of(2);
+            let result = parse_with_filter(&generated.text, keep);
+            let expected: Vec<_> = generated
+                .expected
+                .objects
+                .into_iter()
+                .filter(|object| keep(&object.name))
+                .collect();
+
+            assert_eq!(result.objects, expected, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_object_filter_does_not_check_skipped_objects() {
+        let obj_file = "o broken\nv 0 0 zero\nf 1 2 3\nbogus 1\no good\nv 0 0 0\np 2\n";
+        let result = parse_with_filter(obj_file, |name: &str| name == "good");
+
+        assert_eq!(result.objects.len(), 1);
+        assert_eq!(result.objects[0].name, "good");
+        assert!(super::parse(obj_file).is_err());
+    }
+
+    #[test]
+    fn test_object_filter_records_material_libraries_of_skipped_objects() {
+        let result = parse_with_filter(OBJ_FILE, |name: &str| name == "low");
+        let names: Vec<&str> = result
+            .material_libraries
+            .iter()
+            .map(|library| library.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["base.mtl", "medium.mtl"]);
+    }
+
+    #[test]
+    fn test_object_filter_drops_leading_material_of_a_skipped_first_object() {
+        let obj_file = "usemtl red\no a\nv 0 0 0\np 1\no b\nv 1 1 1\np 2\n";
+        let result = parse_with_filter(obj_file, |name: &str| name == "b");
+        let material_names: Vec<_> = result.objects[0]
+            .geometry_set
+            .iter()
+            .map(|geometry| geometry.material_name.clone())
+            .collect();
+
+        assert_eq!(material_names, vec![None]);
+    }
+
+    #[test]
+    fn test_object_filter_sees_the_empty_name_of_an_unnamed_object() {
+        let obj_file = "v 0 0 0\np 1\no named\nv 1 1 1\np 2\n";
+        let result = parse_with_filter(obj_file, |name: &str| !name.is_empty());
+
+        assert_eq!(result.objects.len(), 1);
+        assert_eq!(result.objects[0].name, "named");
+    }
+
+    #[test]
+    fn test_next_object_with_object_filter() {
+        let options = ParserOptions::default().object_filter(|name: &str| name.contains('i'));
+        let mut parser = Parser::with_options(OBJ_FILE, options);
+        let mut names = vec![];
+        while let Some(object) = parser.next_object() {
+            names.push(object.unwrap().name);
+        }
+
+        assert_eq!(names, vec!["medium", "high"]);
+    }
+
+    #[test]
+    fn test_next_object_when_every_object_is_rejected() {
+        let options = ParserOptions::default().object_filter(|_: &str| false);
+        let mut parser = Parser::with_options(OBJ_FILE, options);
+
+        assert!(parser.next_object().is_none());
+        assert!(parser.next_object().is_none());
+    }
+
+    #[test]
+    fn test_parse_with_visitor_with_object_filter() {
+        let options = ParserOptions::default().object_filter(|name: &str| name != "medium");
+        let mut parser = Parser::with_options(OBJ_FILE, options);
+        let mut faces = vec![];
+        let mut visitor = |vtn_indices: &[VTNIndex], groups: &GroupContext, _: &MaterialContext| {
+            faces.push((String::from(groups.object_name), vtn_indices.to_vec()));
+        };
+        parser.parse_with_visitor(&mut visitor).unwrap();
+        let expected = vec![
+            (
+                String::from("low"),
+                vec![VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)],
+            ),
+            (
+                String::from("high"),
+                vec![VTNIndex::VN(0, 0), VTNIndex::VN(1, 0), VTNIndex::VN(2, 0)],
+            ),
+        ];
+
+        assert_eq!(faces, expected);
+    }
+}
+
+#[cfg(test)]
+mod group_filter_tests {
+    use super::{
+        Element,
+        GroupContext,
+        MaterialContext,
+        ObjectSet,
+        Parser,
+        ParserOptions,
+        VTNIndex,
+    };
+
+
+    const OBJ_FILE: &str = "\
+        o Crate\n\
+        v 0 0 0\n\
+        v 1 0 0\n\
+        v 1 1 0\n\
+        v 0 1 0\n\
+        f 1 2 3\n\
+        g body\n\
+        usemtl wood\n\
+        f 1 2 3 4\n\
+        g collision_box\n\
+        f 4 3 2\n\
+        g body collision_lid\n\
+        l 1 2\n\
+        g body\n\
+        p 3\n\
+    ";
+
+    fn parse_with_filter<F>(text: &str, filter: F) -> ObjectSet
+    where
+        F: FnMut(&str) -> bool + 'static,
+    {
+        let options = ParserOptions::default().group_filter(filter);
+        let mut parser = Parser::with_options(text, options);
+
+        parser.parse_objset().unwrap()
+    }
+
+    #[test]
+    fn test_group_filter_matches_removing_rejected_statements() {
+        let result = parse_with_filter(OBJ_FILE, |name: &str| name.starts_with("collision_"));
+        // The skipped first face still puts the object in the default group.
+        let expected = super::parse(
+            "\
+            o Crate\n\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 1 1 0\n\
+            v 0 1 0\n\
+            g default\n\
+            g body\n\
+            usemtl wood\n\
+            g collision_box\n\
+            f 4 3 2\n\
+            g body collision_lid\n\
+            l 1 2\n\
+            g body\n\
+        ",
+        )
+        .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_group_filter_keeps_elements_in_any_accepted_group() {
+        let result = parse_with_filter(OBJ_FILE, |name: &str| name == "body");
+        let expected_elements = vec![
+            Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+            Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
+            Element::Line(VTNIndex::V(0), VTNIndex::V(1)),
+            Element::Point(VTNIndex::V(2)),
+        ];
+
+        assert_eq!(*result.objects[0].element_set, expected_elements);
+    }
+
+    #[test]
+    fn test_group_filter_sees_the_default_group() {
+        let result = parse_with_filter(OBJ_FILE, |name: &str| name == "default");
+        let expected_elements = vec![Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2))];
+
+        assert_eq!(*result.objects[0].element_set, expected_elements);
+        assert_eq!(result.objects[0].vertex_set.len(), 4);
+    }
+
+    #[test]
+    fn test_group_filter_does_not_check_skipped_elements() {
+        let obj_file = "v 0 0 0\ng broken\nf 1 2 99\np one\ng good\np 1\n";
+        let result = parse_with_filter(obj_file, |name: &str| name == "good");
+
+        assert_eq!(
+            *result.objects[0].element_set,
+            vec![Element::Point(VTNIndex::V(0))]
+        );
+        assert!(super::parse(obj_file).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_visitor_with_group_filter() {
+        let options = ParserOptions::default().group_filter(|name: &str| name.starts_with("collision_"));
+        let mut parser = Parser::with_options(OBJ_FILE, options);
+        let mut faces = vec![];
+        let mut visitor = |vtn_indices: &[VTNIndex], groups: &GroupContext, _: &MaterialContext| {
+            faces.push((groups.groups[0].0.clone(), vtn_indices.to_vec()));
+        };
+        parser.parse_with_visitor(&mut visitor).unwrap();
+        let expected = vec![(
+            String::from("collision_box"),
+            vec![VTNIndex::V(3), VTNIndex::V(2), VTNIndex::V(1)],
+        )];
+
+        assert_eq!(faces, expected);
+    }
+}
+
+#[cfg(test)]
+mod duplicate_object_name_tests {
+    use super::{
+        DuplicateObjectNames,
+        Element,
+        ErrorKind,
+        Group,
+        Parser,
+        ParserOptions,
+        ShapeEntryIndex,
+        VTNIndex,
+    };
+
+
+    const OBJ_FILE: &str = "\
+        o Cube\n\
+        v 0 0 0\n\
+        v 1 0 0\n\
+        v 0 1 0\n\
+        g side\n\
+        f 1 2 3\n\
+        o Sphere\n\
+        v 0 0 1\n\
+        p 4\n\
+        o Cube\n\
+        v 0 0 2\n\
+        v 1 0 2\n\
+        v 0 1 2\n\
+        g side\n\
+        f 5 6 7\n\
+        o Cube\n\
+        v 0 0 3\n\
+        p 8\n\
+    ";
+
+    fn parse_with(policy: DuplicateObjectNames) -> Parser<'static> {
+        let options = ParserOptions {
+            duplicate_object_names: policy,
+            ..ParserOptions::default()
+        };
+
+        Parser::with_options(OBJ_FILE, options)
+    }
+
+    fn object_names(parser: &mut Parser) -> Vec<String> {
+        let result = parser.parse_objset().unwrap();
+
+        result.objects.into_iter().map(|object| object.name).collect()
+    }
+
+    #[test]
+    fn test_keep_duplicate_object_names() {
+        let names = object_names(&mut parse_with(DuplicateObjectNames::Keep));
+
+        assert_eq!(names, vec!["Cube", "Sphere", "Cube", "Cube"]);
+    }
+
+    #[test]
+    fn test_rename_duplicate_object_names() {
+        let names = object_names(&mut parse_with(DuplicateObjectNames::Rename));
+
+        assert_eq!(names, vec!["Cube", "Sphere", "Cube.001", "Cube.002"]);
+    }
+
+    #[test]
+    fn test_rename_skips_names_in_use() {
+        let options = ParserOptions {
+            duplicate_object_names: DuplicateObjectNames::Rename,
+            ..ParserOptions::default()
+        };
+        let mut parser =
+            Parser::with_options("o Cube.001\nv 0 0 0\no Cube\nv 0 0 0\no Cube\nv 0 0 0\n", options);
+        let names = object_names(&mut parser);
+
+        assert_eq!(names, vec!["Cube.001", "Cube", "Cube.002"]);
+    }
+
+    #[test]
+    fn test_merge_duplicate_object_names() {
+        let result = parse_with(DuplicateObjectNames::Merge).parse_objset().unwrap();
+        let names: Vec<&str> = result.objects.iter().map(|object| object.name.as_str()).collect();
+        let cube = &result.objects[0];
+        let element_set = vec![
+            Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+            Element::Face(VTNIndex::V(3), VTNIndex::V(4), VTNIndex::V(5)),
+            Element::Point(VTNIndex::V(6)),
+        ];
+        let shape_elements: Vec<usize> = cube.shape_set.iter().map(|shape| shape.element.0).collect();
+        let geometry_shapes: Vec<usize> = cube
+            .geometry_set
+            .iter()
+            .flat_map(|geometry| geometry.shapes.iter().map(|shape| shape.0))
+            .collect();
+
+        assert_eq!(names, vec!["Cube", "Sphere"]);
+        assert_eq!(cube.vertex_set.len(), 7);
+        assert_eq!(cube.element_set, element_set.into());
+        assert_eq!(shape_elements, vec![0, 1, 2]);
+        assert_eq!(geometry_shapes, vec![0, 1, 2]);
+        assert_eq!(
+            cube.group_set
+                .iter()
+                .filter(|group| **group == Group(String::from("side")))
+                .count(),
+            1
+        );
+        assert_eq!(
+            cube.shape_set[ShapeEntryIndex(1)].groups,
+            cube.shape_set[ShapeEntryIndex(0)].groups
+        );
+    }
+
+    #[test]
+    fn test_reject_duplicate_object_names() {
+        let error = parse_with(DuplicateObjectNames::Reject)
+            .parse_objset()
+            .unwrap_err();
+
+        assert_eq!(error.kind(), &ErrorKind::DuplicateObjectName);
+        assert_eq!(error.code(), "OBJ0021");
+        assert_eq!(error.line_number(), 10);
+    }
+}
+
+#[cfg(test)]
+mod vtn_index_tests {
+    use super::{
+        Element,
+        Parser,
+        VTNIndex,
+    };
+
+
+    #[test]
+    fn test_parse_vtn_index1() {
+        let mut parser = Parser::new("1291");
+        let expected = VTNIndex::V(1290);
+        let result = parser.parse_vtn_index((0, 1300), (0, 1300), (0, 1300));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_vtn_index2() {
+        let mut parser = Parser::new("1291/1315");
+        let expected = VTNIndex::VT(1290, 1314);
+        let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_vtn_index3() {
+        let mut parser = Parser::new("1291/1315/1314");
+        let expected = VTNIndex::VTN(1290, 1314, 1313);
+        let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_vtn_index4() {
+        let mut parser = Parser::new("1291//1315");
+        let expected = VTNIndex::VN(1290, 1314);
+        let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_vtn_index_trailing_slashes() {
+        let cases = [
+            ("1291/", VTNIndex::V(1290)),
+            ("1291//", VTNIndex::V(1290)),
+            ("1291/1315/", VTNIndex::VT(1290, 1314)),
+        ];
+        for (input, expected) in cases {
+            let mut parser = Parser::new(input);
+            let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
+            assert_eq!(result, Ok(expected), "input = {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_vtn_index_relative() {
+        let cases = [
+            ("-1", Ok(VTNIndex::V(1315))),
+            ("-1316/-2", Ok(VTNIndex::VT(0, 1314))),
+            ("-3//-1", Ok(VTNIndex::VN(1313, 1315))),
+            ("-1317", Err(())),
+            ("0", Err(())),
+        ];
+        for (input, expected) in cases {
+            let mut parser = Parser::new(input);
+            let result = parser
+                .parse_vtn_index((0, 1316), (0, 1316), (0, 1316))
+                .map_err(|_| ());
+            assert_eq!(result, expected, "input = {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_vtn_index_rejects_empty_vertex_components() {
+        for input in ["/", "//", "/1315", "//1315", "1291///", "1291/1315/1314/"] {
+            let mut parser = Parser::new(input);
+            let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
+            assert!(result.is_err(), "input = {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_trailing_slash_elements() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1/ 2// 3\nl 1// 2/\np 1/ 2// 3\n";
+        let result = Parser::new(obj_file).parse_objset().unwrap();
+        let expected = vec![
+            Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+            Element::Line(VTNIndex::V(0), VTNIndex::V(1)),
+            Element::Point(VTNIndex::V(0)),
+            Element::Point(VTNIndex::V(1)),
+            Element::Point(VTNIndex::V(2)),
+        ];
+
+        assert_eq!(result.objects[0].element_set, expected.into());
+    }
+
+    #[test]
+    fn test_parse_trailing_slash_isolated_statements() {
+        assert_eq!("4/".parse::<VTNIndex>(), Ok(VTNIndex::V(3)));
+        assert_eq!("4/5/".parse::<VTNIndex>(), Ok(VTNIndex::VT(3, 4)));
+        assert_eq!("p 4//".parse::<Element>(), Ok(Element::Point(VTNIndex::V(3))));
+        assert!("p 4///".parse::<Element>().is_err());
+    }
+}
+
+#[cfg(test)]
+mod element_tests {
+    use super::{
+        Element,
+        ErrorKind,
+        Parser,
+        VTNIndex,
+    };
+
+
+    #[test]
+    fn test_parse_point1() {
+        let mut parser = Parser::new("p 1 2 3 4 \n");
+        let mut result = vec![];
+        let expected = vec![
+            Element::Point(VTNIndex::V(0)),
+            Element::Point(VTNIndex::V(1)),
+            Element::Point(VTNIndex::V(2)),
+            Element::Point(VTNIndex::V(3)),
+        ];
+        assert!(parser.parse_elements(&mut result, (0, 5), (0, 5), (0, 5)).is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_point2() {
+        let mut parser = Parser::new("p 1 1/2 3 4/5");
+        let mut result = vec![];
+        assert!(parser
+            .parse_elements(&mut result, (0, 6), (0, 6), (0, 6))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_line1() {
+        let mut parser = Parser::new("l 297 38 118 108 \n");
+        let mut result = vec![];
+        let expected = vec![
+            Element::Line(VTNIndex::V(296), VTNIndex::V(37)),
+            Element::Line(VTNIndex::V(37), VTNIndex::V(117)),
+            Element::Line(VTNIndex::V(117), VTNIndex::V(107)),
+        ];
+        assert!(parser
+            .parse_elements(&mut result, (0, 300), (0, 300), (0, 300))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_line2() {
+        let mut parser = Parser::new("l 297/38 118/108 \n");
+        let mut result = vec![];
+        let expected = vec![Element::Line(VTNIndex::VT(296, 37), VTNIndex::VT(117, 107))];
+        assert!(parser
+            .parse_elements(&mut result, (0, 300), (0, 300), (0, 300))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_line3() {
+        let mut parser = Parser::new("l 297/38 118/108 324/398 \n");
+        let mut result = vec![];
+        let expected = vec![
+            Element::Line(VTNIndex::VT(296, 37), VTNIndex::VT(117, 107)),
+            Element::Line(VTNIndex::VT(117, 107), VTNIndex::VT(323, 397)),
+        ];
+        assert!(parser
+            .parse_elements(&mut result, (0, 400), (0, 400), (0, 400))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_line4() {
+        let mut parser = Parser::new("l 297/38 118 324 \n");
+        let mut result = vec![];
+        assert!(parser
+            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_line5() {
+        let mut parser = Parser::new("l 297 118/108 324/398 \n");
+        let mut result = vec![];
+        assert!(parser
+            .parse_elements(&mut result, (0, 400), (0, 400), (0, 400))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_face1() {
+        let mut parser = Parser::new("f 297 118 108\n");
+        let mut result = vec![];
+        let expected = vec![Element::Face(
+            VTNIndex::V(296),
+            VTNIndex::V(117),
+            VTNIndex::V(107),
+        )];
+        assert!(parser
+            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_face2() {
+        let mut parser = Parser::new("f 297 118 108 324\n");
+        let mut result = vec![];
+        let expected = vec![
+            Element::Face(VTNIndex::V(296), VTNIndex::V(117), VTNIndex::V(107)),
+            Element::Face(VTNIndex::V(296), VTNIndex::V(107), VTNIndex::V(323)),
+        ];
+        assert!(parser
+            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_face3() {
+        let mut parser = Parser::new("f 297 118 108 324 398 \n");
+        let mut result = vec![];
+        let expected = vec![
+            Element::Face(VTNIndex::V(296), VTNIndex::V(117), VTNIndex::V(107)),
+            Element::Face(VTNIndex::V(296), VTNIndex::V(107), VTNIndex::V(323)),
+            Element::Face(VTNIndex::V(296), VTNIndex::V(323), VTNIndex::V(397)),
+        ];
+        assert!(parser
+            .parse_elements(&mut result, (0, 400), (0, 400), (0, 400))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_face4() {
+        let mut parser = Parser::new("f 297 118 \n");
+        let mut result = vec![];
+        assert!(parser
+            .parse_face(&mut result, (0, 400), (0, 400), (0, 400))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_face5() {
+        let min_index = 320;
+        let max_index = 35000;
+        let vertex_index_range = (min_index, max_index);
+        let texture_index_range = (min_index, max_index);
+        let normal_index_range = (min_index, max_index);
+        let mut parser =
+            Parser::new("f 34184//34184 34088//34088 34079//34079 34084//34084 34091//34091 34076//34076\n");
+        let mut result = vec![];
+        /*
+        let expected = vec![
+            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34087, 34087), VTNIndex::VN(34078, 34078)),
+            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34078, 34078), VTNIndex::VN(34083, 34083)),
+            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34083, 34083), VTNIndex::VN(34090, 34090)),
+            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34090, 34090), VTNIndex::VN(34075, 34075)),
+        ];
+        */
+        let expected = vec![
+            Element::Face(
+                VTNIndex::VN(33863, 33863),
+                VTNIndex::VN(33767, 33767),
+                VTNIndex::VN(33758, 33758),
+            ),
+            Element::Face(
+                VTNIndex::VN(33863, 33863),
+                VTNIndex::VN(33758, 33758),
+                VTNIndex::VN(33763, 33763),
+            ),
+            Element::Face(
+                VTNIndex::VN(33863, 33863),
+                VTNIndex::VN(33763, 33763),
+                VTNIndex::VN(33770, 33770),
+            ),
+            Element::Face(
+                VTNIndex::VN(33863, 33863),
+                VTNIndex::VN(33770, 33770),
+                VTNIndex::VN(33755, 33755),
+            ),
+        ];
+        parser
+            .parse_elements(
+                &mut result,
+                vertex_index_range,
+                texture_index_range,
+                normal_index_range,
+            )
+            .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_face6() {
+        let mut parser = Parser::new("f 297/13/12 118/124/45 108/93/7\n");
+        let mut result = vec![];
+        let expected = vec![Element::Face(
+            VTNIndex::VTN(296, 12, 11),
+            VTNIndex::VTN(117, 123, 44),
+            VTNIndex::VTN(107, 92, 6),
+        )];
+        assert!(parser
+            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
+            .is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_faces_of_different_lengths_in_sequence() {
+        let mut parser = Parser::new("f 1 2 3 4 5\nf 6 7 8\nl 1 2\n");
+        let mut result = vec![];
+        let expected = vec![
+            Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
+            Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
+            Element::Face(VTNIndex::V(0), VTNIndex::V(3), VTNIndex::V(4)),
+            Element::Face(VTNIndex::V(5), VTNIndex::V(6), VTNIndex::V(7)),
+            Element::Line(VTNIndex::V(0), VTNIndex::V(1)),
+        ];
+        for _ in 0..3 {
+            assert!(parser.parse_elements(&mut result, (0, 8), (0, 8), (0, 8)).is_ok());
+        }
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_elements_rejects_a_bad_trailing_corner() {
+        let kind = |obj_file| super::parse(obj_file).unwrap_err().kind().clone();
+
+        assert_eq!(
+            kind("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3 9\n"),
+            ErrorKind::VTNIndexOutOfRange
+        );
+        assert_eq!(kind("v 0 0 0\nv 1 0 0\nl 1 2 x\n"), ErrorKind::ExpectedInteger);
+    }
+
+    #[test]
+    fn test_parse_elements_keeps_the_scratch_buffer_after_an_error() {
+        for obj_file in ["f 1 2 3 4 5 6 7 8\nl 1 2/1\n", "l 1 2 3 4 5 6 7 8\nf 1 2/1 3\n"] {
+            let mut parser = Parser::new(obj_file);
+            let mut result = vec![];
+            assert!(parser.parse_elements(&mut result, (0, 8), (0, 8), (0, 8)).is_ok());
+            let capacity = parser.vtn_index_buffer.capacity();
+
+            assert!(parser
+                .parse_elements(&mut result, (0, 8), (0, 8), (0, 8))
+                .is_err());
+            assert_eq!(parser.vtn_index_buffer.capacity(), capacity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod group_tests {
+    use super::{
+        Group,
+        Parser,
+    };
+
+
+    #[test]
+    fn parse_group_name1() {
+        let mut parser = Parser::new("g group");
+        let mut result = vec![];
+        let expected = vec![Group(String::from("group"))];
+        let parsed = parser.parse_groups(&mut result);
+
+        assert!(parsed.is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_group_name2() {
+        let mut parser = Parser::new("g group1 group2 group3");
+        let mut result = vec![];
+        let parsed = parser.parse_groups(&mut result);
+        let expected = vec![
+            Group(String::from("group1")),
+            Group(String::from("group2")),
+            Group(String::from("group3")),
+        ];
+
+        assert!(parsed.is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_group_name_empty() {
+        let mut parser = Parser::new("g\n");
+        let mut result = vec![];
+        let parsed = parser.parse_groups(&mut result);
+
+        assert_eq!(parsed, Ok(1));
+        assert_eq!(result, vec![Group::default()]);
+    }
+
+    #[test]
+    fn test_empty_group_statement_resets_to_default_group() {
+        let mut parser = Parser::new("v 0 0 0\ng wheel\np 1\ng\np 1\ng body\np 1\n");
+        let result = parser.parse_objset().unwrap();
+        let object = &result.objects[0];
+        let groups: Vec<Vec<&str>> = object
+            .shape_set
+            .iter()
+            .map(|shape| {
+                shape
+                    .groups
+                    .iter()
+                    .map(|&group| object.group_set[group].0.as_str())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(groups, vec![vec!["wheel"], vec!["default"], vec!["body"]]);
+    }
+}
+
+#[cfg(test)]
+mod smoothing_group_tests {
+    use super::{
+        Parser,
+        SmoothingGroup,
+    };
+
+
+    #[test]
+    fn test_smoothing_group_name1() {
+        let mut parser = Parser::new("s off");
+        let mut result = vec![];
+        let parsed = parser.parse_smoothing_group(&mut result);
+        let expected = vec![SmoothingGroup(0)];
+
+        assert!(parsed.is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_smoothing_group_name2() {
+        let mut parser = Parser::new("s 0");
+        let mut result = vec![];
+        let parsed = parser.parse_smoothing_group(&mut result);
+        let expected = vec![SmoothingGroup(0)];
+
+        assert!(parsed.is_ok());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_smoothing_group_name3() {
+        let mut parser = Parser::new("s 3434");
+        let mut result = vec![];
+        let parsed = parser.parse_smoothing_group(&mut result);
+        let expected = vec![SmoothingGroup(3434)];
+
+        assert!(parsed.is_ok());
+        assert_eq!(result, expected);
+    }
+}
+
+#[cfg(test)]
+mod mtllib_tests {
+    use super::{
+        MtlLibRef,
+        Parser,
+    };
+
+
+    #[test]
+    fn test_mtllib_empty() {
+        let mut parser = Parser::new("mtllib       ");
+        let expected: Vec<MtlLibRef> = vec![];
+        let expected_count = Ok(0);
+        let mut result = vec![];
+        let result_count = parser.parse_material_library_line(&mut result);
+
+        assert_eq!(result, expected);
+        assert_eq!(result_count, expected_count);
+    }
+
+    #[test]
+    fn test_mtllib1() {
+        let mut parser = Parser::new("mtllib library1.mtl");
+        let expected = vec![MtlLibRef::new("library1.mtl", 1)];
+        let expected_count = Ok(1);
+        let mut result = vec![];
+        let result_count = parser.parse_material_library_line(&mut result);
+
+        assert_eq!(result, expected);
+        assert_eq!(result_count, expected_count);
+    }
+
+    #[test]
+    fn test_mtllib2() {
+        let mut parser = Parser::new("mtllib library1.mtl library2.mtl library3.mtl");
+        let expected = vec![
+            MtlLibRef::new("library1.mtl", 1),
+            MtlLibRef::new("library2.mtl", 1),
+            MtlLibRef::new("library3.mtl", 1),
+        ];
+        let expected_count = Ok(3);
+        let mut result = vec![];
+        let result_count = parser.parse_material_library_line(&mut result);
+
+        assert_eq!(result, expected);
+        assert_eq!(result_count, expected_count);
+    }
+}
+
+
 #[cfg(test)]
-mod vertex_tests {
+mod external_reference_tests {
     use super::{
+        ErrorKind,
+        ExternalReference,
+        MtlLibRef,
         Parser,
-        Vertex,
     };
 
 
     #[test]
-    fn test_parse_vertex1() {
-        let mut parser = Parser::new("v -1.929448 13.329624 -5.221914\n");
-        let vertex = Vertex {
-            x: -1.929448,
-            y: 13.329624,
-            z: -5.221914,
-            w: 1.0,
-        };
-        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    fn test_parse_call() {
+        let mut parser = Parser::new("call part.obj 1 2\n");
+        let expected = Ok(ExternalReference::Call {
+            file_name: String::from("part.obj"),
+            arguments: vec![String::from("1"), String::from("2")],
+        });
+        let result = parser.parse_external_reference();
+
+        assert_eq!(result, expected);
+        assert_eq!(parser.peek(), Some("\n"));
     }
 
     #[test]
-    fn test_parse_vertex2() {
-        let mut parser = Parser::new("v -1.929448 13.329624 -5.221914 1.329624\n");
-        let vertex = Vertex {
-            x: -1.929448,
-            y: 13.329624,
-            z: -5.221914,
-            w: 1.329624,
-        };
-        assert_eq!(parser.parse_vertex(), Ok(vertex));
+    fn test_parse_call_without_file_name() {
+        let mut parser = Parser::new("call\n");
+        let result = parser.parse_external_reference();
+
+        assert_eq!(
+            result.map_err(|error| error.kind),
+            Err(ErrorKind::CallStatementHasNoFileName)
+        );
     }
 
     #[test]
-    fn test_parse_vertex3() {
-        let mut parser = Parser::new("v -1.929448 13.329624 \n");
-        assert!(parser.parse_vertex().is_err());
+    fn test_parse_csh() {
+        let mut parser = Parser::new("csh   rm  -f  part.obj");
+        let expected = Ok(ExternalReference::Shell {
+            command: String::from("rm -f part.obj"),
+            ignore_errors: false,
+        });
+        let result = parser.parse_external_reference();
+
+        assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_parse_vertex4() {
-        let mut parser = Parser::new("v -1.929448 13.329624 -5.221914 1.329624\n v");
-        assert!(parser.parse_vertex().is_ok());
+    fn test_parse_csh_ignore_errors() {
+        let mut parser = Parser::new("csh -make part.obj");
+        let expected = Ok(ExternalReference::Shell {
+            command: String::from("make part.obj"),
+            ignore_errors: true,
+        });
+        let result = parser.parse_external_reference();
+
+        assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_parse_vertex5() {
-        let mut parser = Parser::new(
-            "v -6.207583 1.699077 8.466142
-              v -14.299248 1.700244 8.468981 1.329624",
-        );
-        assert_eq!(
-            parser.parse_vertex(),
-            Ok(Vertex {
-                x: -6.207583,
-                y: 1.699077,
-                z: 8.466142,
-                w: 1.0,
-            })
-        );
-        assert_eq!(parser.next(), Some("\n"));
+    fn test_parse_csh_without_command() {
+        let mut parser = Parser::new("csh -");
+        let result = parser.parse_external_reference();
+
         assert_eq!(
-            parser.parse_vertex(),
-            Ok(Vertex {
-                x: -14.299248,
-                y: 1.700244,
-                z: 8.468981,
-                w: 1.329624,
-            })
+            result.map_err(|error| error.kind),
+            Err(ErrorKind::ShellStatementHasNoCommand)
         );
     }
 
     #[test]
-    fn test_parse_vertex6() {
-        let mut parser = Parser::new("v -6.207583 1.699077 8.466142 v -14.299248 1.700244 8.468981 1.329624");
-        assert_eq!(
-            parser.parse_vertex(),
-            Ok(Vertex {
-                x: -6.207583,
-                y: 1.699077,
-                z: 8.466142,
-                w: 1.0,
-            })
+    fn test_parse_objset_collects_external_references() {
+        let mut parser = Parser::new(
+            "call header.obj\nmtllib a.mtl\nv 0 0 0\ncsh echo done\np 1\no second\ncall footer.obj x\nv 1 1 1\np 2\n",
         );
-        assert_eq!(parser.peek(), Some("v"));
-        assert_eq!(
-            parser.parse_vertex(),
-            Ok(Vertex {
-                x: -14.299248,
-                y: 1.700244,
-                z: 8.468981,
-                w: 1.329624,
-            })
+        let result = parser.parse_objset().unwrap();
+        let expected = vec![
+            ExternalReference::Call {
+                file_name: String::from("header.obj"),
+                arguments: vec![],
+            },
+            ExternalReference::Shell {
+                command: String::from("echo done"),
+                ignore_errors: false,
+            },
+            ExternalReference::Call {
+                file_name: String::from("footer.obj"),
+                arguments: vec![String::from("x")],
+            },
+        ];
+
+        assert_eq!(result.external_references, expected);
+        assert_eq!(result.material_libraries, vec![MtlLibRef::new("a.mtl", 2)]);
+        assert_eq!(result.objects.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_objset_usemtl_before_elements() {
+        let mut parser = Parser::new("usemtl first\nusemtl second\nv 0 0 0\np 1\nusemtl third\np 1\n");
+        let result = parser.parse_objset().unwrap();
+        let materials: Vec<Option<&str>> = result.objects[0]
+            .geometry_set
+            .iter()
+            .map(|geometry| geometry.material_name.as_deref())
+            .collect();
+
+        assert_eq!(result.objects.len(), 1);
+        assert_eq!(materials, vec![Some("second"), Some("third")]);
+    }
+
+    #[test]
+    fn test_parse_objset_collects_material_libraries_anywhere() {
+        let mut parser = Parser::new(
+            "mtllib a.mtl\no first\nv 0 0 0\nmtllib b.mtl c.mtl\np 1\no second\nv 1 1 1\np 2\nmtllib d.mtl\n",
         );
+        let result = parser.parse_objset().unwrap();
+        let expected = vec![
+            MtlLibRef::new("a.mtl", 1),
+            MtlLibRef::new("b.mtl", 4),
+            MtlLibRef::new("c.mtl", 4),
+            MtlLibRef::new("d.mtl", 9),
+        ];
+
+        assert_eq!(result.material_libraries, expected);
+        assert_eq!(result.objects.len(), 2);
     }
 }
 
 #[cfg(test)]
-mod texture_vertex_tests {
+mod statement_handler_tests {
     use super::{
+        ErrorKind,
         Parser,
-        TextureVertex,
+        ParserOptions,
+        UnknownStatement,
     };
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
 
     #[test]
-    fn test_parse_texture_vertex1() {
-        let mut parser = Parser::new("vt -1.929448");
-        let vt = TextureVertex {
-            u: -1.929448,
-            v: 0.0,
-            w: 0.0,
-        };
-        assert_eq!(parser.parse_texture_vertex(), Ok(vt));
+    fn test_unknown_statement_without_handler() {
+        let mut parser = Parser::new("v 0 0 0\nvp 0.5 0.5\np 1\n");
+        let result = parser.parse_objset();
+
+        assert_eq!(
+            result.map_err(|error| error.kind),
+            Err(ErrorKind::InvalidObjectStatement)
+        );
     }
 
     #[test]
-    fn test_parse_texture_vertex2() {
-        let mut parser = Parser::new("vt -1.929448 13.329624 -5.221914");
-        let vt = TextureVertex {
-            u: -1.929448,
-            v: 13.329624,
-            w: -5.221914,
+    fn test_handler_receives_unknown_statements() {
+        let statements = Rc::new(RefCell::new(vec![]));
+        let captured = statements.clone();
+        let handler = move |keyword: &str, arguments: &[&str]| {
+            let arguments: Vec<String> = arguments.iter().map(|argument| String::from(*argument)).collect();
+            captured.borrow_mut().push((String::from(keyword), arguments));
+            Ok(())
         };
-        assert_eq!(parser.parse_texture_vertex(), Ok(vt));
+        let options = ParserOptions::default().statement_handler(handler);
+        let mut parser = Parser::with_options("cstype bezier\nv 0 0 0\nvp 0.5 0.5\np 1\n", options);
+        let result = parser.parse_objset().unwrap();
+        let expected = vec![
+            (String::from("cstype"), vec![String::from("bezier")]),
+            (String::from("vp"), vec![String::from("0.5"), String::from("0.5")]),
+        ];
+
+        assert_eq!(*statements.borrow(), expected);
+        assert_eq!(result.objects.len(), 1);
+        assert_eq!(result.objects[0].element_set.len(), 1);
     }
 
     #[test]
-    fn test_parse_texture_vertex3() {
-        let mut parser = Parser::new(
-            "vt -1.929448 13.329624 -5.221914
-             vt -27.6068  31.1438    27.2099",
-        );
-        assert_eq!(
-            parser.parse_texture_vertex(),
-            Ok(TextureVertex {
-                u: -1.929448,
-                v: 13.329624,
-                w: -5.221914,
-            })
-        );
-        assert_eq!(parser.next(), Some("\n"));
-        assert_eq!(
-            parser.parse_texture_vertex(),
-            Ok(TextureVertex {
-                u: -27.6068,
-                v: 31.1438,
-                w: 27.2099,
-            })
-        );
+    fn test_handler_rejects_statement() {
+        let handler = |keyword: &str, _: &[&str]| Err(format!("Rejected `{}`.", keyword));
+        let options = ParserOptions::default().statement_handler(handler);
+        let mut parser = Parser::with_options("v 0 0 0\np 1\nvp 0.5\n", options);
+        let error = parser.parse_objset().unwrap_err();
+
+        assert_eq!(error.line_number, 3);
+        assert_eq!(error.message, "Rejected `vp`.");
     }
 
     #[test]
-    fn test_parse_texture_vertex4() {
-        let mut parser = Parser::new("vt -1.929448 13.329624 -5.221914 vt -27.6068  31.1438    27.2099");
-        assert_eq!(
-            parser.parse_texture_vertex(),
-            Ok(TextureVertex {
-                u: -1.929448,
-                v: 13.329624,
-                w: -5.221914,
-            })
-        );
-        assert_eq!(parser.peek(), Some("vt"));
-        assert_eq!(
-            parser.parse_texture_vertex(),
-            Ok(TextureVertex {
-                u: -27.6068,
-                v: 31.1438,
-                w: 27.2099,
-            })
-        );
+    fn test_lenient_mode_records_unknown_statements() {
+        let options = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        };
+        let obj_file = "cstype bezier\nv 0 0 0\nvp   0.5  0.5 # A parameter vertex.\np 1\nbevel\n";
+        let mut parser = Parser::with_options(obj_file, options);
+        let result = parser.parse_objset().unwrap();
+        let expected = vec![
+            UnknownStatement {
+                line_number: 1,
+                text: String::from("cstype bezier"),
+            },
+            UnknownStatement {
+                line_number: 3,
+                text: String::from("vp   0.5  0.5"),
+            },
+            UnknownStatement {
+                line_number: 5,
+                text: String::from("bevel"),
+            },
+        ];
+
+        assert_eq!(result.unknown_statements, expected);
+        assert_eq!(result.objects[0].element_set.len(), 1);
+    }
+
+    #[test]
+    fn test_handler_takes_precedence_over_lenient_mode() {
+        let handler = |_: &str, _: &[&str]| Ok(());
+        let options = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        }
+        .statement_handler(handler);
+        let mut parser = Parser::with_options("v 0 0 0\nvp 0.5\np 1\n", options);
+        let result = parser.parse_objset().unwrap();
+
+        assert!(result.unknown_statements.is_empty());
     }
 }
 
 #[cfg(test)]
-mod normal_vertex_tests {
+mod from_str_tests {
     use super::{
+        Element,
+        ErrorKind,
         NormalVertex,
-        Parser,
+        TextureVertex,
+        VTNIndex,
+        Vertex,
     };
 
 
     #[test]
-    fn test_parse_normal_vertex1() {
-        let mut parser = Parser::new("vn  -0.966742  -0.255752  9.97231e-09");
-        let vn = NormalVertex {
-            x: -0.966742,
-            y: -0.255752,
-            z: 9.97231e-09,
+    fn test_vertex_from_str() {
+        let expected = Vertex {
+            x: 1.0,
+            y: -2.0,
+            z: 3.5,
+            w: 0.5,
         };
-        assert_eq!(parser.parse_normal_vertex(), Ok(vn));
-    }
 
-    #[test]
-    fn test_parse_normal_vertex2() {
-        let mut parser = Parser::new(
-            "vn -1.929448 13.329624 -5.221914
-             vn -27.6068  31.1438    27.2099",
-        );
-        assert_eq!(
-            parser.parse_normal_vertex(),
-            Ok(NormalVertex {
-                x: -1.929448,
-                y: 13.329624,
-                z: -5.221914,
-            })
-        );
-        assert_eq!(parser.next(), Some("\n"));
-        assert_eq!(
-            parser.parse_normal_vertex(),
-            Ok(NormalVertex {
-                x: -27.6068,
-                y: 31.1438,
-                z: 27.2099,
-            })
-        );
+        assert_eq!("v 1 -2 3.5 0.5\n".parse::<Vertex>(), Ok(expected));
     }
 
     #[test]
-    fn test_parse_normal_vertex3() {
-        let mut parser = Parser::new("vn -1.929448 13.329624 -5.221914 vn -27.6068  31.1438    27.2099");
-        assert_eq!(
-            parser.parse_normal_vertex(),
-            Ok(NormalVertex {
-                x: -1.929448,
-                y: 13.329624,
-                z: -5.221914,
-            })
-        );
-        assert_eq!(parser.peek(), Some("vn"));
+    fn test_vertex_from_str_trailing_input() {
+        let result = "v 1 2 3 4 5".parse::<Vertex>();
+
         assert_eq!(
-            parser.parse_normal_vertex(),
-            Ok(NormalVertex {
-                x: -27.6068,
-                y: 31.1438,
-                z: 27.2099,
-            })
+            result.map_err(|error| error.kind),
+            Err(ErrorKind::ExpectedEndOfStatement)
         );
     }
-}
-
-#[cfg(test)]
-mod object_tests {
-    use super::Parser;
-
 
     #[test]
-    fn test_parse_object_name1() {
-        let mut parser = Parser::new("o object_name \n\n");
-        assert_eq!(parser.parse_object_name(), Ok("object_name"));
+    fn test_vertex_from_str_wrong_statement() {
+        assert!("vn 1 2 3".parse::<Vertex>().is_err());
     }
 
     #[test]
-    fn test_parse_object_name2() {
-        let mut parser = Parser::new("o object_name");
-        assert!(parser.parse_object_name().is_err());
+    fn test_texture_vertex_from_str() {
+        let expected = TextureVertex {
+            u: 0.25,
+            v: 0.75,
+            w: 0.0,
+        };
+
+        assert_eq!("vt 0.25 0.75".parse::<TextureVertex>(), Ok(expected));
     }
-}
 
-#[cfg(test)]
-mod vtn_index_tests {
-    use super::{
-        Parser,
-        VTNIndex,
-    };
+    #[test]
+    fn test_normal_vertex_from_str() {
+        let expected = NormalVertex {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
 
+        assert_eq!("vn 0 0 1".parse::<NormalVertex>(), Ok(expected));
+    }
 
     #[test]
-    fn test_parse_vtn_index1() {
-        let mut parser = Parser::new("1291");
-        let expected = VTNIndex::V(1290);
-        let result = parser.parse_vtn_index((0, 1300), (0, 1300), (0, 1300));
-        assert_eq!(result, Ok(expected));
+    fn test_vtn_index_from_str() {
+        assert_eq!("7".parse::<VTNIndex>(), Ok(VTNIndex::V(6)));
+        assert_eq!("7/8".parse::<VTNIndex>(), Ok(VTNIndex::VT(6, 7)));
+        assert_eq!("7//9".parse::<VTNIndex>(), Ok(VTNIndex::VN(6, 8)));
+        assert_eq!("7/8/9".parse::<VTNIndex>(), Ok(VTNIndex::VTN(6, 7, 8)));
+        assert!("7/8/9/10".parse::<VTNIndex>().is_err());
+        assert!("7 8".parse::<VTNIndex>().is_err());
     }
 
     #[test]
-    fn test_parse_vtn_index2() {
-        let mut parser = Parser::new("1291/1315");
-        let expected = VTNIndex::VT(1290, 1314);
-        let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
-        assert_eq!(result, Ok(expected));
+    fn test_element_from_str() {
+        assert_eq!("p 2".parse::<Element>(), Ok(Element::Point(VTNIndex::V(1))));
+        assert_eq!(
+            "l 1 2".parse::<Element>(),
+            Ok(Element::Line(VTNIndex::V(0), VTNIndex::V(1)))
+        );
     }
 
     #[test]
-    fn test_parse_vtn_index3() {
-        let mut parser = Parser::new("1291/1315/1314");
-        let expected = VTNIndex::VTN(1290, 1314, 1313);
-        let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
-        assert_eq!(result, Ok(expected));
+    fn test_element_from_str_multiple_elements() {
+        let result = "l 1 2 3".parse::<Element>();
+
+        assert_eq!(
+            result.map_err(|error| error.kind),
+            Err(ErrorKind::ExpectedSingleElement)
+        );
     }
 
     #[test]
-    fn test_parse_vtn_index4() {
-        let mut parser = Parser::new("1291//1315");
-        let expected = VTNIndex::VN(1290, 1314);
-        let result = parser.parse_vtn_index((0, 1316), (0, 1316), (0, 1316));
-        assert_eq!(result, Ok(expected));
+    fn test_display_output_parses_back() {
+        let vertex = Vertex {
+            x: 1.5,
+            y: 2.0,
+            z: -3.0,
+            w: 1.0,
+        };
+        let element = Element::Face(
+            VTNIndex::VTN(0, 1, 2),
+            VTNIndex::VTN(3, 4, 5),
+            VTNIndex::VTN(6, 7, 8),
+        );
+
+        assert_eq!(vertex.to_string().parse::<Vertex>(), Ok(vertex));
+        assert_eq!(element.to_string().parse::<Element>(), Ok(element));
     }
 }
 
 #[cfg(test)]
-mod element_tests {
+mod typed_set_tests {
     use super::{
-        Element,
-        Parser,
+        Group,
+        GroupIndex,
+        GroupSet,
+        IndexError,
         VTNIndex,
+        VertexIndex,
     };
 
 
-    #[test]
-    fn test_parse_point1() {
-        let mut parser = Parser::new("p 1 2 3 4 \n");
-        let mut result = vec![];
-        let expected = vec![
-            Element::Point(VTNIndex::V(0)),
-            Element::Point(VTNIndex::V(1)),
-            Element::Point(VTNIndex::V(2)),
-            Element::Point(VTNIndex::V(3)),
-        ];
-        assert!(parser.parse_elements(&mut result, (0, 5), (0, 5), (0, 5)).is_ok());
-        assert_eq!(result, expected);
+    fn group_set() -> GroupSet {
+        GroupSet::from(vec![Group(String::from("a")), Group(String::from("b"))])
     }
 
     #[test]
-    fn test_parse_point2() {
-        let mut parser = Parser::new("p 1 1/2 3 4/5");
-        let mut result = vec![];
-        assert!(parser
-            .parse_elements(&mut result, (0, 6), (0, 6), (0, 6))
-            .is_err());
-    }
+    fn test_typed_set_index() {
+        let group_set = group_set();
 
-    #[test]
-    fn test_parse_line1() {
-        let mut parser = Parser::new("l 297 38 118 108 \n");
-        let mut result = vec![];
-        let expected = vec![
-            Element::Line(VTNIndex::V(296), VTNIndex::V(37)),
-            Element::Line(VTNIndex::V(37), VTNIndex::V(117)),
-            Element::Line(VTNIndex::V(117), VTNIndex::V(107)),
-        ];
-        assert!(parser
-            .parse_elements(&mut result, (0, 300), (0, 300), (0, 300))
-            .is_ok());
-        assert_eq!(result, expected);
+        assert_eq!(group_set[GroupIndex(1)], Group(String::from("b")));
     }
 
     #[test]
-    fn test_parse_line2() {
-        let mut parser = Parser::new("l 297/38 118/108 \n");
-        let mut result = vec![];
-        let expected = vec![Element::Line(VTNIndex::VT(296, 37), VTNIndex::VT(117, 107))];
-        assert!(parser
-            .parse_elements(&mut result, (0, 300), (0, 300), (0, 300))
-            .is_ok());
-        assert_eq!(result, expected);
+    fn test_typed_set_try_get_out_of_range() {
+        let group_set = group_set();
+        let expected = Err(IndexError {
+            set: "group set",
+            index: 2,
+            length: 2,
+        });
+
+        assert_eq!(group_set.try_get(GroupIndex(2)), expected);
     }
 
     #[test]
-    fn test_parse_line3() {
-        let mut parser = Parser::new("l 297/38 118/108 324/398 \n");
-        let mut result = vec![];
-        let expected = vec![
-            Element::Line(VTNIndex::VT(296, 37), VTNIndex::VT(117, 107)),
-            Element::Line(VTNIndex::VT(117, 107), VTNIndex::VT(323, 397)),
-        ];
-        assert!(parser
-            .parse_elements(&mut result, (0, 400), (0, 400), (0, 400))
-            .is_ok());
-        assert_eq!(result, expected);
-    }
+    fn test_typed_set_iter_indexed() {
+        let group_set = group_set();
+        let indices: Vec<GroupIndex> = group_set.iter_indexed().map(|(index, _)| index).collect();
 
-    #[test]
-    fn test_parse_line4() {
-        let mut parser = Parser::new("l 297/38 118 324 \n");
-        let mut result = vec![];
-        assert!(parser
-            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
-            .is_err());
+        assert_eq!(indices, group_set.indices().collect::<Vec<_>>());
+        assert_eq!(indices, vec![GroupIndex(0), GroupIndex(1)]);
     }
 
     #[test]
-    fn test_parse_line5() {
-        let mut parser = Parser::new("l 297 118/108 324/398 \n");
-        let mut result = vec![];
-        assert!(parser
-            .parse_elements(&mut result, (0, 400), (0, 400), (0, 400))
-            .is_err());
-    }
+    fn test_typed_set_push_indexed() {
+        let mut group_set = group_set();
+        let index = group_set.push_indexed(Group(String::from("c")));
 
-    #[test]
-    fn test_parse_face1() {
-        let mut parser = Parser::new("f 297 118 108\n");
-        let mut result = vec![];
-        let expected = vec![Element::Face(
-            VTNIndex::V(296),
-            VTNIndex::V(117),
-            VTNIndex::V(107),
-        )];
-        assert!(parser
-            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
-            .is_ok());
-        assert_eq!(result, expected);
+        assert_eq!(index, GroupIndex(2));
+        assert_eq!(group_set[index], Group(String::from("c")));
     }
 
     #[test]
-    fn test_parse_face2() {
-        let mut parser = Parser::new("f 297 118 108 324\n");
-        let mut result = vec![];
-        let expected = vec![
-            Element::Face(VTNIndex::V(296), VTNIndex::V(117), VTNIndex::V(107)),
-            Element::Face(VTNIndex::V(296), VTNIndex::V(107), VTNIndex::V(323)),
-        ];
-        assert!(parser
-            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
-            .is_ok());
-        assert_eq!(result, expected);
-    }
+    fn test_vtn_index_typed_indices() {
+        let vtn_index = VTNIndex::VN(3, 4);
 
-    #[test]
-    fn test_parse_face3() {
-        let mut parser = Parser::new("f 297 118 108 324 398 \n");
-        let mut result = vec![];
-        let expected = vec![
-            Element::Face(VTNIndex::V(296), VTNIndex::V(117), VTNIndex::V(107)),
-            Element::Face(VTNIndex::V(296), VTNIndex::V(107), VTNIndex::V(323)),
-            Element::Face(VTNIndex::V(296), VTNIndex::V(323), VTNIndex::V(397)),
-        ];
-        assert!(parser
-            .parse_elements(&mut result, (0, 400), (0, 400), (0, 400))
-            .is_ok());
-        assert_eq!(result, expected);
+        assert_eq!(vtn_index.vertex_index(), VertexIndex(3));
+        assert_eq!(vtn_index.texture_vertex_index(), None);
+        assert_eq!(vtn_index.normal_vertex_index().map(|index| index.0), Some(4));
     }
+}
+
+#[cfg(test)]
+mod vertex_adjacency_tests {
+    use super::{
+        ElementIndex,
+        VertexIndex,
+    };
+
 
     #[test]
-    fn test_parse_face4() {
-        let mut parser = Parser::new("f 297 118 \n");
-        let mut result = vec![];
-        assert!(parser
-            .parse_face(&mut result, (0, 400), (0, 400), (0, 400))
-            .is_err());
+    fn test_vertex_adjacency_ignores_points_and_lines() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 5 5 5\np 4\nl 3 4\nf 1 2 3\n";
+        let object_set = super::parse(obj_file).unwrap();
+        let adjacency = object_set.objects[0].vertex_adjacency();
+
+        assert_eq!(adjacency.len(), 4);
+        assert_eq!(adjacency.faces(VertexIndex(2)), &[ElementIndex(2)]);
+        assert_eq!(
+            adjacency.neighbors(VertexIndex(2)),
+            &[VertexIndex(0), VertexIndex(1)]
+        );
+        assert!(adjacency.faces(VertexIndex(3)).is_empty());
+        assert!(adjacency.neighbors(VertexIndex(3)).is_empty());
     }
 
     #[test]
-    fn test_parse_face5() {
-        let min_index = 320;
-        let max_index = 35000;
-        let vertex_index_range = (min_index, max_index);
-        let texture_index_range = (min_index, max_index);
-        let normal_index_range = (min_index, max_index);
-        let mut parser =
-            Parser::new("f 34184//34184 34088//34088 34079//34079 34084//34084 34091//34091 34076//34076\n");
-        let mut result = vec![];
-        /*
-        let expected = vec![
-            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34087, 34087), VTNIndex::VN(34078, 34078)),
-            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34078, 34078), VTNIndex::VN(34083, 34083)),
-            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34083, 34083), VTNIndex::VN(34090, 34090)),
-            Element::Face(VTNIndex::VN(34183, 34183), VTNIndex::VN(34090, 34090), VTNIndex::VN(34075, 34075)),
-        ];
-        */
-        let expected = vec![
-            Element::Face(
-                VTNIndex::VN(33863, 33863),
-                VTNIndex::VN(33767, 33767),
-                VTNIndex::VN(33758, 33758),
-            ),
-            Element::Face(
-                VTNIndex::VN(33863, 33863),
-                VTNIndex::VN(33758, 33758),
-                VTNIndex::VN(33763, 33763),
-            ),
-            Element::Face(
-                VTNIndex::VN(33863, 33863),
-                VTNIndex::VN(33763, 33763),
-                VTNIndex::VN(33770, 33770),
-            ),
-            Element::Face(
-                VTNIndex::VN(33863, 33863),
-                VTNIndex::VN(33770, 33770),
-                VTNIndex::VN(33755, 33755),
-            ),
-        ];
-        parser
-            .parse_elements(
-                &mut result,
-                vertex_index_range,
-                texture_index_range,
-                normal_index_range,
-            )
-            .unwrap();
+    fn test_vertex_adjacency_degenerate_face() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nf 1 1 2\n";
+        let object_set = super::parse(obj_file).unwrap();
+        let adjacency = object_set.objects[0].vertex_adjacency();
 
-        assert_eq!(result, expected);
+        assert_eq!(adjacency.faces(VertexIndex(0)), &[ElementIndex(0)]);
+        assert_eq!(adjacency.neighbors(VertexIndex(0)), &[VertexIndex(1)]);
     }
 
     #[test]
-    fn test_parse_face6() {
-        let mut parser = Parser::new("f 297/13/12 118/124/45 108/93/7\n");
-        let mut result = vec![];
-        let expected = vec![Element::Face(
-            VTNIndex::VTN(296, 12, 11),
-            VTNIndex::VTN(117, 123, 44),
-            VTNIndex::VTN(107, 92, 6),
-        )];
-        assert!(parser
-            .parse_elements(&mut result, (0, 340), (0, 340), (0, 340))
-            .is_ok());
-        assert_eq!(result, expected);
+    fn test_vertex_adjacency_out_of_range() {
+        let object_set = super::parse("v 0 0 0\nv 1 0 0\nv 1 1 0\nf 1 2 3\n").unwrap();
+        let adjacency = object_set.objects[0].vertex_adjacency();
+
+        assert!(adjacency.faces(VertexIndex(3)).is_empty());
+        assert!(adjacency.neighbors(VertexIndex(usize::MAX)).is_empty());
     }
 }
 
 #[cfg(test)]
-mod group_tests {
+mod simplify_tests {
     use super::{
-        Group,
-        Parser,
+        Element,
+        Object,
+        VTNIndex,
     };
+    use std::f64::consts::PI;
+
+
+    /// A closed latitude/longitude sphere with texture coordinates.
+    fn sphere(rings: usize, segments: usize) -> Object {
+        let mut obj_file = String::from("v 0 0 1\n");
+        for ring in 1..rings {
+            let theta = PI * ring as f64 / rings as f64;
+            for segment in 0..segments {
+                let phi = 2_f64 * PI * segment as f64 / segments as f64;
+                obj_file += &format!(
+                    "v {} {} {}\n",
+                    theta.sin() * phi.cos(),
+                    theta.sin() * phi.sin(),
+                    theta.cos()
+                );
+            }
+        }
+        obj_file += "v 0 0 -1\nvt 0 0\n";
+        let bottom = 2 + (rings - 1) * segments;
+        let ring_vertex = |ring: usize, segment: usize| 2 + (ring - 1) * segments + segment % segments;
+        for segment in 0..segments {
+            obj_file += &format!(
+                "f 1/1 {}/1 {}/1\n",
+                ring_vertex(1, segment),
+                ring_vertex(1, segment + 1)
+            );
+            obj_file += &format!(
+                "f {}/1 {}/1 {}/1\n",
+                bottom,
+                ring_vertex(rings - 1, segment + 1),
+                ring_vertex(rings - 1, segment)
+            );
+            for ring in 1..(rings - 1) {
+                obj_file += &format!(
+                    "f {}/1 {}/1 {}/1 {}/1\n",
+                    ring_vertex(ring, segment),
+                    ring_vertex(ring + 1, segment),
+                    ring_vertex(ring + 1, segment + 1),
+                    ring_vertex(ring, segment + 1)
+                );
+            }
+        }
+        obj_file += "l 1/1 2/1\n";
+
+        super::parse(&obj_file).unwrap().objects.remove(0)
+    }
 
+    fn face_count(object: &Object) -> usize {
+        object
+            .element_set
+            .iter()
+            .filter(|element| matches!(element, Element::Face(..)))
+            .count()
+    }
 
     #[test]
-    fn parse_group_name1() {
-        let mut parser = Parser::new("g group");
-        let mut result = vec![];
-        let expected = vec![Group(String::from("group"))];
-        let parsed = parser.parse_groups(&mut result);
+    fn test_simplify_reduces_faces() {
+        let object = sphere(12, 16);
+        let simplified = object.simplify(0.5);
+        let original_faces = face_count(&object);
+
+        assert!(face_count(&simplified) <= original_faces / 2);
+        assert!(face_count(&simplified) > 0);
+        assert!(simplified.vertex_set.len() < object.vertex_set.len());
+    }
 
-        assert!(parsed.is_ok());
-        assert_eq!(result, expected);
+    #[test]
+    fn test_simplify_keeps_object_consistent() {
+        let object = sphere(12, 16);
+        let simplified = object.simplify(0.3);
+        let vertex_count = simplified.vertex_set.len();
+
+        for element in simplified.element_set.iter() {
+            let vtn_indices: Vec<VTNIndex> = match *element {
+                Element::Point(vtn1) => vec![vtn1],
+                Element::Line(vtn1, vtn2) => vec![vtn1, vtn2],
+                Element::Face(vtn1, vtn2, vtn3) => vec![vtn1, vtn2, vtn3],
+            };
+            for vtn_index in vtn_indices {
+                assert!(vtn_index.vertex_index().0 < vertex_count);
+                assert_eq!(vtn_index.texture_vertex_index().map(|index| index.0), Some(0));
+            }
+        }
+        assert_eq!(simplified.shape_set.len(), simplified.element_set.len());
+        let shape_count: usize = simplified
+            .geometry_set
+            .iter()
+            .map(|geometry| geometry.shapes.len())
+            .sum();
+        assert_eq!(shape_count, simplified.element_set.len());
+        assert!(simplified
+            .element_set
+            .iter()
+            .any(|element| matches!(element, Element::Line(..))));
     }
 
     #[test]
-    fn parse_group_name2() {
-        let mut parser = Parser::new("g group1 group2 group3");
-        let mut result = vec![];
-        let parsed = parser.parse_groups(&mut result);
-        let expected = vec![
-            Group(String::from("group1")),
-            Group(String::from("group2")),
-            Group(String::from("group3")),
-        ];
+    fn test_simplify_full_ratio_is_identity() {
+        let object = sphere(6, 8);
 
-        assert!(parsed.is_ok());
-        assert_eq!(result, expected);
+        assert_eq!(object.simplify(1.0), object);
     }
 }
 
 #[cfg(test)]
-mod smoothing_group_tests {
+mod subdivide_tests {
     use super::{
-        Parser,
-        SmoothingGroup,
+        Element,
+        ElementIndex,
+        GeometryIndex,
+        NormalVertex,
+        NormalVertexIndex,
+        ShapeEntryIndex,
+        TextureVertex,
+        TextureVertexIndex,
+        VTNIndex,
     };
 
 
     #[test]
-    fn test_smoothing_group_name1() {
-        let mut parser = Parser::new("s off");
-        let mut result = vec![];
-        let parsed = parser.parse_smoothing_group(&mut result);
-        let expected = vec![SmoothingGroup(0)];
-
-        assert!(parsed.is_ok());
-        assert_eq!(result, expected);
+    fn test_subdivide_shares_edge_midpoints() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4\n";
+        let object = super::parse(obj_file).unwrap().objects.remove(0);
+        let subdivided = object.subdivide(1);
+
+        // Four corners, four outer edges, and the diagonal shared by both faces.
+        assert_eq!(subdivided.vertex_set.len(), 9);
+        assert_eq!(subdivided.element_set.len(), 8);
+        assert_eq!(subdivided.shape_set.len(), 8);
+        assert_eq!(
+            subdivided.geometry_set[GeometryIndex(0)].shapes,
+            (0..8).map(ShapeEntryIndex).collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    fn test_smoothing_group_name2() {
-        let mut parser = Parser::new("s 0");
-        let mut result = vec![];
-        let parsed = parser.parse_smoothing_group(&mut result);
-        let expected = vec![SmoothingGroup(0)];
+    fn test_subdivide_interpolates_texture_and_normal_vertices() {
+        let obj_file = "\
+            v 0 0 0\nv 2 0 0\nv 0 2 0\n\
+            vt 0 0\nvt 1 0\nvt 0 1\n\
+            vn 1 0 0\nvn 0 1 0\nvn 0 0 1\n\
+            f 1/1/1 2/2/2 3/3/3\n\
+            l 1/1 2/2\n\
+        ";
+        let object = super::parse(obj_file).unwrap().objects.remove(0);
+        let subdivided = object.subdivide(1);
+        let (vtn1, vtn12) = match subdivided.element_set[ElementIndex(0)] {
+            Element::Face(vtn1, vtn12, _) => (vtn1, vtn12),
+            other => panic!("Expected a face but got {:?}", other),
+        };
+        let half = 0.5_f64.sqrt();
 
-        assert!(parsed.is_ok());
-        assert_eq!(result, expected);
+        assert_eq!(vtn1, VTNIndex::VTN(0, 0, 0));
+        assert_eq!(vtn12, VTNIndex::VTN(3, 3, 3));
+        assert_eq!(
+            subdivided.texture_vertex_set[TextureVertexIndex(3)],
+            TextureVertex {
+                u: 0.5,
+                v: 0.0,
+                w: 0.0
+            }
+        );
+        let normal = subdivided.normal_vertex_set[NormalVertexIndex(3)];
+        assert!((normal.x - half).abs() < 1e-12 && (normal.y - half).abs() < 1e-12 && normal.z == 0.0);
+        assert_eq!(
+            subdivided.element_set[ElementIndex(4)],
+            Element::Line(VTNIndex::VT(0, 0), VTNIndex::VT(1, 1))
+        );
+        assert_eq!(subdivided.normal_vertex_set.len(), 6);
+        assert!(subdivided
+            .normal_vertex_set
+            .iter()
+            .all(|normal: &NormalVertex| (normal.magnitude() - 1.0).abs() < 1e-12));
     }
 
     #[test]
-    fn test_smoothing_group_name3() {
-        let mut parser = Parser::new("s 3434");
-        let mut result = vec![];
-        let parsed = parser.parse_smoothing_group(&mut result);
-        let expected = vec![SmoothingGroup(3434)];
+    fn test_subdivide_zero_levels_is_identity() {
+        let object = super::parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n")
+            .unwrap()
+            .objects
+            .remove(0);
 
-        assert!(parsed.is_ok());
-        assert_eq!(result, expected);
+        assert_eq!(object.subdivide(0), object);
     }
 }
 
 #[cfg(test)]
-mod mtllib_tests {
-    use super::Parser;
-
-
-    #[test]
-    fn test_mtllib_empty() {
-        let mut parser = Parser::new("mtllib       ");
-        let expected: Vec<String> = vec![];
-        let expected_count = Ok(0);
-        let mut result = vec![];
-        let result_count = parser.parse_material_library_line(&mut result);
+mod compact_tests {
+    use super::{
+        Element,
+        ElementIndex,
+        NormalVertex,
+        VTNIndex,
+    };
 
-        assert_eq!(result, expected);
-        assert_eq!(result_count, expected_count);
-    }
 
     #[test]
-    fn test_mtllib1() {
-        let mut parser = Parser::new("mtllib library1.mtl");
-        let expected: Vec<String> = vec![String::from("library1.mtl")];
-        let expected_count = Ok(1);
-        let mut result = vec![];
-        let result_count = parser.parse_material_library_line(&mut result);
+    fn test_compact_removes_unused_normal_vertices() {
+        let obj_file = "\
+            v 0 0 0\nv 1 0 0\nv 0 1 0\nv 5 5 5\n\
+            vn 1 0 0\nvn 0 1 0\nvn 0 0 1\n\
+            f 1//3 2//3 3//3\n\
+            l 2 3\n\
+        ";
+        let mut object = super::parse(obj_file).unwrap().objects.remove(0);
+        object.compact();
 
-        assert_eq!(result, expected);
-        assert_eq!(result_count, expected_count);
+        assert_eq!(object.vertex_set.len(), 3);
+        assert_eq!(
+            object.normal_vertex_set.as_slice(),
+            &[NormalVertex {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0
+            }]
+        );
+        assert_eq!(
+            object.element_set[ElementIndex(0)],
+            Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 0), VTNIndex::VN(2, 0))
+        );
+        assert_eq!(
+            object.element_set[ElementIndex(1)],
+            Element::Line(VTNIndex::V(1), VTNIndex::V(2))
+        );
     }
 
     #[test]
-    fn test_mtllib2() {
-        let mut parser = Parser::new("mtllib library1.mtl library2.mtl library3.mtl");
-        let expected: Vec<String> = vec![
-            String::from("library1.mtl"),
-            String::from("library2.mtl"),
-            String::from("library3.mtl"),
-        ];
-        let expected_count = Ok(3);
-        let mut result = vec![];
-        let result_count = parser.parse_material_library_line(&mut result);
+    fn test_compact_without_unused_data() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nf 1/1 2/1 3/1\n";
+        let object = super::parse(obj_file).unwrap().objects.remove(0);
+        let mut compacted = object.clone();
+        compacted.compact();
 
-        assert_eq!(result, expected);
-        assert_eq!(result_count, expected_count);
+        assert_eq!(compacted, object);
     }
 }
 
-
 #[cfg(test)]
 mod objectset_tests {
     use super::{
+        Axes,
+        Axis,
         Element,
+        ElementIndex,
         Geometry,
         Group,
+        GroupIndex,
         NormalVertex,
         Object,
         ObjectSet,
         ParseError,
         Parser,
         ShapeEntry,
+        ShapeEntryIndex,
         SmoothingGroup,
+        SmoothingGroupIndex,
+        Units,
         VTNIndex,
         Vertex,
     };
+    use std::collections::HashMap;
+    use std::sync::Arc;
 
 
     #[rustfmt::skip]
@@ -2441,40 +13008,48 @@ mod objectset_tests {
         let group_set = vec![Group(String::from("cube"))];
         let smoothing_group_set = vec![SmoothingGroup(0)];
         let shape_set = vec![
-            ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 4,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 5,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 6,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 7,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 8,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 9,  groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 10, groups: vec![0], smoothing_group: 0 },
-            ShapeEntry { element: 11, groups: vec![0], smoothing_group: 0 },
+            ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(4),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(5),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(6),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(7),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(8),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(9),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(10), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+            ShapeEntry { element: ElementIndex(11), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
         ];
         let geometry_set = vec![
             Geometry { 
                 material_name: None, 
-                shapes: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+                shapes: (0..12).map(ShapeEntryIndex).collect(),
             },
         ];
         let object = Object {
             name: name,
-            vertex_set: vertex_set,
-            texture_vertex_set: texture_vertex_set,
-            normal_vertex_set: normal_vertex_set,
-            group_set: group_set,
-            smoothing_group_set: smoothing_group_set,
-            element_set: element_set,
-            shape_set: shape_set,
-            geometry_set: geometry_set,
+            source: None,
+            vertex_set: vertex_set.into(),
+            texture_vertex_set: texture_vertex_set.into(),
+            normal_vertex_set: normal_vertex_set.into(),
+            group_set: group_set.into(),
+            smoothing_group_set: smoothing_group_set.into(),
+            element_set: element_set.into(),
+            shape_set: shape_set.into(),
+            geometry_set: geometry_set.into(),
+            element_line_set: vec![],
+            element_text_set: vec![],
+            original_element_set: vec![],
+            numeric_text: Default::default(),
         };
         let material_libraries = vec![];
         let objects = vec![object];
         let expected = ObjectSet {
             material_libraries: material_libraries,
+            external_references: vec![],
+            unknown_statements: vec![],
+            warnings: vec![],
             objects: objects,
         };
         let mut parser = Parser::new(obj_file);
@@ -2506,4 +13081,520 @@ mod objectset_tests {
             assert_eq!(result.shape_set, expected.shape_set);
         }
     }
+
+    #[test]
+    fn test_parse_object_set_display_is_valid_obj() {
+        let (result, _) = test_case();
+        let result = result.unwrap();
+        let text = result.to_string();
+
+        assert_eq!(Parser::new(&text).parse_objset(), Ok(result));
+    }
+
+    #[test]
+    fn test_parse_object_display_is_valid_obj() {
+        let (result, _) = test_case();
+        let result = result.unwrap();
+        for object in result.objects.iter() {
+            let reparsed = Parser::new(&object.to_string()).parse_objset().unwrap();
+
+            assert_eq!(reparsed.objects[0].vertex_set, object.vertex_set);
+            assert_eq!(reparsed.objects[0].element_set, object.element_set);
+        }
+    }
+
+    #[test]
+    fn test_parse_object_set_summary() {
+        let (result, _) = test_case();
+        let summary = result.unwrap().summary();
+
+        assert!(summary.starts_with("ObjectSet {\nObject {\n    name: object1\n"));
+        assert!(!summary.contains("}}"));
+    }
+
+    #[test]
+    fn test_parse_object_set_shares_group_lists() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\ng a b\nf 1 2 3\nf 1 2 3\ng c\nf 1 2 3\n";
+        let result = Parser::new(obj_file).parse_objset().unwrap();
+        let shape_set = result.objects[0].shape_set.as_slice();
+
+        assert!(Arc::ptr_eq(&shape_set[0].groups, &shape_set[1].groups));
+        assert!(!Arc::ptr_eq(&shape_set[1].groups, &shape_set[2].groups));
+        assert_eq!(&shape_set[2].groups[..], &[GroupIndex(2)]);
+    }
+
+    #[test]
+    fn test_audit_texture_coordinates_across_objects() {
+        let obj_file = "\
+            o first\n\
+            v 0 0 0\nv 1 0 0\nv 0 1 0\n\
+            vt 0 0\nvt 1.5 0\nvt 0 1\n\
+            usemtl tiled\n\
+            f 1/1 2/2 3/3\n\
+            f 1/1 2/2 3/3\n\
+            o second\n\
+            v 0 0 0\nv 1 0 0\nv 0 1 0\n\
+            vt 0 -4\nvt 1 0\nvt 0 1\n\
+            f 4/4 5/5 6/6\n\
+            usemtl tiled\n\
+            f 4/4 5/5 6/6\n\
+        ";
+        let object_set = super::parse(obj_file).unwrap();
+        let report = object_set.audit_texture_coordinates(1.0);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].material_name.as_deref(), Some("tiled"));
+        assert_eq!(report[0].texture_vertex_count, 6);
+        assert_eq!(report[0].out_of_range_count, 1);
+        assert_eq!((report[0].min_u, report[0].max_u), (0.0, 1.5));
+        assert_eq!((report[0].min_v, report[0].max_v), (-4.0, 1.0));
+        assert_eq!(report[1].material_name, None);
+        assert_eq!(
+            report[1].to_string(),
+            "No material: 1 of 3 texture vertices out of range, u in [0, 1], v in [-4, 1]"
+        );
+        assert!(object_set.audit_texture_coordinates(5.0).is_empty());
+    }
+
+    #[test]
+    fn test_convert_axes_round_trip() {
+        let obj_file = "v 1 2 3\nv 4 5 6\nv 7 8 10\nvn 0.6 0 0.8\nf 1//1 2//1 3//1\n";
+        let original = super::parse(obj_file).unwrap();
+        let conventions = [
+            Axes::YUpRightHanded,
+            Axes::ZUpRightHanded,
+            Axes::YUpLeftHanded,
+            Axes::ZUpLeftHanded,
+        ];
+        for &from in conventions.iter() {
+            for &to in conventions.iter() {
+                let mut object_set = original.clone();
+                object_set.convert_axes(from, to);
+                object_set.convert_axes(to, from);
+
+                assert_eq!(object_set, original, "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_axes_reverses_winding_when_mirroring() {
+        let obj_file = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+        let mut object_set = super::parse(obj_file).unwrap();
+        object_set.convert_axes(Axes::YUpRightHanded, Axes::YUpLeftHanded);
+
+        assert_eq!(
+            object_set.objects[0].element_set.as_slice(),
+            &[Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(1))]
+        );
+
+        let mut object_set = super::parse(obj_file).unwrap();
+        object_set.convert_axes(Axes::ZUpRightHanded, Axes::YUpRightHanded);
+
+        assert_eq!(
+            object_set.objects[0].element_set.as_slice(),
+            &[Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2))]
+        );
+        assert_eq!(
+            object_set.objects[0].vertex_set.as_slice()[2],
+            Vertex {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+                w: 1.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_scale_units_leaves_normals_and_texture_vertices_alone() {
+        let obj_file = "v 1 2 3 0.5\nvt 0.25 0.75\nvn 0 0 1\np 1\n";
+        let mut object_set = super::parse(obj_file).unwrap();
+        let expected = super::parse(obj_file).unwrap();
+        object_set.scale_units(Units::conversion_factor(Units::Feet, Units::Inches));
+        let object = &object_set.objects[0];
+
+        assert_eq!(
+            object.vertex_set.as_slice(),
+            &[Vertex {
+                x: 12.0,
+                y: 24.0,
+                z: 36.0,
+                w: 0.5
+            }]
+        );
+        assert_eq!(object.texture_vertex_set, expected.objects[0].texture_vertex_set);
+        assert_eq!(object.normal_vertex_set, expected.objects[0].normal_vertex_set);
+    }
+
+    #[test]
+    fn test_remap_materials() {
+        let mut object_set = super::parse(
+            "mtllib a.mtl b.mtl\nmtllib a.mtl\no x\nv 0 0 0\nusemtl grey\np 1\np 1\np 1\n\
+             p 1\nusemtl red\np 1\no y\nv 1 1 1\np 2\nusemtl grey\np 2\n",
+        )
+        .unwrap();
+        let original = object_set.clone();
+        let table: HashMap<String, String> = [("a.mtl", "c.mtl"), ("grey", "steel"), ("steel", "iron")]
+            .iter()
+            .map(|&(old, new)| (String::from(old), String::from(new)))
+            .collect();
+        object_set.remap_materials(&table);
+
+        let library_names: Vec<&str> = object_set
+            .material_libraries
+            .iter()
+            .map(|material_library| material_library.name.as_str())
+            .collect();
+        assert_eq!(library_names, vec!["c.mtl", "b.mtl", "c.mtl"]);
+        let material_names: Vec<Option<&str>> = object_set
+            .objects
+            .iter()
+            .flat_map(|object| object.geometry_set.iter())
+            .map(|geometry| geometry.material_name.as_deref())
+            .collect();
+        assert_eq!(
+            material_names,
+            vec![Some("steel"), Some("red"), None, Some("steel")]
+        );
+        for (object, original_object) in object_set.objects.iter().zip(original.objects.iter()) {
+            assert_eq!(object.vertex_set, original_object.vertex_set);
+            assert_eq!(object.element_set, original_object.element_set);
+            assert_eq!(object.shape_set, original_object.shape_set);
+        }
+    }
+
+    #[test]
+    fn test_mirror_twice_is_identity() {
+        let obj_file = "v 1 2 3\nv 4 5 6\nv 7 8 10\nvn 0.6 0 0.8\nf 1//1 2//1 3//1\nl 1 2\n";
+        let original = super::parse(obj_file).unwrap().objects.remove(0);
+        for &axis in [Axis::X, Axis::Y, Axis::Z].iter() {
+            let mut object = original.clone();
+            object.mirror(axis);
+
+            assert_ne!(object, original);
+            object.mirror(axis);
+            assert_eq!(object, original);
+        }
+    }
+}
+
+#[cfg(test)]
+mod comment_tests {
+    use super::{
+        ExternalReference,
+        Group,
+        Parser,
+        SmoothingGroup,
+        TextureVertex,
+        Vertex,
+    };
+
+
+    const OBJ_FILE: &str = "\
+        mtllib scene.mtl # The material library.\n\
+        o corner # The name ends before the comment.\n\
+        v 1.0 2.0 3.0  # corner A\n\
+        v 1.0 2.0 3.0 0.5#corner B\n\
+        v 0.0 0.0 0.0\t# corner C\n\
+        vt 0.5 # u only\n\
+        g left right # two groups\n\
+        s 1 # smooth\n\
+        usemtl red#the red material\n\
+        f 1/1 2/1 3/1 # face\n\
+        l 1/1 2/1#line\n\
+        call other.obj 1 2 # call\n\
+        # A comment on its own line.\n\
+    ";
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_parse_trailing_comments() {
+        let result = Parser::new(OBJ_FILE).parse_objset().unwrap();
+        let object = &result.objects[0];
+        let vertex_set = vec![
+            Vertex { x: 1.0, y: 2.0, z: 3.0, w: 1.0 },
+            Vertex { x: 1.0, y: 2.0, z: 3.0, w: 0.5 },
+            Vertex { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+        ];
+        let group_set = vec![Group(String::from("left")), Group(String::from("right"))];
+        let external_references = vec![ExternalReference::Call {
+            file_name: String::from("other.obj"),
+            arguments: vec![String::from("1"), String::from("2")],
+        }];
+
+        assert_eq!(result.material_libraries[0].name, "scene.mtl");
+        assert_eq!(object.name, "corner");
+        assert_eq!(object.vertex_set, vertex_set.into());
+        assert_eq!(object.texture_vertex_set, vec![TextureVertex { u: 0.5, v: 0.0, w: 0.0 }].into());
+        assert_eq!(object.group_set, group_set.into());
+        assert_eq!(object.smoothing_group_set, vec![SmoothingGroup(1)].into());
+        let material_name = object.geometry_set.first().and_then(|geometry| geometry.material_name.as_deref());
+        assert_eq!(material_name, Some("red"));
+        assert_eq!(object.element_set.len(), 2);
+        assert_eq!(result.external_references, external_references);
+    }
+
+    #[test]
+    fn test_parse_comment_glued_to_keyword() {
+        let result = Parser::new("v 0 0 0\nv 1 0 0\nv 0 1 0\nf#1 2 3\n").parse_objset();
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod non_finite_tests {
+    use super::{
+        ErrorKind,
+        NonFinitePolicy,
+        Parser,
+        ParserOptions,
+        TextureVertex,
+        Vertex,
+        WarningKind,
+    };
+
+
+    const OBJ_FILE: &str = "v 1.0 nan 3.0\nv inf -inf 0.0 NaN\nvt 0.5 inf\nvn 0.0 0.0 1.0\n";
+
+    fn parse_with(policy: NonFinitePolicy) -> Parser<'static> {
+        let options = ParserOptions {
+            non_finite: policy,
+            ..ParserOptions::default()
+        };
+
+        Parser::with_options(OBJ_FILE, options)
+    }
+
+    #[test]
+    fn test_accept_keeps_non_finite_values() {
+        let result = parse_with(NonFinitePolicy::Accept).parse_objset().unwrap();
+        let vertex = result.objects[0].vertex_set.last().unwrap();
+
+        assert!(result.objects[0].vertex_set.first().unwrap().y.is_nan());
+        assert_eq!(vertex.x, f64::INFINITY);
+        assert_eq!(vertex.y, f64::NEG_INFINITY);
+        assert!(vertex.w.is_nan());
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_reject_fails_on_the_first_non_finite_value() {
+        let error = parse_with(NonFinitePolicy::Reject).parse_objset().unwrap_err();
+
+        assert_eq!(error.kind(), &ErrorKind::NonFiniteValue);
+        assert_eq!(error.code(), "OBJ0020");
+        assert_eq!(error.line_number(), 1);
+        assert_eq!(error.span(), Some(6..9));
+    }
+
+    #[test]
+    fn test_warn_records_each_non_finite_value() {
+        let result = parse_with(NonFinitePolicy::Warn).parse_objset().unwrap();
+        let lines: Vec<usize> = result
+            .warnings
+            .iter()
+            .map(|warning| warning.line_number)
+            .collect();
+
+        assert_eq!(lines, vec![1, 2, 2, 2, 3]);
+        assert!(result
+            .warnings
+            .iter()
+            .all(|warning| warning.kind == WarningKind::NonFiniteValue));
+        assert_eq!(result.objects[0].vertex_set.last().unwrap().x, f64::INFINITY);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_clamp_replaces_non_finite_values() {
+        let result = parse_with(NonFinitePolicy::Clamp).parse_objset().unwrap();
+        let vertex_set = vec![
+            Vertex { x: 1.0, y: 0.0, z: 3.0, w: 1.0 },
+            Vertex { x: f64::MAX, y: f64::MIN, z: 0.0, w: 0.0 },
+        ];
+
+        assert_eq!(result.objects[0].vertex_set, vertex_set.into());
+        assert_eq!(
+            result.objects[0].texture_vertex_set,
+            vec![TextureVertex { u: 0.5, v: f64::MAX, w: 0.0 }].into()
+        );
+        assert!(result.warnings.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod line_ending_tests {
+    use super::{
+        ErrorKind,
+        Parser,
+    };
+
+
+    fn with_line_ending(line_ending: &str) -> String {
+        [
+            "o quad",
+            "v 0.0 0.0 0.0",
+            "v 1.0 0.0 0.0",
+            "v 1.0 1.0 0.0",
+            "v 0.0 1.0 0.0 # The last vertex.",
+            "f 1 2 3 4",
+            "",
+        ]
+        .join(line_ending)
+    }
+
+    #[test]
+    fn test_parse_every_line_ending_convention() {
+        let expected = Parser::new(&with_line_ending("\n")).parse_objset().unwrap();
+        for line_ending in ["\r\n", "\r"] {
+            let text = with_line_ending(line_ending);
+            let result = Parser::new(&text).parse_objset().unwrap();
+
+            assert_eq!(result, expected, "line_ending = {:?}", line_ending);
+        }
+    }
+
+    #[test]
+    fn test_parse_counts_lines_for_every_line_ending_convention() {
+        for line_ending in ["\n", "\r\n", "\r"] {
+            let text = with_line_ending(line_ending) + "v 0.0 x 0.0";
+            let error = Parser::new(&text).parse_objset().unwrap_err();
+
+            assert_eq!(error.kind(), &ErrorKind::ExpectedFloat);
+            assert_eq!(error.line_number(), 7, "line_ending = {:?}", line_ending);
+        }
+    }
+
+    #[test]
+    fn test_parse_mixed_line_endings() {
+        let text = "v 0.0 0.0 0.0\r\nv 1.0 0.0 0.0\rv 1.0 1.0 0.0\n\r\nf 1 2 3\r";
+        let result = Parser::new(text).parse_objset().unwrap();
+
+        assert_eq!(result.objects[0].vertex_set.len(), 3);
+        assert_eq!(result.objects[0].element_set.len(), 1);
+    }
+}
+
+
+#[cfg(test)]
+mod error_code_tests {
+    use super::{
+        ErrorKind,
+        ObjError,
+    };
+    use std::collections::HashSet;
+
+
+    #[test]
+    fn test_parse_error_display_includes_code() {
+        let error = super::parse("v 0 0 0\nv 0 x 0\n").unwrap_err();
+
+        assert_eq!(error.kind(), &ErrorKind::ExpectedFloat);
+        assert_eq!(error.code(), "OBJ0003");
+        assert!(error.to_string().starts_with("Parse error OBJ0003 at line 2: "));
+    }
+
+    #[test]
+    fn test_parse_error_span_points_at_offending_token() {
+        let text = "v 0 0 0\nv 0 x 0\n";
+        let error = super::parse(text).unwrap_err();
+
+        assert_eq!(error.span().map(|span| &text[span]), Some("x"));
+    }
+
+    #[test]
+    fn test_parse_rejects_nul_bytes() {
+        let text = "v 0 0 0\nv 0 0\0 0\n";
+        let error = super::parse(text).unwrap_err();
+
+        assert_eq!(error.kind(), &ErrorKind::NotATextFile);
+        assert_eq!(error.line_number(), 2);
+        assert_eq!(error.span(), Some(13..14));
+    }
+
+    #[test]
+    fn test_parse_slice_rejects_invalid_utf8() {
+        let bytes = b"v 0 0 0\r\nv 1 0 0\r\xff\xfe\x00\x01";
+        match super::parse_slice(bytes) {
+            Err(ObjError::Parse { path: None, error }) => {
+                assert_eq!(error.kind(), &ErrorKind::NotATextFile);
+                assert_eq!(error.code(), "OBJ0018");
+                assert_eq!(error.line_number(), 3);
+                assert_eq!(error.span(), Some(17..18));
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_codes_are_unique() {
+        let kinds = [
+            ErrorKind::EndOfFile,
+            ErrorKind::ExpectedTagStatement,
+            ErrorKind::ExpectedFloat,
+            ErrorKind::ExpectedInteger,
+            ErrorKind::ExpectedVTNIndex,
+            ErrorKind::VTNIndexOutOfRange,
+            ErrorKind::EveryFaceElementMustHaveAtLeastThreeVertices,
+            ErrorKind::EveryVTNIndexMustHaveTheSameFormForAGivenElement,
+            ErrorKind::InvalidObjectStatement,
+            ErrorKind::ElementMustBeAPointLineOrFace,
+            ErrorKind::SmoothingGroupNameMustBeOffOrInteger,
+            ErrorKind::SmoothingGroupDeclarationHasNoName,
+            ErrorKind::MaterialStatementHasNoName,
+            ErrorKind::CallStatementHasNoFileName,
+            ErrorKind::ShellStatementHasNoCommand,
+            ErrorKind::ExpectedEndOfStatement,
+            ErrorKind::ExpectedSingleElement,
+            ErrorKind::NotATextFile,
+            ErrorKind::LineTooLong,
+            ErrorKind::NonFiniteValue,
+            ErrorKind::DuplicateObjectName,
+        ];
+        let codes: HashSet<&str> = kinds.iter().map(|kind| kind.code()).collect();
+
+        assert_eq!(codes.len(), kinds.len());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod json_tests {
+    use super::{
+        ObjectSet,
+        ParserOptions,
+        JSON_SCHEMA_VERSION,
+    };
+
+
+    #[test]
+    fn test_json_roundtrip_of_file_with_groups_and_materials() {
+        let options = ParserOptions {
+            record_element_lines: true,
+            ..ParserOptions::default()
+        };
+        let object_set = super::parse_with(include_str!("../assets/minicooper.obj"), options).unwrap();
+        let json = object_set.to_json().unwrap();
+
+        assert_eq!(ObjectSet::from_json(&json).unwrap(), object_set);
+    }
+
+    #[test]
+    fn test_json_rejects_newer_schema_versions() {
+        let object_set = super::parse("v 0 0 0\np 1\n").unwrap();
+        let json = object_set.to_json().unwrap().replacen(
+            &format!("\"version\":{}", JSON_SCHEMA_VERSION),
+            &format!("\"version\":{}", JSON_SCHEMA_VERSION + 1),
+            1,
+        );
+
+        assert!(ObjectSet::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn test_json_rejects_malformed_documents() {
+        assert!(ObjectSet::from_json("{\"version\":1}").is_err());
+        assert!(ObjectSet::from_json("not json").is_err());
+    }
 }
@@ -0,0 +1,2210 @@
+//! Load a complete scene: a Wavefront OBJ file together with the material
+//! libraries it references.
+//!
+//! The scene loader never touches the file system directly. Instead, every
+//! file the loader needs is requested by name from a [`Resolver`], so assets
+//! may live on disk, inside an archive, in memory, or behind a network
+//! connection.
+use crate::input;
+use crate::mtl;
+use crate::mtl::{
+    MaterialConflict,
+    MaterialSet,
+};
+use crate::obj;
+use crate::obj::{
+    Geometry,
+    MtlLibRef,
+    Object,
+    ObjectSet,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::{
+    Path,
+    PathBuf,
+};
+#[cfg(not(feature = "rayon"))]
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+#[cfg(not(feature = "rayon"))]
+use std::thread;
+
+
+/// A resolver locates the files a scene refers to by name.
+///
+/// Names are the strings that appear in the scene files themselves, e.g. the
+/// arguments of `mtllib` statements, joined onto the directory of the file
+/// that refers to them. Implement this trait to load assets from archives,
+/// pak files, or network storage.
+pub trait Resolver {
+    /// Open the file with the given name for reading.
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>>;
+
+    /// Determine whether a file with the given name exists.
+    ///
+    /// The default implementation tries to open the file. Resolvers that can
+    /// check for a file more cheaply should override it.
+    fn exists(&self, name: &str) -> bool {
+        self.open(name).is_ok()
+    }
+}
+
+/// A resolver that looks files up relative to a root directory on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSystemResolver {
+    /// The directory that names are resolved against.
+    root: PathBuf,
+}
+
+impl FileSystemResolver {
+    /// Construct a new resolver that looks up files relative to `root`.
+    pub fn new<P: AsRef<Path>>(root: P) -> FileSystemResolver {
+        FileSystemResolver {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// The directory that names are resolved against.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Resolver for FileSystemResolver {
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        let file = File::open(self.root.join(name))?;
+
+        Ok(Box::new(file))
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.root.join(name).is_file()
+    }
+}
+
+/// A resolver that serves files from memory.
+///
+/// This is useful for assets embedded into an executable, and for testing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryResolver {
+    /// The contents of each file, keyed by name.
+    files: HashMap<String, Vec<u8>>,
+}
+
+impl MemoryResolver {
+    /// Construct a new resolver with no files in it.
+    pub fn new() -> MemoryResolver {
+        MemoryResolver {
+            files: HashMap::new(),
+        }
+    }
+
+    /// Add a file to the resolver, replacing any existing file with the same name.
+    pub fn insert<S: Into<String>, B: Into<Vec<u8>>>(&mut self, name: S, contents: B) {
+        self.files.insert(name.into(), contents.into());
+    }
+}
+
+impl Resolver for MemoryResolver {
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        match self.files.get(name) {
+            Some(contents) => Ok(Box::new(io::Cursor::new(contents.clone()))),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No file named `{}`.", name),
+            )),
+        }
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.files.contains_key(name)
+    }
+}
+
+/// An error that is returned from loading a scene.
+#[derive(Debug)]
+pub enum SceneError {
+    /// A file could not be opened or read.
+    Io {
+        /// The name of the file as passed to the resolver.
+        name: String,
+        /// The underlying input/output error.
+        error: io::Error,
+    },
+    /// The Wavefront OBJ file failed to parse.
+    Obj {
+        /// The name of the file as passed to the resolver.
+        name: String,
+        /// The underlying parse error.
+        error: obj::ParseError,
+    },
+    /// A material library named in an `mtllib` statement could not be opened
+    /// or read.
+    MaterialLibrary {
+        /// The name of the file as passed to the resolver.
+        name: String,
+        /// The line number of the `mtllib` statement in the Wavefront OBJ file.
+        line_number: usize,
+        /// The underlying input/output error.
+        error: io::Error,
+    },
+    /// A material library failed to parse.
+    Mtl {
+        /// The name of the file as passed to the resolver.
+        name: String,
+        /// The underlying parse error.
+        error: mtl::ParseError,
+    },
+    /// Two material libraries define a material with the same name, and the
+    /// loader rejects those with [`MaterialConflict::Error`].
+    MaterialConflict {
+        /// The resolved name of the later material library.
+        name: String,
+        /// The names of the materials defined twice.
+        error: mtl::MergeError,
+    },
+    /// The material libraries of a scene loaded with
+    /// [`SceneLoader::lazy_materials`] through a borrowed resolver were
+    /// requested from [`Scene::materials`], which has no resolver to load
+    /// them with. Use [`Scene::materials_with`] instead.
+    NoResolver {
+        /// The name of the Wavefront OBJ file as passed to the resolver.
+        name: String,
+    },
+    /// The scene failed the validation requested with
+    /// [`SceneLoader::validate`].
+    Invalid {
+        /// The name of the Wavefront OBJ file as passed to the resolver.
+        name: String,
+        /// The problems found in the scene.
+        report: SceneReport,
+    },
+    /// A texture map could not be decoded.
+    #[cfg(feature = "image")]
+    Texture {
+        /// The name of the file as passed to the resolver.
+        name: String,
+        /// The underlying decoding error.
+        error: image::ImageError,
+    },
+}
+
+impl SceneError {
+    /// The name of the file that generated the error.
+    pub fn name(&self) -> &str {
+        match *self {
+            SceneError::Io { ref name, .. } => name,
+            SceneError::MaterialLibrary { ref name, .. } => name,
+            SceneError::Obj { ref name, .. } => name,
+            SceneError::Mtl { ref name, .. } => name,
+            SceneError::MaterialConflict { ref name, .. } => name,
+            SceneError::NoResolver { ref name } => name,
+            SceneError::Invalid { ref name, .. } => name,
+            #[cfg(feature = "image")]
+            SceneError::Texture { ref name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SceneError::Io { ref name, ref error } => {
+                write!(formatter, "Could not read `{}`: {}", name, error)
+            }
+            SceneError::MaterialLibrary {
+                ref name,
+                line_number,
+                ref error,
+            } => write!(
+                formatter,
+                "Could not read the material library `{}` named on line {}: {}",
+                name, line_number, error
+            ),
+            SceneError::Obj { ref name, ref error } => write!(formatter, "In `{}`: {}", name, error),
+            SceneError::Mtl { ref name, ref error } => write!(formatter, "In `{}`: {}", name, error),
+            SceneError::MaterialConflict { ref name, ref error } => {
+                write!(formatter, "In `{}`: {}", name, error)
+            }
+            SceneError::NoResolver { ref name } => write!(
+                formatter,
+                "The material libraries of `{}` were not loaded, and the scene has no resolver to load them",
+                name
+            ),
+            SceneError::Invalid { ref name, ref report } => {
+                write!(formatter, "The scene `{}` failed validation:\n{}", name, report)
+            }
+            #[cfg(feature = "image")]
+            SceneError::Texture { ref name, ref error } => {
+                write!(formatter, "Could not decode the texture `{}`: {}", name, error)
+            }
+        }
+    }
+}
+
+impl error::Error for SceneError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            SceneError::Io { ref error, .. } => Some(error),
+            SceneError::MaterialLibrary { ref error, .. } => Some(error),
+            SceneError::Obj { ref error, .. } => Some(error),
+            SceneError::Mtl { ref error, .. } => Some(error),
+            SceneError::MaterialConflict { ref error, .. } => Some(error),
+            SceneError::NoResolver { .. } => None,
+            SceneError::Invalid { .. } => None,
+            #[cfg(feature = "image")]
+            SceneError::Texture { ref error, .. } => Some(error),
+        }
+    }
+}
+
+/// A scene is the geometry of a Wavefront OBJ file together with the
+/// materials from every material library it references.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scene {
+    /// The objects in the scene.
+    pub objects: ObjectSet,
+    /// The materials from every material library referenced by the objects,
+    /// in the order the libraries are declared.
+    materials: MaterialSet,
+    /// The resolved name of the material library each material was loaded
+    /// from, in the same order as the materials.
+    material_sources: Vec<String>,
+    /// The material libraries that are still to be loaded, when the scene
+    /// was loaded with [`SceneLoader::lazy_materials`].
+    deferred: Option<DeferredMaterials>,
+}
+
+impl Scene {
+    /// Load a scene using a resolver to locate its files.
+    ///
+    /// The Wavefront OBJ file named `name` is opened through the resolver, and
+    /// then each of its material libraries is opened through the resolver,
+    /// relative to the directory of the OBJ file.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("models/triangle.obj", r"
+    ///     mtllib triangle.mtl
+    ///     v 0.0 0.0 0.0
+    ///     v 1.0 0.0 0.0
+    ///     v 0.0 1.0 0.0
+    ///     usemtl red
+    ///     f 1 2 3
+    /// ");
+    /// resolver.insert("models/triangle.mtl", r"
+    ///     newmtl red
+    ///     Kd 1.0 0.0 0.0
+    /// ");
+    ///
+    /// let mut scene = Scene::load_with("models/triangle.obj", &resolver).unwrap();
+    /// assert_eq!(scene.objects.objects.len(), 1);
+    /// assert_eq!(scene.materials().unwrap().materials[0].name, "red");
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip(resolver)))]
+    pub fn load_with<R: Resolver + ?Sized>(name: &str, resolver: &R) -> Result<Scene, SceneError> {
+        Scene::load_sequential(name, resolver, obj::ParserOptions::default(), None)
+    }
+
+    /// Load a scene from a Wavefront OBJ file on disk, together with the
+    /// material libraries it references.
+    ///
+    /// This is the one-call entry point for the common case: the material
+    /// libraries are resolved relative to the directory of the file and
+    /// loaded concurrently with the geometry. Use [`Scene::loader`] to change
+    /// the parser options, merge materials with the same name, or validate
+    /// the scene while loading it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::Scene;
+    /// #
+    /// let scene = Scene::load("assets/cube.obj").unwrap();
+    /// assert_eq!(scene.objects.objects.len(), 1);
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Scene, SceneError> {
+        SceneLoader::new().load(path)
+    }
+
+    /// Construct a builder for loading a scene with non-default options.
+    pub fn loader() -> SceneLoader {
+        SceneLoader::new()
+    }
+
+    /// Load a scene with the material libraries read one after the other.
+    fn load_sequential<R: Resolver + ?Sized>(
+        name: &str,
+        resolver: &R,
+        options: obj::ParserOptions,
+        material_conflict: Option<MaterialConflict>,
+    ) -> Result<Scene, SceneError> {
+        let obj_text = read_to_string(resolver, name, obj_not_a_text_file(name))?;
+        let mut objects = obj::parse_with(obj_text, options).map_err(|error| SceneError::Obj {
+            name: String::from(name),
+            error: error,
+        })?;
+        let materials =
+            load_material_libraries(name, resolver, &mut objects.material_libraries, material_conflict)?;
+
+        Ok(Scene {
+            objects: objects,
+            materials: materials.materials,
+            material_sources: materials.sources,
+            deferred: None,
+        })
+    }
+
+    /// Parse a Wavefront OBJ file and leave its material libraries to be
+    /// loaded by [`Scene::materials`] with the stored resolver, if there is
+    /// one, or by [`Scene::materials_with`].
+    fn load_deferred<R: Resolver + ?Sized>(
+        name: &str,
+        resolver: &R,
+        stored_resolver: Option<FileSystemResolver>,
+        options: obj::ParserOptions,
+        material_conflict: Option<MaterialConflict>,
+    ) -> Result<Scene, SceneError> {
+        let obj_text = read_to_string(resolver, name, obj_not_a_text_file(name))?;
+        let mut objects = obj::parse_with(obj_text, options).map_err(|error| SceneError::Obj {
+            name: String::from(name),
+            error: error,
+        })?;
+        for material_library in objects.material_libraries.iter_mut() {
+            let library_name = resolve_relative(name, &material_library.name);
+            material_library.resolved_path = Some(PathBuf::from(library_name));
+        }
+
+        Ok(Scene {
+            objects: objects,
+            materials: MaterialCollector::new(None).materials,
+            material_sources: vec![],
+            deferred: Some(DeferredMaterials {
+                name: String::from(name),
+                resolver: stored_resolver,
+                material_conflict: material_conflict,
+            }),
+        })
+    }
+
+    /// Load a scene using a resolver to locate its files, loading the material
+    /// libraries concurrently with the geometry.
+    ///
+    /// The Wavefront OBJ file is scanned for `mtllib` statements before it is
+    /// parsed, and the material libraries found are read and parsed on a pool
+    /// of at most [`std::thread::available_parallelism`] threads while the
+    /// current thread parses the geometry. With the optional `rayon` feature,
+    /// the material libraries are parsed on the `rayon` global thread pool
+    /// instead. For large scenes this hides the cost of loading the materials
+    /// behind the cost of parsing the geometry. The result is the same as
+    /// [`Scene::load_with`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("models/triangle.obj", r"
+    ///     mtllib triangle.mtl
+    ///     v 0.0 0.0 0.0
+    ///     v 1.0 0.0 0.0
+    ///     v 0.0 1.0 0.0
+    ///     usemtl red
+    ///     f 1 2 3
+    /// ");
+    /// resolver.insert("models/triangle.mtl", r"
+    ///     newmtl red
+    ///     Kd 1.0 0.0 0.0
+    /// ");
+    ///
+    /// let scene = Scene::load_parallel_with("models/triangle.obj", &resolver).unwrap();
+    /// assert_eq!(scene, Scene::load_with("models/triangle.obj", &resolver).unwrap());
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip(resolver)))]
+    pub fn load_parallel_with<R: Resolver + Sync + ?Sized>(
+        name: &str,
+        resolver: &R,
+    ) -> Result<Scene, SceneError> {
+        Scene::load_parallel(name, resolver, obj::ParserOptions::default(), None)
+    }
+
+    /// Load a scene with the material libraries read concurrently with the
+    /// geometry.
+    fn load_parallel<R: Resolver + Sync + ?Sized>(
+        name: &str,
+        resolver: &R,
+        options: obj::ParserOptions,
+        material_conflict: Option<MaterialConflict>,
+    ) -> Result<Scene, SceneError> {
+        let obj_text = read_to_string(resolver, name, obj_not_a_text_file(name))?;
+        let mut library_names: Vec<String> = vec![];
+        for material_library in scan_material_libraries(&obj_text) {
+            let library_name = resolve_relative(name, &material_library);
+            if !library_names.contains(&library_name) {
+                library_names.push(library_name);
+            }
+        }
+        let (objects, mut loaded) = parse_and_load_materials(&obj_text, options, resolver, &library_names);
+        let mut objects = objects.map_err(|error| SceneError::Obj {
+            name: String::from(name),
+            error: error,
+        })?;
+
+        // The scan for `mtllib` statements is only a prediction of what the
+        // parser finds, so any library it missed is loaded here instead.
+        let mut materials = MaterialCollector::new(material_conflict);
+        for material_library in objects.material_libraries.iter_mut() {
+            let library_name = resolve_relative(name, &material_library.name);
+            let material_set = match loaded.remove(&library_name) {
+                Some(material_set) => material_set,
+                None => load_material_library(resolver, &library_name),
+            }
+            .map_err(|error| referenced_from(error, material_library))?;
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                name = %material_library.name,
+                line_number = material_library.line_number,
+                resolved_name = %library_name,
+                materials = material_set.materials.len(),
+                "Resolved a material library."
+            );
+            material_library.resolved_path = Some(PathBuf::from(&library_name));
+            materials.add(library_name, material_set)?;
+        }
+
+        Ok(Scene {
+            objects: objects,
+            materials: materials.materials,
+            material_sources: materials.sources,
+            deferred: None,
+        })
+    }
+
+    /// The materials of the scene, loading the material libraries first if
+    /// the scene was loaded with [`SceneLoader::lazy_materials`] and they have
+    /// not been loaded yet.
+    ///
+    /// When a material library fails to load, the error is returned and the
+    /// next call tries again. A scene loaded lazily with
+    /// [`SceneLoader::load_with`] does not keep the resolver it borrowed, so
+    /// this returns [`SceneError::NoResolver`] until the materials are loaded
+    /// with [`Scene::materials_with`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use std::fs;
+    /// # use wavefront_obj::scene::Scene;
+    /// #
+    /// # let directory = std::env::temp_dir().join(format!("wavefront_obj_doc_{}", std::process::id()));
+    /// # fs::create_dir_all(&directory).unwrap();
+    /// # fs::write(directory.join("triangle.obj"), "mtllib triangle.mtl\nv 0 0 0\nusemtl red\np 1\n").unwrap();
+    /// # fs::write(directory.join("triangle.mtl"), "newmtl red\nKd 1 0 0\n").unwrap();
+    /// let path = directory.join("triangle.obj");
+    /// let mut scene = Scene::loader().lazy_materials(true).load(path).unwrap();
+    /// assert!(!scene.materials_loaded());
+    ///
+    /// let materials = scene.materials().unwrap();
+    /// assert_eq!(materials.materials.len(), 1);
+    /// assert!(scene.materials_loaded());
+    /// ```
+    pub fn materials(&mut self) -> Result<&MaterialSet, SceneError> {
+        let resolver = match self.deferred {
+            Some(DeferredMaterials {
+                resolver: Some(ref resolver),
+                ..
+            }) => resolver.clone(),
+            Some(ref deferred) => {
+                return Err(SceneError::NoResolver {
+                    name: deferred.name.clone(),
+                });
+            }
+            None => return Ok(&self.materials),
+        };
+
+        self.materials_with(&resolver)
+    }
+
+    /// The materials of the scene, loading the material libraries through a
+    /// resolver first if the scene was loaded with
+    /// [`SceneLoader::lazy_materials`] and they have not been loaded yet.
+    ///
+    /// This works for a scene loaded with any resolver. The resolver should
+    /// locate the same files as the one the scene was loaded with.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("triangle.obj", "mtllib triangle.mtl\nv 0 0 0\nusemtl red\np 1\n");
+    /// resolver.insert("triangle.mtl", "newmtl red\nKd 1 0 0\n");
+    ///
+    /// let mut scene = Scene::loader()
+    ///     .lazy_materials(true)
+    ///     .load_with("triangle.obj", &resolver)
+    ///     .unwrap();
+    /// assert!(scene.materials().is_err());
+    ///
+    /// let materials = scene.materials_with(&resolver).unwrap();
+    /// assert_eq!(materials.materials[0].name, "red");
+    /// ```
+    pub fn materials_with<R: Resolver + ?Sized>(&mut self, resolver: &R) -> Result<&MaterialSet, SceneError> {
+        if let Some(ref deferred) = self.deferred {
+            let materials = load_material_libraries(
+                &deferred.name,
+                resolver,
+                &mut self.objects.material_libraries,
+                deferred.material_conflict,
+            )?;
+            self.materials = materials.materials;
+            self.material_sources = materials.sources;
+            self.deferred = None;
+        }
+
+        Ok(&self.materials)
+    }
+
+    /// Determine whether the material libraries of the scene have been
+    /// loaded.
+    ///
+    /// This is only false for a scene loaded with
+    /// [`SceneLoader::lazy_materials`] before [`Scene::materials`] or
+    /// [`Scene::materials_with`] succeeds.
+    pub fn materials_loaded(&self) -> bool {
+        self.deferred.is_none()
+    }
+
+    /// The resolved name of the material library each material was loaded
+    /// from, in the same order as the materials. This is empty until the
+    /// materials are loaded.
+    pub fn material_sources(&self) -> &[String] {
+        &self.material_sources
+    }
+
+    /// Find every geometry in the scene that uses the material with the
+    /// given name, together with the object it belongs to.
+    ///
+    /// The pairs are in the order of the objects, and in the order of the
+    /// geometry set within each object, which makes this a convenient way to
+    /// batch draw calls by material.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("scene.obj", r"
+    ///     o a
+    ///     v 0.0 0.0 0.0
+    ///     usemtl red
+    ///     p 1
+    ///     usemtl blue
+    ///     p 1
+    ///     o b
+    ///     v 1.0 0.0 0.0
+    ///     usemtl red
+    ///     p 2
+    /// ");
+    ///
+    /// let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+    /// let names: Vec<&str> = scene
+    ///     .geometry_using_material("red")
+    ///     .map(|(object, _)| object.name.as_str())
+    ///     .collect();
+    /// assert_eq!(names, vec!["a", "b"]);
+    /// ```
+    pub fn geometry_using_material<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a Object, &'a Geometry)> + 'a {
+        self.objects.objects.iter().flat_map(move |object| {
+            object
+                .geometry_set
+                .iter()
+                .filter(move |geometry| geometry.material_name.as_deref() == Some(name))
+                .map(move |geometry| (object, geometry))
+        })
+    }
+
+    /// Compare the materials the objects use with the materials the material
+    /// libraries define.
+    ///
+    /// Only the materials already loaded are compared. For a scene loaded
+    /// with [`SceneLoader::lazy_materials`], call [`Scene::materials`] first,
+    /// or every material used is reported as undefined.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("scene.obj", "mtllib scene.mtl\nv 0 0 0\nusemtl red\np 1\nusemtl blue\np 1\n");
+    /// resolver.insert("scene.mtl", "newmtl red\nnewmtl green\n");
+    ///
+    /// let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+    /// let report = scene.check_materials();
+    /// assert_eq!(report.undefined, vec!["blue"]);
+    /// assert_eq!(report.unused, vec!["green"]);
+    /// ```
+    pub fn check_materials(&self) -> MaterialReport {
+        let defined: HashSet<&str> = self
+            .materials
+            .materials
+            .iter()
+            .map(|material| material.name.as_str())
+            .collect();
+
+        let mut used = HashSet::new();
+        let mut undefined = vec![];
+        for object in self.objects.objects.iter() {
+            for geometry in object.geometry_set.iter() {
+                if let Some(ref material_name) = geometry.material_name {
+                    if used.insert(material_name.as_str()) && !defined.contains(material_name.as_str()) {
+                        undefined.push(material_name.clone());
+                    }
+                }
+            }
+        }
+
+        let mut unused = vec![];
+        for material in self.materials.materials.iter() {
+            if !used.contains(material.name.as_str()) && !unused.contains(&material.name) {
+                unused.push(material.name.clone());
+            }
+        }
+
+        MaterialReport {
+            undefined: undefined,
+            unused: unused,
+        }
+    }
+
+    /// Find the texture maps that the materials of the scene refer to but
+    /// that the resolver cannot find.
+    ///
+    /// Each map is resolved relative to the material library that names it.
+    /// The missing maps are reported in the order of the materials, and in
+    /// the order of the fields of [`Material`](mtl::Material) within each
+    /// material.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("models/scene.obj", "mtllib scene.mtl\nv 0 0 0\nusemtl brick\np 1\n");
+    /// resolver.insert("models/scene.mtl", "newmtl brick\nmap_Kd brick.png\nbump brick_bump.png\n");
+    /// resolver.insert("models/brick.png", vec![]);
+    ///
+    /// let scene = Scene::load_with("models/scene.obj", &resolver).unwrap();
+    /// let missing = scene.verify_textures(&resolver);
+    /// assert_eq!(missing.len(), 1);
+    /// assert_eq!(missing[0].resolved_name, "models/brick_bump.png");
+    /// assert_eq!(missing[0].material, "brick");
+    /// assert_eq!(missing[0].keyword, "bump");
+    /// ```
+    pub fn verify_textures<R: Resolver + ?Sized>(&self, resolver: &R) -> Vec<MissingTexture> {
+        let mut missing = vec![];
+        for (index, material) in self.materials.materials.iter().enumerate() {
+            let library_name = self.material_sources.get(index).map(String::as_str).unwrap_or("");
+            for (keyword, map) in texture_maps(material) {
+                if let Some(map_name) = map {
+                    let texture_name = resolve_relative(library_name, map_name);
+                    if !resolver.exists(&texture_name) {
+                        missing.push(MissingTexture {
+                            material: material.name.clone(),
+                            library: String::from(library_name),
+                            line_number: material.line_number,
+                            keyword: keyword,
+                            name: map_name.clone(),
+                            resolved_name: texture_name,
+                        });
+                    }
+                }
+            }
+        }
+
+        missing
+    }
+
+    /// Check the scene for the problems that affect rendering it: materials
+    /// that are used but not defined, suspicious material values, and missing
+    /// texture maps.
+    ///
+    /// Unlike [`Scene::check_materials`], materials that are defined but not
+    /// used are not a problem. As with [`Scene::check_materials`], only the
+    /// materials already loaded are checked, so call [`Scene::materials`]
+    /// first for a scene loaded with [`SceneLoader::lazy_materials`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// #
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("scene.obj", "mtllib scene.mtl\nv 0 0 0\nusemtl red\np 1\nusemtl blue\np 1\n");
+    /// resolver.insert("scene.mtl", "newmtl red\nKd 2 0 0\nmap_Kd red.png\n");
+    ///
+    /// let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+    /// let report = scene.validate(&resolver);
+    /// assert_eq!(report.undefined_materials, vec!["blue"]);
+    /// assert_eq!(report.warnings.len(), 1);
+    /// assert_eq!(report.missing_textures[0].name, "red.png");
+    /// ```
+    pub fn validate<R: Resolver + ?Sized>(&self, resolver: &R) -> SceneReport {
+        SceneReport {
+            undefined_materials: self.check_materials().undefined,
+            warnings: self.materials.validate(),
+            missing_textures: self.verify_textures(resolver),
+        }
+    }
+
+    /// Decode every texture map the materials of the scene refer to.
+    ///
+    /// Each map is resolved relative to the material library that names it,
+    /// read through the resolver, and decoded once into a [`TextureCache`],
+    /// no matter how many materials share it. The result holds the handles
+    /// of the maps of each material, in the same order as the materials.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use wavefront_obj::scene::{
+    /// #     MemoryResolver,
+    /// #     Scene,
+    /// # };
+    /// # use std::io::Cursor;
+    /// #
+    /// let mut png = Cursor::new(vec![]);
+    /// image::RgbaImage::new(2, 2).write_to(&mut png, image::ImageFormat::Png).unwrap();
+    ///
+    /// let mut resolver = MemoryResolver::new();
+    /// resolver.insert("models/scene.obj", "mtllib scene.mtl\nv 0 0 0\nusemtl brick\np 1\n");
+    /// resolver.insert("models/scene.mtl", "newmtl brick\nmap_Kd textures/brick.png\n");
+    /// resolver.insert("models/textures/brick.png", png.into_inner());
+    ///
+    /// let scene = Scene::load_with("models/scene.obj", &resolver).unwrap();
+    /// let textures = scene.load_textures(&resolver).unwrap();
+    /// let diffuse = textures.materials[0].diffuse.unwrap();
+    /// assert_eq!(textures.cache.get(diffuse).width(), 2);
+    /// assert_eq!(textures.cache.handle("models/textures/brick.png"), Some(diffuse));
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn load_textures<R: Resolver + ?Sized>(&self, resolver: &R) -> Result<SceneTextures, SceneError> {
+        let mut cache = TextureCache::new();
+        let mut materials = vec![];
+        for (index, material) in self.materials.materials.iter().enumerate() {
+            let library_name = self.material_sources.get(index).map(String::as_str).unwrap_or("");
+            let mut load = |map: &Option<String>| -> Result<Option<TextureHandle>, SceneError> {
+                match *map {
+                    Some(ref map_name) => {
+                        let texture_name = resolve_relative(library_name, map_name);
+                        cache.load(resolver, &texture_name).map(Some)
+                    }
+                    None => Ok(None),
+                }
+            };
+            materials.push(MaterialTextures {
+                ambient: load(&material.map_ambient)?,
+                diffuse: load(&material.map_diffuse)?,
+                specular: load(&material.map_specular)?,
+                emissive: load(&material.map_emissive)?,
+                specular_exponent: load(&material.map_specular_exponent)?,
+                bump: load(&material.map_bump)?,
+                normal: load(&material.map_normal)?,
+                displacement: load(&material.map_displacement)?,
+                dissolve: load(&material.map_dissolve)?,
+                decal: load(&material.map_decal)?,
+            });
+        }
+
+        Ok(SceneTextures {
+            cache: cache,
+            materials: materials,
+        })
+    }
+}
+
+/// A builder for loading a scene with non-default options.
+///
+/// ## Example
+///
+/// ```
+/// # use wavefront_obj::mtl::MaterialConflict;
+/// # use wavefront_obj::obj;
+/// # use wavefront_obj::scene::{
+/// #     MemoryResolver,
+/// #     Scene,
+/// # };
+/// #
+/// let mut resolver = MemoryResolver::new();
+/// resolver.insert("scene.obj", "mtllib a.mtl b.mtl\nv 0 0 0\nusemtl red\np 1\n");
+/// resolver.insert("a.mtl", "newmtl red\nKd 1 0 0\n");
+/// resolver.insert("b.mtl", "newmtl red\nKd 0.5 0 0\n");
+///
+/// let options = obj::ParserOptions {
+///     lenient: true,
+///     ..obj::ParserOptions::default()
+/// };
+/// let mut scene = Scene::loader()
+///     .obj_options(options)
+///     .material_conflict(MaterialConflict::PreferLast)
+///     .validate(true)
+///     .load_with("scene.obj", &resolver)
+///     .unwrap();
+/// assert_eq!(scene.material_sources(), ["b.mtl"]);
+/// let materials = scene.materials().unwrap();
+/// assert_eq!(materials.materials.len(), 1);
+/// assert_eq!(materials.materials[0].color_diffuse.r, 0.5);
+/// ```
+#[derive(Debug, Default)]
+pub struct SceneLoader {
+    /// The options for parsing the Wavefront OBJ file.
+    obj_options: obj::ParserOptions,
+    /// Load the material libraries concurrently with the geometry.
+    sequential: bool,
+    /// The rule for materials with the same name in different libraries, or
+    /// `None` to keep all of them.
+    material_conflict: Option<MaterialConflict>,
+    /// Fail when the loaded scene has problems.
+    validate: bool,
+    /// Leave the material libraries to be loaded by [`Scene::materials`].
+    lazy_materials: bool,
+}
+
+impl SceneLoader {
+    /// Construct a new loader with the default options.
+    ///
+    /// By default, the material libraries are loaded concurrently with the
+    /// geometry, every material of every library is kept, and the scene is
+    /// not validated.
+    pub fn new() -> SceneLoader {
+        SceneLoader::default()
+    }
+
+    /// Set the options for parsing the Wavefront OBJ file.
+    pub fn obj_options(mut self, options: obj::ParserOptions) -> SceneLoader {
+        self.obj_options = options;
+        self
+    }
+
+    /// Choose whether to load the material libraries concurrently with the
+    /// geometry, as [`Scene::load_parallel_with`] does, or one after the
+    /// other, as [`Scene::load_with`] does. The result is the same.
+    pub fn parallel(mut self, parallel: bool) -> SceneLoader {
+        self.sequential = !parallel;
+        self
+    }
+
+    /// Merge the materials that several material libraries define with the
+    /// same name into one, see [`MaterialSet::merge`].
+    pub fn material_conflict(mut self, policy: MaterialConflict) -> SceneLoader {
+        self.material_conflict = Some(policy);
+        self
+    }
+
+    /// Choose whether to validate the scene with [`Scene::validate`] and fail
+    /// with [`SceneError::Invalid`] when it has problems.
+    pub fn validate(mut self, validate: bool) -> SceneLoader {
+        self.validate = validate;
+        self
+    }
+
+    /// Choose whether to leave the material libraries unread until the
+    /// materials of the scene are first requested.
+    ///
+    /// This keeps loading fast when the materials may not be needed at all,
+    /// e.g. when only extracting a collision mesh. Errors in the material
+    /// libraries are then reported when the materials are requested instead
+    /// of by the loader.
+    ///
+    /// A scene loaded with [`SceneLoader::load`] keeps its resolver, so
+    /// [`Scene::materials`] loads the materials. [`SceneLoader::load_with`]
+    /// only borrows its resolver, so load the materials of such a scene with
+    /// [`Scene::materials_with`]. Validating the scene needs the materials,
+    /// so they are loaded anyway when [`SceneLoader::validate`] is set.
+    pub fn lazy_materials(mut self, lazy: bool) -> SceneLoader {
+        self.lazy_materials = lazy;
+        self
+    }
+
+    /// Load a scene from a Wavefront OBJ file on disk, resolving the files it
+    /// refers to relative to its directory.
+    pub fn load<P: AsRef<Path>>(self, path: P) -> Result<Scene, SceneError> {
+        let path = path.as_ref();
+        let root = path.parent().unwrap_or_else(|| Path::new(""));
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let resolver = FileSystemResolver::new(root);
+        if self.lazy_materials && !self.validate {
+            return Scene::load_deferred(
+                &name,
+                &resolver,
+                Some(resolver.clone()),
+                self.obj_options,
+                self.material_conflict,
+            );
+        }
+
+        self.load_with(&name, &resolver)
+    }
+
+    /// Load a scene using a resolver to locate its files.
+    ///
+    /// With [`SceneLoader::lazy_materials`] set, the scene cannot keep the
+    /// borrowed resolver, so load its materials later with
+    /// [`Scene::materials_with`].
+    pub fn load_with<R: Resolver + Sync + ?Sized>(
+        self,
+        name: &str,
+        resolver: &R,
+    ) -> Result<Scene, SceneError> {
+        if self.lazy_materials && !self.validate {
+            return Scene::load_deferred(name, resolver, None, self.obj_options, self.material_conflict);
+        }
+        let scene = if self.sequential {
+            Scene::load_sequential(name, resolver, self.obj_options, self.material_conflict)?
+        } else {
+            Scene::load_parallel(name, resolver, self.obj_options, self.material_conflict)?
+        };
+        if self.validate {
+            let report = scene.validate(resolver);
+            if !report.is_empty() {
+                return Err(SceneError::Invalid {
+                    name: String::from(name),
+                    report: report,
+                });
+            }
+        }
+
+        Ok(scene)
+    }
+}
+
+/// What a scene needs to load its material libraries after the fact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DeferredMaterials {
+    /// The name of the Wavefront OBJ file that the material libraries are
+    /// resolved relative to.
+    name: String,
+    /// The resolver that locates the material libraries, or `None` if the
+    /// scene was loaded with a borrowed resolver.
+    resolver: Option<FileSystemResolver>,
+    /// The rule for materials with the same name in different libraries, or
+    /// `None` to keep all of them.
+    material_conflict: Option<MaterialConflict>,
+}
+
+/// The materials of the material libraries of a scene, collected one library
+/// at a time.
+struct MaterialCollector {
+    /// The materials collected so far.
+    materials: MaterialSet,
+    /// The resolved name of the material library of each material.
+    sources: Vec<String>,
+    /// The rule for materials with the same name, or `None` to keep all of
+    /// them.
+    policy: Option<MaterialConflict>,
+    /// The resolved names of the material libraries collected so far.
+    libraries: HashSet<String>,
+}
+
+impl MaterialCollector {
+    fn new(policy: Option<MaterialConflict>) -> MaterialCollector {
+        MaterialCollector {
+            materials: MaterialSet::new(vec![]),
+            sources: vec![],
+            policy: policy,
+            libraries: HashSet::new(),
+        }
+    }
+
+    /// Add the materials of a material library.
+    ///
+    /// When merging, a library declared by several `mtllib` statements is
+    /// only added once, so that its materials do not conflict with
+    /// themselves.
+    fn add(&mut self, library_name: String, material_set: MaterialSet) -> Result<(), SceneError> {
+        let policy = match self.policy {
+            Some(policy) => policy,
+            None => {
+                self.sources
+                    .extend(material_set.materials.iter().map(|_| library_name.clone()));
+                self.materials.materials.extend(material_set.materials);
+                self.materials.warnings.extend(material_set.warnings);
+                return Ok(());
+            }
+        };
+        if !self.libraries.insert(library_name.clone()) {
+            return Ok(());
+        }
+
+        let report =
+            self.materials
+                .merge(material_set, policy)
+                .map_err(|error| SceneError::MaterialConflict {
+                    name: library_name.clone(),
+                    error: error,
+                })?;
+        self.sources
+            .resize(self.materials.materials.len(), library_name.clone());
+        if policy == MaterialConflict::PreferLast {
+            for material_name in report.conflicts.iter() {
+                if let Some(index) = self.materials.position(material_name) {
+                    self.sources[index] = library_name.clone();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The problems that affect rendering a scene, as found by
+/// [`Scene::validate`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SceneReport {
+    /// The names in `usemtl` statements that no material library defines, in
+    /// the order they are first used.
+    pub undefined_materials: Vec<String>,
+    /// The suspicious values of the materials.
+    pub warnings: Vec<mtl::ValidationWarning>,
+    /// The texture maps that the resolver cannot find.
+    pub missing_textures: Vec<MissingTexture>,
+}
+
+impl SceneReport {
+    /// Determine whether the scene has no problems.
+    pub fn is_empty(&self) -> bool {
+        self.undefined_materials.is_empty() && self.warnings.is_empty() && self.missing_textures.is_empty()
+    }
+}
+
+impl fmt::Display for SceneReport {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for name in self.undefined_materials.iter() {
+            writeln!(formatter, "Material `{}` is used but not defined.", name)?;
+        }
+        for warning in self.warnings.iter() {
+            writeln!(formatter, "{}", warning)?;
+        }
+        for texture in self.missing_textures.iter() {
+            writeln!(formatter, "{}", texture)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The material names on which the objects and the material libraries of a
+/// scene disagree.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaterialReport {
+    /// The names in `usemtl` statements that no material library defines, in
+    /// the order they are first used.
+    pub undefined: Vec<String>,
+    /// The names of the materials that no geometry uses, in the order they
+    /// are defined.
+    pub unused: Vec<String>,
+}
+
+impl MaterialReport {
+    /// Determine whether the objects and the material libraries agree.
+    pub fn is_empty(&self) -> bool {
+        self.undefined.is_empty() && self.unused.is_empty()
+    }
+}
+
+impl fmt::Display for MaterialReport {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for name in self.undefined.iter() {
+            writeln!(formatter, "Material `{}` is used but not defined.", name)?;
+        }
+        for name in self.unused.iter() {
+            writeln!(formatter, "Material `{}` is defined but not used.", name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A texture map that a material refers to but that the resolver cannot find,
+/// as reported by [`Scene::verify_textures`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingTexture {
+    /// The name of the material.
+    pub material: String,
+    /// The resolved name of the material library that defines the material.
+    pub library: String,
+    /// The line number of the `newmtl` statement of the material in the
+    /// material library.
+    pub line_number: usize,
+    /// The keyword of the statement naming the map, e.g. `map_Kd`.
+    pub keyword: &'static str,
+    /// The name of the map as written in the material library.
+    pub name: String,
+    /// The name of the map as passed to the resolver.
+    pub resolved_name: String,
+}
+
+impl fmt::Display for MissingTexture {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Material `{}` on line {} of `{}` refers to the missing texture `{}` ({}).",
+            self.material, self.line_number, self.library, self.name, self.keyword
+        )
+    }
+}
+
+/// The texture maps of a material, each with the keyword of the statement
+/// that names it.
+fn texture_maps(material: &mtl::Material) -> [(&'static str, &Option<String>); 10] {
+    [
+        ("map_Ka", &material.map_ambient),
+        ("map_Kd", &material.map_diffuse),
+        ("map_Ks", &material.map_specular),
+        ("map_Ke", &material.map_emissive),
+        ("map_Ns", &material.map_specular_exponent),
+        ("bump", &material.map_bump),
+        ("norm", &material.map_normal),
+        ("disp", &material.map_displacement),
+        ("map_d", &material.map_dissolve),
+        ("decal", &material.map_decal),
+    ]
+}
+
+/// A handle to a decoded texture in a [`TextureCache`].
+#[cfg(feature = "image")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextureHandle(pub usize);
+
+/// The decoded texture maps of a scene, each stored once and keyed by the
+/// resolved name it was loaded from.
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Default)]
+pub struct TextureCache {
+    /// The decoded textures, indexed by handle.
+    textures: Vec<image::DynamicImage>,
+    /// The handle of each texture, keyed by its resolved name.
+    handles: HashMap<String, TextureHandle>,
+}
+
+#[cfg(feature = "image")]
+impl TextureCache {
+    /// Construct a new empty texture cache.
+    pub fn new() -> TextureCache {
+        TextureCache {
+            textures: vec![],
+            handles: HashMap::new(),
+        }
+    }
+
+    /// The number of textures in the cache.
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    /// Determine whether the cache contains no textures.
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+
+    /// Get the texture a handle refers to.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the handle did not come from this cache.
+    pub fn get(&self, handle: TextureHandle) -> &image::DynamicImage {
+        &self.textures[handle.0]
+    }
+
+    /// Find the handle of the texture loaded from a resolved name.
+    pub fn handle(&self, name: &str) -> Option<TextureHandle> {
+        self.handles.get(name).copied()
+    }
+
+    /// Read and decode the texture with the given name through a resolver,
+    /// unless the cache already holds it.
+    pub fn load<R: Resolver + ?Sized>(
+        &mut self,
+        resolver: &R,
+        name: &str,
+    ) -> Result<TextureHandle, SceneError> {
+        if let Some(handle) = self.handle(name) {
+            return Ok(handle);
+        }
+
+        let io_error = |error| SceneError::Io {
+            name: String::from(name),
+            error: error,
+        };
+        let mut reader = resolver.open(name).map_err(io_error)?;
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).map_err(io_error)?;
+        let texture = image::load_from_memory(&bytes).map_err(|error| SceneError::Texture {
+            name: String::from(name),
+            error: error,
+        })?;
+
+        let handle = TextureHandle(self.textures.len());
+        self.textures.push(texture);
+        self.handles.insert(String::from(name), handle);
+
+        Ok(handle)
+    }
+}
+
+/// The handles of the texture maps of a material. A map is `None` when the
+/// material does not name one.
+#[cfg(feature = "image")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaterialTextures {
+    /// The ambient texture map, from `map_Ka`.
+    pub ambient: Option<TextureHandle>,
+    /// The diffuse texture map, from `map_Kd`.
+    pub diffuse: Option<TextureHandle>,
+    /// The specular texture map, from `map_Ks`.
+    pub specular: Option<TextureHandle>,
+    /// The emissive texture map, from `map_Ke`.
+    pub emissive: Option<TextureHandle>,
+    /// The specular exponent texture map, from `map_Ns`.
+    pub specular_exponent: Option<TextureHandle>,
+    /// The bump map, from `map_Bump` or `bump`.
+    pub bump: Option<TextureHandle>,
+    /// The normal map, from `norm`.
+    pub normal: Option<TextureHandle>,
+    /// The displacement map, from `disp`.
+    pub displacement: Option<TextureHandle>,
+    /// The dissolve texture map, from `map_d`.
+    pub dissolve: Option<TextureHandle>,
+    /// The decal texture map, from `decal`.
+    pub decal: Option<TextureHandle>,
+}
+
+/// The decoded texture maps of a scene, as returned by [`Scene::load_textures`].
+#[cfg(feature = "image")]
+#[derive(Clone, Debug, Default)]
+pub struct SceneTextures {
+    /// Every texture the materials refer to.
+    pub cache: TextureCache,
+    /// The texture handles of each material, in the same order as the
+    /// materials of the scene.
+    pub materials: Vec<MaterialTextures>,
+}
+
+/// Load the material libraries of a Wavefront OBJ file one after the other,
+/// recording the resolved name of each one.
+fn load_material_libraries<R: Resolver + ?Sized>(
+    name: &str,
+    resolver: &R,
+    material_libraries: &mut [MtlLibRef],
+    material_conflict: Option<MaterialConflict>,
+) -> Result<MaterialCollector, SceneError> {
+    let mut materials = MaterialCollector::new(material_conflict);
+    for material_library in material_libraries.iter_mut() {
+        let library_name = resolve_relative(name, &material_library.name);
+        let material_set = load_material_library(resolver, &library_name)
+            .map_err(|error| referenced_from(error, material_library))?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            name = %material_library.name,
+            line_number = material_library.line_number,
+            resolved_name = %library_name,
+            materials = material_set.materials.len(),
+            "Resolved a material library."
+        );
+        material_library.resolved_path = Some(PathBuf::from(&library_name));
+        materials.add(library_name, material_set)?;
+    }
+
+    Ok(materials)
+}
+
+/// Parse the geometry of a scene on the current thread while loading its
+/// material libraries on the `rayon` global thread pool.
+#[cfg(feature = "rayon")]
+fn parse_and_load_materials<R: Resolver + Sync + ?Sized>(
+    obj_text: &str,
+    options: obj::ParserOptions,
+    resolver: &R,
+    library_names: &[String],
+) -> (
+    Result<ObjectSet, obj::ParseError>,
+    HashMap<String, Result<MaterialSet, SceneError>>,
+) {
+    use rayon::prelude::*;
+
+    let mut loaded = HashMap::new();
+    let objects = rayon::in_place_scope(|scope| {
+        scope.spawn(|_| {
+            loaded = library_names
+                .par_iter()
+                .map(|library_name| {
+                    (
+                        library_name.clone(),
+                        load_material_library(resolver, library_name),
+                    )
+                })
+                .collect();
+        });
+
+        obj::parse_with(obj_text, options)
+    });
+
+    (objects, loaded)
+}
+
+/// Parse the geometry of a scene on the current thread while loading its
+/// material libraries on at most [`std::thread::available_parallelism`] scoped
+/// threads.
+#[cfg(not(feature = "rayon"))]
+fn parse_and_load_materials<R: Resolver + Sync + ?Sized>(
+    obj_text: &str,
+    options: obj::ParserOptions,
+    resolver: &R,
+    library_names: &[String],
+) -> (
+    Result<ObjectSet, obj::ParseError>,
+    HashMap<String, Result<MaterialSet, SceneError>>,
+) {
+    // The material libraries are handed out from a shared queue to a
+    // bounded number of threads, however many libraries there are.
+    let thread_count = usize::min(
+        library_names.len(),
+        thread::available_parallelism().map_or(1, usize::from),
+    );
+    let next_library = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..thread_count)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut loaded = vec![];
+                    while let Some(library_name) =
+                        library_names.get(next_library.fetch_add(1, Ordering::Relaxed))
+                    {
+                        let material_set = load_material_library(resolver, library_name);
+                        loaded.push((library_name.clone(), material_set));
+                    }
+
+                    loaded
+                })
+            })
+            .collect();
+        let objects = obj::parse_with(obj_text, options);
+        let loaded = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("MTL parsing thread panicked."))
+            .collect();
+
+        (objects, loaded)
+    })
+}
+
+/// Open a material library through a resolver and parse it.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(resolver)))]
+fn load_material_library<R: Resolver + ?Sized>(resolver: &R, name: &str) -> Result<MaterialSet, SceneError> {
+    let mtl_text = read_to_string(resolver, name, |not_text| SceneError::Mtl {
+        name: String::from(name),
+        error: mtl::ParseError::not_a_text_file(not_text),
+    })?;
+
+    mtl::parse(mtl_text).map_err(|error| SceneError::Mtl {
+        name: String::from(name),
+        error: error,
+    })
+}
+
+/// Report an error reading a material library against the `mtllib` statement
+/// that names it.
+fn referenced_from(error: SceneError, material_library: &MtlLibRef) -> SceneError {
+    match error {
+        SceneError::Io { name, error } => SceneError::MaterialLibrary {
+            name: name,
+            line_number: material_library.line_number,
+            error: error,
+        },
+        other => other,
+    }
+}
+
+/// Find the names of the material libraries declared by `mtllib` statements
+/// in the text of a Wavefront OBJ file, without parsing the file.
+///
+/// Each name is reported once, in the order it first appears.
+fn scan_material_libraries(text: &str) -> Vec<String> {
+    let mut library_names: Vec<String> = vec![];
+    for line in text.lines() {
+        let line = match line.find('#') {
+            Some(comment_start) => &line[..comment_start],
+            None => line,
+        };
+        let mut tokens = line.split_whitespace();
+        if tokens.next() != Some("mtllib") {
+            continue;
+        }
+        for library_name in tokens {
+            if !library_names.iter().any(|name| name == library_name) {
+                library_names.push(String::from(library_name));
+            }
+        }
+    }
+
+    library_names
+}
+
+/// Open a file through a resolver and read its contents into a string.
+///
+/// The `not_a_text_file` function reports contents that are binary data
+/// rather than text as a parse error of the kind of file being read.
+fn read_to_string<R, F>(resolver: &R, name: &str, not_a_text_file: F) -> Result<String, SceneError>
+where
+    R: Resolver + ?Sized,
+    F: FnOnce(input::NotText) -> SceneError,
+{
+    let io_error = |error| SceneError::Io {
+        name: String::from(name),
+        error: error,
+    };
+    let mut reader = resolver.open(name).map_err(io_error)?;
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes).map_err(io_error)?;
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        name = name,
+        bytes = bytes.len(),
+        "Read a file through the resolver."
+    );
+
+    input::decode(bytes).map_err(|error| match error {
+        input::DecodeError::Io(error) => io_error(error),
+        input::DecodeError::NotText(not_text) => not_a_text_file(not_text),
+    })
+}
+
+/// Report a Wavefront OBJ file that is binary data rather than text.
+fn obj_not_a_text_file(name: &str) -> impl FnOnce(input::NotText) -> SceneError + '_ {
+    move |not_text| SceneError::Obj {
+        name: String::from(name),
+        error: obj::ParseError::not_a_text_file(not_text),
+    }
+}
+
+/// Resolve the name of a file referenced from inside another file, relative
+/// to the directory of the referring file.
+fn resolve_relative(referrer: &str, name: &str) -> String {
+    match Path::new(referrer).parent() {
+        Some(directory) if !directory.as_os_str().is_empty() => {
+            directory.join(name).to_string_lossy().into_owned()
+        }
+        _ => String::from(name),
+    }
+}
+
+
+#[cfg(test)]
+mod scene_tests {
+    use super::{
+        resolve_relative,
+        scan_material_libraries,
+        FileSystemResolver,
+        MemoryResolver,
+        Resolver,
+        Scene,
+        SceneError,
+    };
+    use crate::mtl;
+    use crate::mtl::MaterialConflict;
+    use crate::obj;
+    use std::fs;
+    use std::io;
+    use std::io::Read;
+    use std::path::PathBuf;
+
+
+    #[test]
+    fn test_resolve_relative() {
+        assert_eq!(resolve_relative("scene.obj", "scene.mtl"), "scene.mtl");
+        assert_eq!(
+            resolve_relative("models/scene.obj", "scene.mtl"),
+            "models/scene.mtl"
+        );
+    }
+
+    #[test]
+    fn test_load_with_multiple_material_libraries() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib a.mtl b.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("a.mtl", "newmtl first\n");
+        resolver.insert("b.mtl", "newmtl second\n");
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+        let names: Vec<&str> = scene
+            .materials
+            .materials
+            .iter()
+            .map(|material| material.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["first", "second"]);
+    }
+
+    #[test]
+    fn test_load_with_resolves_material_library_paths() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("models/scene.obj", "# scene\nmtllib scene.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("models/scene.mtl", "newmtl first\n");
+        let scene = Scene::load_with("models/scene.obj", &resolver).unwrap();
+        let material_library = &scene.objects.material_libraries[0];
+
+        assert_eq!(material_library.name, "scene.mtl");
+        assert_eq!(material_library.line_number, 2);
+        assert_eq!(
+            material_library.resolved_path,
+            Some(PathBuf::from("models/scene.mtl"))
+        );
+    }
+
+    #[test]
+    fn test_geometry_using_material() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert(
+            "scene.obj",
+            "mtllib scene.mtl\no a\nv 0 0 0\nusemtl red\np 1\nusemtl blue\np 1\nusemtl red\np 1 1\n\
+             o b\nv 1 0 0\np 2\no c\nv 0 1 0\nusemtl red\np 3\n",
+        );
+        resolver.insert("scene.mtl", "newmtl red\nnewmtl blue\n");
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+
+        let red: Vec<(&str, usize)> = scene
+            .geometry_using_material("red")
+            .map(|(object, geometry)| (object.name.as_str(), geometry.shapes.len()))
+            .collect();
+        assert_eq!(red, vec![("a", 1), ("a", 2), ("c", 1)]);
+        assert_eq!(scene.geometry_using_material("blue").count(), 1);
+        assert_eq!(scene.geometry_using_material("green").count(), 0);
+    }
+
+    #[test]
+    fn test_check_materials() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert(
+            "scene.obj",
+            "mtllib a.mtl b.mtl\nv 0 0 0\nusemtl red\np 1\nusemtl missing\np 1\no second\nv 1 1 1\nusemtl missing\np 2\nusemtl green\np 2\n",
+        );
+        resolver.insert("a.mtl", "newmtl red\nnewmtl unused\n");
+        resolver.insert("b.mtl", "newmtl green\n");
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+        let report = scene.check_materials();
+
+        assert_eq!(report.undefined, vec!["missing"]);
+        assert_eq!(report.unused, vec!["unused"]);
+        assert!(!report.is_empty());
+        assert_eq!(
+            report.to_string(),
+            "Material `missing` is used but not defined.\nMaterial `unused` is defined but not used.\n"
+        );
+    }
+
+    #[test]
+    fn test_check_materials_consistent_scene() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib a.mtl\nv 0 0 0\np 1\nusemtl red\np 1\n");
+        resolver.insert("a.mtl", "newmtl red\n");
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+
+        assert!(scene.check_materials().is_empty());
+    }
+
+    #[test]
+    fn test_load_with_records_material_sources() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("models/scene.obj", "mtllib a.mtl b.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("models/a.mtl", "newmtl first\nnewmtl second\n");
+        resolver.insert("models/b.mtl", "newmtl third\n");
+        let scene = Scene::load_with("models/scene.obj", &resolver).unwrap();
+
+        assert_eq!(
+            scene.material_sources(),
+            ["models/a.mtl", "models/a.mtl", "models/b.mtl"]
+        );
+    }
+
+    #[cfg(feature = "image")]
+    fn encode_png(width: u32, height: u32) -> Vec<u8> {
+        let mut png = std::io::Cursor::new(vec![]);
+        image::RgbaImage::new(width, height)
+            .write_to(&mut png, image::ImageFormat::Png)
+            .unwrap();
+
+        png.into_inner()
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_load_textures_shares_decoded_maps() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("models/scene.obj", "mtllib scene.mtl\nv 0 0 0\np 1\n");
+        resolver.insert(
+            "models/scene.mtl",
+            "newmtl first\nmap_Kd brick.png\nmap_Bump bump.png\nnewmtl second\nmap_Kd brick.png\nnewmtl plain\n",
+        );
+        resolver.insert("models/brick.png", encode_png(4, 2));
+        resolver.insert("models/bump.png", encode_png(1, 1));
+        let scene = Scene::load_with("models/scene.obj", &resolver).unwrap();
+        let textures = scene.load_textures(&resolver).unwrap();
+
+        assert_eq!(textures.cache.len(), 2);
+        assert_eq!(textures.materials.len(), 3);
+        assert_eq!(textures.materials[0].diffuse, textures.materials[1].diffuse);
+        assert_eq!(textures.materials[2], Default::default());
+        let bump = textures.materials[0].bump.unwrap();
+        assert_eq!(textures.cache.handle("models/bump.png"), Some(bump));
+        assert_eq!(textures.cache.get(bump).width(), 1);
+        let diffuse = textures.materials[0].diffuse.unwrap();
+        assert_eq!(textures.cache.get(diffuse).width(), 4);
+        assert_eq!(textures.cache.get(diffuse).height(), 2);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_load_textures_reports_undecodable_maps() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib scene.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("scene.mtl", "newmtl first\nmap_Kd broken.png\n");
+        resolver.insert("broken.png", "not an image");
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+        let result = scene.load_textures(&resolver);
+
+        match result {
+            Err(SceneError::Texture { name, .. }) => assert_eq!(name, "broken.png"),
+            other => panic!("Expected a texture error but got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_load_textures_missing_map() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib scene.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("scene.mtl", "newmtl first\nmap_Kd missing.png\n");
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+
+        assert!(matches!(
+            scene.load_textures(&resolver),
+            Err(SceneError::Io { .. })
+        ));
+    }
+
+    /// A subscriber that records the names of the spans and the messages of
+    /// the events it sees.
+    #[cfg(feature = "tracing")]
+    #[derive(Default)]
+    struct Recorder {
+        records: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::field::Visit for &Recorder {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.records.lock().unwrap().push(format!("{:?}", value));
+            }
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut records = self.records.lock().unwrap();
+            records.push(String::from(attributes.metadata().name()));
+
+            tracing::span::Id::from_u64(records.len() as u64)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            event.record(&mut &*self);
+        }
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_load_with_traces_the_pipeline() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert(
+            "scene.obj",
+            "mtllib scene.mtl\no a\nv 0 0 0\np 1\no b\nv 1 1 1\np 2\n",
+        );
+        resolver.insert("scene.mtl", "newmtl red\n");
+        let recorder = std::sync::Arc::new(Recorder::default());
+        tracing::subscriber::with_default(recorder.clone(), || {
+            Scene::load_with("scene.obj", &resolver).unwrap();
+        });
+        let records = recorder.records.lock().unwrap();
+        let count = |record: &str| records.iter().filter(|&name| name == record).count();
+
+        assert_eq!(count("load_with"), 1);
+        assert_eq!(count("parse_objset"), 1);
+        assert_eq!(count("parse_object"), 2);
+        assert_eq!(count("Parsed an object."), 2);
+        assert_eq!(count("load_material_library"), 1);
+        assert_eq!(count("Parsed a material library."), 1);
+        assert_eq!(count("Resolved a material library."), 1);
+    }
+
+    #[test]
+    fn test_load_with_missing_material_library() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "# scene\nmtllib missing.mtl\nv 0 0 0\np 1\n");
+        let result = Scene::load_with("scene.obj", &resolver);
+
+        match result {
+            Err(SceneError::MaterialLibrary {
+                name, line_number, ..
+            }) => {
+                assert_eq!(name, "missing.mtl");
+                assert_eq!(line_number, 2);
+            }
+            other => panic!("Expected a material library error but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_with_binary_material_library() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib bad.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("bad.mtl", &b"newmtl bad\nKd \xff\xfe\n"[..]);
+        let result = Scene::load_with("scene.obj", &resolver);
+
+        match result {
+            Err(SceneError::Mtl { name, error }) => {
+                assert_eq!(name, "bad.mtl");
+                assert_eq!(error.kind(), &mtl::ErrorKind::NotATextFile);
+                assert_eq!(error.line_number(), 2);
+            }
+            other => panic!("expected a material library parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_with_malformed_material_library() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib bad.mtl\nv 0 0 0\np 1\n");
+        resolver.insert("bad.mtl", "newmtl bad\nKd 1.0\n");
+        let result = Scene::load_with("scene.obj", &resolver);
+
+        assert!(matches!(result, Err(SceneError::Mtl { .. })));
+    }
+
+    #[test]
+    fn test_file_system_resolver() {
+        let resolver = FileSystemResolver::new("assets");
+        let mut scene = Scene::load_with("cube.obj", &resolver).unwrap();
+
+        assert_eq!(scene.objects.objects.len(), 1);
+        assert!(scene.materials().unwrap().materials.is_empty());
+    }
+
+    #[test]
+    fn test_resolver_exists() {
+        let file_system = FileSystemResolver::new("assets");
+        let mut memory = MemoryResolver::new();
+        memory.insert("a.png", vec![]);
+
+        assert!(file_system.exists("cube.obj"));
+        assert!(!file_system.exists("missing.png"));
+        assert!(!file_system.exists(""));
+        assert!(memory.exists("a.png"));
+        assert!(!memory.exists("b.png"));
+    }
+
+    #[test]
+    fn test_verify_textures() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib materials/a.mtl b.mtl\nv 0 0 0\np 1\n");
+        resolver.insert(
+            "materials/a.mtl",
+            "newmtl red\nmap_Kd red.png\nmap_Ks shared.png\n\nnewmtl green\nmap_Kd green.png\n",
+        );
+        resolver.insert("b.mtl", "newmtl blue\nmap_d shared.png\nnorm blue.png\n");
+        resolver.insert("materials/red.png", vec![]);
+        resolver.insert("materials/green.png", vec![]);
+        resolver.insert("shared.png", vec![]);
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+        let missing = scene.verify_textures(&resolver);
+        let missing: Vec<(&str, usize, &str, &str)> = missing
+            .iter()
+            .map(|texture| {
+                (
+                    texture.material.as_str(),
+                    texture.line_number,
+                    texture.keyword,
+                    texture.resolved_name.as_str(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            missing,
+            vec![
+                ("red", 1, "map_Ks", "materials/shared.png"),
+                ("blue", 1, "norm", "blue.png")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_textures_with_the_default_exists() {
+        struct OpenOnly(MemoryResolver);
+
+        impl Resolver for OpenOnly {
+            fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+                self.0.open(name)
+            }
+        }
+
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib scene.mtl\n");
+        resolver.insert("scene.mtl", "newmtl red\nmap_Kd red.png\ndecal decal.png\n");
+        resolver.insert("red.png", vec![]);
+        let resolver = OpenOnly(resolver);
+        let scene = Scene::load_with("scene.obj", &resolver).unwrap();
+        let missing = scene.verify_textures(&resolver);
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(
+            missing[0].to_string(),
+            "Material `red` on line 1 of `scene.mtl` refers to the missing texture `decal.png` (decal)."
+        );
+    }
+
+    #[test]
+    fn test_loader_sequential_and_parallel_agree() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert(
+            "models/scene.obj",
+            "mtllib a.mtl b.mtl\nv 0 0 0\nusemtl red\np 1\n",
+        );
+        resolver.insert("models/a.mtl", "newmtl red\nKd 1 0 0\n");
+        resolver.insert("models/b.mtl", "newmtl blue\nKd 0 0 1\n");
+        let sequential = Scene::loader()
+            .parallel(false)
+            .load_with("models/scene.obj", &resolver)
+            .unwrap();
+        let parallel = Scene::loader().load_with("models/scene.obj", &resolver).unwrap();
+
+        assert_eq!(sequential, parallel);
+        assert_eq!(
+            sequential,
+            Scene::load_with("models/scene.obj", &resolver).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_loader_obj_options() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "v 0 0 0\nvendor_tag 1\np 1\n");
+        let options = obj::ParserOptions {
+            lenient: true,
+            ..obj::ParserOptions::default()
+        };
+
+        assert!(Scene::loader().load_with("scene.obj", &resolver).is_err());
+        assert!(Scene::loader()
+            .obj_options(options)
+            .load_with("scene.obj", &resolver)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_loader_merges_materials() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib a.mtl b.mtl\nmtllib a.mtl a.mtl\n");
+        resolver.insert("a.mtl", "newmtl red\nKd 1 0 0\nnewmtl green\nKd 0 1 0\n");
+        resolver.insert("b.mtl", "newmtl blue\nKd 0 0 1\nnewmtl red\nKd 0.5 0 0\n");
+        let load = |policy| {
+            Scene::loader()
+                .material_conflict(policy)
+                .load_with("scene.obj", &resolver)
+        };
+        let mut first = load(MaterialConflict::PreferFirst).unwrap();
+        let mut last = load(MaterialConflict::PreferLast).unwrap();
+        let names: Vec<String> = first
+            .materials()
+            .unwrap()
+            .materials
+            .iter()
+            .map(|material| material.name.clone())
+            .collect();
+
+        assert_eq!(names, vec!["red", "green", "blue"]);
+        assert_eq!(first.material_sources(), ["a.mtl", "a.mtl", "b.mtl"]);
+        assert_eq!(
+            first.materials().unwrap().get("red").unwrap().color_diffuse.r,
+            1.0
+        );
+        assert_eq!(last.material_sources(), ["b.mtl", "a.mtl", "b.mtl"]);
+        assert_eq!(last.materials().unwrap().get("red").unwrap().color_diffuse.r, 0.5);
+        match load(MaterialConflict::Error) {
+            Err(SceneError::MaterialConflict { name, error }) => {
+                assert_eq!(name, "b.mtl");
+                assert_eq!(error.conflicts, vec!["red"]);
+            }
+            other => panic!("Expected a material conflict, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_loader_validate() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib scene.mtl\nv 0 0 0\nusemtl red\np 1\n");
+        resolver.insert(
+            "scene.mtl",
+            "newmtl red\nKd 1 0 0\nmap_Kd red.png\nnewmtl unused\n",
+        );
+        let error = Scene::loader()
+            .validate(true)
+            .load_with("scene.obj", &resolver)
+            .unwrap_err();
+
+        match error {
+            SceneError::Invalid { ref name, ref report } => {
+                assert_eq!(name, "scene.obj");
+                assert!(report.undefined_materials.is_empty());
+                assert_eq!(report.missing_textures.len(), 1);
+            }
+            ref other => panic!("Expected a validation error, got {:?}", other),
+        }
+        assert_eq!(
+            error.to_string(),
+            "The scene `scene.obj` failed validation:\n\
+             Material `red` on line 1 of `scene.mtl` refers to the missing texture `red.png` (map_Kd).\n"
+        );
+
+        resolver.insert("red.png", vec![]);
+        assert!(Scene::loader()
+            .validate(true)
+            .load_with("scene.obj", &resolver)
+            .is_ok());
+    }
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("wavefront_obj_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
+    #[test]
+    fn test_loader_lazy_materials() {
+        let directory = temporary_directory("lazy_materials");
+        fs::write(
+            directory.join("scene.obj"),
+            "mtllib materials/scene.mtl\nv 0 0 0\nusemtl red\np 1\n",
+        )
+        .unwrap();
+        fs::create_dir_all(directory.join("materials")).unwrap();
+        fs::write(directory.join("materials/scene.mtl"), "newmtl red\nKd 1 0 0\n").unwrap();
+
+        let path = directory.join("scene.obj");
+        let mut scene = Scene::loader().lazy_materials(true).load(&path).unwrap();
+        assert!(!scene.materials_loaded());
+        assert!(scene.material_sources().is_empty());
+        assert_eq!(
+            scene.objects.material_libraries[0].resolved_path,
+            Some(PathBuf::from("materials/scene.mtl"))
+        );
+
+        assert_eq!(scene.materials().unwrap().materials[0].name, "red");
+        assert!(scene.materials_loaded());
+        assert_eq!(scene, Scene::load(&path).unwrap());
+    }
+
+    #[test]
+    fn test_loader_lazy_materials_reports_errors_on_first_use() {
+        let directory = temporary_directory("lazy_materials_errors");
+        fs::write(
+            directory.join("scene.obj"),
+            "mtllib scene.mtl\nv 0 0 0\nusemtl red\np 1\n",
+        )
+        .unwrap();
+
+        let path = directory.join("scene.obj");
+        assert!(Scene::load(&path).is_err());
+        assert!(Scene::loader()
+            .lazy_materials(true)
+            .validate(true)
+            .load(&path)
+            .is_err());
+
+        let mut scene = Scene::loader().lazy_materials(true).load(&path).unwrap();
+        assert!(matches!(
+            scene.materials(),
+            Err(SceneError::MaterialLibrary { line_number: 1, .. })
+        ));
+        assert!(!scene.materials_loaded());
+
+        fs::write(directory.join("scene.mtl"), "newmtl red\n").unwrap();
+        assert_eq!(scene.materials().unwrap().materials.len(), 1);
+        assert_eq!(scene.material_sources(), ["scene.mtl"]);
+    }
+
+    #[test]
+    fn test_loader_lazy_materials_with_a_borrowed_resolver() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("models/scene.obj", "mtllib scene.mtl\nv 0 0 0\nusemtl red\np 1\n");
+        resolver.insert("models/scene.mtl", "newmtl red\nKd 1 0 0\n");
+        let mut scene = Scene::loader()
+            .lazy_materials(true)
+            .load_with("models/scene.obj", &resolver)
+            .unwrap();
+
+        assert!(!scene.materials_loaded());
+        assert!(matches!(
+            scene.materials(),
+            Err(SceneError::NoResolver { ref name }) if name == "models/scene.obj"
+        ));
+        assert_eq!(scene.materials_with(&resolver).unwrap().materials[0].name, "red");
+        assert!(scene.materials_loaded());
+        assert_eq!(scene.material_sources(), ["models/scene.mtl"]);
+        assert_eq!(scene, Scene::load_with("models/scene.obj", &resolver).unwrap());
+    }
+
+    #[test]
+    fn test_load_from_disk() {
+        assert_eq!(
+            Scene::load("assets/cube.obj").unwrap(),
+            Scene::load_with("cube.obj", &FileSystemResolver::new("assets")).unwrap()
+        );
+        assert!(matches!(
+            Scene::load("assets/missing.obj"),
+            Err(SceneError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_scan_material_libraries() {
+        let text = "mtllib a.mtl b.mtl\n# mtllib commented.mtl\nv 0 0 0\n  mtllib b.mtl c.mtl # trailing\n";
+
+        assert_eq!(scan_material_libraries(text), vec!["a.mtl", "b.mtl", "c.mtl"]);
+    }
+
+    #[test]
+    fn test_load_parallel_with_matches_load_with() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert(
+            "models/scene.obj",
+            "mtllib a.mtl b.mtl\nv 0 0 0\nusemtl first\np 1\nusemtl second\np 1\n",
+        );
+        resolver.insert("models/a.mtl", "newmtl first\nKd 1.0 0.0 0.0\n");
+        resolver.insert("models/b.mtl", "newmtl second\nKd 0.0 1.0 0.0\n");
+        let expected = Scene::load_with("models/scene.obj", &resolver).unwrap();
+        let result = Scene::load_parallel_with("models/scene.obj", &resolver).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_load_parallel_with_many_material_libraries() {
+        let mut resolver = MemoryResolver::new();
+        let mut obj_file = String::from("v 0 0 0\n");
+        for library in 0..100 {
+            obj_file += &format!(
+                "mtllib m{0}.mtl m{1}.mtl\nusemtl m{0}\np 1\n",
+                library,
+                library / 2
+            );
+            resolver.insert(
+                format!("models/m{}.mtl", library),
+                format!("newmtl m{}\n", library),
+            );
+        }
+        resolver.insert("models/scene.obj", obj_file);
+        let expected = Scene::load_with("models/scene.obj", &resolver).unwrap();
+        let mut result = Scene::load_parallel_with("models/scene.obj", &resolver).unwrap();
+
+        assert_eq!(result, expected);
+        assert_eq!(result.materials().unwrap().materials.len(), 200);
+    }
+
+    #[test]
+    fn test_load_parallel_with_missing_material_library() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib missing.mtl\nv 0 0 0\np 1\n");
+        let result = Scene::load_parallel_with("scene.obj", &resolver);
+
+        match result {
+            Err(SceneError::MaterialLibrary {
+                name, line_number, ..
+            }) => {
+                assert_eq!(name, "missing.mtl");
+                assert_eq!(line_number, 1);
+            }
+            other => panic!("Expected a material library error but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_parallel_with_prefers_obj_errors() {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("scene.obj", "mtllib missing.mtl\nv 0 0 0\nf 1 2 3\n");
+        let result = Scene::load_parallel_with("scene.obj", &resolver);
+
+        assert!(matches!(result, Err(SceneError::Obj { .. })));
+    }
+}
@@ -0,0 +1,593 @@
+//! Generate random but valid Wavefront OBJ and MTL files for fuzzing and
+//! model based testing.
+//!
+//! Each generated file comes with the object set or material set that
+//! parsing it must produce, so a test can check a parser, a writer, or a
+//! conversion against a known answer instead of against itself. Generation
+//! is seeded, and the same seed and limits always produce the same file, so
+//! a failing case can be reproduced from its seed alone.
+//!
+//! ## Example
+//!
+//! ```
+//! # use wavefront_obj::obj;
+//! # use wavefront_obj::testing::Generator;
+//! #
+//! for seed in 0..16 {
+//!     let generated = Generator::new(seed).obj();
+//!
+//!     assert_eq!(obj::parse(&generated.text), Ok(generated.expected));
+//! }
+//! ```
+use crate::mtl::{
+    Color,
+    IlluminationModel,
+    Material,
+    MaterialSet,
+};
+use crate::obj::{
+    Element,
+    ElementIndex,
+    Geometry,
+    Group,
+    GroupIndex,
+    MtlLibRef,
+    NormalVertex,
+    Object,
+    ObjectSet,
+    ShapeEntry,
+    ShapeEntryIndex,
+    SmoothingGroup,
+    SmoothingGroupIndex,
+    TextureVertex,
+    VTNIndex,
+    Vertex,
+};
+use std::sync::Arc;
+
+
+/// The largest sizes of the parts of a generated file.
+///
+/// Every count is chosen at random between zero, or one where a file would
+/// not be valid otherwise, and its limit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Limits {
+    /// The number of objects in an OBJ file.
+    pub objects: usize,
+    /// The number of vertices, texture vertices, and normal vectors each in
+    /// an object.
+    pub vertices: usize,
+    /// The number of runs of elements in an object that share their groups,
+    /// smoothing group, and material.
+    pub sections: usize,
+    /// The number of `p`, `l`, and `f` statements in a section.
+    pub statements: usize,
+    /// The number of vertices of a face.
+    pub face_vertices: usize,
+    /// The number of materials in an MTL file.
+    pub materials: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            objects: 3,
+            vertices: 8,
+            sections: 3,
+            statements: 4,
+            face_vertices: 6,
+            materials: 4,
+        }
+    }
+}
+
+/// A generated Wavefront OBJ file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedObj {
+    /// The text of the file.
+    pub text: String,
+    /// The object set that parsing the file with the default parser options
+    /// produces.
+    pub expected: ObjectSet,
+}
+
+/// A generated Wavefront MTL file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedMtl {
+    /// The text of the file.
+    pub text: String,
+    /// The material set that parsing the file with the default parser options
+    /// produces.
+    pub expected: MaterialSet,
+}
+
+/// A seeded generator of random Wavefront OBJ and MTL files.
+///
+/// The files only use statements whose meaning the parser fixes without any
+/// options: vertex data, objects, groups, smoothing groups, materials, and
+/// points, lines, and faces with every VTN index form, including relative
+/// indices.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    /// The state of the pseudorandom number generator.
+    state: u64,
+    /// The largest sizes of the parts of a generated file.
+    limits: Limits,
+}
+
+impl Generator {
+    /// Construct a new generator with the default limits.
+    pub fn new(seed: u64) -> Generator {
+        Generator::with_limits(seed, Limits::default())
+    }
+
+    /// Construct a new generator with the given limits.
+    pub fn with_limits(seed: u64, limits: Limits) -> Generator {
+        Generator {
+            state: seed,
+            limits: limits,
+        }
+    }
+
+    /// Generate an OBJ file and the object set it describes.
+    pub fn obj(&mut self) -> GeneratedObj {
+        let mut text = Text::new();
+        let mut material_libraries = vec![];
+        if self.chance() {
+            let line_number = text.push(String::from("mtllib library.mtl"));
+            material_libraries.push(MtlLibRef::new("library.mtl", line_number));
+        }
+
+        let object_count = self.between(1, self.limits.objects.max(1));
+        let mut offsets = (0, 0, 0);
+        let objects = (0..object_count)
+            .map(|i| self.object(&mut text, format!("object{}", i), &mut offsets))
+            .collect();
+
+        GeneratedObj {
+            text: text.text,
+            expected: ObjectSet {
+                material_libraries: material_libraries,
+                external_references: vec![],
+                unknown_statements: vec![],
+                warnings: vec![],
+                objects: objects,
+            },
+        }
+    }
+
+    /// Generate an MTL file and the material set it describes.
+    pub fn mtl(&mut self) -> GeneratedMtl {
+        let mut text = Text::new();
+        let material_count = self.between(1, self.limits.materials.max(1));
+        let materials = (0..material_count)
+            .map(|i| {
+                let mut material = Material::new();
+                material.name = format!("material{}", i);
+                material.line_number = text.push(format!("newmtl {}", material.name));
+                material.color_ambient = self.color();
+                text.push(format!("Ka {}", format_color(material.color_ambient)));
+                material.color_diffuse = self.color();
+                text.push(format!("Kd {}", format_color(material.color_diffuse)));
+                material.color_specular = self.color();
+                text.push(format!("Ks {}", format_color(material.color_specular)));
+                material.specular_exponent = self.below(1001) as f64;
+                text.push(format!("Ns {}", material.specular_exponent));
+                material.dissolve = self.unit();
+                text.push(format!("d {}", material.dissolve));
+                let illumination_model = self.below(11) as u8;
+                material.illumination_model = match illumination_model {
+                    0 => IlluminationModel::Ambient,
+                    1 => IlluminationModel::AmbientDiffuse,
+                    2 => IlluminationModel::AmbientDiffuseSpecular,
+                    number => IlluminationModel::Other(number),
+                };
+                material.end_line_number = text.push(format!("illum {}", illumination_model));
+                if self.chance() {
+                    let map_diffuse = format!("texture{}.png", i);
+                    material.end_line_number = text.push(format!("map_Kd {}", map_diffuse));
+                    material.map_diffuse = Some(map_diffuse);
+                }
+                text.push(String::new());
+
+                material
+            })
+            .collect();
+
+        GeneratedMtl {
+            text: text.text,
+            expected: MaterialSet::new(materials),
+        }
+    }
+
+    /// Generate one object, given the number of each kind of vertex in the
+    /// objects before it.
+    fn object(&mut self, text: &mut Text, name: String, offsets: &mut (usize, usize, usize)) -> Object {
+        text.push(format!("o {}", name));
+        let vertex_set: Vec<Vertex> = (0..self.below(self.limits.vertices + 1))
+            .map(|_| {
+                let vertex = Vertex {
+                    x: self.number(),
+                    y: self.number(),
+                    z: self.number(),
+                    w: self.number(),
+                };
+                text.push(format!("v {} {} {} {}", vertex.x, vertex.y, vertex.z, vertex.w));
+                vertex
+            })
+            .collect();
+        let texture_vertex_count = if self.chance() {
+            self.below(self.limits.vertices + 1)
+        } else {
+            0
+        };
+        let texture_vertex_set: Vec<TextureVertex> = (0..texture_vertex_count)
+            .map(|_| {
+                let texture_vertex = TextureVertex {
+                    u: self.unit(),
+                    v: self.unit(),
+                    w: self.unit(),
+                };
+                text.push(format!(
+                    "vt {} {} {}",
+                    texture_vertex.u, texture_vertex.v, texture_vertex.w
+                ));
+                texture_vertex
+            })
+            .collect();
+        let normal_vertex_count = if self.chance() {
+            self.below(self.limits.vertices + 1)
+        } else {
+            0
+        };
+        let normal_vertex_set: Vec<NormalVertex> = (0..normal_vertex_count)
+            .map(|_| {
+                let normal_vertex = NormalVertex {
+                    x: self.number(),
+                    y: self.number(),
+                    z: self.number(),
+                };
+                text.push(format!(
+                    "vn {} {} {}",
+                    normal_vertex.x, normal_vertex.y, normal_vertex.z
+                ));
+                normal_vertex
+            })
+            .collect();
+
+        let counts = (
+            vertex_set.len(),
+            texture_vertex_set.len(),
+            normal_vertex_set.len(),
+        );
+        let mut object = Object {
+            name: name,
+            vertex_set: vertex_set.into(),
+            texture_vertex_set: texture_vertex_set.into(),
+            normal_vertex_set: normal_vertex_set.into(),
+            ..Default::default()
+        };
+        let section_count = if counts.0 > 0 {
+            self.below(self.limits.sections + 1)
+        } else {
+            0
+        };
+        for section in 0..section_count {
+            self.section(text, &mut object, section, *offsets, counts);
+        }
+        if section_count == 0 {
+            object.geometry_set.push(Geometry {
+                material_name: None,
+                shapes: vec![],
+            });
+        }
+
+        offsets.0 += counts.0;
+        offsets.1 += counts.1;
+        offsets.2 += counts.2;
+
+        object
+    }
+
+    /// Generate a run of elements that share their groups, smoothing group,
+    /// and material, preceded by the `g`, `s`, and `usemtl` statements that
+    /// set them.
+    fn section(
+        &mut self,
+        text: &mut Text,
+        object: &mut Object,
+        section: usize,
+        offsets: (usize, usize, usize),
+        counts: (usize, usize, usize),
+    ) {
+        let group_count = self.below(3);
+        let first_group = object.group_set.len();
+        if group_count == 0 {
+            text.push(String::from("g"));
+            object.group_set.push(Group::default());
+        } else {
+            let names: Vec<String> = (0..group_count)
+                .map(|_| format!("group{}", self.below(4)))
+                .collect();
+            text.push(format!("g {}", names.join(" ")));
+            object.group_set.extend(names.into_iter().map(Group));
+        }
+        let groups: Arc<[GroupIndex]> = (first_group..object.group_set.len()).map(GroupIndex).collect();
+
+        let smoothing_group = self.below(5);
+        if smoothing_group == 0 {
+            text.push(String::from("s off"));
+        } else {
+            text.push(format!("s {}", smoothing_group));
+        }
+        object.smoothing_group_set.push(SmoothingGroup(smoothing_group));
+
+        let material_name = format!("material{}", self.below(4));
+        text.push(format!("usemtl {}", material_name));
+
+        let first_element = object.element_set.len();
+        for _ in 0..self.between(1, self.limits.statements.max(1)) {
+            self.statement(text, object, offsets, counts);
+        }
+        for element in first_element..object.element_set.len() {
+            object.shape_set.push(ShapeEntry {
+                element: ElementIndex(element),
+                groups: Arc::clone(&groups),
+                smoothing_group: SmoothingGroupIndex(section),
+            });
+        }
+        object.geometry_set.push(Geometry {
+            material_name: Some(material_name),
+            shapes: (first_element..object.element_set.len())
+                .map(ShapeEntryIndex)
+                .collect(),
+        });
+    }
+
+    /// Generate one `p`, `l`, or `f` statement and the elements it adds.
+    fn statement(
+        &mut self,
+        text: &mut Text,
+        object: &mut Object,
+        offsets: (usize, usize, usize),
+        counts: (usize, usize, usize),
+    ) {
+        let (keyword, vtn_indices) = match self.below(3) {
+            0 => {
+                let vtn_indices: Vec<VTNIndex> = (0..self.between(1, 3))
+                    .map(|_| VTNIndex::V(self.below(counts.0)))
+                    .collect();
+                object
+                    .element_set
+                    .extend(vtn_indices.iter().map(|&vtn_index| Element::Point(vtn_index)));
+                ("p", vtn_indices)
+            }
+            1 => {
+                let texture_vertex_count = if self.chance() { counts.1 } else { 0 };
+                let vertex_count = self.between(2, 4);
+                let vtn_indices = self.vtn_indices(vertex_count, (counts.0, texture_vertex_count, 0));
+                object
+                    .element_set
+                    .extend(vtn_indices.windows(2).map(|pair| Element::Line(pair[0], pair[1])));
+                ("l", vtn_indices)
+            }
+            _ => {
+                let texture_vertex_count = if self.chance() { counts.1 } else { 0 };
+                let normal_vertex_count = if self.chance() { counts.2 } else { 0 };
+                let vertex_count = self.between(3, self.limits.face_vertices.max(3));
+                let vtn_indices = self.vtn_indices(
+                    vertex_count,
+                    (counts.0, texture_vertex_count, normal_vertex_count),
+                );
+                object.element_set.extend(
+                    (1..vtn_indices.len() - 1)
+                        .map(|i| Element::Face(vtn_indices[0], vtn_indices[i], vtn_indices[i + 1])),
+                );
+                ("f", vtn_indices)
+            }
+        };
+
+        let mut line = String::from(keyword);
+        for vtn_index in vtn_indices {
+            let v = self.index_text(vtn_index.vertex_index().0, offsets.0, counts.0);
+            let argument = match vtn_index {
+                VTNIndex::V(_) => v,
+                VTNIndex::VT(_, vt) => format!("{}/{}", v, self.index_text(vt, offsets.1, counts.1)),
+                VTNIndex::VN(_, vn) => format!("{}//{}", v, self.index_text(vn, offsets.2, counts.2)),
+                VTNIndex::VTN(_, vt, vn) => format!(
+                    "{}/{}/{}",
+                    v,
+                    self.index_text(vt, offsets.1, counts.1),
+                    self.index_text(vn, offsets.2, counts.2)
+                ),
+            };
+            line.push(' ');
+            line.push_str(&argument);
+        }
+        text.push(line);
+    }
+
+    /// Generate the VTN indices of an element, all of the same form. A set
+    /// with no entries leaves its index out of the form.
+    fn vtn_indices(&mut self, length: usize, counts: (usize, usize, usize)) -> Vec<VTNIndex> {
+        (0..length)
+            .map(|_| {
+                let v = self.below(counts.0);
+                match (counts.1, counts.2) {
+                    (0, 0) => VTNIndex::V(v),
+                    (texture_vertex_count, 0) => VTNIndex::VT(v, self.below(texture_vertex_count)),
+                    (0, normal_vertex_count) => VTNIndex::VN(v, self.below(normal_vertex_count)),
+                    (texture_vertex_count, normal_vertex_count) => VTNIndex::VTN(
+                        v,
+                        self.below(texture_vertex_count),
+                        self.below(normal_vertex_count),
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Write an index into the vertices of an object either as an absolute
+    /// index into the whole file or as an index relative to the end of the
+    /// object's vertices.
+    fn index_text(&mut self, index: usize, offset: usize, count: usize) -> String {
+        if self.chance() {
+            format!("{}", offset + index + 1)
+        } else {
+            format!("-{}", count - index)
+        }
+    }
+
+    /// A random color with each component in the range `[0, 1]`.
+    fn color(&mut self) -> Color {
+        Color {
+            r: self.unit(),
+            g: self.unit(),
+            b: self.unit(),
+        }
+    }
+
+    /// A random number in the range `[-16, 16]` that the text of a file
+    /// represents exactly.
+    fn number(&mut self) -> f64 {
+        (self.below(8193) as f64 - 4096_f64) / 256_f64
+    }
+
+    /// A random number in the range `[0, 1]` that the text of a file
+    /// represents exactly.
+    fn unit(&mut self) -> f64 {
+        self.below(257) as f64 / 256_f64
+    }
+
+    /// A fair coin flip.
+    fn chance(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// A random number in the range `[low, high]`.
+    fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// A random number in the range `[0, bound)`. The bound must be positive.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// The next output of the SplitMix64 pseudorandom number generator.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+}
+
+/// The text of a generated file, built one line at a time.
+struct Text {
+    /// The text so far.
+    text: String,
+    /// The number of lines in the text so far.
+    line_count: usize,
+}
+
+impl Text {
+    /// Construct a new empty text.
+    fn new() -> Text {
+        Text {
+            text: String::new(),
+            line_count: 0,
+        }
+    }
+
+    /// Append a line to the text, returning its line number.
+    fn push(&mut self, line: String) -> usize {
+        self.text.push_str(&line);
+        self.text.push('\n');
+        self.line_count += 1;
+
+        self.line_count
+    }
+}
+
+/// Write the components of a color separated by spaces.
+fn format_color(color: Color) -> String {
+    format!("{} {} {}", color.r, color.g, color.b)
+}
+
+
+#[cfg(test)]
+mod testing_tests {
+    use super::{
+        Generator,
+        Limits,
+    };
+    use crate::mtl;
+    use crate::obj;
+
+
+    #[test]
+    fn test_generated_obj_files_parse_to_the_expected_object_set() {
+        for seed in 0..256 {
+            let generated = Generator::new(seed).obj();
+
+            assert_eq!(
+                obj::parse(&generated.text),
+                Ok(generated.expected),
+                "seed {}:\n{}",
+                seed,
+                generated.text
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_mtl_files_parse_to_the_expected_material_set() {
+        for seed in 0..256 {
+            let generated = Generator::new(seed).mtl();
+
+            assert_eq!(
+                mtl::parse(&generated.text),
+                Ok(generated.expected),
+                "seed {}:\n{}",
+                seed,
+                generated.text
+            );
+        }
+    }
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        let mut generator1 = Generator::new(7);
+        let mut generator2 = Generator::new(7);
+
+        assert_eq!(generator1.obj(), generator2.obj());
+        assert_eq!(generator1.mtl(), generator2.mtl());
+        assert_ne!(Generator::new(7).obj().text, Generator::new(8).obj().text);
+    }
+
+    #[test]
+    fn test_generation_respects_the_limits() {
+        let limits = Limits {
+            objects: 1,
+            vertices: 3,
+            sections: 1,
+            statements: 1,
+            face_vertices: 3,
+            materials: 1,
+        };
+        for seed in 0..64 {
+            let mut generator = Generator::with_limits(seed, limits);
+            let object_set = generator.obj().expected;
+            let material_set = generator.mtl().expected;
+
+            assert_eq!(object_set.objects.len(), 1);
+            assert!(object_set.objects[0].vertex_set.len() <= 3);
+            assert!(object_set.objects[0].geometry_set.len() <= 1);
+            assert!(object_set.objects[0].element_set.len() <= 3);
+            assert_eq!(material_set.materials.len(), 1);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! JavaScript bindings for parsing Wavefront OBJ files and extracting vertex
+//! buffers in the browser, built with `wasm-bindgen`.
+//!
+//! ```text
+//! import init, { parseObj } from "./wavefront_obj.js";
+//!
+//! await init();
+//! const model = parseObj(new Uint8Array(await response.arrayBuffer()));
+//! for (let i = 0; i < model.objectCount; i++) {
+//!     const vertices = model.vertices(i);
+//!     const indices = model.indices(i);
+//!     const stride = model.stride(i);
+//!     const normalOffset = model.normalOffset(i);
+//! }
+//! ```
+use crate::buffer::{
+    VertexBuffer,
+    VertexSemantic,
+};
+use crate::obj;
+use wasm_bindgen::prelude::*;
+
+
+/// The objects of a parsed Wavefront OBJ file, each extracted into an
+/// interleaved vertex buffer and an index buffer.
+#[wasm_bindgen]
+pub struct ParsedObj {
+    /// The name of each object.
+    names: Vec<String>,
+    /// The vertex and index buffers of each object.
+    buffers: Vec<VertexBuffer>,
+}
+
+#[wasm_bindgen]
+impl ParsedObj {
+    /// The number of objects in the file.
+    #[wasm_bindgen(getter, js_name = objectCount)]
+    pub fn object_count(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// The name of an object.
+    #[wasm_bindgen(js_name = objectName)]
+    pub fn object_name(&self, object: usize) -> Option<String> {
+        self.names.get(object).cloned()
+    }
+
+    /// The interleaved vertex data of an object.
+    pub fn vertices(&self, object: usize) -> Option<Vec<f32>> {
+        self.buffers.get(object).map(|buffer| buffer.vertices.clone())
+    }
+
+    /// The indices of the vertices of each triangle of an object.
+    pub fn indices(&self, object: usize) -> Option<Vec<u32>> {
+        self.buffers.get(object).map(|buffer| buffer.indices.clone())
+    }
+
+    /// The distance between the starts of consecutive vertices, in bytes.
+    pub fn stride(&self, object: usize) -> Option<u32> {
+        self.buffers
+            .get(object)
+            .map(|buffer| buffer.layout.array_stride as u32)
+    }
+
+    /// The offset of the texture coordinates inside a vertex in bytes, if
+    /// the vertices of the object have them.
+    #[wasm_bindgen(js_name = textureCoordinateOffset)]
+    pub fn texture_coordinate_offset(&self, object: usize) -> Option<u32> {
+        self.offset(object, VertexSemantic::TextureCoordinate)
+    }
+
+    /// The offset of the normal inside a vertex in bytes, if the vertices of
+    /// the object have them.
+    #[wasm_bindgen(js_name = normalOffset)]
+    pub fn normal_offset(&self, object: usize) -> Option<u32> {
+        self.offset(object, VertexSemantic::Normal)
+    }
+}
+
+impl ParsedObj {
+    /// Parse the bytes of a Wavefront OBJ file and extract its buffers.
+    fn parse(bytes: &[u8]) -> Result<ParsedObj, obj::ObjError> {
+        let object_set = obj::parse_slice(bytes)?;
+
+        Ok(ParsedObj {
+            names: object_set
+                .objects
+                .iter()
+                .map(|object| object.name.clone())
+                .collect(),
+            buffers: object_set.objects.iter().map(VertexBuffer::from_object).collect(),
+        })
+    }
+
+    /// The offset of an attribute inside a vertex of an object in bytes.
+    fn offset(&self, object: usize, semantic: VertexSemantic) -> Option<u32> {
+        self.buffers
+            .get(object)
+            .and_then(|buffer| buffer.layout.attribute(semantic))
+            .map(|attribute| attribute.offset as u32)
+    }
+}
+
+/// Parse the bytes of a Wavefront OBJ file and extract the vertex and index
+/// buffers of each of its objects.
+#[wasm_bindgen(js_name = parseObj)]
+pub fn parse_obj(bytes: &[u8]) -> Result<ParsedObj, JsError> {
+    ParsedObj::parse(bytes).map_err(|error| JsError::new(&error.to_string()))
+}
+
+
+#[cfg(test)]
+mod wasm_tests {
+    use super::ParsedObj;
+
+
+    #[test]
+    fn test_parse_extracts_buffers_of_each_object() {
+        let parsed = ParsedObj::parse(
+            b"o a\nv 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1\no b\nv 0 0 1\np 4\n",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.object_count(), 2);
+        assert_eq!(parsed.object_name(0).as_deref(), Some("a"));
+        assert_eq!(parsed.indices(0), Some(vec![0, 1, 2]));
+        assert_eq!(parsed.stride(0), Some(24));
+        assert_eq!(parsed.normal_offset(0), Some(12));
+        assert_eq!(parsed.texture_coordinate_offset(0), None);
+        assert_eq!(parsed.vertices(1), Some(vec![]));
+        assert_eq!(parsed.vertices(2), None);
+    }
+
+    #[test]
+    fn test_parse_reports_errors() {
+        assert!(ParsedObj::parse(b"f 1 2 3\n").is_err());
+    }
+}
@@ -1,10 +1,16 @@
+#![allow(clippy::redundant_field_names)]
 use std::slice;
+use wavefront_obj::mtl;
 use wavefront_obj::mtl::{
     Color,
+    ErrorKind,
     IlluminationModel,
     Material,
     MaterialSet,
+    NonFinitePolicy,
     Parser,
+    ParserOptions,
+    WarningKind,
 };
 
 
@@ -18,7 +24,7 @@ struct TestSet {
 }
 
 impl TestSet {
-    fn iter(&self) -> TestSetIter {
+    fn iter(&self) -> TestSetIter<'_> {
         TestSetIter {
             inner: self.data.iter(),
         }
@@ -43,9 +49,7 @@ fn test_cases() -> TestSet {
         data: vec![
             Test {
                 data: String::from(r""),
-                expected: MaterialSet {
-                    materials: vec![],
-                }
+                expected: MaterialSet::new(vec![])
             },
             Test {
                 data: String::from(r"
@@ -58,30 +62,35 @@ fn test_cases() -> TestSet {
                     illum 2
                     map_d window.png
                 "),
-                expected: MaterialSet {
-                    materials: vec![
-                        Material {
-                            name: String::from("frost_wind"),
-                            color_ambient: Color { r: 0.2, g: 0.2, b: 0.2 },
-                            color_diffuse: Color { r: 0.6, g: 0.6, b: 0.6 },
-                            color_specular: Color { r: 0.1, g: 0.1, b: 0.1 },
-                            color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            specular_exponent: 200_f64,
-                            dissolve: 1_f64,
-                            optical_density: None,
-                            illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                            map_ambient: None,
-                            map_diffuse: None,
-                            map_specular: None,
-                            map_emissive: None,
-                            map_specular_exponent: None,
-                            map_bump: None,
-                            map_displacement: None,
-                            map_dissolve: Some(String::from("window.png")),
-                            map_decal: None,
-                        }
-                    ]
-                }
+                expected: MaterialSet::new(vec![
+                    Material {
+                        name: String::from("frost_wind"),
+                        color_ambient: Color { r: 0.2, g: 0.2, b: 0.2 },
+                        color_diffuse: Color { r: 0.6, g: 0.6, b: 0.6 },
+                        color_specular: Color { r: 0.1, g: 0.1, b: 0.1 },
+                        color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        emissive_strength: None,
+                        specular_exponent: 200_f64,
+                        dissolve: 1_f64,
+                        optical_density: None,
+                        illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                        map_ambient: None,
+                        map_diffuse: None,
+                        map_specular: None,
+                        map_emissive: None,
+                        map_specular_exponent: None,
+                        map_bump: None,
+                        map_normal: None,
+                        map_displacement: None,
+                        map_dissolve: Some(String::from("window.png")),
+                        map_decal: None,
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 2,
+                        end_line_number: 9,
+                    }
+                ])
             },
             Test {
                 data: String::from(r"
@@ -97,30 +106,35 @@ fn test_cases() -> TestSet {
                     map_Ka cube.png
                     map_Kd cube.png
                 "),
-                expected: MaterialSet {
-                    materials: vec![
-                        Material {
-                            name: String::from("cube"),
-                            color_ambient: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_diffuse: Color { r: 0.5880, g: 0.5880, b: 0.5880 },
-                            color_specular: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_emissive: Color { r: 0.3, g: 0.3, b: 0.3 },
-                            specular_exponent: 10.0,
-                            dissolve: 1.0,
-                            optical_density: Some(1.5),
-                            illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                            map_ambient: Some(String::from("cube.png")),
-                            map_diffuse: Some(String::from("cube.png")),
-                            map_specular: None,
-                            map_emissive: None,
-                            map_specular_exponent: None,
-                            map_bump: None,
-                            map_displacement: None,
-                            map_dissolve: None,
-                            map_decal: None,
-                        }
-                    ]
-                }
+                expected: MaterialSet::new(vec![
+                    Material {
+                        name: String::from("cube"),
+                        color_ambient: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_diffuse: Color { r: 0.5880, g: 0.5880, b: 0.5880 },
+                        color_specular: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_emissive: Color { r: 0.3, g: 0.3, b: 0.3 },
+                        emissive_strength: Some(0.3),
+                        specular_exponent: 10.0,
+                        dissolve: 1.0,
+                        optical_density: Some(1.5),
+                        illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                        map_ambient: Some(String::from("cube.png")),
+                        map_diffuse: Some(String::from("cube.png")),
+                        map_specular: None,
+                        map_emissive: None,
+                        map_specular_exponent: None,
+                        map_bump: None,
+                        map_normal: None,
+                        map_displacement: None,
+                        map_dissolve: None,
+                        map_decal: None,
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 2,
+                        end_line_number: 12,
+                    }
+                ])
             },
             Test {
                 data: String::from(r"
@@ -173,110 +187,143 @@ fn test_cases() -> TestSet {
                     disp material_displacement.png
 
                 "),
-                expected: MaterialSet {
-                    materials: vec![
-                        Material {
-                            name: String::from("fresnel_blu"),
-                            color_ambient: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_diffuse: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_specular: Color { r: 0.6180, g: 0.8760, b: 0.1430 },
-                            color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            specular_exponent: 200.0,
-                            dissolve: 1.0,
-                            optical_density: None,
-                            illumination_model: IlluminationModel::AmbientDiffuse,
-                            map_ambient: None,
-                            map_diffuse: None,
-                            map_specular: None,
-                            map_emissive: None,
-                            map_specular_exponent: None,
-                            map_bump: None,
-                            map_displacement: None,
-                            map_dissolve: Some(String::from("fresnel_blu_dissolve.png")),
-                            map_decal: None,
-                        },
-                        Material {
-                            name: String::from("real_windsh"),
-                            color_ambient: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_diffuse: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_specular: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            specular_exponent: 200.0,
-                            dissolve: 1.0,
-                            optical_density: Some(1.5),
-                            illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                            map_ambient: None,
-                            map_diffuse: None,
-                            map_specular: None,
-                            map_emissive: None,
-                            map_specular_exponent: None,
-                            map_bump: None,
-                            map_displacement: None,
-                            map_dissolve: None,
-                            map_decal: Some(String::from("decal.jpg")),
-                        },
-                        Material {
-                            name: String::from("fresnel_win"),
-                            color_ambient: Color { r: 0.0, g: 0.0, b: 1.0 },
-                            color_diffuse: Color { r: 0.0, g: 0.0, b: 1.0 },
-                            color_specular: Color { r: 0.6180, g: 0.8760, b: 0.1430 },
-                            color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            specular_exponent: 200.0,
-                            dissolve: 1.0,
-                            optical_density: Some(1.2000),
-                            illumination_model: IlluminationModel::Ambient,
-                            map_ambient: None,
-                            map_diffuse: None,
-                            map_specular: None,
-                            map_emissive: None,
-                            map_specular_exponent: None,
-                            map_bump: None,
-                            map_displacement: None,
-                            map_dissolve: None,
-                            map_decal: None,
-                        },
-                        Material {
-                            name: String::from("tin"),
-                            color_ambient: Color { r: 0.5000, g: 0.5000, b: 0.5000 },
-                            color_diffuse: Color { r: 0.3000, g: 0.2540, b: 0.3128 },
-                            color_specular: Color { r: 0.3245, g: 0.2976, b: 0.1234 },
-                            color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
-                            specular_exponent: 200.0,
-                            dissolve: 1.0,
-                            optical_density: None,
-                            illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                            map_ambient: Some(String::from("tin_Ka.png")),
-                            map_diffuse: Some(String::from("tin_Kd.png")),
-                            map_specular: Some(String::from("tin_Ks.png")),
-                            map_emissive: None,
-                            map_specular_exponent: None,
-                            map_bump: Some(String::from("tin_bump.png")),
-                            map_displacement: None,
-                            map_dissolve: None,
-                            map_decal: None,
-                        },
-                        Material {
-                            name: String::from("material"),
-                            color_ambient: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
-                            color_diffuse: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
-                            color_specular: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
-                            color_emissive: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
-                            specular_exponent: 0.0,
-                            dissolve: 0.9,
-                            optical_density: Some(3.4924),
-                            illumination_model: IlluminationModel::AmbientDiffuseSpecular,
-                            map_ambient: Some(String::from("material_Ka.png")),
-                            map_diffuse: Some(String::from("material_Kd.png")),
-                            map_specular: Some(String::from("material_Ks.png")),
-                            map_emissive: Some(String::from("material_Ke.png")),
-                            map_specular_exponent: Some(String::from("material_Ns.png")),
-                            map_bump: Some(String::from("material_bump.png")),
-                            map_displacement: Some(String::from("material_displacement.png")),
-                            map_dissolve: None,
-                            map_decal: None,
-                        },
-                    ]
-                }
+                expected: MaterialSet::new(vec![
+                    Material {
+                        name: String::from("fresnel_blu"),
+                        color_ambient: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_diffuse: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_specular: Color { r: 0.6180, g: 0.8760, b: 0.1430 },
+                        color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        emissive_strength: None,
+                        specular_exponent: 200.0,
+                        dissolve: 1.0,
+                        optical_density: None,
+                        illumination_model: IlluminationModel::AmbientDiffuse,
+                        map_ambient: None,
+                        map_diffuse: None,
+                        map_specular: None,
+                        map_emissive: None,
+                        map_specular_exponent: None,
+                        map_bump: None,
+                        map_normal: None,
+                        map_displacement: None,
+                        map_dissolve: Some(String::from("fresnel_blu_dissolve.png")),
+                        map_decal: None,
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 2,
+                        end_line_number: 8,
+                    },
+                    Material {
+                        name: String::from("real_windsh"),
+                        color_ambient: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_diffuse: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_specular: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        emissive_strength: None,
+                        specular_exponent: 200.0,
+                        dissolve: 1.0,
+                        optical_density: Some(1.5),
+                        illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                        map_ambient: None,
+                        map_diffuse: None,
+                        map_specular: None,
+                        map_emissive: None,
+                        map_specular_exponent: None,
+                        map_bump: None,
+                        map_normal: None,
+                        map_displacement: None,
+                        map_dissolve: None,
+                        map_decal: Some(String::from("decal.jpg")),
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 10,
+                        end_line_number: 17,
+                    },
+                    Material {
+                        name: String::from("fresnel_win"),
+                        color_ambient: Color { r: 0.0, g: 0.0, b: 1.0 },
+                        color_diffuse: Color { r: 0.0, g: 0.0, b: 1.0 },
+                        color_specular: Color { r: 0.6180, g: 0.8760, b: 0.1430 },
+                        color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        emissive_strength: None,
+                        specular_exponent: 200.0,
+                        dissolve: 1.0,
+                        optical_density: Some(1.2000),
+                        illumination_model: IlluminationModel::Ambient,
+                        map_ambient: None,
+                        map_diffuse: None,
+                        map_specular: None,
+                        map_emissive: None,
+                        map_specular_exponent: None,
+                        map_bump: None,
+                        map_normal: None,
+                        map_displacement: None,
+                        map_dissolve: None,
+                        map_decal: None,
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 19,
+                        end_line_number: 25,
+                    },
+                    Material {
+                        name: String::from("tin"),
+                        color_ambient: Color { r: 0.5000, g: 0.5000, b: 0.5000 },
+                        color_diffuse: Color { r: 0.3000, g: 0.2540, b: 0.3128 },
+                        color_specular: Color { r: 0.3245, g: 0.2976, b: 0.1234 },
+                        color_emissive: Color { r: 0.0, g: 0.0, b: 0.0 },
+                        emissive_strength: None,
+                        specular_exponent: 200.0,
+                        dissolve: 1.0,
+                        optical_density: None,
+                        illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                        map_ambient: Some(String::from("tin_Ka.png")),
+                        map_diffuse: Some(String::from("tin_Kd.png")),
+                        map_specular: Some(String::from("tin_Ks.png")),
+                        map_emissive: None,
+                        map_specular_exponent: None,
+                        map_bump: Some(String::from("tin_bump.png")),
+                        map_normal: None,
+                        map_displacement: None,
+                        map_dissolve: None,
+                        map_decal: None,
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 27,
+                        end_line_number: 36,
+                    },
+                    Material {
+                        name: String::from("material"),
+                        color_ambient: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
+                        color_diffuse: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
+                        color_specular: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
+                        color_emissive: Color { r: 0.0000, g: 0.0000, b: 0.0000 },
+                        emissive_strength: None,
+                        specular_exponent: 0.0,
+                        dissolve: 0.9,
+                        optical_density: Some(3.4924),
+                        illumination_model: IlluminationModel::AmbientDiffuseSpecular,
+                        map_ambient: Some(String::from("material_Ka.png")),
+                        map_diffuse: Some(String::from("material_Kd.png")),
+                        map_specular: Some(String::from("material_Ks.png")),
+                        map_emissive: Some(String::from("material_Ke.png")),
+                        map_specular_exponent: Some(String::from("material_Ns.png")),
+                        map_bump: Some(String::from("material_bump.png")),
+                        map_normal: None,
+                        map_displacement: Some(String::from("material_displacement.png")),
+                        map_dissolve: None,
+                        map_decal: None,
+                        anti_alias_maps: false,
+                        transparency: None,
+                        extras: Default::default(),
+                        line_number: 38,
+                        end_line_number: 48,
+                    },
+                ])
             }
         ]
     }
@@ -625,3 +672,237 @@ fn test_parse_material_set_should_parse_correct_decal_maps() {
         }
     }
 }
+
+/// A parse error should expose where and why parsing failed.
+#[test]
+fn test_parse_error_accessors() {
+    let mut parser = Parser::new("newmtl material\nKd 1.0 0.0 0.0\nillum 256\n");
+    let error = parser.parse_mtlset().unwrap_err();
+
+    assert_eq!(error.line_number(), 3);
+    assert_eq!(error.kind(), &ErrorKind::UnknownIlluminationModel);
+    assert!(!error.message().is_empty());
+}
+
+#[test]
+fn test_parse_error_code() {
+    let mut parser = Parser::new("newmtl material\nKd 1.0 0.0 0.0\nillum 256\n");
+    let error = parser.parse_mtlset().unwrap_err();
+
+    assert_eq!(error.code(), "MTL0006");
+    assert!(error.to_string().starts_with("Parse error MTL0006 at line 3: "));
+}
+
+#[test]
+fn test_parse_error_span() {
+    let text = "newmtl material\nKd 1.0 0.0 0.0\nillum 256\n";
+    let mut parser = Parser::new(text);
+    let error = parser.parse_mtlset().unwrap_err();
+
+    assert_eq!(error.span().map(|span| &text[span]), Some("256"));
+}
+
+/// Material libraries saved with Windows or classic Mac OS line endings should
+/// parse the same as those saved with Unix line endings.
+#[test]
+fn test_parse_material_set_every_line_ending_convention() {
+    let lines = [
+        "newmtl red",
+        "Kd 1.0 0.0 0.0",
+        "Ns 10.0",
+        "newmtl blue",
+        "Kd 0.0 0.0 1.0",
+        "",
+    ];
+    let expected = Parser::new(&lines.join("\n")).parse_mtlset().unwrap();
+    for line_ending in ["\r\n", "\r"] {
+        let text = lines.join(line_ending);
+        let result = Parser::new(&text).parse_mtlset().unwrap();
+
+        assert_eq!(result, expected, "line_ending = {:?}", line_ending);
+    }
+
+    let text = lines.join("\r") + "illum 256\r";
+    let error = Parser::new(&text).parse_mtlset().unwrap_err();
+
+    assert_eq!(error.line_number(), 6);
+}
+
+#[test]
+fn test_parse_material_set_tab_delimited() {
+    let expected = Parser::new("newmtl red\nKd 1.0 0.0 0.0\nNs 10.0\n")
+        .parse_mtlset()
+        .unwrap();
+    let mut parser = Parser::new("newmtl\tred\n\tKd\t1.0\t0.0\x0C0.0\t\n\x0BNs\t\t10.0\n");
+    let result = parser.parse_mtlset().unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_parse_error_not_a_text_file() {
+    let mut parser = Parser::new("newmtl material\nKd 1.0 0.0 0.0\n\0\0\0\n");
+    let error = parser.parse_mtlset().unwrap_err();
+
+    assert_eq!(error.kind(), &ErrorKind::NotATextFile);
+    assert_eq!(error.code(), "MTL0008");
+    assert_eq!(error.line_number(), 3);
+    assert_eq!(error.span(), Some(31..32));
+}
+
+#[test]
+fn test_parse_material_set_numeric_literal_forms() {
+    let mut parser = Parser::new("newmtl material\nKd .5 1. +0.25\nNs 1e2\n");
+    let result = parser.parse_mtlset().unwrap();
+
+    assert_eq!(
+        result.materials[0].color_diffuse,
+        Color {
+            r: 0.5,
+            g: 1.0,
+            b: 0.25
+        }
+    );
+    assert_eq!(result.materials[0].specular_exponent, 100.0);
+}
+
+#[test]
+fn test_parse_material_set_decimal_comma() {
+    let options = ParserOptions {
+        decimal_comma: true,
+        ..ParserOptions::default()
+    };
+    let result = mtl::parse_with("newmtl material\nKd 0,5 1 0,25\nNs 12,5\n", options).unwrap();
+
+    assert_eq!(
+        result.materials[0].color_diffuse,
+        Color {
+            r: 0.5,
+            g: 1.0,
+            b: 0.25
+        }
+    );
+    assert_eq!(result.materials[0].specular_exponent, 12.5);
+    assert!(mtl::parse("newmtl material\nKd 0,5 1 0,25\n").is_err());
+}
+
+#[test]
+fn test_parse_material_set_trailing_comments() {
+    let mtl_file = "\
+        newmtl red # The first material.\n\
+        Kd 1.0 0.0 0.0  # diffuse\n\
+        Ns 10.0#exponent\n\
+        illum 2 # model\n\
+        map_Kd red.png # diffuse map\n\
+        bump bump.png#bump map\n\
+    ";
+    let result = mtl::parse(mtl_file).unwrap();
+    let material = &result.materials[0];
+
+    assert_eq!(material.name, "red");
+    assert_eq!(
+        material.color_diffuse,
+        Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0
+        }
+    );
+    assert_eq!(material.specular_exponent, 10.0);
+    assert_eq!(
+        material.illumination_model,
+        IlluminationModel::AmbientDiffuseSpecular
+    );
+    assert_eq!(material.map_diffuse.as_deref(), Some("red.png"));
+    assert_eq!(material.map_bump.as_deref(), Some("bump.png"));
+}
+
+fn parse_non_finite(policy: NonFinitePolicy) -> Result<MaterialSet, mtl::ParseError> {
+    let options = ParserOptions {
+        non_finite: policy,
+        ..ParserOptions::default()
+    };
+
+    Parser::with_options("newmtl broken\nKd nan 0.5 inf\nNs 10.0\n", options).parse_mtlset()
+}
+
+#[test]
+fn test_parse_non_finite_accept() {
+    let result = parse_non_finite(NonFinitePolicy::Accept).unwrap();
+
+    assert!(result.materials[0].color_diffuse.r.is_nan());
+    assert_eq!(result.materials[0].color_diffuse.b, f64::INFINITY);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_parse_non_finite_reject() {
+    let error = parse_non_finite(NonFinitePolicy::Reject).unwrap_err();
+
+    assert_eq!(error.kind(), &ErrorKind::NonFiniteValue);
+    assert_eq!(error.code(), "MTL0009");
+    assert_eq!(error.line_number(), 2);
+}
+
+#[test]
+fn test_parse_non_finite_warn() {
+    let result = parse_non_finite(NonFinitePolicy::Warn).unwrap();
+    let kinds: Vec<WarningKind> = result.warnings.iter().map(|warning| warning.kind).collect();
+
+    assert_eq!(
+        kinds,
+        vec![WarningKind::NonFiniteValue, WarningKind::NonFiniteValue]
+    );
+    assert!(result.warnings.iter().all(|warning| warning.material == "broken"));
+    assert_eq!(
+        result.warnings[0].message,
+        "The number `NaN` on line 2 is not finite."
+    );
+}
+
+#[test]
+fn test_parse_non_finite_clamp() {
+    let result = parse_non_finite(NonFinitePolicy::Clamp).unwrap();
+
+    assert_eq!(
+        result.materials[0].color_diffuse,
+        Color {
+            r: 0.0,
+            g: 0.5,
+            b: f64::MAX
+        }
+    );
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_parse_material_line_numbers() {
+    let mtl_file = "\
+        # A library with two materials.\n\
+        newmtl red\n\
+        Kd 1.0 0.0 0.0\n\
+        \n\
+        Ns 10.0 # exponent\n\
+        # A comment after the last statement.\n\
+        \n\
+        newmtl blue\r\n\
+        Kd 0.0 0.0 1.0\r\n\
+    ";
+    let result = mtl::parse(mtl_file).unwrap();
+    let lines: Vec<(usize, usize)> = result
+        .materials
+        .iter()
+        .map(|material| (material.line_number, material.end_line_number))
+        .collect();
+
+    assert_eq!(lines, vec![(2, 5), (8, 9)]);
+}
+
+#[test]
+fn test_parse_material_line_numbers_without_statements() {
+    let result = mtl::parse("newmtl empty\nnewmtl other\n").unwrap();
+
+    assert_eq!(result.materials[0].line_number, 1);
+    assert_eq!(result.materials[0].end_line_number, 1);
+    assert_eq!(result.materials[1].line_number, 2);
+}
@@ -0,0 +1,91 @@
+use wavefront_obj::mtl;
+use wavefront_obj::mtl::{
+    Compositor,
+    Material,
+    MaterialSet,
+    ParserOptions,
+    StoreExtras,
+    TextMaterialSetCompositor,
+};
+
+
+/// Compare two material sets, ignoring the line numbers of the materials.
+fn assert_same_materials(result: &MaterialSet, expected: &MaterialSet) {
+    let without_lines = |material: &Material| Material {
+        line_number: 0,
+        end_line_number: 0,
+        ..material.clone()
+    };
+
+    assert_eq!(result.materials.len(), expected.materials.len());
+    for (result_material, expected_material) in result.materials.iter().zip(expected.materials.iter()) {
+        assert_eq!(without_lines(result_material), without_lines(expected_material));
+    }
+}
+
+/// Every field of a material should survive a round trip through the writer.
+#[test]
+fn test_roundtrip_every_field() {
+    let mtl_file = "\
+        newmtl full\n\
+        Ka 0.1 0.2 0.3\n\
+        Kd 0.4 0.5 0.6\n\
+        Ks 0.7 0.8 0.9\n\
+        Ke 2 1 0.5\n\
+        Ns 96.078431\n\
+        Ni 1.45\n\
+        d 0.75\n\
+        Tr 0.5\n\
+        illum 7\n\
+        map_Ka ambient.png\n\
+        map_Kd diffuse.png\n\
+        map_Ks specular.png\n\
+        map_Ke emissive.png\n\
+        map_Ns shininess.png\n\
+        map_Bump bump.png\n\
+        norm normal.png\n\
+        disp displacement.png\n\
+        map_d alpha.png\n\
+        decal decal.png\n\
+        map_aat on\n\
+        \n\
+        newmtl plain\n\
+        Kd 1 1 1\n";
+    let material_set = mtl::parse(mtl_file).unwrap();
+    let output = TextMaterialSetCompositor::new().compose(&material_set);
+
+    assert_same_materials(&mtl::parse(&output).unwrap(), &material_set);
+    assert!(!output.contains("Ke 0 0 0"));
+}
+
+/// The extras of a material should be written so that a parser that stores
+/// them reads them back.
+#[test]
+fn test_roundtrip_extras() {
+    let options = || ParserOptions {
+        statement_handler: Some(Box::new(StoreExtras)),
+        ..ParserOptions::default()
+    };
+    let material_set = mtl::parse_with("newmtl metal\nKd 1 1 1\nPm 1\nPr 0.25\n", options()).unwrap();
+    let output = TextMaterialSetCompositor::new().compose(&material_set);
+
+    assert!(output.contains("Pm 1\nPr 0.25\n"));
+    assert_same_materials(&mtl::parse_with(&output, options()).unwrap(), &material_set);
+}
+
+/// The compositor should stream its output to a writer, and the display
+/// implementation of a material set should use it.
+#[test]
+fn test_compose_to_matches_display() {
+    let material_set = mtl::parse("newmtl a\nKd 1 0 0\nnewmtl b\nKd 0 1 0\n").unwrap();
+    let mut bytes = vec![];
+    TextMaterialSetCompositor::new()
+        .compose_to(&material_set, &mut bytes)
+        .unwrap();
+
+    assert_eq!(String::from_utf8(bytes).unwrap(), material_set.to_string());
+    assert_eq!(
+        TextMaterialSetCompositor::new().compose(&mtl::parse("").unwrap()),
+        ""
+    );
+}
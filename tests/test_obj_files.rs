@@ -0,0 +1,305 @@
+use std::fs;
+use std::io;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use wavefront_obj::obj;
+use wavefront_obj::obj::{
+    GeometryIndex,
+    ObjError,
+    ShapeEntryIndex,
+};
+
+
+const CUBE: &str = "assets/cube.obj";
+const CUBE_VT: &str = "assets/cube_vt.obj";
+const USEMTL_BEFORE_OBJECT: &str = "assets/usemtl_before_object.obj";
+
+
+fn temporary_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("wavefront_obj_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+
+    directory
+}
+
+/// Parsing a single file should record the file as the source of every object.
+#[test]
+fn test_parse_file_records_source() {
+    let result = obj::parse_file(CUBE).unwrap();
+
+    assert!(!result.objects.is_empty());
+    for object in result.objects.iter() {
+        assert_eq!(object.source.as_deref(), Some(Path::new(CUBE)));
+    }
+}
+
+/// Parsing multiple files should yield the objects of each file in the order the
+/// files were given.
+#[test]
+fn test_parse_files_preserves_order_and_provenance() {
+    let cube = obj::parse_file(CUBE).unwrap();
+    let cube_vt = obj::parse_file(CUBE_VT).unwrap();
+    let result = obj::parse_files(vec![PathBuf::from(CUBE), PathBuf::from(CUBE_VT)]).unwrap();
+
+    assert_eq!(result.objects.len(), cube.objects.len() + cube_vt.objects.len());
+    let expected = cube.objects.iter().chain(cube_vt.objects.iter());
+    for (result_object, expected_object) in result.objects.iter().zip(expected) {
+        assert_eq!(result_object, expected_object);
+    }
+}
+
+/// Parsing files in parallel should give the same result as parsing them one at a time.
+#[test]
+fn test_parse_files_parallel_matches_sequential() {
+    let paths = vec![
+        PathBuf::from(CUBE),
+        PathBuf::from(CUBE_VT),
+        PathBuf::from(CUBE),
+        PathBuf::from(CUBE_VT),
+        PathBuf::from(CUBE),
+    ];
+    let expected = obj::parse_files(paths.clone()).unwrap();
+    for max_threads in [0, 1, 2, 3, 8] {
+        let result = obj::parse_files_parallel(paths.clone(), max_threads).unwrap();
+        assert_eq!(result, expected);
+    }
+}
+
+/// A missing file should be reported as an input/output error naming the file.
+#[test]
+fn test_parse_files_missing_file() {
+    let missing = PathBuf::from("assets/does_not_exist.obj");
+    let result = obj::parse_files(vec![PathBuf::from(CUBE), missing.clone()]);
+
+    match result {
+        Err(ObjError::Io { path, .. }) => assert_eq!(path, Some(missing)),
+        other => panic!("Expected an input/output error but got {:?}", other),
+    }
+}
+
+/// The underlying input/output error should be kept, so the cause of the
+/// failure can be inspected.
+#[test]
+fn test_parse_file_keeps_io_error() {
+    let missing = PathBuf::from("assets/does_not_exist.obj");
+    let result = obj::parse_file(&missing);
+    let error = match result {
+        Err(error) => error,
+        Ok(object_set) => panic!("Expected an input/output error but got {:?}", object_set),
+    };
+    let source = std::error::Error::source(&error).and_then(|source| source.downcast_ref::<io::Error>());
+
+    assert_eq!(error.path(), Some(missing.as_path()));
+    assert_eq!(source.map(io::Error::kind), Some(io::ErrorKind::NotFound));
+    assert!(error
+        .to_string()
+        .starts_with("Could not read `assets/does_not_exist.obj`: "));
+}
+
+/// A malformed file should be reported as a parse error naming the file.
+#[test]
+fn test_parse_files_malformed_file() {
+    let directory = temporary_directory("malformed");
+    let malformed = directory.join("malformed.obj");
+    fs::write(&malformed, "v 1.0 2.0\n").unwrap();
+    let result = obj::parse_files_parallel(vec![PathBuf::from(CUBE), malformed.clone()], 2);
+    fs::remove_dir_all(&directory).unwrap();
+
+    match result {
+        Err(ObjError::Parse { path, .. }) => assert_eq!(path, Some(malformed)),
+        other => panic!("Expected a parse error but got {:?}", other),
+    }
+}
+
+/// A `usemtl` statement before the first object should apply to the first
+/// geometry of the first object, and no empty object should be produced for it.
+#[test]
+fn test_parse_file_usemtl_before_object() {
+    let result = obj::parse_file(USEMTL_BEFORE_OBJECT).unwrap();
+    let names: Vec<&str> = result.objects.iter().map(|object| object.name.as_str()).collect();
+    let materials: Vec<Vec<Option<&str>>> = result
+        .objects
+        .iter()
+        .map(|object| {
+            object
+                .geometry_set
+                .iter()
+                .map(|geometry| geometry.material_name.as_deref())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(names, vec!["first", "second"]);
+    assert_eq!(materials, vec![vec![Some("red"), Some("blue")], vec![None]]);
+    assert_eq!(
+        result.objects[0].geometry_set[GeometryIndex(0)].shapes,
+        vec![ShapeEntryIndex(0)]
+    );
+}
+
+/// Parsing a directory should load every OBJ file in it in path order, and skip
+/// everything else.
+#[test]
+fn test_parse_directory() {
+    let directory = temporary_directory("directory");
+    fs::copy(CUBE_VT, directory.join("b.OBJ")).unwrap();
+    fs::copy(CUBE, directory.join("a.obj")).unwrap();
+    fs::write(directory.join("notes.txt"), "not an obj file").unwrap();
+    let result = obj::parse_directory(&directory).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    let sources: Vec<PathBuf> = result
+        .objects
+        .iter()
+        .map(|object| object.source.clone().unwrap())
+        .collect();
+    assert_eq!(sources, vec![directory.join("a.obj"), directory.join("b.OBJ")]);
+}
+
+/// Parsing a directory with a pattern should load every file whose whole name
+/// matches the pattern in path order, and skip everything else.
+#[test]
+fn test_parse_directory_matching() {
+    let directory = temporary_directory("directory_matching");
+    fs::copy(CUBE_VT, directory.join("part_wheel.obj")).unwrap();
+    fs::copy(CUBE, directory.join("part_1.obj")).unwrap();
+    fs::copy(CUBE, directory.join("old_part_2.obj")).unwrap();
+    fs::copy(CUBE, directory.join("part_3.obj.bak")).unwrap();
+    fs::copy(CUBE, directory.join("PART_4.obj")).unwrap();
+    fs::create_dir(directory.join("part_5.obj")).unwrap();
+    let result = obj::parse_directory_matching(&directory, "part_*.obj").unwrap();
+    let single = obj::parse_directory_matching(&directory, "part_?.obj").unwrap();
+    let none = obj::parse_directory_matching(&directory, "*.mtl").unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    let sources = |object_set: &obj::ObjectSet| -> Vec<PathBuf> {
+        object_set
+            .objects
+            .iter()
+            .map(|object| object.source.clone().unwrap())
+            .collect()
+    };
+    assert_eq!(
+        sources(&result),
+        vec![directory.join("part_1.obj"), directory.join("part_wheel.obj")]
+    );
+    assert_eq!(sources(&single), vec![directory.join("part_1.obj")]);
+    assert!(none.objects.is_empty());
+}
+
+/// A pattern should match names through repeated and trailing wildcards.
+#[test]
+fn test_parse_directory_matching_wildcards() {
+    let directory = temporary_directory("directory_matching_wildcards");
+    fs::copy(CUBE, directory.join("a_b_c.obj")).unwrap();
+    fs::copy(CUBE, directory.join("a_c.obj")).unwrap();
+    let patterns = [
+        ("*", 2),
+        ("*.*", 2),
+        ("a_*_c.obj", 1),
+        ("a*c.obj", 2),
+        ("a_**c.obj", 2),
+        ("?_?.obj", 1),
+        ("a_b_c.obj", 1),
+        ("a_b_c.ob", 0),
+        ("a_b_c.obj?", 0),
+        ("b*", 0),
+    ];
+    let results: Vec<usize> = patterns
+        .iter()
+        .map(|(pattern, _)| {
+            obj::parse_directory_matching(&directory, pattern)
+                .unwrap()
+                .objects
+                .len()
+        })
+        .collect();
+    let objects_per_file = obj::parse_file(CUBE).unwrap().objects.len();
+    fs::remove_dir_all(&directory).unwrap();
+
+    for ((pattern, files), objects) in patterns.iter().zip(results) {
+        assert_eq!(objects, files * objects_per_file, "pattern {}", pattern);
+    }
+}
+
+/// Parsing a directory with a pattern should report a missing directory as an
+/// input/output error.
+#[test]
+fn test_parse_directory_matching_missing_directory() {
+    let directory = temporary_directory("directory_matching_missing").join("missing");
+    let result = obj::parse_directory_matching(&directory, "*.obj");
+
+    match result {
+        Err(ObjError::Io { path, .. }) => assert_eq!(path, Some(directory)),
+        other => panic!("expected an input/output error, got {:?}", other),
+    }
+}
+
+/// Gzip compressed files should be decompressed transparently.
+#[cfg(feature = "flate2")]
+#[test]
+fn test_parse_file_gzip() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let directory = temporary_directory("gzip");
+    let compressed = directory.join("cube.obj.gz");
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&fs::read(CUBE).unwrap()).unwrap();
+    fs::write(&compressed, encoder.finish().unwrap()).unwrap();
+    let result = obj::parse_file(&compressed).unwrap();
+    let expected = obj::parse_file(CUBE).unwrap();
+    let from_reader = obj::parse_gzip(fs::File::open(&compressed).unwrap()).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(result.objects.len(), expected.objects.len());
+    for (result_object, expected_object) in result.objects.iter().zip(expected.objects.iter()) {
+        assert_eq!(result_object.source.as_deref(), Some(compressed.as_path()));
+        assert_eq!(result_object.vertex_set, expected_object.vertex_set);
+        assert_eq!(result_object.element_set, expected_object.element_set);
+    }
+    assert_eq!(
+        from_reader.objects[0].element_set,
+        expected.objects[0].element_set
+    );
+}
+
+/// A gzip compressed file written by the crate should parse back to the same objects.
+#[cfg(feature = "flate2")]
+#[test]
+fn test_write_gzip_file() {
+    use wavefront_obj::obj::TextObjectSetCompositor;
+
+    let directory = temporary_directory("write_gzip");
+    let compressed = directory.join("cube.obj.gz");
+    let expected = obj::parse_file(CUBE).unwrap();
+    obj::write_gzip_file(&compressed, &expected, &TextObjectSetCompositor::new()).unwrap();
+    let bytes = fs::read(&compressed).unwrap();
+    let result = obj::parse_file(&compressed).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+    assert!(bytes.len() < fs::metadata(CUBE).unwrap().len() as usize);
+    assert!(obj::diff(&expected, &result, 0.0).is_empty());
+}
+
+/// Without gzip support, a gzip compressed file should be reported as an input/output
+/// error instead of producing a confusing parse error.
+#[cfg(not(feature = "flate2"))]
+#[test]
+fn test_parse_file_gzip_without_feature() {
+    let directory = temporary_directory("gzip_without_feature");
+    let compressed = directory.join("cube.obj.gz");
+    fs::write(&compressed, [0x1f, 0x8b, 0x08, 0x00, 0x00]).unwrap();
+    let result = obj::parse_file(&compressed);
+    fs::remove_dir_all(&directory).unwrap();
+
+    match result {
+        Err(ObjError::Io { error, .. }) => assert_eq!(error.kind(), std::io::ErrorKind::InvalidData),
+        other => panic!("Expected an input/output error but got {:?}", other),
+    }
+}
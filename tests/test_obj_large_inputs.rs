@@ -0,0 +1,124 @@
+use std::iter;
+use wavefront_obj::obj;
+use wavefront_obj::obj::{
+    ErrorKind,
+    ParserOptions,
+};
+
+
+/// Generate a face statement with `vertex_count` vertices that cycles through
+/// the vertices of a triangle.
+fn gigantic_face(vertex_count: usize) -> String {
+    let indices = ["1", "2", "3"].iter().cycle().take(vertex_count);
+
+    iter::once("f")
+        .chain(indices.copied())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// A face with hundreds of thousands of vertices should be triangulated into
+/// one triangle per vertex beyond the second.
+#[test]
+fn test_parse_gigantic_face() {
+    let obj_file = format!("v 0 0 0\nv 1 0 0\nv 0 1 0\n{}\n", gigantic_face(300_000));
+    let result = obj::parse(obj_file).unwrap();
+
+    assert_eq!(result.objects[0].element_set.len(), 300_000 - 2);
+    assert_eq!(result.objects[0].shape_set.len(), 300_000 - 2);
+}
+
+/// A gigantic face statement on the last line of a file without a line
+/// terminator should parse like any other.
+#[test]
+fn test_parse_gigantic_face_without_newline() {
+    let obj_file = format!("v 0 0 0\nv 1 0 0\nv 0 1 0\n{}", gigantic_face(300_000));
+    let result = obj::parse(obj_file).unwrap();
+
+    assert_eq!(result.objects[0].element_set.len(), 300_000 - 2);
+}
+
+/// A file that is one gigantic comment without a line terminator contains no
+/// objects.
+#[test]
+fn test_parse_gigantic_comment_without_newline() {
+    let obj_file = format!("# {}", "x".repeat(16 * 1024 * 1024));
+    let result = obj::parse(obj_file).unwrap();
+
+    assert!(result.objects.is_empty());
+}
+
+/// A file that is one gigantic run of whitespace contains no objects.
+#[test]
+fn test_parse_gigantic_whitespace_without_newline() {
+    let obj_file = " \t".repeat(8 * 1024 * 1024);
+    let result = obj::parse(obj_file).unwrap();
+
+    assert!(result.objects.is_empty());
+}
+
+/// A gigantic token is a single token, however long it is.
+#[test]
+fn test_parse_gigantic_token_without_newline() {
+    let obj_file = format!("v 0 0 {}", "1".repeat(16 * 1024 * 1024));
+    let result = obj::parse(obj_file).unwrap();
+
+    assert_eq!(result.objects[0].vertex_set.len(), 1);
+}
+
+/// Vertex statements that are not separated by line terminators should still
+/// parse, since the optional w component of a vertex ends at the next keyword.
+#[test]
+fn test_parse_vertices_on_one_line() {
+    let obj_file = "v 0 0 0 ".repeat(100_000);
+    let result = obj::parse(obj_file).unwrap();
+
+    assert_eq!(result.objects[0].vertex_set.len(), 100_000);
+    assert!(result.objects[0].element_set.is_empty());
+}
+
+#[test]
+fn test_max_line_length_rejects_long_lines() {
+    let obj_file = format!("v 0 0 0\nv 1 0 0\nv 0 1 0\n{}\n", gigantic_face(100_000));
+    let options = ParserOptions {
+        max_line_length: Some(4096),
+        ..ParserOptions::default()
+    };
+    let error = obj::parse_with(&obj_file, options).unwrap_err();
+
+    assert_eq!(error.kind(), &ErrorKind::LineTooLong);
+    assert_eq!(error.line_number(), 4);
+    assert_eq!(error.span(), Some(24..obj_file.len() - 1));
+}
+
+#[test]
+fn test_max_line_length_accepts_short_lines() {
+    let obj_file = "v 0 0 0\r\nv 1 0 0\r\nv 0 1 0\r\nf 1 2 3\r\n";
+    let options = ParserOptions {
+        max_line_length: Some(7),
+        ..ParserOptions::default()
+    };
+    let result = obj::parse_with(obj_file, options).unwrap();
+
+    assert_eq!(result.objects[0].element_set.len(), 1);
+}
+
+/// A 100 MB face statement on a single line should parse in linear time and
+/// memory. The indices are zero padded so that the resulting object set stays
+/// small. This test is slow in debug builds, so run it explicitly with
+/// `cargo test --release -- --ignored`.
+#[test]
+#[ignore]
+fn test_parse_100_megabyte_face() {
+    let vertex_count = 2 * 1024 * 1024;
+    let indices = ["1", "2", "3"].iter().cycle().take(vertex_count);
+    let face = indices.fold(String::from("f"), |mut face, index| {
+        face.push_str(&format!(" {:0>49}", index));
+        face
+    });
+    let obj_file = format!("v 0 0 0\nv 1 0 0\nv 0 1 0\n{}", face);
+    assert!(obj_file.len() >= 100 * 1024 * 1024);
+    let result = obj::parse(obj_file).unwrap();
+
+    assert_eq!(result.objects[0].element_set.len(), vertex_count - 2);
+}
@@ -1,15 +1,20 @@
+#![allow(clippy::redundant_field_names)]
 use std::fs::File;
 use std::io::Read;
 use wavefront_obj::obj::{
     Element,
+    ElementIndex,
     Geometry,
     Group,
+    GroupIndex,
     NormalVertex,
     Object,
     ObjectSet,
     Parser,
     ShapeEntry,
+    ShapeEntryIndex,
     SmoothingGroup,
+    SmoothingGroupIndex,
     VTNIndex,
     Vertex,
 };
@@ -2128,1030 +2133,1030 @@ fn test_case(file_path: &str) -> Test {
     ];
     let smoothing_group_set = vec![SmoothingGroup(0)];
     let shape_set = vec![
-        ShapeEntry { element: 0,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 2,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 3,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 4,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 5,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 6,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 7,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 8,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 9,    groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 10,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 11,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 12,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 13,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 14,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 15,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 16,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 17,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 18,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 19,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 20,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 21,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 22,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 23,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 24,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 25,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 26,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 27,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 28,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 29,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 30,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 31,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 32,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 33,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 34,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 35,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 36,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 37,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 38,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 39,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 40,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 41,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 42,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 43,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 44,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 45,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 46,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 47,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 48,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 49,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 50,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 51,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 52,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 53,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 54,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 55,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 56,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 57,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 58,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 59,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 60,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 61,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 62,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 63,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 64,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 65,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 66,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 67,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 68,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 69,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 70,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 71,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 72,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 73,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 74,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 75,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 76,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 77,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 78,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 79,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 80,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 81,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 82,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 83,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 84,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 85,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 86,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 87,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 88,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 89,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 90,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 91,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 92,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 93,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 94,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 95,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 96,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 97,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 98,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 99,   groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 100,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 101,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 102,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 103,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 104,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 105,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 106,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 107,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 108,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 109,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 110,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 111,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 112,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 113,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 114,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 115,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 116,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 117,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 118,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 119,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 120,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 121,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 122,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 123,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 124,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 125,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 126,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 127,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 128,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 129,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 130,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 131,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 132,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 133,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 134,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 135,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 136,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 137,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 138,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 139,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 140,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 141,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 142,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 143,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 144,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 145,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 146,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 147,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 148,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 149,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 150,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 151,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 152,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 153,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 154,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 155,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 156,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 157,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 158,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 159,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 160,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 161,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 162,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 163,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 164,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 165,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 166,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 167,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 168,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 169,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 170,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 171,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 172,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 173,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 174,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 175,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 176,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 177,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 178,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 179,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 180,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 181,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 182,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 183,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 184,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 185,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 186,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 187,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 188,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 189,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 190,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 191,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 192,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 193,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 194,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 195,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 196,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 197,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 198,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 199,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 200,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 201,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 202,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 203,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 204,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 205,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 206,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 207,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 208,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 209,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 210,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 211,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 212,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 213,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 214,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 215,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 216,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 217,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 218,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 219,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 220,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 221,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 222,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 223,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 224,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 225,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 226,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 227,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 228,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 229,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 230,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 231,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 232,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 233,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 234,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 235,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 236,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 237,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 238,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 239,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 240,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 241,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 242,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 243,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 244,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 245,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 246,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 247,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 248,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 249,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 250,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 251,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 252,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 253,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 254,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 255,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 256,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 257,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 258,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 259,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 260,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 261,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 262,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 263,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 264,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 265,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 266,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 267,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 268,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 269,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 270,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 271,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 272,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 273,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 274,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 275,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 276,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 277,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 278,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 279,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 280,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 281,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 282,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 283,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 284,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 285,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 286,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 287,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 288,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 289,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 290,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 291,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 292,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 293,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 294,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 295,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 296,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 297,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 298,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 299,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 300,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 301,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 302,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 303,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 304,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 305,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 306,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 307,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 308,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 309,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 310,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 311,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 312,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 313,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 314,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 315,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 316,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 317,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 318,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 319,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 320,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 321,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 322,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 323,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 324,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 325,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 326,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 327,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 328,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 329,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 330,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 331,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 332,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 333,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 334,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 335,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 336,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 337,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 338,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 339,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 340,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 341,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 342,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 343,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 344,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 345,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 346,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 347,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 348,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 349,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 350,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 351,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 352,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 353,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 354,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 355,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 356,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 357,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 358,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 359,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 360,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 361,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 362,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 363,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 364,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 365,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 366,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 367,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 368,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 369,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 370,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 371,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 372,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 373,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 374,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 375,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 376,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 377,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 378,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 379,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 380,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 381,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 382,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 383,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 384,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 385,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 386,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 387,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 388,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 389,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 390,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 391,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 392,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 393,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 394,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 395,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 396,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 397,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 398,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 399,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 400,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 401,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 402,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 403,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 404,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 405,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 406,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 407,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 408,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 409,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 410,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 411,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 412,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 413,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 414,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 415,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 416,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 417,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 418,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 419,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 420,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 421,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 422,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 423,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 424,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 425,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 426,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 427,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 428,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 429,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 430,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 431,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 432,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 433,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 434,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 435,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 436,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 437,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 438,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 439,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 440,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 441,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 442,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 443,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 444,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 445,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 446,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 447,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 448,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 449,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 450,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 451,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 452,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 453,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 454,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 455,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 456,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 457,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 458,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 459,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 460,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 461,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 462,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 463,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 464,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 465,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 466,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 467,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 468,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 469,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 470,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 471,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 472,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 473,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 474,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 475,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 476,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 477,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 478,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 479,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 480,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 481,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 482,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 483,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 484,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 485,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 486,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 487,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 488,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 489,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 490,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 491,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 492,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 493,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 494,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 495,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 496,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 497,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 498,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 499,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 500,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 501,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 502,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 503,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 504,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 505,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 506,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 507,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 508,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 509,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 510,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 511,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 512,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 513,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 514,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 515,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 516,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 517,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 518,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 519,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 520,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 521,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 522,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 523,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 524,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 525,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 526,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 527,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 528,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 529,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 530,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 531,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 532,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 533,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 534,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 535,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 536,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 537,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 538,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 539,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 540,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 541,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 542,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 543,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 544,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 545,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 546,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 547,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 548,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 549,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 550,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 551,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 552,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 553,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 554,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 555,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 556,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 557,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 558,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 559,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 560,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 561,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 562,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 563,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 564,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 565,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 566,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 567,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 568,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 569,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 570,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 571,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 572,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 573,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 574,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 575,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 576,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 577,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 578,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 579,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 580,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 581,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 582,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 583,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 584,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 585,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 586,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 587,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 588,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 589,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 590,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 591,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 592,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 593,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 594,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 595,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 596,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 597,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 598,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 599,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 600,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 601,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 602,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 603,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 604,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 605,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 606,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 607,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 608,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 609,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 610,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 611,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 612,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 613,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 614,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 615,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 616,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 617,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 618,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 619,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 620,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 621,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 622,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 623,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 624,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 625,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 626,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 627,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 628,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 629,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 630,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 631,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 632,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 633,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 634,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 635,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 636,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 637,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 638,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 639,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 640,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 641,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 642,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 643,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 644,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 645,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 646,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 647,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 648,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 649,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 650,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 651,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 652,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 653,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 654,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 655,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 656,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 657,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 658,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 659,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 660,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 661,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 662,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 663,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 664,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 665,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 666,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 667,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 668,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 669,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 670,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 671,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 672,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 673,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 674,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 675,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 676,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 677,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 678,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 679,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 680,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 681,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 682,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 683,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 684,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 685,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 686,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 687,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 688,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 689,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 690,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 691,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 692,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 693,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 694,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 695,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 696,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 697,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 698,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 699,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 700,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 701,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 702,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 703,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 704,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 705,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 706,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 707,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 708,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 709,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 710,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 711,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 712,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 713,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 714,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 715,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 716,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 717,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 718,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 719,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 720,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 721,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 722,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 723,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 724,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 725,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 726,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 727,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 728,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 729,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 730,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 731,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 732,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 733,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 734,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 735,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 736,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 737,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 738,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 739,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 740,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 741,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 742,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 743,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 744,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 745,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 746,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 747,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 748,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 749,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 750,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 751,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 752,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 753,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 754,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 755,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 756,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 757,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 758,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 759,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 760,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 761,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 762,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 763,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 764,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 765,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 766,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 767,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 768,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 769,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 770,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 771,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 772,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 773,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 774,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 775,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 776,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 777,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 778,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 779,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 780,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 781,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 782,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 783,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 784,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 785,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 786,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 787,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 788,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 789,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 790,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 791,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 792,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 793,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 794,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 795,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 796,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 797,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 798,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 799,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 800,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 801,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 802,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 803,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 804,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 805,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 806,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 807,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 808,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 809,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 810,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 811,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 812,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 813,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 814,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 815,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 816,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 817,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 818,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 819,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 820,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 821,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 822,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 823,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 824,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 825,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 826,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 827,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 828,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 829,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 830,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 831,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 832,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 833,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 834,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 835,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 836,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 837,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 838,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 839,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 840,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 841,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 842,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 843,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 844,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 845,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 846,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 847,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 848,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 849,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 850,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 851,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 852,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 853,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 854,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 855,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 856,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 857,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 858,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 859,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 860,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 861,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 862,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 863,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 864,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 865,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 866,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 867,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 868,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 869,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 870,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 871,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 872,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 873,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 874,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 875,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 876,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 877,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 878,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 879,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 880,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 881,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 882,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 883,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 884,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 885,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 886,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 887,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 888,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 889,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 890,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 891,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 892,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 893,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 894,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 895,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 896,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 897,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 898,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 899,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 900,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 901,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 902,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 903,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 904,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 905,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 906,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 907,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 908,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 909,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 910,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 911,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 912,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 913,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 914,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 915,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 916,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 917,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 918,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 919,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 920,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 921,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 922,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 923,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 924,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 925,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 926,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 927,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 928,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 929,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 930,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 931,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 932,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 933,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 934,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 935,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 936,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 937,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 938,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 939,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 940,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 941,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 942,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 943,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 944,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 945,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 946,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 947,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 948,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 949,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 950,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 951,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 952,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 953,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 954,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 955,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 956,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 957,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 958,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 959,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 960,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 961,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 962,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 963,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 964,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 965,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 966,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 967,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 968,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 969,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 970,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 971,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 972,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 973,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 974,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 975,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 976,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 977,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 978,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 979,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 980,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 981,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 982,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 983,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 984,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 985,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 986,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 987,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 988,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 989,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 990,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 991,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 992,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 993,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 994,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 995,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 996,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 997,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 998,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 999,  groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1000, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1001, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1002, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1003, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1004, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1005, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1006, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1007, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1008, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1009, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1010, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1011, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1012, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1013, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1014, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1015, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1016, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1017, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1018, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1019, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1020, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1021, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1022, groups: vec![0, 1, 2], smoothing_group: 0 },
-        ShapeEntry { element: 1023, groups: vec![0, 1, 2], smoothing_group: 0 },
+        ShapeEntry { element: ElementIndex(0),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(2),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(3),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(4),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(5),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(6),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(7),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(8),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(9),    groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(10),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(11),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(12),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(13),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(14),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(15),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(16),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(17),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(18),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(19),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(20),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(21),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(22),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(23),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(24),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(25),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(26),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(27),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(28),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(29),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(30),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(31),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(32),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(33),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(34),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(35),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(36),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(37),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(38),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(39),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(40),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(41),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(42),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(43),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(44),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(45),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(46),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(47),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(48),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(49),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(50),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(51),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(52),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(53),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(54),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(55),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(56),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(57),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(58),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(59),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(60),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(61),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(62),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(63),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(64),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(65),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(66),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(67),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(68),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(69),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(70),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(71),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(72),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(73),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(74),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(75),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(76),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(77),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(78),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(79),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(80),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(81),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(82),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(83),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(84),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(85),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(86),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(87),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(88),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(89),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(90),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(91),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(92),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(93),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(94),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(95),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(96),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(97),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(98),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(99),   groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(100),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(101),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(102),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(103),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(104),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(105),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(106),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(107),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(108),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(109),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(110),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(111),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(112),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(113),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(114),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(115),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(116),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(117),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(118),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(119),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(120),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(121),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(122),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(123),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(124),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(125),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(126),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(127),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(128),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(129),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(130),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(131),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(132),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(133),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(134),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(135),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(136),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(137),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(138),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(139),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(140),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(141),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(142),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(143),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(144),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(145),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(146),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(147),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(148),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(149),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(150),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(151),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(152),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(153),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(154),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(155),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(156),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(157),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(158),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(159),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(160),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(161),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(162),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(163),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(164),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(165),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(166),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(167),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(168),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(169),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(170),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(171),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(172),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(173),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(174),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(175),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(176),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(177),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(178),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(179),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(180),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(181),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(182),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(183),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(184),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(185),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(186),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(187),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(188),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(189),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(190),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(191),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(192),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(193),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(194),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(195),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(196),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(197),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(198),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(199),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(200),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(201),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(202),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(203),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(204),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(205),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(206),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(207),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(208),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(209),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(210),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(211),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(212),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(213),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(214),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(215),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(216),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(217),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(218),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(219),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(220),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(221),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(222),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(223),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(224),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(225),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(226),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(227),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(228),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(229),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(230),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(231),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(232),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(233),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(234),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(235),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(236),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(237),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(238),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(239),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(240),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(241),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(242),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(243),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(244),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(245),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(246),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(247),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(248),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(249),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(250),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(251),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(252),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(253),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(254),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(255),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(256),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(257),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(258),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(259),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(260),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(261),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(262),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(263),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(264),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(265),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(266),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(267),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(268),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(269),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(270),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(271),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(272),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(273),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(274),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(275),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(276),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(277),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(278),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(279),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(280),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(281),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(282),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(283),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(284),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(285),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(286),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(287),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(288),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(289),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(290),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(291),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(292),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(293),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(294),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(295),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(296),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(297),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(298),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(299),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(300),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(301),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(302),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(303),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(304),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(305),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(306),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(307),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(308),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(309),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(310),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(311),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(312),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(313),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(314),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(315),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(316),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(317),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(318),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(319),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(320),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(321),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(322),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(323),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(324),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(325),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(326),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(327),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(328),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(329),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(330),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(331),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(332),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(333),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(334),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(335),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(336),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(337),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(338),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(339),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(340),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(341),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(342),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(343),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(344),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(345),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(346),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(347),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(348),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(349),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(350),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(351),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(352),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(353),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(354),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(355),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(356),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(357),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(358),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(359),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(360),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(361),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(362),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(363),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(364),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(365),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(366),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(367),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(368),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(369),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(370),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(371),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(372),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(373),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(374),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(375),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(376),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(377),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(378),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(379),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(380),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(381),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(382),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(383),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(384),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(385),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(386),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(387),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(388),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(389),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(390),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(391),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(392),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(393),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(394),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(395),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(396),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(397),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(398),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(399),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(400),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(401),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(402),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(403),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(404),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(405),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(406),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(407),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(408),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(409),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(410),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(411),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(412),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(413),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(414),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(415),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(416),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(417),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(418),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(419),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(420),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(421),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(422),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(423),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(424),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(425),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(426),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(427),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(428),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(429),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(430),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(431),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(432),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(433),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(434),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(435),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(436),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(437),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(438),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(439),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(440),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(441),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(442),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(443),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(444),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(445),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(446),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(447),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(448),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(449),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(450),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(451),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(452),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(453),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(454),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(455),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(456),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(457),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(458),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(459),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(460),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(461),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(462),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(463),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(464),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(465),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(466),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(467),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(468),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(469),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(470),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(471),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(472),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(473),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(474),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(475),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(476),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(477),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(478),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(479),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(480),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(481),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(482),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(483),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(484),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(485),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(486),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(487),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(488),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(489),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(490),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(491),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(492),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(493),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(494),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(495),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(496),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(497),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(498),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(499),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(500),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(501),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(502),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(503),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(504),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(505),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(506),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(507),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(508),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(509),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(510),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(511),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(512),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(513),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(514),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(515),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(516),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(517),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(518),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(519),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(520),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(521),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(522),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(523),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(524),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(525),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(526),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(527),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(528),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(529),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(530),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(531),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(532),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(533),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(534),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(535),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(536),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(537),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(538),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(539),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(540),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(541),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(542),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(543),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(544),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(545),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(546),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(547),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(548),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(549),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(550),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(551),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(552),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(553),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(554),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(555),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(556),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(557),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(558),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(559),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(560),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(561),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(562),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(563),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(564),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(565),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(566),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(567),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(568),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(569),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(570),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(571),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(572),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(573),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(574),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(575),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(576),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(577),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(578),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(579),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(580),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(581),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(582),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(583),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(584),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(585),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(586),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(587),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(588),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(589),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(590),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(591),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(592),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(593),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(594),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(595),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(596),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(597),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(598),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(599),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(600),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(601),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(602),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(603),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(604),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(605),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(606),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(607),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(608),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(609),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(610),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(611),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(612),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(613),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(614),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(615),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(616),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(617),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(618),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(619),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(620),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(621),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(622),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(623),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(624),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(625),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(626),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(627),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(628),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(629),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(630),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(631),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(632),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(633),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(634),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(635),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(636),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(637),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(638),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(639),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(640),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(641),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(642),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(643),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(644),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(645),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(646),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(647),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(648),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(649),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(650),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(651),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(652),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(653),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(654),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(655),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(656),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(657),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(658),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(659),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(660),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(661),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(662),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(663),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(664),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(665),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(666),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(667),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(668),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(669),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(670),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(671),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(672),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(673),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(674),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(675),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(676),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(677),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(678),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(679),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(680),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(681),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(682),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(683),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(684),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(685),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(686),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(687),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(688),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(689),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(690),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(691),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(692),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(693),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(694),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(695),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(696),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(697),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(698),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(699),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(700),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(701),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(702),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(703),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(704),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(705),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(706),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(707),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(708),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(709),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(710),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(711),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(712),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(713),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(714),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(715),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(716),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(717),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(718),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(719),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(720),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(721),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(722),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(723),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(724),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(725),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(726),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(727),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(728),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(729),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(730),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(731),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(732),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(733),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(734),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(735),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(736),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(737),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(738),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(739),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(740),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(741),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(742),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(743),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(744),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(745),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(746),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(747),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(748),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(749),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(750),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(751),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(752),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(753),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(754),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(755),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(756),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(757),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(758),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(759),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(760),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(761),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(762),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(763),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(764),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(765),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(766),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(767),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(768),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(769),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(770),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(771),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(772),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(773),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(774),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(775),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(776),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(777),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(778),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(779),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(780),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(781),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(782),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(783),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(784),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(785),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(786),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(787),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(788),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(789),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(790),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(791),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(792),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(793),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(794),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(795),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(796),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(797),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(798),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(799),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(800),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(801),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(802),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(803),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(804),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(805),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(806),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(807),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(808),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(809),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(810),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(811),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(812),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(813),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(814),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(815),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(816),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(817),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(818),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(819),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(820),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(821),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(822),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(823),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(824),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(825),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(826),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(827),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(828),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(829),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(830),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(831),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(832),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(833),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(834),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(835),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(836),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(837),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(838),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(839),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(840),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(841),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(842),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(843),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(844),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(845),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(846),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(847),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(848),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(849),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(850),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(851),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(852),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(853),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(854),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(855),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(856),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(857),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(858),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(859),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(860),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(861),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(862),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(863),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(864),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(865),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(866),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(867),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(868),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(869),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(870),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(871),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(872),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(873),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(874),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(875),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(876),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(877),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(878),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(879),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(880),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(881),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(882),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(883),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(884),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(885),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(886),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(887),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(888),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(889),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(890),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(891),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(892),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(893),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(894),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(895),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(896),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(897),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(898),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(899),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(900),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(901),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(902),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(903),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(904),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(905),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(906),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(907),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(908),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(909),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(910),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(911),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(912),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(913),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(914),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(915),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(916),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(917),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(918),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(919),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(920),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(921),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(922),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(923),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(924),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(925),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(926),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(927),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(928),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(929),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(930),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(931),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(932),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(933),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(934),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(935),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(936),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(937),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(938),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(939),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(940),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(941),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(942),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(943),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(944),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(945),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(946),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(947),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(948),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(949),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(950),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(951),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(952),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(953),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(954),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(955),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(956),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(957),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(958),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(959),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(960),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(961),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(962),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(963),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(964),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(965),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(966),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(967),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(968),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(969),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(970),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(971),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(972),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(973),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(974),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(975),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(976),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(977),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(978),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(979),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(980),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(981),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(982),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(983),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(984),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(985),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(986),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(987),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(988),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(989),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(990),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(991),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(992),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(993),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(994),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(995),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(996),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(997),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(998),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(999),  groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1000), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1001), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1002), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1003), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1004), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1005), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1006), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1007), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1008), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1009), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1010), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1011), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1012), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1013), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1014), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1015), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1016), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1017), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1018), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1019), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1020), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1021), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1022), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1023), groups: vec![GroupIndex(0), GroupIndex(1), GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
     ];
     let material_name = None;
     let shapes = vec![
@@ -3220,20 +3225,31 @@ fn test_case(file_path: &str) -> Test {
         992,  993,  994,  995,  996,  997,  998,  999,  1000, 1001, 1002, 1003, 1004, 1005, 1006, 1007,
         1008, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023,
     ];
-    let geometry_set = vec![Geometry { material_name: material_name, shapes: shapes }];
+    let geometry_set = vec![Geometry {
+        material_name: material_name,
+        shapes: shapes.into_iter().map(ShapeEntryIndex).collect(),
+    }];
     let object = Object {
         name: name,
-        vertex_set: vertex_set,
-        texture_vertex_set: texture_vertex_set,
-        normal_vertex_set: normal_vertex_set,
-        group_set: group_set,
-        smoothing_group_set: smoothing_group_set,
-        element_set: element_set,
-        shape_set: shape_set,
-        geometry_set: geometry_set,
+        source: None,
+        vertex_set: vertex_set.into(),
+        texture_vertex_set: texture_vertex_set.into(),
+        normal_vertex_set: normal_vertex_set.into(),
+        group_set: group_set.into(),
+        smoothing_group_set: smoothing_group_set.into(),
+        element_set: element_set.into(),
+        shape_set: shape_set.into(),
+        geometry_set: geometry_set.into(),
+        element_line_set: vec![],
+        element_text_set: vec![],
+        original_element_set: vec![],
+        numeric_text: Default::default(),
     };
     let expected = ObjectSet {
         material_libraries: vec![],
+        external_references: vec![],
+        unknown_statements: vec![],
+        warnings: vec![],
         objects: vec![object],
     };
 
@@ -3447,7 +3463,7 @@ fn test_parse_object_set_every_element_set_should_be_monotone_increasing() {
     let result_set = parser.parse_objset().unwrap();
 
     for result in result_set.objects.iter() {
-        for (shape, next_shape) in result.shape_set.iter().zip(result.shape_set[1..].iter()) {
+        for (shape, next_shape) in result.shape_set.iter().zip(result.shape_set.iter().skip(1)) {
             assert!(shape.element <= next_shape.element);
         }
     }
@@ -3483,7 +3499,7 @@ fn test_parse_object_every_element_group_exists() {
             assert!(shape
                 .groups
                 .iter()
-                .all(|&group_index| { group_index <= result.group_set.len() }));
+                .all(|&group_index| { group_index.0 <= result.group_set.len() }));
         }
     }
 }
@@ -3499,7 +3515,7 @@ fn test_parse_object_every_element_smoothing_group_exists() {
 
     for result in result_set.objects.iter() {
         for shape in result.shape_set.iter() {
-            assert!(shape.smoothing_group < result.smoothing_group_set.len());
+            assert!(shape.smoothing_group.0 < result.smoothing_group_set.len());
         }
     }
 }
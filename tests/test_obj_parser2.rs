@@ -1,14 +1,20 @@
+#![allow(clippy::redundant_field_names)]
 use std::slice;
 use wavefront_obj::obj::{
     Element,
+    ElementIndex,
     Geometry,
     Group,
+    GroupIndex,
+    MtlLibRef,
     NormalVertex,
     Object,
     ObjectSet,
     Parser,
     ShapeEntry,
+    ShapeEntryIndex,
     SmoothingGroup,
+    SmoothingGroupIndex,
     TextureVertex,
     VTNIndex,
     Vertex,
@@ -25,7 +31,7 @@ struct TestSet {
 }
 
 impl TestSet {
-    fn iter(&self) -> TestSetIter {
+    fn iter(&self) -> TestSetIter<'_> {
         TestSetIter {
             inner: self.data.iter(),
         }
@@ -75,39 +81,47 @@ fn test_cases() -> TestSet {
                 "),
                 expected: ObjectSet { 
                     material_libraries: vec![],
+                    external_references: vec![],
+                    unknown_statements: vec![],
+                    warnings: vec![],
                     objects: vec![
                         Object {
                             name: String::from("Object0"),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x: -36.84435, y: -31.289864, z: -23.619797, w: -8.21862 },
-                            ],
+                            ].into(),
                             texture_vertex_set: vec![
                                 TextureVertex { u: -44.275238, v: 28.583176, w: -23.780418 },
-                            ],
+                            ].into(),
                             normal_vertex_set: vec![
                                 NormalVertex { x: 93.94331, y: -61.460472, z: -32.00753 },
-                            ],
+                            ].into(),
                             group_set: vec![
                                 Group(String::from("Group0")),
                                 Group(String::from("Group1")),
                                 Group(String::from("Group2")),
                                 Group(String::from("Group3")),
                                 Group(String::from("Group4")),
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(0),
                                 SmoothingGroup(1),
                                 SmoothingGroup(2),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::VTN(0, 0, 0), VTNIndex::VTN(0, 0, 0), VTNIndex::VTN(0, 0, 0)),
-                            ],
+                            ].into(),
                             shape_set: vec![
-                                ShapeEntry { element: 0, groups: vec![3], smoothing_group: 1 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0), groups: vec![GroupIndex(3)].into(), smoothing_group: SmoothingGroupIndex(1) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: None, shapes: vec![0] },
-                            ],
+                                Geometry { material_name: None, shapes: vec![ShapeEntryIndex(0)] },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         },
                     ],
                 },
@@ -131,33 +145,41 @@ fn test_cases() -> TestSet {
                 "),
                 expected: ObjectSet {
                     material_libraries: vec![],
+                    external_references: vec![],
+                    unknown_statements: vec![],
+                    warnings: vec![],
                     objects: vec![
                         Object {
                             name: String::from("Object0"),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x: -36.84435, y: -31.289864, z: -23.619797, w: -8.21862 },
-                            ],
+                            ].into(),
                             texture_vertex_set: vec![
                                 TextureVertex { u: -44.275238, v: 28.583176, w: -23.780418 },
-                            ],
+                            ].into(),
                             normal_vertex_set: vec![
                                 NormalVertex { x: 93.94331, y: -61.460472, z: -32.00753 },
-                            ],
+                            ].into(),
                             group_set: vec![
                                 Group(String::from("default")),
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(0),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::VTN(0, 0, 0), VTNIndex::VTN(0, 0, 0), VTNIndex::VTN(0, 0, 0)),
-                            ],
+                            ].into(),
                             shape_set: vec![
-                                ShapeEntry { element: 0, groups: vec![0], smoothing_group: 0 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: None, shapes: vec![0] },
-                            ]
+                                Geometry { material_name: None, shapes: vec![ShapeEntryIndex(0)] },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         }
                     ]
                 }
@@ -187,9 +209,13 @@ fn test_cases() -> TestSet {
                 "),
                 expected: ObjectSet { 
                     material_libraries: vec![],
+                    external_references: vec![],
+                    unknown_statements: vec![],
+                    warnings: vec![],
                     objects: vec![
                         Object {
                             name: String::from(""),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x:  0.0,  y:  0.0,  z:  78.0, w: 1.0 },
                                 Vertex { x:  45.0, y:  45.0, z:  0.0,  w: 1.0 },
@@ -197,15 +223,15 @@ fn test_cases() -> TestSet {
                                 Vertex { x: -45.0, y: -45.0, z:  0.0,  w: 1.0 },
                                 Vertex { x: -45.0, y:  45.0, z:  0.0,  w: 1.0 },
                                 Vertex { x:  0.0,  y:  0.0,  z: -78.0, w: 1.0 },
-                            ],
-                            texture_vertex_set: vec![],
-                            normal_vertex_set: vec![],
+                            ].into(),
+                            texture_vertex_set: vec![].into(),
+                            normal_vertex_set: vec![].into(),
                             group_set: vec![
                                 Group(String::from("Object001")),
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(0),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
                                 Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
@@ -216,21 +242,25 @@ fn test_cases() -> TestSet {
                                 Element::Face(VTNIndex::V(5), VTNIndex::V(2), VTNIndex::V(1)),
                                 Element::Face(VTNIndex::V(5), VTNIndex::V(1), VTNIndex::V(0)),
                                 Element::Face(VTNIndex::V(5), VTNIndex::V(0), VTNIndex::V(4)),
-                            ], 
+                            ].into(), 
                             shape_set: vec![
-                                ShapeEntry { element: 0, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 1, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 2, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 3, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 4, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 5, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 6, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 7, groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 8, groups: vec![0], smoothing_group: 0 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(1), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(2), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(3), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(4), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(5), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(6), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(7), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(8), groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: None, shapes: vec![0, 1, 2, 3, 4, 5, 6, 7, 8] },
-                            ]
+                                Geometry { material_name: None, shapes: (0..9).map(ShapeEntryIndex).collect() },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         }
                     ]
                 }
@@ -271,11 +301,15 @@ fn test_cases() -> TestSet {
                 "),
                 expected: ObjectSet {
                     material_libraries: vec![
-                        String::from("master.mtl"),
+                        MtlLibRef::new("master.mtl", 2),
                     ],
+                    external_references: vec![],
+                    unknown_statements: vec![],
+                    warnings: vec![],
                     objects: vec![
                         Object {
                             name: String::from("Object001"),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x: 0.000000, y: 2.000000, z: 2.000000, w: 1.0 },
                                 Vertex { x: 0.000000, y: 0.000000, z: 2.000000, w: 1.0 },
@@ -285,9 +319,9 @@ fn test_cases() -> TestSet {
                                 Vertex { x: 0.000000, y: 0.000000, z: 0.000000, w: 1.0 },
                                 Vertex { x: 2.000000, y: 0.000000, z: 0.000000, w: 1.0 },
                                 Vertex { x: 2.000000, y: 2.000000, z: 0.000000, w: 1.0 },
-                            ],
-                            texture_vertex_set: vec![],
-                            normal_vertex_set: vec![],
+                            ].into(),
+                            texture_vertex_set: vec![].into(),
+                            normal_vertex_set: vec![].into(),
                             group_set: vec![
                                 Group(String::from("front")), 
                                 Group(String::from("back")), 
@@ -295,10 +329,10 @@ fn test_cases() -> TestSet {
                                 Group(String::from("top")),
                                 Group(String::from("left")),
                                 Group(String::from("bottom")),
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(0),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::V(0), VTNIndex::V(1), VTNIndex::V(2)),
                                 Element::Face(VTNIndex::V(0), VTNIndex::V(2), VTNIndex::V(3)),
@@ -312,29 +346,33 @@ fn test_cases() -> TestSet {
                                 Element::Face(VTNIndex::V(4), VTNIndex::V(1), VTNIndex::V(0)),
                                 Element::Face(VTNIndex::V(1), VTNIndex::V(5), VTNIndex::V(6)),
                                 Element::Face(VTNIndex::V(1), VTNIndex::V(6), VTNIndex::V(2)),
-                            ],
+                            ].into(),
                             shape_set: vec![
-                                ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 2,  groups: vec![1], smoothing_group: 0 },
-                                ShapeEntry { element: 3,  groups: vec![1], smoothing_group: 0 },
-                                ShapeEntry { element: 4,  groups: vec![2], smoothing_group: 0 },
-                                ShapeEntry { element: 5,  groups: vec![2], smoothing_group: 0 },
-                                ShapeEntry { element: 6,  groups: vec![3], smoothing_group: 0 },
-                                ShapeEntry { element: 7,  groups: vec![3], smoothing_group: 0 },
-                                ShapeEntry { element: 8,  groups: vec![4], smoothing_group: 0 },
-                                ShapeEntry { element: 9,  groups: vec![4], smoothing_group: 0 },
-                                ShapeEntry { element: 10, groups: vec![5], smoothing_group: 0 },
-                                ShapeEntry { element: 11, groups: vec![5], smoothing_group: 0 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(1)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(1)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(4),  groups: vec![GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(5),  groups: vec![GroupIndex(2)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(6),  groups: vec![GroupIndex(3)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(7),  groups: vec![GroupIndex(3)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(8),  groups: vec![GroupIndex(4)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(9),  groups: vec![GroupIndex(4)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(10), groups: vec![GroupIndex(5)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(11), groups: vec![GroupIndex(5)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: Some(String::from("red")),    shapes: vec![0,  1]  },
-                                Geometry { material_name: Some(String::from("blue")),   shapes: vec![2,  3]  },
-                                Geometry { material_name: Some(String::from("green")),  shapes: vec![4,  5]  },
-                                Geometry { material_name: Some(String::from("gold")),   shapes: vec![6,  7]  },
-                                Geometry { material_name: Some(String::from("orange")), shapes: vec![8,  9]  },
-                                Geometry { material_name: Some(String::from("purple")), shapes: vec![10, 11] },
-                            ]
+                                Geometry { material_name: Some(String::from("red")),    shapes: vec![ShapeEntryIndex(0),  ShapeEntryIndex(1)]  },
+                                Geometry { material_name: Some(String::from("blue")),   shapes: vec![ShapeEntryIndex(2),  ShapeEntryIndex(3)]  },
+                                Geometry { material_name: Some(String::from("green")),  shapes: vec![ShapeEntryIndex(4),  ShapeEntryIndex(5)]  },
+                                Geometry { material_name: Some(String::from("gold")),   shapes: vec![ShapeEntryIndex(6),  ShapeEntryIndex(7)]  },
+                                Geometry { material_name: Some(String::from("orange")), shapes: vec![ShapeEntryIndex(8),  ShapeEntryIndex(9)]  },
+                                Geometry { material_name: Some(String::from("purple")), shapes: vec![ShapeEntryIndex(10), ShapeEntryIndex(11)] },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         }
                     ]
                 }
@@ -419,11 +457,15 @@ fn test_cases() -> TestSet {
                 "),
                 expected: ObjectSet {
                     material_libraries: vec![
-                        String::from("material_library.mtl"),
+                        MtlLibRef::new("material_library.mtl", 2),
                     ],
+                    external_references: vec![],
+                    unknown_statements: vec![],
+                    warnings: vec![],
                     objects: vec![
                         Object {
                             name: String::from("Object001"),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
                                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
@@ -431,8 +473,8 @@ fn test_cases() -> TestSet {
                                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
                                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
                                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
-                            ],
-                            texture_vertex_set: vec![],
+                            ].into(),
+                            texture_vertex_set: vec![].into(),
                             normal_vertex_set: vec![
                                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
                                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
@@ -440,31 +482,36 @@ fn test_cases() -> TestSet {
                                 NormalVertex { x: 0.276597, y: 0.000000, z: 0.960986 },
                                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
                                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
-                            ],
+                            ].into(),
                             group_set: vec![
                                 Group(String::from("all")),
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(1),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 1), VTNIndex::VN(2, 2)),
                                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(2, 2), VTNIndex::VN(3, 3)),
                                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(2, 2), VTNIndex::VN(4, 4)),
                                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(4, 4), VTNIndex::VN(5, 5)),
-                            ],
+                            ].into(),
                             shape_set: vec![
-                                ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: Some(String::from("material1")), shapes: vec![0, 1, 2, 3] },
-                            ]
+                                Geometry { material_name: Some(String::from("material1")), shapes: vec![ShapeEntryIndex(0), ShapeEntryIndex(1), ShapeEntryIndex(2), ShapeEntryIndex(3)] },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         },
                         Object {
                             name: String::from("Object002"),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
                                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
@@ -472,8 +519,8 @@ fn test_cases() -> TestSet {
                                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
                                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
                                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
-                            ],
-                            texture_vertex_set: vec![],
+                            ].into(),
+                            texture_vertex_set: vec![].into(),
                             normal_vertex_set: vec![
                                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
                                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
@@ -481,31 +528,36 @@ fn test_cases() -> TestSet {
                                 NormalVertex { x: 0.276597, y: 0.000000, z: 0.960986 },
                                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
                                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
-                            ],
+                            ].into(),
                             group_set: vec![
                                 Group(String::from("all")),
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(1),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 1), VTNIndex::VN(2, 2)),
                                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(2, 2), VTNIndex::VN(3, 3)),
                                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(2, 2), VTNIndex::VN(4, 4)),
                                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(4, 4), VTNIndex::VN(5, 5)),
-                            ],
+                            ].into(),
                             shape_set: vec![
-                                ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: Some(String::from("material2")), shapes: vec![0, 1, 2, 3] },
-                            ],
+                                Geometry { material_name: Some(String::from("material2")), shapes: vec![ShapeEntryIndex(0), ShapeEntryIndex(1), ShapeEntryIndex(2), ShapeEntryIndex(3)] },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         },
                         Object {
                             name: String::from("Object003"),
+                            source: None,
                             vertex_set: vec![
                                 Vertex { x: 0.000000, y: 2.000000, z:  0.000000, w: 1.0 },
                                 Vertex { x: 0.000000, y: 0.000000, z:  0.000000, w: 1.0 },
@@ -513,8 +565,8 @@ fn test_cases() -> TestSet {
                                 Vertex { x: 2.000000, y: 2.000000, z:  0.000000, w: 1.0 },
                                 Vertex { x: 4.000000, y: 0.000000, z: -1.255298, w: 1.0 },
                                 Vertex { x: 4.000000, y: 2.000000, z: -1.255298, w: 1.0 },
-                            ],
-                            texture_vertex_set: vec![],
+                            ].into(),
+                            texture_vertex_set: vec![].into(),
                             normal_vertex_set: vec![
                                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
                                 NormalVertex { x: 0.000000, y: 0.000000, z: 1.000000 },
@@ -522,28 +574,32 @@ fn test_cases() -> TestSet {
                                 NormalVertex { x: 0.276597, y: 0.000000, z: 0.960986 },
                                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
                                 NormalVertex { x: 0.531611, y: 0.000000, z: 0.846988 },
-                            ],
+                            ].into(),
                             group_set: vec![
                                 Group(String::from("all")), 
-                            ],
+                            ].into(),
                             smoothing_group_set: vec![
                                 SmoothingGroup(1),
-                            ],
+                            ].into(),
                             element_set: vec![
                                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(1, 1), VTNIndex::VN(2, 2)),
                                 Element::Face(VTNIndex::VN(0, 0), VTNIndex::VN(2, 2), VTNIndex::VN(3, 3)),
                                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(2, 2), VTNIndex::VN(4, 4)),
                                 Element::Face(VTNIndex::VN(3, 3), VTNIndex::VN(4, 4), VTNIndex::VN(5, 5)),
-                            ],
+                            ].into(),
                             shape_set: vec![
-                                ShapeEntry { element: 0,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 1,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 2,  groups: vec![0], smoothing_group: 0 },
-                                ShapeEntry { element: 3,  groups: vec![0], smoothing_group: 0 },
-                            ],
+                                ShapeEntry { element: ElementIndex(0),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(1),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(2),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                                ShapeEntry { element: ElementIndex(3),  groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+                            ].into(),
                             geometry_set: vec![
-                                Geometry { material_name: Some(String::from("material3")), shapes: vec![0, 1, 2, 3] },
-                            ]
+                                Geometry { material_name: Some(String::from("material3")), shapes: vec![ShapeEntryIndex(0), ShapeEntryIndex(1), ShapeEntryIndex(2), ShapeEntryIndex(3)] },
+                            ].into(),
+                            element_line_set: vec![],
+                            element_text_set: vec![],
+                            original_element_set: vec![],
+                            numeric_text: Default::default(),
                         }
                     ]
                 }
@@ -778,7 +834,7 @@ fn test_parse_object_set_every_element_set_should_be_monotone_increasing() {
         let mut parser = Parser::new(&test.data);
         let result_set = parser.parse_objset().unwrap();
         for result in result_set.objects.iter() {
-            for (shape, next_shape) in result.shape_set.iter().zip(result.shape_set[1..].iter()) {
+            for (shape, next_shape) in result.shape_set.iter().zip(result.shape_set.iter().skip(1)) {
                 assert!(shape.element <= next_shape.element);
             }
         }
@@ -818,7 +874,7 @@ fn test_parse_object_every_element_group_exists() {
                 assert!(shape
                     .groups
                     .iter()
-                    .all(|&group_index| { group_index < result.group_set.len() }));
+                    .all(|&group_index| { group_index.0 < result.group_set.len() }));
             }
         }
     }
@@ -836,7 +892,7 @@ fn test_parse_object_every_element_smoothing_group_exists() {
         let result_set = parser.parse_objset().unwrap();
         for result in result_set.objects.iter() {
             for shape in result.shape_set.iter() {
-                assert!(shape.smoothing_group < result.smoothing_group_set.len());
+                assert!(shape.smoothing_group.0 < result.smoothing_group_set.len());
             }
         }
     }
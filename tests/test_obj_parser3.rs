@@ -1,15 +1,20 @@
+#![allow(clippy::redundant_field_names)]
 use std::fs::File;
 use std::io::Read;
 use wavefront_obj::obj::{
     Element,
+    ElementIndex,
     Geometry,
     Group,
+    GroupIndex,
     NormalVertex,
     Object,
     ObjectSet,
     Parser,
     ShapeEntry,
+    ShapeEntryIndex,
     SmoothingGroup,
+    SmoothingGroupIndex,
     TextureVertex,
     VTNIndex,
     Vertex,
@@ -84,37 +89,48 @@ fn test_case(file_path: &str) -> Test {
     ];
     let smoothing_group_set = vec![SmoothingGroup(0)];
     let shape_set = vec![
-        ShapeEntry { element: 0,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 1,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 2,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 3,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 4,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 5,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 6,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 7,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 8,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 9,    groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 10,   groups: vec![0], smoothing_group: 0 },
-        ShapeEntry { element: 11,   groups: vec![0], smoothing_group: 0 },
+        ShapeEntry { element: ElementIndex(0),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(1),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(2),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(3),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(4),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(5),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(6),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(7),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(8),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(9),    groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(10),   groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
+        ShapeEntry { element: ElementIndex(11),   groups: vec![GroupIndex(0)].into(), smoothing_group: SmoothingGroupIndex(0) },
     ];
     let material_name = None;
     let shapes = vec![
         0,    1,    2,    3,    4,    5,    6,    7,    8,    9,    10,   11,
     ];
-    let geometry_set = vec![Geometry { material_name: material_name, shapes: shapes }];
+    let geometry_set = vec![Geometry {
+        material_name: material_name,
+        shapes: shapes.into_iter().map(ShapeEntryIndex).collect(),
+    }];
     let object = Object {
         name: name,
-        vertex_set: vertex_set,
-        texture_vertex_set: texture_vertex_set,
-        normal_vertex_set: normal_vertex_set,
-        group_set: group_set,
-        smoothing_group_set: smoothing_group_set,
-        element_set: element_set,
-        shape_set: shape_set,
-        geometry_set: geometry_set,
+        source: None,
+        vertex_set: vertex_set.into(),
+        texture_vertex_set: texture_vertex_set.into(),
+        normal_vertex_set: normal_vertex_set.into(),
+        group_set: group_set.into(),
+        smoothing_group_set: smoothing_group_set.into(),
+        element_set: element_set.into(),
+        shape_set: shape_set.into(),
+        geometry_set: geometry_set.into(),
+        element_line_set: vec![],
+        element_text_set: vec![],
+        original_element_set: vec![],
+        numeric_text: Default::default(),
     };
     let expected = ObjectSet {
         material_libraries: vec![],
+        external_references: vec![],
+        unknown_statements: vec![],
+        warnings: vec![],
         objects: vec![object]
     };
 
@@ -328,7 +344,7 @@ fn test_parse_object_set_every_element_set_should_be_monotone_increasing() {
     let result_set = parser.parse_objset().unwrap();
 
     for result in result_set.objects.iter() {
-        for (shape, next_shape) in result.shape_set.iter().zip(result.shape_set[1..].iter()) {
+        for (shape, next_shape) in result.shape_set.iter().zip(result.shape_set.iter().skip(1)) {
             assert!(shape.element <= next_shape.element);
         }
     }
@@ -364,7 +380,7 @@ fn test_parse_object_every_element_group_exists() {
             assert!(shape
                 .groups
                 .iter()
-                .all(|&group_index| { group_index <= result.group_set.len() }));
+                .all(|&group_index| { group_index.0 <= result.group_set.len() }));
         }
     }
 }
@@ -380,7 +396,7 @@ fn test_parse_object_every_element_smoothing_group_exists() {
 
     for result in result_set.objects.iter() {
         for shape in result.shape_set.iter() {
-            assert!(shape.smoothing_group < result.smoothing_group_set.len());
+            assert!(shape.smoothing_group.0 < result.smoothing_group_set.len());
         }
     }
 }
@@ -0,0 +1,466 @@
+use std::fs;
+use std::io;
+use wavefront_obj::obj;
+use wavefront_obj::obj::{
+    Compositor,
+    Group,
+    IndexOffsets,
+    ObjectDelimiter,
+    ObjectSet,
+    ObjectSetWriter,
+    ParserOptions,
+    RoundTripReport,
+    TextObjectSetCompositor,
+    VTNIndex,
+    VertexIndex,
+    WriteOptions,
+};
+
+
+const ASSETS: [&str; 5] = [
+    "assets/al.obj",
+    "assets/cube.obj",
+    "assets/cube_vt.obj",
+    "assets/minicooper.obj",
+    "assets/teapot.obj",
+];
+
+
+/// Every sample asset should survive a round trip through the writer.
+#[test]
+fn test_roundtrip_assets() {
+    for asset in ASSETS.iter() {
+        let input = fs::read_to_string(asset).unwrap();
+        let result = obj::roundtrip_check(&input);
+
+        assert!(
+            result.is_ok(),
+            "Round trip of {} failed: {}",
+            asset,
+            result.unwrap_err()
+        );
+    }
+}
+
+/// Written files should preserve groups, smoothing groups, materials, and the
+/// global indexing of vertices across objects.
+#[test]
+fn test_roundtrip_organization() {
+    let input = r"
+        mtllib first.mtl second.mtl
+        call header.obj 1
+        csh -echo header
+        o first
+        v 0 0 0
+        v 1 0 0
+        v 1 1 0
+        vt 0 0
+        vt 1 0
+        vt 1 1
+        g a b
+        s 1
+        usemtl red
+        f 1/1 2/2 3/3
+        s off
+        f 3/3 2/2 1/1
+        usemtl green
+        l 1/1 2/2
+        o second
+        v 0 0 1 0.5
+        v 1 0 1
+        v 1 1 1
+        vn 0 0 1
+        g c
+        f 4//1 5//1 6//1
+        p 6
+    ";
+
+    assert_eq!(obj::roundtrip_check(input), Ok(()));
+}
+
+/// The writer should emit one-based indices that are global across objects.
+#[test]
+fn test_text_compositor_global_indices() {
+    let object_set = obj::parse("o a\nv 0 0 0\np 1\no b\nv 1 1 1\np 2\n").unwrap();
+    let output = TextObjectSetCompositor::new().compose(&object_set);
+
+    assert!(output.contains("p 1\n"));
+    assert!(output.contains("p 2\n"));
+}
+
+/// The writer should omit the w component of a vertex when it equals the default,
+/// unless asked to write it.
+#[test]
+fn test_text_compositor_w_component() {
+    let object_set = obj::parse("v 1 2 3\nv 1 2 3 0.5\np 1\n").unwrap();
+    let output = TextObjectSetCompositor::new().compose(&object_set);
+
+    assert!(output.contains("v 1 2 3\n"));
+    assert!(output.contains("v 1 2 3 0.5\n"));
+
+    let options = WriteOptions {
+        write_default_w: true,
+        ..WriteOptions::default()
+    };
+    let output = TextObjectSetCompositor::with_options(options).compose(&object_set);
+
+    assert!(output.contains("v 1 2 3 1\n"));
+}
+
+/// Coplanar quads that the parser triangulated should be written as quads again
+/// when asked, and everything else should be written as it is.
+#[test]
+fn test_text_compositor_reconstruct_quads() {
+    let input = "\
+        v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nv 2 0 1\nv 2 1 0\n\
+        vt 0 0\n\
+        f 1/1 2/1 3/1 4/1\n\
+        f 2 5 6 3\n\
+        f 1 2 3\n\
+        f 3 4 1\n\
+    ";
+    let object_set = obj::parse(input).unwrap();
+    let options = WriteOptions {
+        reconstruct_quads: true,
+        ..WriteOptions::default()
+    };
+    let output = TextObjectSetCompositor::with_options(options).compose(&object_set);
+    let faces: Vec<&str> = output.lines().filter(|line| line.starts_with("f ")).collect();
+
+    assert_eq!(
+        faces,
+        vec!["f 1/1 2/1 3/1 4/1", "f 2 5 6", "f 2 6 3", "f 1 2 3", "f 3 4 1"]
+    );
+    assert_eq!(obj::parse(&output).unwrap().objects, object_set.objects);
+}
+
+/// A writer that records the size of every write it receives.
+struct ChunkRecorder {
+    bytes: Vec<u8>,
+    chunk_sizes: Vec<usize>,
+}
+
+impl io::Write for ChunkRecorder {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.bytes.extend_from_slice(buf);
+        self.chunk_sizes.push(buf.len());
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The writer should stream its output in chunks of the configured size.
+#[test]
+fn test_text_compositor_streams_in_chunks() {
+    let buffer = fs::read_to_string("assets/teapot.obj").unwrap();
+    let object_set = obj::parse(&buffer).unwrap();
+    let options = WriteOptions {
+        buffer_capacity: 4096,
+        ..WriteOptions::default()
+    };
+    let compositor = TextObjectSetCompositor::with_options(options);
+    let mut writer = ChunkRecorder {
+        bytes: vec![],
+        chunk_sizes: vec![],
+    };
+    compositor.compose_to(&object_set, &mut writer).unwrap();
+
+    assert!(writer.chunk_sizes.len() > 1);
+    assert!(writer.chunk_sizes.iter().all(|&size| size <= 4096));
+    assert_eq!(
+        String::from_utf8(writer.bytes).unwrap(),
+        compositor.compose(&object_set)
+    );
+}
+
+/// Writing an object set to a file and reading it back should give the same objects.
+#[test]
+fn test_write_file() {
+    let object_set = obj::parse("o triangle\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+    let path = std::env::temp_dir().join(format!("wavefront_obj_write_file_{}.obj", std::process::id()));
+    obj::write_file(&path, &object_set, &TextObjectSetCompositor::new()).unwrap();
+    let result = obj::parse_file(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(obj::diff(&object_set, &result, 0.0).is_empty());
+}
+
+/// Appending object sets one at a time should give the same file as writing
+/// all of their objects at once.
+#[test]
+fn test_object_set_writer_offsets_indices() {
+    // The assets have unnamed objects, which would merge when appended.
+    let object_sets: Vec<ObjectSet> = ["assets/cube.obj", "assets/cube_vt.obj", "assets/teapot.obj"]
+        .iter()
+        .map(|asset| {
+            let mut object_set = obj::parse(fs::read_to_string(asset).unwrap()).unwrap();
+            for object in object_set.objects.iter_mut() {
+                object.name = String::from(*asset);
+            }
+            object_set
+        })
+        .collect();
+    let mut writer = ObjectSetWriter::new(vec![]);
+    for object_set in object_sets.iter() {
+        writer.append(object_set).unwrap();
+    }
+    let offsets = writer.offsets();
+    let result = obj::parse(String::from_utf8(writer.into_inner().unwrap()).unwrap()).unwrap();
+
+    let expected = ObjectSet {
+        objects: object_sets
+            .iter()
+            .flat_map(|object_set| object_set.objects.clone())
+            .collect(),
+        ..obj::parse("").unwrap()
+    };
+    assert!(obj::diff(&expected, &result, 0.0).is_empty());
+    assert_eq!(
+        offsets.vertices,
+        expected
+            .objects
+            .iter()
+            .map(|object| object.vertex_set.len())
+            .sum::<usize>()
+    );
+}
+
+/// Appending to an existing file should continue its vertex indices.
+#[test]
+fn test_object_set_writer_append_to_file() {
+    let path = std::env::temp_dir().join(format!("wavefront_obj_append_file_{}.obj", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let first = obj::parse("o first\nv 0 0 0\nv 1 0 0\nvn 0 0 1\nl 1 2\n").unwrap();
+    let second = obj::parse("o second\nv 0 1 0\nv 0 0 1\nvn 1 0 0\nf 1//1 2//1 1//1\n").unwrap();
+    for object_set in [&first, &second].iter() {
+        let mut writer = ObjectSetWriter::append_to_file(&path).unwrap();
+        writer.append(object_set).unwrap();
+        writer.flush().unwrap();
+    }
+    let text = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(text.contains("f 3//2 4//2 3//2\n"));
+    assert_eq!(
+        IndexOffsets::count(text.as_bytes()).unwrap(),
+        IndexOffsets {
+            vertices: 4,
+            texture_vertices: 0,
+            normal_vertices: 2,
+        }
+    );
+    let result = obj::parse(&text).unwrap();
+    assert_eq!(result.objects[0], first.objects[0]);
+    assert_eq!(result.objects[1], second.objects[0]);
+}
+
+/// Appending to a file without a final line terminator should not run the
+/// first appended statement onto the last line of the file.
+#[test]
+fn test_object_set_writer_append_to_file_without_final_newline() {
+    let path = std::env::temp_dir().join(format!(
+        "wavefront_obj_append_unterminated_{}.obj",
+        std::process::id()
+    ));
+    fs::write(&path, "o first\rv 0 0 0\rv 1 0 0\rl 1 2").unwrap();
+    let mut writer = ObjectSetWriter::append_to_file(&path).unwrap();
+    assert_eq!(writer.offsets().vertices, 2);
+    writer
+        .append(&obj::parse("o second\nv 0 1 0\nv 0 0 1\nl 1 2\n").unwrap())
+        .unwrap();
+    writer.flush().unwrap();
+    let text = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(text.starts_with("o first\rv 0 0 0\rv 1 0 0\rl 1 2\n"));
+    assert!(text.contains("l 3 4\n"));
+    let result = obj::parse(&text).unwrap();
+    assert_eq!(result.objects.len(), 2);
+    assert_eq!(result.objects[0].element_set.len(), 1);
+}
+
+/// Counting vertex statements should follow the line terminators of the
+/// parser.
+#[test]
+fn test_index_offsets_count_line_endings() {
+    let text = "v 0 0 0\rv 1 0 0\r\nvt 0 0\n\t vn 0 0 1\r# v 1 1 1\rvt 1 1";
+    let offsets = IndexOffsets::count(io::BufReader::with_capacity(3, text.as_bytes())).unwrap();
+
+    assert_eq!(
+        offsets,
+        IndexOffsets {
+            vertices: 2,
+            texture_vertices: 2,
+            normal_vertices: 1,
+        }
+    );
+}
+
+/// Recorded numeric text should be written back byte for byte, except for the
+/// vertices that changed after parsing.
+#[test]
+fn test_preserve_numeric_text() {
+    let obj_file = "\
+        v 1.000000 -0.0 2.50E+00\n\
+        v  0.1   0.2\t0.3  # comment\n\
+        v 1 2 3 1.0\n\
+        vt 0.500\n\
+        vt 0.25 0.75 0\n\
+        vn 0.000 0.000 1.000\n\
+        f 1/1/1 2/2/1 3/1/1\n";
+    let options = ParserOptions {
+        record_numeric_text: true,
+        ..ParserOptions::default()
+    };
+    let mut object_set = obj::parse_with(obj_file, options).unwrap();
+    assert_eq!(
+        object_set.objects[0].numeric_text.vertices,
+        vec!["1.000000 -0.0 2.50E+00", "0.1 0.2 0.3", "1 2 3 1.0"]
+    );
+    object_set.objects[0].vertex_set[VertexIndex(2)].z = 4.0;
+
+    let write_options = WriteOptions {
+        preserve_numeric_text: true,
+        ..WriteOptions::default()
+    };
+    let output = TextObjectSetCompositor::with_options(write_options).compose(&object_set);
+    let expected =
+        "v 1.000000 -0.0 2.50E+00\nv 0.1 0.2 0.3\nv 1 2 4\nvt 0.500\nvt 0.25 0.75 0\nvn 0.000 0.000 1.000\n";
+    assert!(output.starts_with(expected), "{}", output);
+    assert!(!TextObjectSetCompositor::new()
+        .compose(&object_set)
+        .contains("1.000000"));
+}
+
+/// Omitting texture vertices and normals should downgrade the VTN indices of
+/// the elements, and flattening groups should leave out the `g` statements.
+#[test]
+fn test_write_options_omit_attributes() {
+    let obj_file = "\
+        v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvn 0 0 1\n\
+        g a b\nf 1/1/1 2/2/1 3/1/1\ng c\nf 3/2 2/1 1/1\nl 1//1 2//1\n";
+    let object_set = obj::parse(obj_file).unwrap();
+    let compose = |options: WriteOptions| {
+        let output = TextObjectSetCompositor::with_options(options).compose(&object_set);
+        obj::parse(&output).unwrap().objects.remove(0)
+    };
+
+    let without_texture_vertices = compose(WriteOptions {
+        omit_texture_vertices: true,
+        ..WriteOptions::default()
+    });
+    assert!(without_texture_vertices.texture_vertex_set.is_empty());
+    assert_eq!(
+        without_texture_vertices.normal_vertex_set,
+        object_set.objects[0].normal_vertex_set
+    );
+    assert!(without_texture_vertices
+        .element_set
+        .iter()
+        .all(|element| match element {
+            obj::Element::Face(vtn, _, _) => matches!(vtn, VTNIndex::VN(..) | VTNIndex::V(..)),
+            obj::Element::Line(vtn, _) => matches!(vtn, VTNIndex::VN(..)),
+            obj::Element::Point(_) => false,
+        }));
+
+    let without_normals = compose(WriteOptions {
+        omit_normals: true,
+        ..WriteOptions::default()
+    });
+    assert!(without_normals.normal_vertex_set.is_empty());
+    assert_eq!(
+        without_normals.texture_vertex_set,
+        object_set.objects[0].texture_vertex_set
+    );
+
+    let positions_only = compose(WriteOptions {
+        omit_texture_vertices: true,
+        omit_normals: true,
+        flatten_groups: true,
+        ..WriteOptions::default()
+    });
+    assert_eq!(positions_only.vertex_set, object_set.objects[0].vertex_set);
+    assert!(positions_only.texture_vertex_set.is_empty());
+    assert!(positions_only.normal_vertex_set.is_empty());
+    assert_eq!(positions_only.group_set.as_slice(), &[Group::default()]);
+    assert_eq!(
+        positions_only.element_set.len(),
+        object_set.objects[0].element_set.len()
+    );
+    assert!(positions_only.element_set.iter().all(|element| match element {
+        obj::Element::Face(vtn1, vtn2, vtn3) =>
+            [vtn1, vtn2, vtn3].iter().all(|vtn| matches!(vtn, VTNIndex::V(_))),
+        obj::Element::Line(vtn1, vtn2) => [vtn1, vtn2].iter().all(|vtn| matches!(vtn, VTNIndex::V(_))),
+        obj::Element::Point(vtn) => matches!(vtn, VTNIndex::V(_)),
+    }));
+}
+
+/// Objects delimited by groups should start with a `g` statement named after
+/// the object, which replaces the groups of its elements.
+#[test]
+fn test_write_options_object_delimiter() {
+    let object_set = obj::parse("o a\nv 0 0 0\ng x\np 1\ng y\np 1\no b\nv 1 1 1\np 2\n").unwrap();
+    let compose = |delimiter: ObjectDelimiter| {
+        let options = WriteOptions {
+            object_delimiter: delimiter,
+            ..WriteOptions::default()
+        };
+        TextObjectSetCompositor::with_options(options).compose(&object_set)
+    };
+
+    let by_object = compose(ObjectDelimiter::Object);
+    assert!(by_object.starts_with("o a\nv 0 0 0\ng x\n"));
+    assert_eq!(obj::parse(&by_object).unwrap(), object_set);
+
+    let by_group = compose(ObjectDelimiter::Group);
+    assert!(by_group.starts_with("g a\nv 0 0 0\n"));
+    assert!(!by_group.contains("o ") && !by_group.contains("g x"));
+    let result = obj::parse(&by_group).unwrap();
+    assert_eq!(result.objects.len(), 1);
+    assert_eq!(
+        result.objects[0].group_set.as_slice(),
+        &[Group(String::from("a")), Group(String::from("b"))]
+    );
+
+    let by_both = compose(ObjectDelimiter::Both);
+    assert!(by_both.starts_with("o a\ng a\nv 0 0 0\n"));
+    let result = obj::parse(&by_both).unwrap();
+    assert_eq!(result.objects.len(), 2);
+    assert_eq!(
+        result.objects[1].group_set.as_slice(),
+        &[Group(String::from("b"))]
+    );
+}
+
+/// Malformed input should be reported as invalid input rather than a mismatch.
+#[test]
+fn test_roundtrip_invalid_input() {
+    let result = obj::roundtrip_check("f 1 2 3\n");
+
+    assert!(matches!(result, Err(RoundTripReport::InvalidInput(_))));
+}
+
+/// The diff should report elements whose groups differ.
+#[test]
+fn test_diff_detects_group_changes() {
+    let expected = obj::parse("v 0 0 0\ng a\np 1\n").unwrap();
+    let result = obj::parse("v 0 0 0\ng b\np 1\n").unwrap();
+    let differences = obj::diff(&expected, &result, 0.0);
+
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].object, Some(0));
+}
+
+/// The diff should tolerate floating point differences up to the tolerance.
+#[test]
+fn test_diff_tolerance() {
+    let expected = obj::parse("v 0 0 0\nvn 0 0 1\np 1\n").unwrap();
+    let result = obj::parse("v 0 0 0.001\nvn 0 0 1\np 1\n").unwrap();
+
+    assert!(obj::diff(&expected, &result, 0.01).is_empty());
+    assert!(!obj::diff(&expected, &result, 0.0001).is_empty());
+}